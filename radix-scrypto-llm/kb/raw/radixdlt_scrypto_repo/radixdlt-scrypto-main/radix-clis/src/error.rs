@@ -1,4 +1,6 @@
+use crate::utils::render_error_message;
+
 pub fn exit_with_error(msg: String, exit_code: i32) {
-    eprintln!("{}", msg);
+    eprintln!("{}", render_error_message(msg));
     std::process::exit(exit_code)
 }
@@ -2,14 +2,22 @@
 pub mod replay;
 /// Radix Engine Simulator CLI.
 pub mod resim;
+/// Radix transaction manifest language server.
+pub mod rtm_lsp;
 /// Radix transaction manifest compiler CLI.
 pub mod rtmc;
 /// Radix transaction manifest decompiler CLI.
 pub mod rtmd;
+/// Radix transaction manifest formatter CLI.
+pub mod rtmfmt;
+/// Radix subintent and V2 transaction composer CLI.
+pub mod rtmtx;
 /// Scrypto CLI.
 pub mod scrypto;
 /// Stubs Generator CLI.
 pub mod scrypto_bindgen;
+/// Reference signing daemon for the remote signer.
+pub mod signing_daemon;
 /// Utility functions.
 pub mod utils;
 
@@ -1,11 +1,10 @@
-use super::ledger_transaction_execution::execute_ledger_transaction;
+use super::ledger_transaction_execution::{create_vm_modules, execute_ledger_transaction};
 use super::txn_reader::TxnReader;
 use super::Error;
 use clap::Parser;
 use flate2::read::GzDecoder;
 use flume;
 use radix_common::prelude::*;
-use radix_engine::vm::VmModules;
 use radix_substate_store_impls::rocks_db_with_merkle_tree::RocksDBWithMerkleTreeSubstateStore;
 use radix_substate_store_interface::interface::*;
 use std::fs::File;
@@ -32,6 +31,11 @@ pub struct TxnExecute {
     /// Trace transaction execution
     #[clap(long)]
     pub trace: bool,
+
+    /// Cache the instrumented code of the published packages in the given folder, and reuse it on
+    /// the next run
+    #[clap(long)]
+    pub prepared_code_cache: Option<PathBuf>,
 }
 
 impl TxnExecute {
@@ -71,8 +75,9 @@ impl TxnExecute {
         // txn executor
         let mut database = RocksDBWithMerkleTreeSubstateStore::standard(self.database_dir.clone());
         let trace = self.trace;
+        let prepared_code_cache = self.prepared_code_cache.clone();
         let txn_write_thread_handle = thread::spawn(move || {
-            let vm_modules = VmModules::default();
+            let vm_modules = create_vm_modules(prepared_code_cache);
             let iter = rx.iter();
             for tx_payload in iter {
                 let (_hash, receipt) = execute_ledger_transaction(
@@ -1,14 +1,15 @@
-use super::ledger_transaction_execution::execute_ledger_transaction;
+use super::ledger_transaction_execution::*;
 use super::txn_reader::TxnReader;
 use super::Error;
 use clap::Parser;
 use flate2::read::GzDecoder;
 use flume;
 use radix_common::prelude::*;
-use radix_engine::vm::VmModules;
+use radix_engine::transaction::execute_speculatively_in_parallel;
 use radix_substate_store_impls::memory_db::InMemorySubstateDatabase;
 use radix_substate_store_impls::state_tree_support::StateTreeUpdatingDatabase;
 use radix_substate_store_interface::interface::*;
+use radix_transactions::model::RawLedgerTransaction;
 use std::fs::File;
 use std::path::PathBuf;
 use std::thread;
@@ -35,6 +36,16 @@ pub struct TxnExecuteInMemory {
     /// Trace transaction execution
     #[clap(long)]
     pub trace: bool,
+
+    /// Cache the instrumented code of the published packages in the given folder, and reuse it on
+    /// the next run
+    #[clap(long)]
+    pub prepared_code_cache: Option<PathBuf>,
+
+    /// Execute up to the given number of transactions at once, speculatively in parallel - the
+    /// ones which conflict with an earlier transaction of the batch are executed again, in order
+    #[clap(long)]
+    pub parallel_batch_size: Option<usize>,
 }
 
 impl TxnExecuteInMemory {
@@ -83,38 +94,82 @@ impl TxnExecuteInMemory {
         let substate_database = InMemorySubstateDatabase::standard();
         let mut database = StateTreeUpdatingDatabase::new(substate_database);
         let trace = self.trace;
+        let prepared_code_cache = self.prepared_code_cache.clone();
+        let batch_size = self.parallel_batch_size.unwrap_or(1).max(1);
         let txn_write_thread_handle = thread::spawn(move || {
-            let vm_modules = VmModules::default();
-            let iter = rx.iter();
-            for tx_payload in iter {
-                let (_hash, receipt) = execute_ledger_transaction(
-                    &database,
-                    &vm_modules,
-                    &network,
-                    &tx_payload,
-                    trace,
-                );
-                let state_updates = receipt.into_state_updates();
-                let database_updates = state_updates.create_database_updates();
-                database.commit(&database_updates);
-
-                let new_state_root_hash = database.get_current_root_hash();
-                let new_version = database.get_current_version();
-
-                if let Some(expected) = breakpoints.get(&new_version) {
-                    if new_state_root_hash != *expected {
-                        panic!(
-                            "Unexpected state hash at version {}: expected = {}, actual = {}",
-                            new_version, expected, new_state_root_hash
-                        )
+            let vm_modules = create_vm_modules(prepared_code_cache);
+            let mut iter = rx.iter().peekable();
+            let mut re_executed_count = 0;
+            while iter.peek().is_some() {
+                let batch: Vec<RawLedgerTransaction> = iter.by_ref().take(batch_size).collect();
+                let receipts: Vec<LedgerTransactionReceipt> = if batch.len() > 1 {
+                    // The state tree isn't shared across threads, so the transactions read the
+                    // underlying database
+                    let results = execute_speculatively_in_parallel(
+                        database.underlying(),
+                        &batch,
+                        |database, tx_payload| {
+                            try_execute_ledger_transaction(
+                                database,
+                                &vm_modules,
+                                &network,
+                                tx_payload,
+                                trace,
+                                true,
+                            )
+                            .map(|(_hash, receipt)| receipt)
+                        },
+                    );
+                    re_executed_count += results.re_executed_count;
+                    // A transaction invalid on the outdated state read a key written earlier in
+                    // the batch, so only the final results are expected to be valid
+                    results
+                        .results
+                        .into_iter()
+                        .map(|result| result.expect("Ledger transaction should be valid"))
+                        .collect()
+                } else {
+                    batch
+                        .iter()
+                        .map(|tx_payload| {
+                            execute_ledger_transaction(
+                                &database,
+                                &vm_modules,
+                                &network,
+                                tx_payload,
+                                trace,
+                            )
+                            .1
+                        })
+                        .collect()
+                };
+
+                for receipt in receipts {
+                    let state_updates = receipt.into_state_updates();
+                    let database_updates = state_updates.create_database_updates();
+                    database.commit(&database_updates);
+
+                    let new_state_root_hash = database.get_current_root_hash();
+                    let new_version = database.get_current_version();
+
+                    if let Some(expected) = breakpoints.get(&new_version) {
+                        if new_state_root_hash != *expected {
+                            panic!(
+                                "Unexpected state hash at version {}: expected = {}, actual = {}",
+                                new_version, expected, new_state_root_hash
+                            )
+                        }
                     }
-                }
 
-                if new_version < 1000 || new_version % 1000 == 0 {
-                    print_progress(start.elapsed(), new_version, new_state_root_hash);
+                    if new_version < 1000 || new_version % 1000 == 0 {
+                        print_progress(start.elapsed(), new_version, new_state_root_hash);
+                    }
                 }
             }
 
+            if batch_size > 1 {
+                println!("Re-executed transactions: {}", re_executed_count);
+            }
             let duration = start.elapsed();
             println!("Time elapsed: {:?}", duration);
             println!("State version: {}", database.get_current_version());
@@ -5,7 +5,6 @@ use clap::Parser;
 use flate2::read::GzDecoder;
 use flume;
 use radix_common::prelude::*;
-use radix_engine::vm::*;
 use radix_substate_store_impls::rocks_db_with_merkle_tree::RocksDBWithMerkleTreeSubstateStore;
 use radix_substate_store_interface::interface::*;
 use radix_transactions::prelude::*;
@@ -37,6 +36,11 @@ pub struct TxnMeasure {
     /// Trace transaction execution
     #[clap(long)]
     pub trace: bool,
+
+    /// Cache the instrumented code of the published packages in the given folder, and reuse it on
+    /// the next run
+    #[clap(long)]
+    pub prepared_code_cache: Option<PathBuf>,
 }
 
 impl TxnMeasure {
@@ -91,8 +95,9 @@ impl TxnMeasure {
         }
 
         let trace = self.trace;
+        let prepared_code_cache = self.prepared_code_cache.clone();
         let txn_write_thread_handle = thread::spawn(move || {
-            let vm_modules = VmModules::default();
+            let vm_modules = create_vm_modules(prepared_code_cache);
             let iter = rx.iter();
             for tx_payload in iter {
                 let tx_start_time = std::time::Instant::now();
@@ -1,10 +1,9 @@
-use super::ledger_transaction_execution::execute_ledger_transaction;
+use super::ledger_transaction_execution::{create_vm_modules, execute_ledger_transaction};
 use super::Error;
 use clap::Parser;
 use flume;
 use flume::Sender;
 use radix_common::prelude::*;
-use radix_engine::vm::VmModules;
 use radix_substate_store_impls::rocks_db_with_merkle_tree::RocksDBWithMerkleTreeSubstateStore;
 use radix_substate_store_interface::interface::*;
 use radix_transactions::prelude::*;
@@ -31,6 +30,11 @@ pub struct TxnSync {
     /// Trace transaction execution
     #[clap(long)]
     pub trace: bool,
+
+    /// Cache the instrumented code of the published packages in the given folder, and reuse it on
+    /// the next run
+    #[clap(long)]
+    pub prepared_code_cache: Option<PathBuf>,
 }
 
 impl TxnSync {
@@ -61,8 +65,9 @@ impl TxnSync {
         // txn executor
         let mut database = RocksDBWithMerkleTreeSubstateStore::standard(self.database_dir.clone());
         let trace = self.trace;
+        let prepared_code_cache = self.prepared_code_cache.clone();
         let txn_write_thread_handle = thread::spawn(move || {
-            let vm_modules = VmModules::default();
+            let vm_modules = create_vm_modules(prepared_code_cache);
             let iter = rx.iter();
             for (tx_payload, expected_state_root_hash) in iter {
                 let (_hash, receipt) = execute_ledger_transaction(
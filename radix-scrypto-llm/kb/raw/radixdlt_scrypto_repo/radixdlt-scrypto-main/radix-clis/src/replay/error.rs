@@ -1,3 +1,4 @@
+use crate::utils::Diagnostic;
 use radix_common::prelude::ParseNetworkError;
 use std::fmt;
 
@@ -11,15 +12,38 @@ pub enum Error {
     InvalidBreakpoints(String),
 }
 
+impl Error {
+    pub fn diagnostic(&self) -> Diagnostic {
+        match self {
+            Error::ParseNetworkError(err) => Diagnostic::from_debug("ParseNetworkError", err)
+                .with_help("Supported networks include simulator, stokenet and mainnet."),
+            Error::IOError(err) => Diagnostic::new("IOError", err.to_string()),
+            Error::DatabaseError(err) => Diagnostic::new("DatabaseError", err.to_string()),
+            Error::InvalidTransactionArchive => Diagnostic::new(
+                "InvalidTransactionArchive",
+                "The transaction archive could not be read.",
+            ),
+            Error::InvalidTransactionSource => Diagnostic::new(
+                "InvalidTransactionSource",
+                "The transaction source is neither an archive file nor a database directory.",
+            ),
+            Error::InvalidBreakpoints(breakpoints) => Diagnostic::new(
+                "InvalidBreakpoints",
+                format!("The breakpoints {} could not be parsed.", breakpoints),
+            )
+            .with_help("Breakpoints are given as comma separated `<version>:<state hash>` pairs."),
+        }
+    }
+}
+
 impl fmt::Display for Error {
-    // TODO Implement pretty error printing
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:?}", self)
+        write!(f, "{}", self.diagnostic())
     }
 }
 
 impl From<Error> for String {
     fn from(err: Error) -> String {
-        err.to_string()
+        err.diagnostic().render()
     }
 }
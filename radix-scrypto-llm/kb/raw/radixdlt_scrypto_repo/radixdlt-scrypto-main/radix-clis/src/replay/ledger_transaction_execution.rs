@@ -1,13 +1,17 @@
 use radix_common::prelude::*;
 use radix_engine::system::bootstrap::*;
+use radix_engine::track::ReadSet;
 use radix_engine::transaction::{
-    execute_transaction, ExecutionConfig, TransactionFeeSummary, TransactionReceipt,
+    execute_transaction, ExecutionConfig, SpeculativeExecutionResult, TransactionFeeSummary,
+    TransactionReceipt,
 };
+use radix_engine::vm::wasm::PreparedCodeCache;
 use radix_engine::vm::*;
 use radix_engine_interface::prelude::system_execution;
 use radix_substate_store_interface::interface::SubstateDatabase;
 use radix_transactions::prelude::*;
 use radix_transactions::validation::*;
+use std::path::PathBuf;
 
 pub enum LedgerTransactionReceipt {
     Flash(FlashReceipt),
@@ -35,6 +39,34 @@ impl LedgerTransactionReceipt {
     }
 }
 
+/// The flashes don't record what they read, so they are executed again after any other transaction
+/// of a speculative batch.
+impl SpeculativeExecutionResult for LedgerTransactionReceipt {
+    fn read_set(&self) -> Option<&ReadSet> {
+        match self {
+            LedgerTransactionReceipt::Flash(_) => None,
+            LedgerTransactionReceipt::Standard(receipt) => receipt.read_set(),
+            LedgerTransactionReceipt::ProtocolUpdateFlash(_) => None,
+        }
+    }
+
+    fn state_updates(&self) -> Option<&StateUpdates> {
+        match self {
+            LedgerTransactionReceipt::Flash(receipt) => Some(&receipt.state_updates),
+            LedgerTransactionReceipt::Standard(receipt) => receipt.state_updates(),
+            LedgerTransactionReceipt::ProtocolUpdateFlash(state_updates) => Some(state_updates),
+        }
+    }
+
+    fn state_updates_mut(&mut self) -> Option<&mut StateUpdates> {
+        match self {
+            LedgerTransactionReceipt::Flash(receipt) => Some(&mut receipt.state_updates),
+            LedgerTransactionReceipt::Standard(receipt) => receipt.state_updates_mut(),
+            LedgerTransactionReceipt::ProtocolUpdateFlash(state_updates) => Some(state_updates),
+        }
+    }
+}
+
 pub enum LedgerTransactionKindedHash {
     Genesis(SystemTransactionHash),
     User(NotarizedTransactionHash),
@@ -42,6 +74,16 @@ pub enum LedgerTransactionKindedHash {
     ProtocolUpdate(Hash),
 }
 
+/// The VMs of the replay, which keep the code prepared for the published packages in the given
+/// folder, if any - so that the next replay doesn't have to prepare it again.
+pub fn create_vm_modules(prepared_code_cache: Option<PathBuf>) -> DefaultVmModules {
+    let mut vm_modules = VmModules::default();
+    if let Some(folder) = prepared_code_cache {
+        vm_modules.scrypto_vm.prepared_code_cache = Some(PreparedCodeCache::new(folder));
+    }
+    vm_modules
+}
+
 pub fn execute_ledger_transaction<S: SubstateDatabase>(
     database: &S,
     vm_modules: &impl VmInitialize,
@@ -49,10 +91,24 @@ pub fn execute_ledger_transaction<S: SubstateDatabase>(
     raw: &RawLedgerTransaction,
     trace: bool,
 ) -> (LedgerTransactionKindedHash, LedgerTransactionReceipt) {
+    try_execute_ledger_transaction(database, vm_modules, network, raw, trace, false)
+        .expect("Ledger transaction should be valid")
+}
+
+/// Same as [`execute_ledger_transaction`], but returns the validation error of a transaction which
+/// is invalid on the given database - e.g. when executing it speculatively, on an outdated state -
+/// and can record the read set of the receipts.
+pub fn try_execute_ledger_transaction<S: SubstateDatabase>(
+    database: &S,
+    vm_modules: &impl VmInitialize,
+    network: &NetworkDefinition,
+    raw: &RawLedgerTransaction,
+    trace: bool,
+    read_set: bool,
+) -> Result<(LedgerTransactionKindedHash, LedgerTransactionReceipt), LedgerTransactionValidationError>
+{
     let validator = TransactionValidator::new(database, network);
-    let validated = raw
-        .validate(&validator, AcceptedLedgerTransactionKind::Any)
-        .expect("Ledger transaction should be valid");
+    let validated = raw.validate(&validator, AcceptedLedgerTransactionKind::Any)?;
 
     let kinded_hash = match &validated.inner {
         ValidatedLedgerTransactionInner::Genesis(tx) => {
@@ -82,7 +138,8 @@ pub fn execute_ledger_transaction<S: SubstateDatabase>(
                         vm_modules,
                         &ExecutionConfig::for_genesis_transaction(network.clone())
                             .with_kernel_trace(trace)
-                            .with_cost_breakdown(trace),
+                            .with_cost_breakdown(trace)
+                            .with_read_set(read_set),
                         tx.create_executable(btreeset!(system_execution(
                             SystemExecution::Protocol
                         ))),
@@ -97,7 +154,8 @@ pub fn execute_ledger_transaction<S: SubstateDatabase>(
                 vm_modules,
                 &ExecutionConfig::for_notarized_transaction(network.clone())
                     .with_kernel_trace(trace)
-                    .with_cost_breakdown(trace),
+                    .with_cost_breakdown(trace)
+                    .with_read_set(read_set),
                 tx.create_executable(),
             );
             LedgerTransactionReceipt::Standard(receipt)
@@ -108,7 +166,8 @@ pub fn execute_ledger_transaction<S: SubstateDatabase>(
                 vm_modules,
                 &ExecutionConfig::for_system_transaction(network.clone())
                     .with_kernel_trace(trace)
-                    .with_cost_breakdown(trace),
+                    .with_cost_breakdown(trace)
+                    .with_read_set(read_set),
                 tx.create_executable(),
             );
             LedgerTransactionReceipt::Standard(receipt)
@@ -118,5 +177,5 @@ pub fn execute_ledger_transaction<S: SubstateDatabase>(
         }
     };
 
-    (kinded_hash, receipt)
+    Ok((kinded_hash, receipt))
 }
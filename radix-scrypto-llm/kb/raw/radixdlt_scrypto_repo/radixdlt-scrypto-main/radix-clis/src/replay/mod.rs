@@ -17,6 +17,7 @@ pub use cmd_prepare::*;
 pub use cmd_sync::*;
 pub use error::*;
 
+use crate::utils::{set_error_format, ErrorFormat};
 use clap::{Parser, Subcommand};
 
 /// Transaction replay toolkit
@@ -25,6 +26,10 @@ use clap::{Parser, Subcommand};
 pub struct ReplayCli {
     #[clap(subcommand)]
     command: Command,
+
+    /// The format in which errors are reported [text | json]
+    #[clap(long, global = true, default_value = "text")]
+    error_format: ErrorFormat,
 }
 
 #[derive(Subcommand, Debug)]
@@ -39,6 +44,7 @@ pub enum Command {
 
 pub fn run() -> Result<(), String> {
     let cli = ReplayCli::parse();
+    set_error_format(cli.error_format);
 
     match cli.command {
         Command::Prepare(cmd) => cmd.run(),
@@ -1,8 +1,13 @@
 use crate::prelude::*;
+use crate::resim::resolve_address_alias;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AddressError {
     InvalidAddress(String),
+    /// An `@<name>` which isn't in the alias table.
+    UnknownAlias(String),
+    /// The alias table couldn't be read, for the given reason.
+    AliasesUnavailable(String),
 }
 
 impl std::error::Error for AddressError {}
@@ -32,6 +37,7 @@ impl FromStr for SimulatorPackageAddress {
     type Err = AddressError;
 
     fn from_str(address: &str) -> Result<Self, Self::Err> {
+        let address = &resolve_address_alias(address)?;
         PackageAddress::try_from_hex(address)
             .or(PackageAddress::try_from_bech32(
                 &AddressBech32Decoder::for_simulator(),
@@ -77,6 +83,7 @@ impl FromStr for SimulatorResourceAddress {
     type Err = AddressError;
 
     fn from_str(address: &str) -> Result<Self, Self::Err> {
+        let address = &resolve_address_alias(address)?;
         ResourceAddress::try_from_hex(address)
             .or(ResourceAddress::try_from_bech32(
                 &AddressBech32Decoder::for_simulator(),
@@ -122,6 +129,7 @@ impl FromStr for SimulatorComponentAddress {
     type Err = AddressError;
 
     fn from_str(address: &str) -> Result<Self, Self::Err> {
+        let address = &resolve_address_alias(address)?;
         ComponentAddress::try_from_hex(address)
             .or(ComponentAddress::try_from_bech32(
                 &AddressBech32Decoder::for_simulator(),
@@ -167,6 +175,8 @@ impl FromStr for SimulatorNonFungibleGlobalId {
     type Err = ParseNonFungibleGlobalIdError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = &resolve_address_alias(s)
+            .map_err(|_| ParseNonFungibleGlobalIdError::InvalidResourceAddress)?;
         let global_id = NonFungibleGlobalId::try_from_canonical_string(
             &AddressBech32Decoder::for_simulator(),
             s,
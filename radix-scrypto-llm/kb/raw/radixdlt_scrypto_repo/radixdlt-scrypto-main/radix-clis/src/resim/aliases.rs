@@ -0,0 +1,168 @@
+use crate::resim::*;
+use radix_common::prelude::*;
+use radix_rust::ContextualDisplay;
+use radix_transactions::manifest::lexer::tokenize;
+use radix_transactions::manifest::token::Token;
+use std::fs;
+use std::path::PathBuf;
+
+/// Names for the addresses of the simulator entities, which can be given as `@<name>` wherever
+/// resim takes an address.
+///
+/// The aliases are kept in the data directory, as the addresses are only meaningful for its ledger.
+#[derive(Debug, Clone, Default, ScryptoSbor)]
+pub struct AddressAliases {
+    pub aliases: BTreeMap<String, GlobalAddress>,
+}
+
+impl AddressAliases {
+    pub fn get(&self, name: &str) -> Option<GlobalAddress> {
+        self.aliases.get(name.trim_start_matches('@')).cloned()
+    }
+
+    /// Registers a name, with or without the leading `@`, replacing any address it had.
+    pub fn insert(&mut self, name: &str, address: GlobalAddress) -> Result<(), Error> {
+        let name = name.trim_start_matches('@');
+        let is_valid = !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !is_valid {
+            return Err(Error::InvalidAddressAlias(name.to_owned()));
+        }
+        self.aliases.insert(name.to_owned(), address);
+        Ok(())
+    }
+
+    /// Replaces an `@<name>` at the start of an argument - such as `@token:10` or `@badge:#1#` -
+    /// with the Bech32 encoded address.
+    pub fn resolve(&self, argument: &str) -> Result<String, AddressError> {
+        let Some(aliased) = argument.strip_prefix('@') else {
+            return Ok(argument.to_owned());
+        };
+        let (name, rest) = aliased.split_at(aliased.find(':').unwrap_or(aliased.len()));
+        let address = self
+            .get(name)
+            .ok_or_else(|| AddressError::UnknownAlias(name.to_owned()))?;
+        Ok(format!(
+            "{}{}",
+            address.display(&AddressBech32Encoder::for_simulator()),
+            rest
+        ))
+    }
+
+    /// Replaces the `@<name>` given to the `Address(...)` values of a manifest, such as in
+    /// `Address("@gumball_pkg")`, with the Bech32 encoded address - any other string is left as it
+    /// is, as is a manifest which can't be tokenized, for the compiler to report.
+    pub fn resolve_in_manifest(&self, manifest: &str) -> Result<String, AddressError> {
+        let Ok(tokens) = tokenize(manifest) else {
+            return Ok(manifest.to_owned());
+        };
+        let mut chars: Vec<char> = manifest.chars().collect();
+        // Replaced from the end, so that the spans of the earlier tokens remain valid
+        for window in tokens.windows(4).rev() {
+            let [ident, open, literal, close] = window else {
+                unreachable!()
+            };
+            let (
+                Token::Ident(ident),
+                Token::OpenParenthesis,
+                Token::StringLiteral(name),
+                Token::CloseParenthesis,
+            ) = (&ident.token, &open.token, &literal.token, &close.token)
+            else {
+                continue;
+            };
+            if ident != "Address" || !name.starts_with('@') {
+                continue;
+            }
+            let address = self
+                .get(name)
+                .ok_or_else(|| AddressError::UnknownAlias(name.to_owned()))?;
+            let resolved = format!(
+                "\"{}\"",
+                address.display(&AddressBech32Encoder::for_simulator())
+            );
+            chars.splice(
+                literal.span.start.full_index..literal.span.end.full_index,
+                resolved.chars(),
+            );
+        }
+        Ok(chars.into_iter().collect())
+    }
+
+    /// Displays an address with its names, e.g. `account_sim1... (@alice)`.
+    pub fn display<A: Into<GlobalAddress>>(
+        &self,
+        address: A,
+        encoder: &AddressBech32Encoder,
+    ) -> String {
+        let address = address.into();
+        let names = self
+            .aliases
+            .iter()
+            .filter(|(_, aliased)| **aliased == address)
+            .map(|(name, _)| format!("@{}", name))
+            .collect::<Vec<_>>();
+        if names.is_empty() {
+            address.display(encoder).to_string()
+        } else {
+            format!("{} ({})", address.display(encoder), names.join(", "))
+        }
+    }
+}
+
+pub fn get_address_aliases_path() -> Result<PathBuf, Error> {
+    let mut path = get_data_dir()?;
+    path.push("aliases");
+    Ok(path.with_extension("sbor"))
+}
+
+pub fn get_address_aliases() -> Result<AddressAliases, Error> {
+    let path = get_address_aliases_path()?;
+    if path.exists() {
+        scrypto_decode(&fs::read(path).map_err(Error::IOError)?.as_ref())
+            .map_err(Error::SborDecodeError)
+    } else {
+        Ok(AddressAliases::default())
+    }
+}
+
+pub fn set_address_aliases(aliases: &AddressAliases) -> Result<(), Error> {
+    fs::write(
+        get_address_aliases_path()?,
+        scrypto_encode(aliases).unwrap(),
+    )
+    .map_err(Error::IOError)
+}
+
+/// Registers a name for the address of a new entity, if one was requested.
+pub fn register_address_alias<A: Into<GlobalAddress>>(
+    name: &Option<String>,
+    address: A,
+) -> Result<(), Error> {
+    if let Some(name) = name {
+        let mut aliases = get_address_aliases()?;
+        aliases.insert(name, address.into())?;
+        set_address_aliases(&aliases)?;
+    }
+    Ok(())
+}
+
+/// Resolves `@<name>` arguments, such as call arguments and resource specifiers.
+pub fn resolve_address_aliases(arguments: &[String]) -> Result<Vec<String>, Error> {
+    arguments
+        .iter()
+        .map(|argument| resolve_address_alias(argument).map_err(Error::AddressError))
+        .collect()
+}
+
+/// Resolves an `@<name>` argument, only reading the alias table if the argument is one.
+pub fn resolve_address_alias(argument: &str) -> Result<String, AddressError> {
+    if !argument.starts_with('@') {
+        return Ok(argument.to_owned());
+    }
+    get_address_aliases()
+        .map_err(|err| AddressError::AliasesUnavailable(format!("{:?}", err)))?
+        .resolve(argument)
+}
@@ -0,0 +1,54 @@
+use clap::{Parser, Subcommand};
+use colored::*;
+
+use crate::resim::*;
+
+/// Set or list the names of addresses, which can be given as `@<name>` wherever resim takes one
+#[derive(Parser, Debug)]
+pub struct Alias {
+    #[clap(subcommand)]
+    pub command: AliasCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum AliasCommand {
+    /// Register a name for an address, replacing any address it had
+    Set {
+        /// The name, which may contain letters, digits, `-` and `_`
+        name: String,
+
+        /// The address of a package, component or resource
+        address: String,
+    },
+    /// List the names with their addresses
+    List,
+}
+
+impl Alias {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), String> {
+        match &self.command {
+            AliasCommand::Set { name, address } => {
+                let address = GlobalAddress::try_from_bech32(
+                    &AddressBech32Decoder::for_simulator(),
+                    &resolve_address_alias(address).map_err(Error::AddressError)?,
+                )
+                .ok_or_else(|| Error::InvalidId(address.clone()))?;
+                register_address_alias(&Some(name.clone()), address)?;
+                writeln!(out, "Alias {} set.", name).map_err(Error::IOError)?;
+            }
+            AliasCommand::List => {
+                let encoder = AddressBech32Encoder::for_simulator();
+                for (name, address) in get_address_aliases()?.aliases {
+                    writeln!(
+                        out,
+                        "@{}: {}",
+                        name,
+                        address.display(&encoder).to_string().green()
+                    )
+                    .map_err(Error::IOError)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
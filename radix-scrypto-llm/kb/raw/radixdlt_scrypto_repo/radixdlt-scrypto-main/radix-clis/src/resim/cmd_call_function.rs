@@ -32,13 +32,16 @@ pub struct CallFunction {
     #[clap(short, long)]
     pub manifest: Option<PathBuf>,
 
-    /// The private keys used for signing, separated by comma
+    /// The keystore aliases or private keys used for signing, separated by comma
     #[clap(short, long)]
     pub signing_keys: Option<String>,
 
     /// Turn on tracing
     #[clap(short, long)]
     pub trace: bool,
+
+    #[clap(flatten)]
+    pub cost_report: CostReport,
 }
 
 impl CallFunction {
@@ -46,7 +49,7 @@ impl CallFunction {
         let address_bech32_decoder = AddressBech32Decoder::for_simulator();
 
         let default_account = get_default_account()?;
-        let proofs = self.proofs.clone().unwrap_or_default();
+        let proofs = resolve_address_aliases(&self.proofs.clone().unwrap_or_default())?;
 
         let mut builder = ManifestBuilder::new();
         builder = builder.lock_fee_from_faucet();
@@ -66,18 +69,20 @@ impl CallFunction {
                 self.package_address.0,
                 self.blueprint_name.clone(),
                 self.function_name.clone(),
-                self.arguments.clone(),
+                resolve_address_aliases(&self.arguments)?,
                 Some(default_account),
             )?
             .try_deposit_entire_worktop_or_refund(default_account, None)
             .build();
-        handle_manifest(
+        handle_manifest_with_cost_report(
             manifest.into(),
             &self.signing_keys,
             &self.network,
             &self.manifest,
             self.trace,
             true,
+            &self.cost_report,
+            &self.function_name,
             out,
         )
         .map(|_| ())
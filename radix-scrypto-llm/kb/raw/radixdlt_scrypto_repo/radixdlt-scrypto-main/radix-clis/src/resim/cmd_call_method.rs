@@ -30,13 +30,16 @@ pub struct CallMethod {
     #[clap(short, long)]
     pub manifest: Option<PathBuf>,
 
-    /// The private keys used for signing, separated by comma
+    /// The keystore aliases or private keys used for signing, separated by comma
     #[clap(short, long)]
     pub signing_keys: Option<String>,
 
     /// Turn on tracing
     #[clap(short, long)]
     pub trace: bool,
+
+    #[clap(flatten)]
+    pub cost_report: CostReport,
 }
 
 impl CallMethod {
@@ -44,7 +47,7 @@ impl CallMethod {
         let address_bech32_decoder = AddressBech32Decoder::for_simulator();
 
         let default_account = get_default_account()?;
-        let proofs = self.proofs.clone().unwrap_or_default();
+        let proofs = resolve_address_aliases(&self.proofs.clone().unwrap_or_default())?;
 
         let mut builder = ManifestBuilder::new().lock_fee_from_faucet();
         for resource_specifier in proofs {
@@ -63,18 +66,20 @@ impl CallMethod {
                 &address_bech32_decoder,
                 self.component_address.0,
                 self.method_name.clone(),
-                self.arguments.clone(),
+                resolve_address_aliases(&self.arguments)?,
                 Some(default_account),
             )?
             .try_deposit_entire_worktop_or_refund(default_account, None)
             .build();
-        handle_manifest(
+        handle_manifest_with_cost_report(
             manifest.into(),
             &self.signing_keys,
             &self.network,
             &self.manifest,
             self.trace,
             true,
+            &self.cost_report,
+            &self.method_name,
             out,
         )
         .map(|_| ())
@@ -0,0 +1,70 @@
+use crate::resim::*;
+use clap::Parser;
+use colored::*;
+use radix_common::prelude::*;
+use radix_engine_interface::prelude::*;
+
+/// List the events in the resim transaction log
+#[derive(Parser, Debug)]
+pub struct Events {
+    /// Only list events emitted by this address (for function events, the package address)
+    #[clap(long)]
+    pub emitter: Option<String>,
+
+    /// Only list events with this name
+    #[clap(long)]
+    pub name: Option<String>,
+}
+
+impl Events {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), String> {
+        let emitter = match &self.emitter {
+            Some(address) => Some(parse_emitter_address(address)?),
+            None => None,
+        };
+        let SimulatorEnvironment { db, .. } = SimulatorEnvironment::new()?;
+        let encoder = AddressBech32Encoder::for_simulator();
+
+        let log_index = get_transaction_log_index()?;
+        let transactions = match &emitter {
+            Some(emitter) => log_index
+                .by_emitter
+                .get(emitter)
+                .cloned()
+                .unwrap_or_default(),
+            None => (0..log_index.len).collect(),
+        };
+        for entry in iter_transaction_log_entries(transactions) {
+            let (index, entry) = entry?;
+            let commit_result = &entry.commit_result;
+            for (event_type_identifier, event_data) in &commit_result.application_events {
+                let EventTypeIdentifier(event_emitter, event_name) = event_type_identifier;
+                if let Some(emitter) = &emitter {
+                    if &get_emitter_node_id(event_emitter) != emitter {
+                        continue;
+                    }
+                }
+                if let Some(name) = &self.name {
+                    if event_name != name {
+                        continue;
+                    }
+                }
+
+                writeln!(
+                    out,
+                    "{} {}\n├─ {} {}\n├─ {} {}\n└─ {} {}",
+                    "Transaction:".green().bold(),
+                    index,
+                    "Emitter:".green().bold(),
+                    event_emitter.display(&encoder),
+                    "Name:".green().bold(),
+                    event_name,
+                    "Data:".green().bold(),
+                    format_event_data(&db, commit_result, event_type_identifier, event_data),
+                )
+                .map_err(Error::IOError)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -6,7 +6,11 @@ use rand::Rng;
 
 /// Generate a key pair
 #[derive(Parser, Debug)]
-pub struct GenerateKeyPair {}
+pub struct GenerateKeyPair {
+    /// Store the private key in the keystore under this alias, instead of printing it
+    #[clap(long)]
+    pub alias: Option<String>,
+}
 
 impl GenerateKeyPair {
     pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), String> {
@@ -14,12 +18,25 @@ impl GenerateKeyPair {
         let private_key = Secp256k1PrivateKey::from_bytes(&secret).unwrap();
         let public_key = private_key.public_key();
         writeln!(out, "Public key: {}", public_key.to_string().green()).map_err(Error::IOError)?;
-        writeln!(
-            out,
-            "Private key: {}",
-            hex::encode(private_key.to_bytes()).green()
-        )
-        .map_err(Error::IOError)?;
+        match &self.alias {
+            Some(alias) => {
+                let (mut keystore, cipher) = unlock_keystore()?;
+                if keystore.keys.contains_key(alias) {
+                    return Err(Error::KeyAliasAlreadyExists(alias.clone()).into());
+                }
+                keystore.insert(alias, &private_key, &cipher)?;
+                set_keystore(&keystore)?;
+                writeln!(out, "Key alias: {}", alias.green()).map_err(Error::IOError)?;
+            }
+            None => {
+                writeln!(
+                    out,
+                    "Private key: {}",
+                    hex::encode(private_key.to_bytes()).green()
+                )
+                .map_err(Error::IOError)?;
+            }
+        }
         Ok(())
     }
 }
@@ -0,0 +1,76 @@
+use crate::resim::*;
+use crate::utils::*;
+use clap::Parser;
+use colored::*;
+use radix_common::prelude::*;
+use radix_engine::transaction::*;
+use radix_engine_interface::prelude::*;
+
+/// Show the transactions from the resim transaction log which involved an account
+#[derive(Parser, Debug)]
+pub struct History {
+    /// The account component address
+    pub account: SimulatorComponentAddress,
+}
+
+impl History {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), String> {
+        let account = self.account.0.into_node_id();
+        let SimulatorEnvironment { db, .. } = SimulatorEnvironment::new()?;
+
+        let transactions = get_transaction_log_index()?
+            .by_account
+            .remove(&account)
+            .unwrap_or_default();
+        for entry in iter_transaction_log_entries(transactions) {
+            let (index, entry) = entry?;
+            let commit_result = &entry.commit_result;
+            let created = commit_result
+                .state_update_summary
+                .new_components
+                .contains(&self.account.0);
+            let account_events = commit_result
+                .application_events
+                .iter()
+                .filter(|(EventTypeIdentifier(emitter, _), _)| {
+                    get_emitter_node_id(emitter) == account
+                })
+                .collect::<Vec<_>>();
+            if !created && account_events.is_empty() {
+                continue;
+            }
+
+            let outcome = match &commit_result.outcome {
+                TransactionOutcome::Success(_) => "COMMITTED SUCCESS".green(),
+                TransactionOutcome::Failure(_) => "COMMITTED FAILURE".red(),
+            };
+            writeln!(
+                out,
+                "{} {} ({})",
+                "Transaction:".green().bold(),
+                index,
+                outcome
+            )
+            .map_err(Error::IOError)?;
+            if created {
+                writeln!(
+                    out,
+                    "{} Account created",
+                    list_item_prefix(account_events.is_empty())
+                )
+                .map_err(Error::IOError)?;
+            }
+            for (i, (event_type_identifier, event_data)) in account_events.iter().enumerate() {
+                writeln!(
+                    out,
+                    "{} {}: {}",
+                    list_item_prefix(i == account_events.len() - 1),
+                    event_type_identifier.1,
+                    format_event_data(&db, commit_result, event_type_identifier, event_data),
+                )
+                .map_err(Error::IOError)?;
+            }
+        }
+        Ok(())
+    }
+}
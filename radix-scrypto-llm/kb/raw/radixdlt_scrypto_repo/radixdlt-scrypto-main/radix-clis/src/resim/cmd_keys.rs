@@ -0,0 +1,68 @@
+use clap::{Parser, Subcommand};
+use colored::*;
+use radix_common::prelude::*;
+
+use crate::resim::*;
+
+/// List, export or import the keys of the encrypted keystore
+#[derive(Parser, Debug)]
+pub struct Keys {
+    #[clap(subcommand)]
+    pub command: KeysCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum KeysCommand {
+    /// List the aliases of the keys, with their public keys
+    List,
+    /// Print the private key with an alias
+    Export {
+        /// The key alias
+        alias: String,
+    },
+    /// Encrypt a private key into the keystore under an alias - the hex encoded Secp256k1 private
+    /// key is read from the standard input, or prompted for
+    Import {
+        /// The key alias, which may contain letters, digits, `-` and `_`
+        alias: String,
+    },
+}
+
+impl Keys {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), String> {
+        match &self.command {
+            KeysCommand::List => {
+                for (alias, entry) in get_keystore()?.keys {
+                    writeln!(out, "{}: {}", alias, entry.public_key.to_string().green())
+                        .map_err(Error::IOError)?;
+                }
+            }
+            KeysCommand::Export { alias } => {
+                let (keystore, cipher) = unlock_keystore()?;
+                let private_key = keystore.private_key(alias, &cipher)?;
+                writeln!(out, "{}", private_key.to_hex()).map_err(Error::IOError)?;
+            }
+            KeysCommand::Import { alias } => {
+                import_key(alias, &read_private_key()?, out)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn import_key<O: std::io::Write>(
+    alias: &str,
+    private_key: &Secp256k1PrivateKey,
+    out: &mut O,
+) -> Result<(), Error> {
+    let (mut keystore, cipher) = unlock_keystore()?;
+    keystore.insert(alias, private_key, &cipher)?;
+    set_keystore(&keystore)?;
+    writeln!(
+        out,
+        "Key {} imported, with public key {}.",
+        alias,
+        private_key.public_key().to_string().green()
+    )
+    .map_err(Error::IOError)
+}
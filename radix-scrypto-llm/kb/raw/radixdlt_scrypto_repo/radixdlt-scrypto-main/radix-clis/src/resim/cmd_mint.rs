@@ -25,7 +25,7 @@ pub struct Mint {
     #[clap(short, long)]
     pub manifest: Option<PathBuf>,
 
-    /// The private keys used for signing, separated by comma
+    /// The keystore aliases or private keys used for signing, separated by comma
     #[clap(short, long)]
     pub signing_keys: Option<String>,
 
@@ -39,7 +39,7 @@ impl Mint {
         let address_bech32_decoder = AddressBech32Decoder::for_simulator();
 
         let default_account = get_default_account()?;
-        let proofs = self.proofs.clone().unwrap_or_default();
+        let proofs = resolve_address_aliases(&self.proofs.clone().unwrap_or_default())?;
 
         let mut builder = ManifestBuilder::new().lock_fee_from_faucet();
         for resource_specifier in proofs {
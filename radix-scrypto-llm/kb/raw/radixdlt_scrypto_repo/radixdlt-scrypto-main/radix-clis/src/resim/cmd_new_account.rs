@@ -25,6 +25,11 @@ pub struct NewAccount {
     /// Turn on tracing
     #[clap(short, long)]
     pub trace: bool,
+
+    /// The name of the account - under which its private key is stored in the keystore, and its
+    /// address can be given as `@<name>` - `account<N>` by default
+    #[clap(long)]
+    pub alias: Option<String>,
 }
 
 #[derive(ScryptoSbor, ManifestSbor)]
@@ -35,6 +40,26 @@ impl NewAccount {
         let secret = rand::thread_rng().gen::<[u8; 32]>();
         let private_key = Secp256k1PrivateKey::from_bytes(&secret).unwrap();
         let public_key = private_key.public_key();
+
+        // The key is stored before the account is created, so that it can't be lost
+        let (mut keystore, cipher) = unlock_keystore()?;
+        let alias = match self
+            .alias
+            .as_deref()
+            .map(|alias| alias.trim_start_matches('@'))
+        {
+            Some(alias) if keystore.keys.contains_key(alias) => {
+                return Err(Error::KeyAliasAlreadyExists(alias.to_owned()).into());
+            }
+            Some(alias) => alias.to_owned(),
+            None => (1..)
+                .map(|index| format!("account{}", index))
+                .find(|alias| !keystore.keys.contains_key(alias))
+                .unwrap(),
+        };
+        keystore.insert(&alias, &private_key, &cipher)?;
+        set_keystore(&keystore)?;
+
         let auth_global_id = NonFungibleGlobalId::from_public_key(&public_key);
         let withdraw_auth = rule!(require(auth_global_id));
         let manifest = ManifestBuilder::new()
@@ -61,6 +86,7 @@ impl NewAccount {
                 .success_or_else(|err| TransactionFailed(err.clone()))?;
 
             let account = commit_result.new_component_addresses()[0];
+            register_address_alias(&Some(alias.clone()), account)?;
             let manifest = ManifestBuilder::new()
                 .lock_fee_from_faucet()
                 .get_free_xrd_from_faucet()
@@ -103,12 +129,7 @@ impl NewAccount {
             .map_err(Error::IOError)?;
             writeln!(out, "Public key: {}", public_key.to_string().green())
                 .map_err(Error::IOError)?;
-            writeln!(
-                out,
-                "Private key: {}",
-                hex::encode(private_key.to_bytes()).green()
-            )
-            .map_err(Error::IOError)?;
+            writeln!(out, "Key alias: {}", alias.green()).map_err(Error::IOError)?;
             writeln!(
                 out,
                 "Owner badge: {}",
@@ -120,11 +141,11 @@ impl NewAccount {
 
             let mut configs = get_configs()?;
             if configs.default_account.is_none()
-                || configs.default_private_key.is_none()
+                || configs.default_key.is_none()
                 || configs.default_owner_badge.is_none()
             {
                 configs.default_account = Some(account);
-                configs.default_private_key = Some(private_key.to_hex());
+                configs.default_key = Some(alias);
                 configs.default_owner_badge = Some(owner_badge);
                 set_configs(&configs)?;
 
@@ -135,15 +156,10 @@ impl NewAccount {
                 .map_err(Error::IOError)?;
             }
         } else {
-            writeln!(out, "A manifest has been produced for the following key pair. To complete account creation, you will need to run the manifest!").map_err(Error::IOError)?;
+            writeln!(out, "A manifest has been produced for the following key, which has been stored in the keystore. To complete account creation, you will need to run the manifest!").map_err(Error::IOError)?;
             writeln!(out, "Public key: {}", public_key.to_string().green())
                 .map_err(Error::IOError)?;
-            writeln!(
-                out,
-                "Private key: {}",
-                hex::encode(private_key.to_bytes()).green()
-            )
-            .map_err(Error::IOError)?;
+            writeln!(out, "Key alias: {}", alias.green()).map_err(Error::IOError)?;
         }
 
         Ok(())
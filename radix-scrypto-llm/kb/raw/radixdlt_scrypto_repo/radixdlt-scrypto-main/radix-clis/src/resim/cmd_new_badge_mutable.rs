@@ -40,7 +40,7 @@ pub struct NewBadgeMutable {
     #[clap(short, long)]
     pub manifest: Option<PathBuf>,
 
-    /// The private keys used for signing, separated by comma
+    /// The keystore aliases or private keys used for signing, separated by comma
     #[clap(short, long)]
     pub signing_keys: Option<String>,
 
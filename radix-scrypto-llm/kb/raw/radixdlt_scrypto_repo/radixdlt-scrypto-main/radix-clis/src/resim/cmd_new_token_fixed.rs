@@ -40,13 +40,16 @@ pub struct NewTokenFixed {
     #[clap(short, long)]
     pub manifest: Option<PathBuf>,
 
-    /// The private keys used for signing, separated by comma
+    /// The keystore aliases or private keys used for signing, separated by comma
     #[clap(short, long)]
     pub signing_keys: Option<String>,
 
     /// Turn on tracing
     #[clap(short, long)]
     pub trace: bool,
+    /// Register the address of the new token under this name, to be given as `@<name>`
+    #[clap(long)]
+    pub alias: Option<String>,
 }
 
 impl NewTokenFixed {
@@ -88,7 +91,7 @@ impl NewTokenFixed {
             .new_token_fixed(OwnerRole::None, metadata, self.total_supply)
             .try_deposit_entire_worktop_or_refund(default_account, None)
             .build();
-        handle_manifest(
+        let receipt = handle_manifest(
             manifest.into(),
             &self.signing_keys,
             &self.network,
@@ -96,7 +99,11 @@ impl NewTokenFixed {
             self.trace,
             true,
             out,
-        )
-        .map(|_| ())
+        )?;
+        if let Some(receipt) = receipt {
+            let resource_address = receipt.expect_commit(true).new_resource_addresses()[0];
+            register_address_alias(&self.alias, resource_address)?;
+        }
+        Ok(())
     }
 }
@@ -39,13 +39,16 @@ pub struct NewTokenMutable {
     #[clap(short, long)]
     pub manifest: Option<PathBuf>,
 
-    /// The private keys used for signing, separated by comma
+    /// The keystore aliases or private keys used for signing, separated by comma
     #[clap(short, long)]
     pub signing_keys: Option<String>,
 
     /// Turn on tracing
     #[clap(short, long)]
     pub trace: bool,
+    /// Register the address of the new token under this name, to be given as `@<name>`
+    #[clap(long)]
+    pub alias: Option<String>,
 }
 
 impl NewTokenMutable {
@@ -85,7 +88,7 @@ impl NewTokenMutable {
             .lock_fee_from_faucet()
             .new_token_mutable(metadata, self.minter_badge.clone().into())
             .build();
-        handle_manifest(
+        let receipt = handle_manifest(
             manifest.into(),
             &self.signing_keys,
             &self.network,
@@ -93,7 +96,11 @@ impl NewTokenMutable {
             self.trace,
             true,
             out,
-        )
-        .map(|_| ())
+        )?;
+        if let Some(receipt) = receipt {
+            let resource_address = receipt.expect_commit(true).new_resource_addresses()[0];
+            register_address_alias(&self.alias, resource_address)?;
+        }
+        Ok(())
     }
 }
@@ -54,6 +54,9 @@ pub struct Publish {
     /// The default is INFO.
     #[clap(long)]
     log_level: Option<Level>,
+    /// Register the address of the new package under this name, to be given as `@<name>`
+    #[clap(long)]
+    pub alias: Option<String>,
 }
 
 impl Publish {
@@ -340,10 +343,12 @@ impl Publish {
                 out,
             )?;
             if let Some(receipt) = receipt {
+                let package_address = receipt.expect_commit(true).new_package_addresses()[0];
+                register_address_alias(&self.alias, package_address)?;
                 writeln!(
                     out,
                     "Success! New Package: {}",
-                    receipt.expect_commit(true).new_package_addresses()[0]
+                    package_address
                         .display(&AddressBech32Encoder::for_simulator())
                         .to_string()
                         .green()
@@ -20,7 +20,7 @@ pub struct Run {
     #[clap(short, long, multiple = true)]
     pub blobs: Option<Vec<String>>,
 
-    /// The private keys used for signing, separated by comma
+    /// The keystore aliases or private keys used for signing, separated by comma
     #[clap(short, long)]
     pub signing_keys: Option<String>,
 
@@ -28,6 +28,9 @@ pub struct Run {
     #[clap(short, long)]
     pub trace: bool,
 
+    #[clap(flatten)]
+    pub cost_report: CostReport,
+
     /// The manifest type [V1 | SystemV1 | V2 | SubintentV2], defaults to V2
     #[clap(short, long)]
     kind: Option<String>,
@@ -44,7 +47,9 @@ impl Run {
 
     pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), String> {
         let manifest = std::fs::read_to_string(&self.path).map_err(Error::IOError)?;
-        let pre_processed_manifest = Self::pre_process_manifest(&manifest);
+        let pre_processed_manifest = get_address_aliases()?
+            .resolve_in_manifest(&Self::pre_process_manifest(&manifest))
+            .map_err(Error::AddressError)?;
         let network = match &self.network {
             Some(n) => NetworkDefinition::from_str(&n).map_err(Error::ParseNetworkError)?,
             None => NetworkDefinition::simulator(),
@@ -68,13 +73,15 @@ impl Run {
         validate_call_arguments_to_native_components(&manifest)
             .map_err(Error::InstructionSchemaValidationError)?;
 
-        handle_manifest(
+        handle_manifest_with_cost_report(
             manifest,
             &self.signing_keys,
             &self.network,
             &None,
             self.trace,
             true,
+            &self.cost_report,
+            &self.path.display().to_string(),
             out,
         )
         .map(|_| ())
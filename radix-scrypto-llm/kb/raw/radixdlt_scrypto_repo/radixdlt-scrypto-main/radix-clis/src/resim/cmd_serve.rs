@@ -0,0 +1,723 @@
+use crate::prelude::*;
+use crate::resim::*;
+use radix_engine::system::system_db_reader::SystemDatabaseReader;
+use radix_engine::transaction::*;
+use radix_engine_toolkit_common::receipt::RuntimeToolkitTransactionReceipt;
+use radix_substate_store_impls::rocks_db::RocksdbSubstateStore;
+use sbor::representations::{SerializationMode, SerializationParameters};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::{json, Value as JsonValue};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::time::Duration;
+
+/// The longest request line the server is willing to read.
+const MAX_REQUEST_LINE_SIZE: usize = 8 * 1024;
+
+/// The largest total size of the request headers the server is willing to read.
+const MAX_REQUEST_HEADERS_SIZE: usize = 64 * 1024;
+
+/// The largest request body the server is willing to read.
+const MAX_REQUEST_BODY_SIZE: usize = 16 * 1024 * 1024;
+
+/// How long the server waits on a client to send its request or receive the response, so that an
+/// idle connection can't hold up the server, which handles one connection at a time.
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Run a local HTTP server exposing preview, submit, entity-details and events endpoints
+///
+/// All endpoints accept a `POST` with an `application/json` body and respond with JSON:
+/// * `/preview` - `{ manifest, blobs?, signing_keys?, kind? }`, executes without committing.
+/// * `/submit` - same body as `/preview`, commits the transaction to the ledger.
+/// * `/entity-details` - `{ address }`, describes a package, component or resource.
+/// * `/events` - `{ emitter?, name? }`, lists events from the resim transaction log.
+///
+/// Transaction receipts use the serializable receipt of `radix-engine-toolkit-common`.
+///
+/// No CORS headers are sent unless `--allow-origin` is given, and requests from any other origin
+/// are rejected, so that a web page can't submit transactions signed with the simulator keys
+/// without the server being started for it - not even with a simple cross-site `POST`.
+#[derive(Parser, Debug)]
+pub struct Serve {
+    /// The socket address to listen on
+    #[clap(short, long, default_value = "127.0.0.1:3333")]
+    pub address: String,
+
+    /// The origin of the dApp frontend allowed to call the server from a browser, if any
+    /// (e.g. `http://localhost:5173`)
+    #[clap(long)]
+    pub allow_origin: Option<String>,
+}
+
+impl Serve {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), String> {
+        // Requests signed with keystore aliases must never block on a password prompt, so the
+        // keystore is unlocked before listening - keys imported later need a restart.
+        let keystore = UnlockedKeystore::load()?;
+
+        let listener = TcpListener::bind(&self.address).map_err(Error::IOError)?;
+        // Ledger dumps are returned to HTTP clients, so they should never contain ANSI codes.
+        colored::control::set_override(false);
+        writeln!(out, "Listening on http://{}", self.address).map_err(Error::IOError)?;
+
+        // Requests are handled one at a time. The database is only opened for the duration of
+        // each request, so other resim commands can still be used while the server is running.
+        let server = Server {
+            keystore,
+            allow_origin: self.allow_origin.clone(),
+        };
+        for stream in listener.incoming() {
+            let mut stream = match stream {
+                Ok(stream) => stream,
+                Err(err) => {
+                    writeln!(out, "Failed to accept connection: {:?}", err)
+                        .map_err(Error::IOError)?;
+                    continue;
+                }
+            };
+            if let Err(err) = stream
+                .set_read_timeout(Some(CONNECTION_TIMEOUT))
+                .and_then(|_| stream.set_write_timeout(Some(CONNECTION_TIMEOUT)))
+            {
+                writeln!(out, "Failed to configure connection: {:?}", err)
+                    .map_err(Error::IOError)?;
+                continue;
+            }
+            let (description, response) = match HttpRequest::read_from(&mut stream) {
+                Ok(request) => (
+                    format!("{} {}", request.method, request.path),
+                    server.handle(&request),
+                ),
+                Err(response) => ("<malformed request>".to_owned(), response),
+            };
+            writeln!(out, "{} -> {}", description, response.status).map_err(Error::IOError)?;
+            if let Err(err) = response.write_to(&mut stream, self.allow_origin.as_deref()) {
+                writeln!(out, "Failed to write response: {:?}", err).map_err(Error::IOError)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TransactionRequest {
+    /// The manifest source, as accepted by `resim run`.
+    manifest: String,
+    /// Hex-encoded blobs referenced by the manifest.
+    #[serde(default)]
+    blobs: Vec<String>,
+    /// The keystore aliases or private keys used for signing, separated by comma.
+    #[serde(default)]
+    signing_keys: Option<String>,
+    /// The manifest type [V1 | SystemV1 | V2 | SubintentV2], defaults to V2.
+    #[serde(default)]
+    kind: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EntityDetailsRequest {
+    address: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct EventsRequest {
+    /// Only return events emitted by this address.
+    #[serde(default)]
+    emitter: Option<String>,
+    /// Only return events with this name.
+    #[serde(default)]
+    name: Option<String>,
+}
+
+struct RecordedEvent {
+    emitter: NodeId,
+    name: String,
+    json: JsonValue,
+}
+
+struct Server {
+    keystore: UnlockedKeystore,
+    allow_origin: Option<String>,
+}
+
+impl Server {
+    fn handle(&self, request: &HttpRequest) -> HttpResponse {
+        if let Err(response) = request.check_allowed(self.allow_origin.as_deref()) {
+            return response;
+        }
+        let result = match (request.method.as_str(), request.path.as_str()) {
+            ("OPTIONS", _) => return HttpResponse::no_content(),
+            ("POST", "/preview") => parse_body(&request.body).and_then(|r| self.preview(r)),
+            ("POST", "/submit") => parse_body(&request.body).and_then(|r| self.submit(r)),
+            ("POST", "/entity-details") => {
+                parse_body(&request.body).and_then(|r| self.entity_details(r))
+            }
+            ("POST", "/events") => parse_body(&request.body).and_then(|r| self.events(r)),
+            (method, path) => {
+                return HttpResponse::error(404, format!("No endpoint for {} {}", method, path))
+            }
+        };
+        match result {
+            Ok(body) => HttpResponse::ok(body),
+            Err(err) => HttpResponse::error(400, err),
+        }
+    }
+
+    fn preview(&self, request: TransactionRequest) -> Result<JsonValue, String> {
+        let (receipt, events) = execute_request(
+            request,
+            &self.keystore,
+            &ExecutionConfig::for_preview(NetworkDefinition::simulator()),
+            false,
+        )?;
+
+        Ok(json!({
+            "receipt": to_toolkit_receipt(receipt)?,
+            "events": events.into_iter().map(|e| e.json).collect::<Vec<_>>(),
+        }))
+    }
+
+    fn submit(&self, request: TransactionRequest) -> Result<JsonValue, String> {
+        let (receipt, events) = execute_request(
+            request,
+            &self.keystore,
+            // The execution trace is required to derive the worktop changes of the receipt.
+            &ExecutionConfig::for_test_transaction()
+                .with_execution_trace(Some(MAX_EXECUTION_TRACE_DEPTH)),
+            true,
+        )?;
+
+        // Committed transactions are given their index in the resim transaction log.
+        let transaction_index = append_to_transaction_log(&receipt)?;
+        if transaction_index.is_some() {
+            increment_nonce()?;
+        }
+
+        Ok(json!({
+            "transaction_index": transaction_index,
+            "receipt": to_toolkit_receipt(receipt)?,
+            "events": events.into_iter().map(|e| e.json).collect::<Vec<_>>(),
+        }))
+    }
+
+    fn entity_details(&self, request: EntityDetailsRequest) -> Result<JsonValue, String> {
+        let SimulatorEnvironment { db, .. } = SimulatorEnvironment::new()?;
+        let address = request.address;
+
+        let address_aliases = get_address_aliases()?;
+        let mut dump = Vec::new();
+        let (entity_type, node_id) = if let Ok(a) = SimulatorPackageAddress::from_str(&address) {
+            dump_package(a.0, &db, &address_aliases, &mut dump).map_err(Error::LedgerDumpError)?;
+            ("Package", a.0.into_node_id())
+        } else if let Ok(a) = SimulatorComponentAddress::from_str(&address) {
+            dump_component(a.0, &db, &address_aliases, &mut dump)
+                .map_err(Error::LedgerDumpError)?;
+            ("Component", a.0.into_node_id())
+        } else if let Ok(a) = SimulatorResourceAddress::from_str(&address) {
+            dump_resource_manager(a.0, &db, &address_aliases, &mut dump)
+                .map_err(Error::LedgerDumpError)?;
+            ("Resource", a.0.into_node_id())
+        } else {
+            return Err(Error::InvalidId(address).into());
+        };
+
+        let encoder = AddressBech32Encoder::for_simulator();
+        let blueprint = SystemDatabaseReader::new(&db)
+            .get_object_info(node_id)
+            .ok()
+            .map(|info| {
+                let blueprint_id = info.blueprint_info.blueprint_id;
+                json!({
+                    "package_address": blueprint_id.package_address.display(&encoder).to_string(),
+                    "blueprint_name": blueprint_id.blueprint_name,
+                })
+            });
+
+        Ok(json!({
+            "address": address,
+            "entity_type": entity_type,
+            "blueprint": blueprint,
+            "details": String::from_utf8_lossy(&dump),
+        }))
+    }
+
+    fn events(&self, request: EventsRequest) -> Result<JsonValue, String> {
+        let SimulatorEnvironment { db, .. } = SimulatorEnvironment::new()?;
+
+        let emitter = match &request.emitter {
+            Some(address) => Some(parse_emitter_address(address)?),
+            None => None,
+        };
+        let log_index = get_transaction_log_index()?;
+        let transactions = match &emitter {
+            Some(emitter) => log_index
+                .by_emitter
+                .get(emitter)
+                .cloned()
+                .unwrap_or_default(),
+            None => (0..log_index.len).collect(),
+        };
+
+        let mut events = Vec::new();
+        for entry in iter_transaction_log_entries(transactions) {
+            let (transaction_index, entry) = entry?;
+            events.extend(
+                record_events(&db, &entry.commit_result)
+                    .into_iter()
+                    .filter(|event| match &emitter {
+                        Some(emitter) => &event.emitter == emitter,
+                        None => true,
+                    })
+                    .filter(|event| match &request.name {
+                        Some(name) => &event.name == name,
+                        None => true,
+                    })
+                    .map(|event| {
+                        json!({
+                            "transaction_index": transaction_index,
+                            "event": event.json,
+                        })
+                    }),
+            );
+        }
+
+        Ok(json!({ "events": events }))
+    }
+}
+
+fn parse_body<T: DeserializeOwned>(body: &[u8]) -> Result<T, String> {
+    serde_json::from_slice(body).map_err(|err| format!("Invalid request body: {}", err))
+}
+
+fn execute_request(
+    request: TransactionRequest,
+    keystore: &UnlockedKeystore,
+    execution_config: &ExecutionConfig,
+    commit: bool,
+) -> Result<(TransactionReceipt, Vec<RecordedEvent>), String> {
+    let blobs = request
+        .blobs
+        .iter()
+        .map(hex::decode)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| format!("Invalid blob hex: {}", err))?;
+    let manifest_kind = ManifestKind::parse_or_latest(request.kind.as_deref())?;
+    let manifest = compile_any_manifest_with_pretty_error(
+        &request.manifest,
+        manifest_kind,
+        &NetworkDefinition::simulator(),
+        BlobProvider::new_with_blobs(blobs),
+        CompileErrorDiagnosticsStyle::PlainText,
+    )?;
+    manifest
+        .validate(ValidationRuleset::all())
+        .map_err(|err| format!("{err:?}"))?;
+    validate_call_arguments_to_native_components(&manifest)
+        .map_err(Error::InstructionSchemaValidationError)?;
+
+    let signing_keys = get_signing_keys_from(&request.signing_keys, keystore)?;
+    let mut env = SimulatorEnvironment::new()?;
+    let receipt = execute_manifest(&mut env, manifest, &signing_keys, execution_config, commit)?;
+    let events = match &receipt.result {
+        TransactionResult::Commit(commit_result) => record_events(&env.db, commit_result),
+        _ => vec![],
+    };
+
+    Ok((receipt, events))
+}
+
+fn to_toolkit_receipt(receipt: TransactionReceipt) -> Result<JsonValue, String> {
+    let encoder = AddressBech32Encoder::for_simulator();
+    let receipt = RuntimeToolkitTransactionReceipt::try_from(receipt)
+        .and_then(|receipt| receipt.into_serializable_receipt(&encoder))
+        .map_err(|err| format!("Failed to convert receipt: {:?}", err))?;
+    serde_json::to_value(&receipt).map_err(|err| err.to_string())
+}
+
+/// Serializes the events of a commit into programmatic JSON, using the event schemas when they
+/// can be resolved (including those of packages published by the transaction itself).
+fn record_events(db: &RocksdbSubstateStore, commit: &CommitResult) -> Vec<RecordedEvent> {
+    let encoder = AddressBech32Encoder::for_simulator();
+    let reader = SystemDatabaseReader::new_with_overlay(db, &commit.state_updates);
+    let encode_node_id = |node_id: &NodeId| {
+        encoder
+            .encode(node_id.as_bytes())
+            .unwrap_or(node_id.to_hex())
+    };
+
+    commit
+        .application_events
+        .iter()
+        .map(|(identifier, data)| {
+            let EventTypeIdentifier(emitter, name) = identifier;
+            let emitter_json = match emitter {
+                Emitter::Function(blueprint_id) => {
+                    let address = encode_node_id(blueprint_id.package_address.as_node_id());
+                    json!({
+                        "kind": "Function",
+                        "package_address": address,
+                        "blueprint_name": blueprint_id.blueprint_name,
+                    })
+                }
+                Emitter::Method(node_id, module_id) => {
+                    let address = encode_node_id(node_id);
+                    json!({
+                        "kind": "Method",
+                        "entity": address,
+                        "module_id": format!("{:?}", module_id),
+                    })
+                }
+            };
+
+            let payload = ScryptoRawPayload::new_from_valid_slice(data);
+            let custom_context = ScryptoValueDisplayContext::with_optional_bech32(Some(&encoder));
+            let schema = commit
+                .system_structure
+                .event_system_structures
+                .get(identifier)
+                .and_then(|structure| {
+                    let FullyScopedTypeId(package_address, schema_hash, type_id) =
+                        &structure.package_type_reference.full_type_id;
+                    reader
+                        .get_schema(package_address.as_node_id(), schema_hash)
+                        .ok()
+                        .map(|schema| (*type_id, schema))
+                });
+            let event_data = match &schema {
+                Some((type_id, schema)) => serde_json::to_value(payload.serializable(
+                    SerializationParameters::WithSchema {
+                        mode: SerializationMode::Programmatic,
+                        custom_context,
+                        schema: schema.v1(),
+                        type_id: *type_id,
+                        depth_limit: SCRYPTO_SBOR_V1_MAX_DEPTH,
+                    },
+                )),
+                None => serde_json::to_value(payload.serializable(
+                    SerializationParameters::Schemaless {
+                        mode: SerializationMode::Programmatic,
+                        custom_context,
+                        depth_limit: SCRYPTO_SBOR_V1_MAX_DEPTH,
+                    },
+                )),
+            }
+            .unwrap_or_else(|err| JsonValue::String(err.to_string()));
+
+            RecordedEvent {
+                json: json!({
+                    "emitter": emitter_json,
+                    "name": name,
+                    "data": event_data,
+                }),
+                emitter: get_emitter_node_id(emitter),
+                name: name.clone(),
+            }
+        })
+        .collect()
+}
+
+struct HttpRequest {
+    method: String,
+    path: String,
+    content_type: Option<String>,
+    origin: Option<String>,
+    body: Vec<u8>,
+}
+
+impl HttpRequest {
+    /// Reads a request, or returns the error response to send back if it can't be read.
+    fn read_from<R: Read>(stream: R) -> Result<Self, HttpResponse> {
+        let mut reader = BufReader::new(stream);
+
+        let request_line = read_line(&mut reader, MAX_REQUEST_LINE_SIZE)?.ok_or_else(|| {
+            HttpResponse::error(
+                414,
+                format!("Request line exceeds {} bytes", MAX_REQUEST_LINE_SIZE),
+            )
+        })?;
+        let mut parts = request_line.split_whitespace();
+        let (method, path) = match (parts.next(), parts.next()) {
+            (Some(method), Some(path)) => (method.to_owned(), path.to_owned()),
+            _ => {
+                return Err(HttpResponse::error(
+                    400,
+                    format!("Invalid request line: {:?}", request_line),
+                ))
+            }
+        };
+
+        let mut content_length = 0usize;
+        let mut content_type = None;
+        let mut origin = None;
+        let mut headers_size = 0usize;
+        loop {
+            let header = read_line(&mut reader, MAX_REQUEST_HEADERS_SIZE - headers_size)?
+                .ok_or_else(|| {
+                    HttpResponse::error(
+                        431,
+                        format!("Request headers exceed {} bytes", MAX_REQUEST_HEADERS_SIZE),
+                    )
+                })?;
+            headers_size += header.len();
+            let header = header.trim_end();
+            if header.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = header.split_once(':') {
+                let name = name.trim();
+                if name.eq_ignore_ascii_case("content-type") {
+                    content_type = Some(value.trim().to_owned());
+                } else if name.eq_ignore_ascii_case("origin") {
+                    origin = Some(value.trim().to_owned());
+                } else if name.eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().map_err(|_| {
+                        HttpResponse::error(
+                            400,
+                            format!("Invalid Content-Length: {}", value.trim()),
+                        )
+                    })?;
+                }
+            }
+        }
+        if content_length > MAX_REQUEST_BODY_SIZE {
+            return Err(HttpResponse::error(
+                413,
+                format!("Request body exceeds {} bytes", MAX_REQUEST_BODY_SIZE),
+            ));
+        }
+
+        let mut body = vec![0u8; content_length];
+        reader
+            .read_exact(&mut body)
+            .map_err(|err| HttpResponse::error(400, err.to_string()))?;
+
+        Ok(Self {
+            method,
+            path,
+            content_type,
+            origin,
+            body,
+        })
+    }
+
+    /// Rejects requests from a web page of any origin but the allowed one.
+    fn check_allowed(&self, allow_origin: Option<&str>) -> Result<(), HttpResponse> {
+        // Browsers send the origin of any cross-site request - requests without an origin
+        // don't come from a web page.
+        if let Some(origin) = &self.origin {
+            if allow_origin != Some(origin.as_str()) {
+                return Err(HttpResponse::error(
+                    403,
+                    format!("Origin {} is not allowed", origin),
+                ));
+            }
+        }
+        // A web page can't send a JSON content type without a CORS preflight, which covers the
+        // cross-site requests of browsers which don't send an origin.
+        if self.method == "POST" && !self.has_json_body() {
+            return Err(HttpResponse::error(
+                415,
+                "Content-Type must be application/json".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn has_json_body(&self) -> bool {
+        // Parameters such as `; charset=utf-8` don't change the media type.
+        self.content_type
+            .as_deref()
+            .and_then(|content_type| content_type.split(';').next())
+            .is_some_and(|media_type| media_type.trim().eq_ignore_ascii_case("application/json"))
+    }
+}
+
+/// Reads a line of at most `limit` bytes, including its line ending, or `None` if it is longer.
+fn read_line<R: BufRead>(reader: &mut R, limit: usize) -> Result<Option<String>, HttpResponse> {
+    let mut line = Vec::new();
+    reader
+        .by_ref()
+        .take(limit as u64)
+        .read_until(b'\n', &mut line)
+        .map_err(|err| HttpResponse::error(400, err.to_string()))?;
+    if line.len() == limit && !line.ends_with(b"\n") {
+        return Ok(None);
+    }
+    String::from_utf8(line)
+        .map(Some)
+        .map_err(|_| HttpResponse::error(400, "Request is not valid UTF-8".to_owned()))
+}
+
+struct HttpResponse {
+    status: u16,
+    body: Option<JsonValue>,
+}
+
+impl HttpResponse {
+    fn ok(body: JsonValue) -> Self {
+        Self {
+            status: 200,
+            body: Some(body),
+        }
+    }
+
+    fn no_content() -> Self {
+        Self {
+            status: 204,
+            body: None,
+        }
+    }
+
+    fn error(status: u16, message: String) -> Self {
+        Self {
+            status,
+            body: Some(json!({ "error": message })),
+        }
+    }
+
+    fn write_to(&self, stream: &mut TcpStream, allow_origin: Option<&str>) -> std::io::Result<()> {
+        let reason = match self.status {
+            200 => "OK",
+            204 => "No Content",
+            403 => "Forbidden",
+            404 => "Not Found",
+            413 => "Payload Too Large",
+            414 => "URI Too Long",
+            415 => "Unsupported Media Type",
+            431 => "Request Header Fields Too Large",
+            _ => "Bad Request",
+        };
+        let body = match &self.body {
+            Some(body) => body.to_string(),
+            None => String::new(),
+        };
+        // The CORS headers allow the dApp frontend served from the allowed origin to call the server.
+        let cors_headers = match allow_origin {
+            Some(origin) => format!(
+                "Access-Control-Allow-Origin: {}\r\n\
+                Access-Control-Allow-Methods: POST, OPTIONS\r\n\
+                Access-Control-Allow-Headers: Content-Type\r\n\
+                Vary: Origin\r\n",
+                origin
+            ),
+            None => String::new(),
+        };
+        write!(
+            stream,
+            "HTTP/1.1 {} {}\r\n\
+            Content-Type: application/json\r\n\
+            Content-Length: {}\r\n\
+            {}\
+            Connection: close\r\n\
+            \r\n\
+            {}",
+            self.status,
+            reason,
+            body.len(),
+            cors_headers,
+            body
+        )?;
+        stream.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_request(request: &str) -> Result<HttpRequest, HttpResponse> {
+        HttpRequest::read_from(request.as_bytes())
+    }
+
+    #[test]
+    fn request_within_the_limits_is_read() {
+        // Arrange
+        let request = "POST /preview HTTP/1.1\r\nContent-Length: 2\r\n\r\n{}";
+
+        // Act
+        let request = read_request(request).ok().unwrap();
+
+        // Assert
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/preview");
+        assert_eq!(request.body, b"{}");
+    }
+
+    #[test]
+    fn overlong_request_line_is_rejected() {
+        // Arrange
+        let request = format!(
+            "POST /{} HTTP/1.1\r\n\r\n",
+            "a".repeat(MAX_REQUEST_LINE_SIZE)
+        );
+
+        // Act
+        let response = read_request(&request).err().unwrap();
+
+        // Assert
+        assert_eq!(response.status, 414);
+    }
+
+    #[test]
+    fn oversized_headers_are_rejected() {
+        // Arrange
+        let header = format!("X-Padding: {}\r\n", "a".repeat(1024));
+        let request = format!(
+            "POST /preview HTTP/1.1\r\n{}\r\n",
+            header.repeat(MAX_REQUEST_HEADERS_SIZE / header.len() + 1)
+        );
+
+        // Act
+        let response = read_request(&request).err().unwrap();
+
+        // Assert
+        assert_eq!(response.status, 431);
+    }
+
+    #[test]
+    fn json_request_from_the_allowed_origin_is_accepted() {
+        // Arrange
+        let request = "POST /preview HTTP/1.1\r\n\
+            Origin: http://localhost:5173\r\n\
+            Content-Type: application/json; charset=utf-8\r\n\r\n";
+
+        // Act
+        let request = read_request(request).ok().unwrap();
+
+        // Assert
+        assert!(request.check_allowed(Some("http://localhost:5173")).is_ok());
+    }
+
+    #[test]
+    fn request_from_another_origin_is_rejected() {
+        // Arrange
+        let request = "POST /submit HTTP/1.1\r\n\
+            Origin: https://example.com\r\n\
+            Content-Type: application/json\r\n\r\n";
+
+        // Act
+        let request = read_request(request).ok().unwrap();
+
+        // Assert
+        let response = request.check_allowed(Some("http://localhost:5173")).err();
+        assert_eq!(response.unwrap().status, 403);
+        let response = request.check_allowed(None).err();
+        assert_eq!(response.unwrap().status, 403);
+    }
+
+    #[test]
+    fn request_without_a_json_content_type_is_rejected() {
+        // Arrange
+        let request = "POST /submit HTTP/1.1\r\n\
+            Content-Type: text/plain\r\n\r\n";
+
+        // Act
+        let request = read_request(request).ok().unwrap();
+
+        // Assert
+        let response = request.check_allowed(None).err();
+        assert_eq!(response.unwrap().status, 415);
+    }
+}
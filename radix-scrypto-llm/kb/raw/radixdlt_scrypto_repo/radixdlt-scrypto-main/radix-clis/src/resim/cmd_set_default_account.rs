@@ -9,8 +9,9 @@ pub struct SetDefaultAccount {
     /// The account component address
     pub component_address: SimulatorComponentAddress,
 
-    /// The private key for accessing the account
-    pub private_key: String,
+    /// The alias of the key for accessing the account, or its private key - which is then imported
+    /// into the keystore under the account address
+    pub key: String,
 
     /// The owner badge.
     pub owner_badge: SimulatorNonFungibleGlobalId,
@@ -19,15 +20,28 @@ pub struct SetDefaultAccount {
 impl SetDefaultAccount {
     pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), String> {
         let mut configs = get_configs()?;
-        let private_key = parse_private_key_from_str(&self.private_key).map_err(|e| {
-            if Secp256k1PublicKey::from_str(&self.private_key).is_ok() {
-                Error::GotPublicKeyExpectedPrivateKey
-            } else {
-                e
-            }
-        })?;
+        let (mut keystore, cipher) = unlock_keystore()?;
+        let alias = if keystore.keys.contains_key(&self.key) {
+            self.key.clone()
+        } else {
+            let private_key = parse_private_key_from_str(&self.key).map_err(|e| {
+                if Secp256k1PublicKey::from_str(&self.key).is_ok() {
+                    Error::GotPublicKeyExpectedPrivateKey
+                } else {
+                    e
+                }
+            })?;
+            let alias = self
+                .component_address
+                .0
+                .display(&AddressBech32Encoder::for_simulator())
+                .to_string();
+            keystore.insert(&alias, &private_key, &cipher)?;
+            set_keystore(&keystore)?;
+            alias
+        };
         configs.default_account = Some(self.component_address.0);
-        configs.default_private_key = Some(private_key.to_hex());
+        configs.default_key = Some(alias);
         configs.default_owner_badge = Some(self.owner_badge.clone().0);
         set_configs(&configs)?;
 
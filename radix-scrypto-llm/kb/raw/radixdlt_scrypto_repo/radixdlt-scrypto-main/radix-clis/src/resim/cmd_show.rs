@@ -1,27 +1,41 @@
 use crate::resim::*;
 use clap::Parser;
 use radix_common::prelude::*;
+use radix_substate_store_interface::interface::*;
 
 /// Show an entity in the ledger state
 #[derive(Parser, Debug)]
 pub struct Show {
-    /// The address of a package, component or resource manager, if no
+    /// The address or `@<alias>` of a package, component or resource manager, if no
     /// address is provided, then we default to `show <DEFAULT_ACCOUNT_ADDRESS>`.
     pub address: Option<String>,
+
+    /// The state version to show the entity at, i.e. the number of commits to the ledger before
+    /// it - defaults to the current state
+    #[clap(long)]
+    pub at_version: Option<u64>,
 }
 
 impl Show {
     pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), String> {
         let SimulatorEnvironment { db, .. } = SimulatorEnvironment::new()?;
+        let state_version = self
+            .at_version
+            .unwrap_or_else(|| db.get_current_state_version());
+        let db = SubstateDatabaseAtVersion::new(&db, state_version)
+            .map_err(Error::StateVersionNotAvailable)?;
+
+        let address_aliases = get_address_aliases()?;
 
         let result = match &self.address {
             Some(address) => {
                 if let Ok(a) = SimulatorPackageAddress::from_str(address) {
-                    dump_package(a.0, &db, out).map_err(Error::LedgerDumpError)
+                    dump_package(a.0, &db, &address_aliases, out).map_err(Error::LedgerDumpError)
                 } else if let Ok(a) = SimulatorComponentAddress::from_str(address) {
-                    dump_component(a.0, &db, out).map_err(Error::LedgerDumpError)
+                    dump_component(a.0, &db, &address_aliases, out).map_err(Error::LedgerDumpError)
                 } else if let Ok(a) = SimulatorResourceAddress::from_str(address) {
-                    dump_resource_manager(a.0, &db, out).map_err(Error::LedgerDumpError)
+                    dump_resource_manager(a.0, &db, &address_aliases, out)
+                        .map_err(Error::LedgerDumpError)
                 } else {
                     Err(Error::InvalidId(address.clone()))
                 }
@@ -32,7 +46,9 @@ impl Show {
                         EntityDumpError::NoAddressProvidedAndNotDefaultAccountSet,
                     ))
                 })
-                .and_then(|x| dump_component(x, &db, out).map_err(Error::LedgerDumpError)),
+                .and_then(|x| {
+                    dump_component(x, &db, &address_aliases, out).map_err(Error::LedgerDumpError)
+                }),
         };
         result.map_err(|err| err.into())
     }
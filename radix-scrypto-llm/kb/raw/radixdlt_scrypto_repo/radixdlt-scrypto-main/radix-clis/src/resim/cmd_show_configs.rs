@@ -26,9 +26,9 @@ impl ShowConfigs {
         writeln!(
             out,
             "{}: {}",
-            "Account Private Key".green().bold(),
-            match configs.default_private_key {
-                Some(private_key) => private_key,
+            "Account Key".green().bold(),
+            match configs.default_key {
+                Some(key) => key,
                 None => "None".to_owned(),
             }
         )
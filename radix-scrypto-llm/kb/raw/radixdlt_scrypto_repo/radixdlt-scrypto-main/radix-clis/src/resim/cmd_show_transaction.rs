@@ -0,0 +1,29 @@
+use crate::resim::*;
+use clap::Parser;
+use colored::*;
+use radix_common::prelude::*;
+use radix_engine::transaction::*;
+
+/// Show a transaction from the resim transaction log
+#[derive(Parser, Debug)]
+pub struct ShowTransaction {
+    /// The index of the transaction in the log, starting from 0
+    pub index: u64,
+}
+
+impl ShowTransaction {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), String> {
+        let receipt = read_transaction_log_entry(self.index)?.into_receipt();
+        let SimulatorEnvironment { db, .. } = SimulatorEnvironment::new()?;
+
+        let encoder = AddressBech32Encoder::for_simulator();
+        let display_context = TransactionReceiptDisplayContextBuilder::new()
+            .encoder(&encoder)
+            .schema_lookup_from_db(&db)
+            .build();
+        writeln!(out, "{} {}", "Transaction:".green().bold(), self.index)
+            .map_err(Error::IOError)?;
+        writeln!(out, "{}", receipt.display(display_context)).map_err(Error::IOError)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+use clap::{Parser, Subcommand};
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+use crate::resim::*;
+
+/// Save, restore or list named snapshots of the ledger and configs
+#[derive(Parser, Debug)]
+pub struct Snapshot {
+    #[clap(subcommand)]
+    pub command: SnapshotCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum SnapshotCommand {
+    /// Save the current ledger state, configs and transaction log under a name
+    Save {
+        /// The snapshot name, which may contain letters, digits, `-` and `_`
+        name: String,
+    },
+    /// Replace the current ledger state, configs and transaction log with a saved snapshot
+    Restore {
+        /// The snapshot name
+        name: String,
+    },
+    /// List the saved snapshots
+    List,
+}
+
+impl Snapshot {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), String> {
+        match &self.command {
+            SnapshotCommand::Save { name } => {
+                let snapshot_dir = get_snapshot_dir(name)?;
+                if snapshot_dir.exists() {
+                    fs::remove_dir_all(&snapshot_dir)
+                        .map_err(|err| Error::IOErrorAtPath(err, snapshot_dir.clone()))?;
+                }
+                // Opening the environment first ensures the ledger is bootstrapped, and the
+                // database is closed again before its files are copied.
+                drop(SimulatorEnvironment::new()?);
+                copy_dir_all(&get_data_dir()?, &snapshot_dir)?;
+                writeln!(out, "Snapshot {} saved.", name).map_err(Error::IOError)?;
+            }
+            SnapshotCommand::Restore { name } => {
+                let snapshot_dir = get_snapshot_dir(name)?;
+                if !snapshot_dir.exists() {
+                    return Err(Error::SnapshotNotFound(name.clone()).into());
+                }
+                let data_dir = get_data_dir()?;
+                fs::remove_dir_all(&data_dir)
+                    .map_err(|err| Error::IOErrorAtPath(err, data_dir.clone()))?;
+                copy_dir_all(&snapshot_dir, &data_dir)?;
+                writeln!(out, "Snapshot {} restored.", name).map_err(Error::IOError)?;
+            }
+            SnapshotCommand::List => {
+                let snapshots_dir = get_snapshots_dir()?;
+                let mut names = Vec::new();
+                for entry in fs::read_dir(&snapshots_dir)
+                    .map_err(|err| Error::IOErrorAtPath(err, snapshots_dir.clone()))?
+                {
+                    let entry = entry.map_err(Error::IOError)?;
+                    if entry.path().is_dir() {
+                        names.push(entry.file_name().to_string_lossy().into_owned());
+                    }
+                }
+                names.sort();
+                for name in names {
+                    writeln!(out, "{}", name).map_err(Error::IOError)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn get_snapshot_dir(name: &str) -> Result<PathBuf, Error> {
+    let is_valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !is_valid {
+        return Err(Error::InvalidSnapshotName(name.to_owned()));
+    }
+    let mut path = get_snapshots_dir()?;
+    path.push(name);
+    Ok(path)
+}
+
+fn copy_dir_all(from: &Path, to: &Path) -> Result<(), Error> {
+    for entry in WalkDir::new(from) {
+        let entry = entry.map_err(|err| Error::IOError(err.into()))?;
+        let relative_path = entry
+            .path()
+            .strip_prefix(from)
+            .expect("Walked path is always within the walked directory");
+        let target = to.join(relative_path);
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&target).map_err(|err| Error::IOErrorAtPath(err, target))?;
+        } else {
+            fs::copy(entry.path(), &target).map_err(|err| Error::IOErrorAtPath(err, target))?;
+        }
+    }
+    Ok(())
+}
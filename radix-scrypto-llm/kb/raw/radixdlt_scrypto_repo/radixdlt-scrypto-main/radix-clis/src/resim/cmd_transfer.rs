@@ -25,7 +25,7 @@ pub struct Transfer {
     #[clap(short, long)]
     pub manifest: Option<PathBuf>,
 
-    /// The private keys used for signing, separated by comma
+    /// The keystore aliases or private keys used for signing, separated by comma
     #[clap(short, long)]
     pub signing_keys: Option<String>,
 
@@ -39,7 +39,7 @@ impl Transfer {
         let address_bech32_decoder = AddressBech32Decoder::for_simulator();
 
         let default_account = get_default_account()?;
-        let proofs = self.proofs.clone().unwrap_or_default();
+        let proofs = resolve_address_aliases(&self.proofs.clone().unwrap_or_default())?;
 
         let mut builder = ManifestBuilder::new().lock_fee_from_faucet();
         for resource_specifier in proofs {
@@ -52,9 +52,11 @@ impl Transfer {
             .map_err(Error::FailedToBuildArguments)?
         }
 
-        let resource_specifier =
-            parse_resource_specifier(&self.resource_specifier, &address_bech32_decoder)
-                .map_err(|_| Error::InvalidResourceSpecifier(self.resource_specifier.clone()))?;
+        let resource_specifier = parse_resource_specifier(
+            &resolve_address_alias(&self.resource_specifier).map_err(Error::AddressError)?,
+            &address_bech32_decoder,
+        )
+        .map_err(|_| Error::InvalidResourceSpecifier(self.resource_specifier.clone()))?;
 
         builder = match resource_specifier {
             crate::utils::ResourceSpecifier::Amount(amount, resource_address) => {
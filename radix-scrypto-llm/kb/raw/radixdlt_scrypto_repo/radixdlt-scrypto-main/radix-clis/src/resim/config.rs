@@ -1,6 +1,7 @@
 use crate::resim::*;
 use radix_common::prelude::*;
 use radix_engine::updates::*;
+use radix_engine::vm::wasm::PreparedCodeCache;
 use radix_engine::vm::*;
 use std::env;
 use std::fs;
@@ -24,11 +25,14 @@ impl SimulatorEnvironment {
     }
 
     pub fn new() -> Result<Self, Error> {
-        // Create the database
-        let db = RocksdbSubstateStore::standard(get_data_dir()?);
+        // Create the database, keeping the history for `resim show --at-version`
+        let db = RocksdbSubstateStore::standard(get_data_dir()?).with_historical_substates();
 
         // Create the VMs
-        let vm_modules = VmModules::default();
+        let mut vm_modules = VmModules::default();
+        if let Ok(folder) = env::var(ENV_PREPARED_CODE_CACHE_DIR) {
+            vm_modules.scrypto_vm.prepared_code_cache = Some(PreparedCodeCache::new(folder));
+        }
 
         let mut env = Self {
             db,
@@ -53,12 +57,14 @@ impl SimulatorEnvironment {
 #[derive(Debug, Clone, Default, ScryptoSbor)]
 pub struct Configs {
     pub default_account: Option<ComponentAddress>,
-    pub default_private_key: Option<String>,
+    /// The keystore alias of the default account key - or, for configs from before the keystore,
+    /// the hex encoded private key itself.
+    pub default_key: Option<String>,
     pub default_owner_badge: Option<NonFungibleGlobalId>,
     pub nonce: u32,
 }
 
-fn get_data_dir() -> Result<PathBuf, Error> {
+pub fn get_data_dir() -> Result<PathBuf, Error> {
     let path = match env::var(ENV_DATA_DIR) {
         Ok(value) => std::path::PathBuf::from(value),
         Err(..) => {
@@ -73,12 +79,37 @@ fn get_data_dir() -> Result<PathBuf, Error> {
     Ok(path)
 }
 
+/// Snapshots are kept next to the data directory rather than inside it, so that they survive a
+/// `resim reset`.
+pub fn get_snapshots_dir() -> Result<PathBuf, Error> {
+    let data_dir = get_data_dir()?;
+    let mut dir_name = data_dir
+        .file_name()
+        .map(|name| name.to_os_string())
+        .unwrap_or_default();
+    dir_name.push("-snapshots");
+    let path = data_dir.with_file_name(dir_name);
+    if !path.exists() {
+        std::fs::create_dir_all(&path).map_err(Error::IOError)?;
+    }
+    Ok(path)
+}
+
 pub fn get_configs_path() -> Result<PathBuf, Error> {
     let mut path = get_data_dir()?;
     path.push("config");
     Ok(path.with_extension("sbor"))
 }
 
+pub fn get_transaction_log_dir() -> Result<PathBuf, Error> {
+    let mut path = get_data_dir()?;
+    path.push("transactions");
+    if !path.exists() {
+        std::fs::create_dir_all(&path).map_err(Error::IOError)?;
+    }
+    Ok(path)
+}
+
 pub fn get_configs() -> Result<Configs, Error> {
     let path = get_configs_path()?;
     if path.exists() {
@@ -100,10 +131,10 @@ pub fn get_default_account() -> Result<ComponentAddress, Error> {
 }
 
 pub fn get_default_private_key() -> Result<Secp256k1PrivateKey, Error> {
-    get_configs()?
-        .default_private_key
-        .map(|v| Secp256k1PrivateKey::from_hex(&v).unwrap())
-        .ok_or(Error::NoDefaultPrivateKey)
+    let key = get_configs()?
+        .default_key
+        .ok_or(Error::NoDefaultPrivateKey)?;
+    Ok(resolve_private_keys(&[key.as_str()])?.remove(0))
 }
 
 pub fn get_default_owner_badge() -> Result<NonFungibleGlobalId, Error> {
@@ -115,3 +146,9 @@ pub fn get_default_owner_badge() -> Result<NonFungibleGlobalId, Error> {
 pub fn get_nonce() -> Result<u32, Error> {
     Ok(get_configs()?.nonce)
 }
+
+pub fn increment_nonce() -> Result<(), Error> {
+    let mut configs = get_configs()?;
+    configs.nonce = get_nonce()? + 1;
+    set_configs(&configs)
+}
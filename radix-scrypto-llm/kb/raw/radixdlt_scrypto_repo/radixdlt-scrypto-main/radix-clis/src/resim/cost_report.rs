@@ -0,0 +1,59 @@
+use crate::prelude::*;
+use crate::resim::*;
+use colored::*;
+use radix_engine::transaction::*;
+
+/// The reports of the costs of the executed transaction.
+#[derive(Parser, Debug, Clone, Default)]
+pub struct CostReport {
+    /// Write a flamegraph of the execution cost units consumed by each call to the given SVG file
+    #[clap(long)]
+    pub flamegraph: Option<PathBuf>,
+
+    /// Print the breakdown of the execution and finalization costs
+    #[clap(long)]
+    pub cost_report: bool,
+}
+
+impl CostReport {
+    pub fn update_execution_config(&self, config: ExecutionConfig) -> ExecutionConfig {
+        // The cost report needs the cost breakdown, and the flamegraph the detailed breakdown, which
+        // is only part of the debug information - neither is collected unless it is asked for
+        config
+            .with_cost_breakdown(self.cost_report)
+            .with_debug_information(self.flamegraph.is_some())
+    }
+
+    pub fn write<O: std::io::Write>(
+        &self,
+        receipt: &TransactionReceipt,
+        title: &str,
+        network: &NetworkDefinition,
+        out: &mut O,
+    ) -> Result<(), Error> {
+        if let Some(path) = &self.flamegraph {
+            let svg_bytes = receipt
+                .generate_execution_breakdown_flamegraph_svg_bytes(title, network)
+                .map_err(Error::FlamegraphError)?;
+            write_ensuring_folder_exists(path, svg_bytes)
+                .map_err(|err| Error::IOErrorAtPath(err, path.clone()))?;
+            writeln!(out, "{} {}", "Flamegraph:".green().bold(), path.display())
+                .map_err(Error::IOError)?;
+        }
+
+        if self.cost_report {
+            // Rejected transactions have no fee details
+            if let Some(fee_details) = &receipt.fee_details {
+                writeln!(out, "{}", "Cost Report:".green().bold()).map_err(Error::IOError)?;
+                write!(
+                    out,
+                    "{}",
+                    format_cost_breakdown(&receipt.fee_summary, fee_details)
+                )
+                .map_err(Error::IOError)?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 #![allow(unused_must_use)]
+use crate::resim::AddressAliases;
 use crate::utils::*;
 use colored::*;
 use radix_blueprint_schema_init::BlueprintFeature;
@@ -31,6 +32,7 @@ pub enum EntityDumpError {
 pub fn dump_package<T: SubstateDatabase, O: std::io::Write>(
     package_address: PackageAddress,
     substate_db: &T,
+    address_aliases: &AddressAliases,
     output: &mut O,
 ) -> Result<(), EntityDumpError> {
     let address_bech32_encoder = AddressBech32Encoder::new(&NetworkDefinition::simulator());
@@ -47,7 +49,7 @@ pub fn dump_package<T: SubstateDatabase, O: std::io::Write>(
         output,
         "{}: {}",
         "Package Address".green().bold(),
-        package_address.display(&address_bech32_encoder)
+        address_aliases.display(package_address, &address_bech32_encoder)
     );
     writeln!(
         output,
@@ -74,6 +76,7 @@ pub fn dump_package<T: SubstateDatabase, O: std::io::Write>(
 pub fn dump_component<T: SubstateDatabase, O: std::io::Write>(
     component_address: ComponentAddress,
     substate_db: &T,
+    address_aliases: &AddressAliases,
     output: &mut O,
 ) -> Result<(), EntityDumpError> {
     let address_bech32_encoder = AddressBech32Encoder::new(&NetworkDefinition::simulator());
@@ -101,14 +104,14 @@ pub fn dump_component<T: SubstateDatabase, O: std::io::Write>(
         output,
         "{}: {}",
         "Component Address".green().bold(),
-        component_address.display(&address_bech32_encoder),
+        address_aliases.display(component_address, &address_bech32_encoder),
     );
 
     writeln!(
         output,
         "{}: {{ package_address: {}, blueprint_name: \"{}\" }}",
         "Blueprint ID".green().bold(),
-        package_address.display(&address_bech32_encoder),
+        address_aliases.display(package_address, &address_bech32_encoder),
         blueprint_name
     );
 
@@ -134,7 +137,7 @@ pub fn dump_component<T: SubstateDatabase, O: std::io::Write>(
             output,
             "{} {}: {} {}{}",
             list_item_prefix(last),
-            resource_address.display(&address_bech32_encoder),
+            address_aliases.display(*resource_address, &address_bech32_encoder),
             amount,
             name,
             symbol_text,
@@ -163,7 +166,7 @@ pub fn dump_component<T: SubstateDatabase, O: std::io::Write>(
             output,
             "{} {}: {} {}{}",
             list_item_prefix(last),
-            resource_address.display(&address_bech32_encoder),
+            address_aliases.display(*resource_address, &address_bech32_encoder),
             ids.len(),
             name,
             symbol_text,
@@ -186,6 +189,7 @@ pub fn dump_component<T: SubstateDatabase, O: std::io::Write>(
 pub fn dump_resource_manager<T: SubstateDatabase, O: std::io::Write>(
     resource_address: ResourceAddress,
     substate_db: &T,
+    address_aliases: &AddressAliases,
     output: &mut O,
 ) -> Result<(), EntityDumpError> {
     let address_bech32_encoder = AddressBech32Encoder::new(&NetworkDefinition::simulator());
@@ -194,7 +198,7 @@ pub fn dump_resource_manager<T: SubstateDatabase, O: std::io::Write>(
         output,
         "{}: {}",
         "Resource Address".green().bold(),
-        resource_address.display(&address_bech32_encoder)
+        address_aliases.display(resource_address, &address_bech32_encoder)
     );
 
     let reader = SystemDatabaseReader::new(substate_db);
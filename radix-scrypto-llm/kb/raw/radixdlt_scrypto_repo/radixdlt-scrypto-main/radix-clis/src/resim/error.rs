@@ -1,8 +1,9 @@
 use crate::prelude::*;
-use crate::resim::EntityDumpError;
+use crate::resim::{AddressError, EntityDumpError};
 use radix_engine::errors::*;
-use radix_engine::transaction::AbortReason;
+use radix_engine::transaction::{AbortReason, FlamegraphError};
 use radix_engine::vm::wasm::PrepareError as WasmPrepareError;
+use radix_substate_store_interface::interface::StateVersionNotAvailableError;
 use radix_transactions::errors::*;
 use radix_transactions::manifest::DecompileError;
 use radix_transactions::model::PrepareError as TransactionPrepareError;
@@ -48,6 +49,28 @@ pub enum Error {
 
     TransactionAborted(AbortReason),
 
+    TransactionNotFound(u64),
+
+    SnapshotNotFound(String),
+
+    InvalidSnapshotName(String),
+
+    InvalidKeystore,
+
+    InvalidKeystorePassword,
+
+    KeyAliasNotFound(String),
+
+    KeyAliasAlreadyExists(String),
+
+    InvalidKeyAlias(String),
+
+    InvalidAddressAlias(String),
+
+    AddressError(AddressError),
+
+    StateVersionNotAvailable(StateVersionNotAvailableError),
+
     LedgerDumpError(EntityDumpError),
 
     DecompileError(DecompileError),
@@ -72,18 +95,174 @@ pub enum Error {
     InvalidResourceSpecifier(String),
 
     RemoteGenericSubstitutionNotSupported,
+
+    FlamegraphError(FlamegraphError),
+}
+
+impl Error {
+    pub fn diagnostic(&self) -> Diagnostic {
+        let address_encoder = AddressBech32Encoder::for_simulator();
+        let address_encoder = &address_encoder;
+        match self {
+            Self::NoDefaultAccount => {
+                Diagnostic::new("NoDefaultAccount", "No default account is configured.")
+                    .with_help("Create one with `resim new-account`.")
+            }
+            Self::NoDefaultPrivateKey => Diagnostic::new(
+                "NoDefaultPrivateKey",
+                "No default account key is configured.",
+            )
+            .with_help("Set one with `resim set-default-account`."),
+            Self::NoDefaultOwnerBadge => Diagnostic::new(
+                "NoDefaultOwnerBadge",
+                "No default owner badge is configured.",
+            )
+            .with_help("Set one with `resim set-default-account`, or pass `--owner-badge`."),
+            Self::HomeDirUnknown => Diagnostic::new(
+                "HomeDirUnknown",
+                "The home directory could not be determined.",
+            )
+            .with_help("Set the data directory with the `DATA_DIR` environment variable."),
+            Self::PackageNotFound(package_address) => Diagnostic::new(
+                "PackageNotFound",
+                format!(
+                    "Package {} does not exist.",
+                    package_address.to_string(address_encoder)
+                ),
+            ),
+            Self::BlueprintNotFound(package_address, blueprint) => Diagnostic::new(
+                "BlueprintNotFound",
+                format!(
+                    "Package {} does not define a blueprint named {}.",
+                    package_address.to_string(address_encoder),
+                    blueprint
+                ),
+            ),
+            Self::ComponentNotFound(component_address) => Diagnostic::new(
+                "ComponentNotFound",
+                format!(
+                    "Component {} does not exist.",
+                    component_address.to_string(address_encoder)
+                ),
+            ),
+            Self::IOError(err) => Diagnostic::new("IOError", err.to_string()),
+            Self::IOErrorAtPath(err, path) => {
+                Diagnostic::new("IOError", format!("{}: {}", path.display(), err))
+            }
+            Self::BuildError(err) => diagnose_build_error(err),
+            Self::TransactionValidationError(err) => diagnose_transaction_validation_error(err),
+            Self::TransactionFailed(err) => diagnose_runtime_error(err, address_encoder),
+            Self::TransactionRejected(reason) => diagnose_rejection(reason, address_encoder),
+            Self::TransactionNotFound(index) => Diagnostic::new(
+                "TransactionNotFound",
+                format!(
+                    "Transaction {} does not exist in the transaction log.",
+                    index
+                ),
+            ),
+            Self::SnapshotNotFound(name) => Diagnostic::new(
+                "SnapshotNotFound",
+                format!("Snapshot {} does not exist.", name),
+            )
+            .with_help("List the saved snapshots with `resim snapshot list`."),
+            Self::InvalidSnapshotName(name) => Diagnostic::new(
+                "InvalidSnapshotName",
+                format!("{} is not a valid snapshot name.", name),
+            )
+            .with_help("Snapshot names may only contain letters, digits, `-` and `_`."),
+            Self::InvalidKeystore => Diagnostic::new(
+                "InvalidKeystore",
+                "The keystore has invalid key derivation parameters.",
+            ),
+            Self::InvalidKeystorePassword => Diagnostic::new(
+                "InvalidKeystorePassword",
+                "The keystore password is incorrect.",
+            )
+            .with_help(
+                "The password is read from the `KEYSTORE_PASSWORD` environment variable, if set.",
+            ),
+            Self::KeyAliasNotFound(alias) => Diagnostic::new(
+                "KeyAliasNotFound",
+                format!("Key {} does not exist in the keystore.", alias),
+            )
+            .with_help("List the keys with `resim keys list`."),
+            Self::KeyAliasAlreadyExists(alias) => Diagnostic::new(
+                "KeyAliasAlreadyExists",
+                format!(
+                    "Key {} already exists in the keystore, with another private key.",
+                    alias
+                ),
+            ),
+            Self::InvalidKeyAlias(alias) => Diagnostic::new(
+                "InvalidKeyAlias",
+                format!("{} is not a valid key alias.", alias),
+            )
+            .with_help("Key aliases may only contain letters, digits, `-` and `_`."),
+            Self::InvalidAddressAlias(name) => Diagnostic::new(
+                "InvalidAddressAlias",
+                format!("@{} is not a valid address alias.", name),
+            )
+            .with_help("Address aliases may only contain letters, digits, `-` and `_`."),
+            Self::AddressError(AddressError::UnknownAlias(name)) => Diagnostic::new(
+                "UnknownAddressAlias",
+                format!(
+                    "No address has the alias @{}.",
+                    name.trim_start_matches('@')
+                ),
+            )
+            .with_help("List the aliases with `resim alias list`."),
+            Self::AddressError(AddressError::InvalidAddress(address)) => Diagnostic::new(
+                "InvalidAddress",
+                format!("{} is not a valid address.", address),
+            ),
+            Self::AddressError(AddressError::AliasesUnavailable(reason)) => Diagnostic::new(
+                "AddressAliasesUnavailable",
+                format!("The address aliases could not be read: {}", reason),
+            ),
+            Self::StateVersionNotAvailable(err) => Diagnostic::new(
+                "StateVersionNotAvailable",
+                format!(
+                    "State version {} is not available, only versions {} to {} are.",
+                    err.state_version,
+                    err.first_historical_state_version,
+                    err.current_state_version
+                ),
+            ),
+            Self::InvalidPrivateKey => {
+                Diagnostic::new("InvalidPrivateKey", "The private key is invalid.")
+                    .with_help("Private keys are given as 64 hex characters, or by keystore alias.")
+            }
+            Self::GotPublicKeyExpectedPrivateKey => Diagnostic::new(
+                "GotPublicKeyExpectedPrivateKey",
+                "A public key was given where a private key was expected.",
+            ),
+            Self::OwnerBadgeNotSpecified => {
+                Diagnostic::new("OwnerBadgeNotSpecified", "No owner badge was specified.")
+                    .with_help("Pass `--owner-badge`, or set a default account.")
+            }
+            Self::InstructionSchemaValidationError(err) => {
+                Diagnostic::from_debug("InstructionSchemaValidationError", err)
+                    .with_help("Check the arguments against the schema of the called function.")
+            }
+            Self::InvalidResourceSpecifier(specifier) => Diagnostic::new(
+                "InvalidResourceSpecifier",
+                format!("{} is not a valid resource specifier.", specifier),
+            )
+            .with_help("Use `<address>:<amount>`, or `<address>:#1#,#2#` for non-fungibles."),
+            _ => Diagnostic::from_debug("ResimError", self),
+        }
+    }
 }
 
 impl fmt::Display for Error {
-    // TODO Implement pretty error printing
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:?}", self)
+        write!(f, "{}", self.diagnostic())
     }
 }
 
 impl From<Error> for String {
     fn from(err: Error) -> String {
-        err.to_string()
+        err.diagnostic().render()
     }
 }
 
@@ -156,6 +335,30 @@ impl Debug for Error {
             Self::TransactionAborted(reason) => {
                 f.debug_tuple("TransactionAborted").field(reason).finish()
             }
+            Self::TransactionNotFound(index) => {
+                f.debug_tuple("TransactionNotFound").field(index).finish()
+            }
+            Self::SnapshotNotFound(name) => f.debug_tuple("SnapshotNotFound").field(name).finish(),
+            Self::InvalidSnapshotName(name) => {
+                f.debug_tuple("InvalidSnapshotName").field(name).finish()
+            }
+            Self::InvalidKeystore => write!(f, "InvalidKeystore"),
+            Self::InvalidKeystorePassword => write!(f, "InvalidKeystorePassword"),
+            Self::KeyAliasNotFound(alias) => {
+                f.debug_tuple("KeyAliasNotFound").field(alias).finish()
+            }
+            Self::KeyAliasAlreadyExists(alias) => {
+                f.debug_tuple("KeyAliasAlreadyExists").field(alias).finish()
+            }
+            Self::InvalidKeyAlias(alias) => f.debug_tuple("InvalidKeyAlias").field(alias).finish(),
+            Self::InvalidAddressAlias(name) => {
+                f.debug_tuple("InvalidAddressAlias").field(name).finish()
+            }
+            Self::AddressError(err) => f.debug_tuple("AddressError").field(err).finish(),
+            Self::StateVersionNotAvailable(err) => f
+                .debug_tuple("StateVersionNotAvailable")
+                .field(err)
+                .finish(),
             Self::LedgerDumpError(err) => f.debug_tuple("LedgerDumpError").field(err).finish(),
             Self::DecompileError(err) => f.debug_tuple("DecompileError").field(err).finish(),
             Self::InvalidId(id) => f.debug_tuple("InvalidId").field(id).finish(),
@@ -180,6 +383,7 @@ impl Debug for Error {
             Self::RemoteGenericSubstitutionNotSupported => {
                 write!(f, "RemoteGenericSubstitutionNotSupported")
             }
+            Self::FlamegraphError(err) => f.debug_tuple("FlamegraphError").field(err).finish(),
         }
     }
 }
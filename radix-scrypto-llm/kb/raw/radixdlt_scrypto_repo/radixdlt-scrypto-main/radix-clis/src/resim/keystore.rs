@@ -0,0 +1,300 @@
+use crate::resim::*;
+use aes_gcm::aead::{Aead, KeyInit, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
+use radix_common::prelude::*;
+use rand::Rng;
+use std::env;
+use std::fs;
+use std::io::{BufRead, IsTerminal, Write};
+use std::path::PathBuf;
+
+/// The private keys of the simulator accounts by alias, encrypted with AES-256-GCM under a key
+/// derived from the keystore password with scrypt.
+#[derive(Debug, Clone, ScryptoSbor)]
+pub struct Keystore {
+    pub scrypt_log_n: u8,
+    pub scrypt_r: u32,
+    pub scrypt_p: u32,
+    pub salt: Vec<u8>,
+    /// An empty message encrypted under the password, to check the password against - only missing
+    /// from a new keystore, which takes the password its first key is inserted with.
+    pub password_check: Option<PasswordCheck>,
+    pub keys: BTreeMap<String, KeystoreEntry>,
+}
+
+/// The empty message encrypted to check the password of a keystore, with no associated data - which
+/// no key has, as aliases can't be empty.
+#[derive(Debug, Clone, ScryptoSbor)]
+pub struct PasswordCheck {
+    pub nonce: Vec<u8>,
+    pub encrypted_message: Vec<u8>,
+}
+
+/// A private key of the keystore, with its alias as the associated data of the encryption.
+#[derive(Debug, Clone, ScryptoSbor)]
+pub struct KeystoreEntry {
+    pub public_key: Secp256k1PublicKey,
+    pub nonce: Vec<u8>,
+    pub encrypted_private_key: Vec<u8>,
+}
+
+/// The cipher of an unlocked keystore.
+pub struct KeystoreCipher(Aes256Gcm);
+
+/// A keystore which was unlocked up front, so that keys can be resolved by their alias without
+/// prompting for the password - keys imported after it was loaded aren't part of it.
+pub struct UnlockedKeystore {
+    keystore: Keystore,
+    cipher: Option<KeystoreCipher>,
+}
+
+impl Default for Keystore {
+    fn default() -> Self {
+        Self {
+            scrypt_log_n: scrypt::Params::RECOMMENDED_LOG_N,
+            scrypt_r: scrypt::Params::RECOMMENDED_R,
+            scrypt_p: scrypt::Params::RECOMMENDED_P,
+            salt: rand::thread_rng().gen::<[u8; 16]>().to_vec(),
+            password_check: None,
+            keys: BTreeMap::new(),
+        }
+    }
+}
+
+impl Keystore {
+    /// Derives the cipher from the password, checking it against the password check of the keystore.
+    pub fn unlock(&self, password: &str) -> Result<KeystoreCipher, Error> {
+        let params = scrypt::Params::new(self.scrypt_log_n, self.scrypt_r, self.scrypt_p, 32)
+            .map_err(|_| Error::InvalidKeystore)?;
+        let mut key = [0u8; 32];
+        scrypt::scrypt(password.as_bytes(), &self.salt, &params, &mut key)
+            .map_err(|_| Error::InvalidKeystore)?;
+        let cipher = KeystoreCipher(Aes256Gcm::new_from_slice(&key).unwrap());
+        match &self.password_check {
+            Some(password_check) => {
+                cipher
+                    .0
+                    .decrypt(
+                        Nonce::from_slice(&password_check.nonce),
+                        Payload {
+                            msg: &password_check.encrypted_message,
+                            aad: &[],
+                        },
+                    )
+                    .map_err(|_| Error::InvalidKeystorePassword)?;
+            }
+            None if !self.keys.is_empty() => return Err(Error::InvalidKeystore),
+            None => {}
+        }
+        Ok(cipher)
+    }
+
+    pub fn public_key(&self, alias: &str) -> Option<Secp256k1PublicKey> {
+        self.keys.get(alias).map(|entry| entry.public_key)
+    }
+
+    pub fn private_key(
+        &self,
+        alias: &str,
+        cipher: &KeystoreCipher,
+    ) -> Result<Secp256k1PrivateKey, Error> {
+        let entry = self
+            .keys
+            .get(alias)
+            .ok_or_else(|| Error::KeyAliasNotFound(alias.to_owned()))?;
+        let bytes = cipher
+            .0
+            .decrypt(
+                Nonce::from_slice(&entry.nonce),
+                Payload {
+                    msg: &entry.encrypted_private_key,
+                    aad: alias.as_bytes(),
+                },
+            )
+            .map_err(|_| Error::InvalidKeystorePassword)?;
+        parse_private_key_from_bytes(&bytes)
+    }
+
+    /// Adds a private key under a new alias - or under an existing one, if it is the same key.
+    pub fn insert(
+        &mut self,
+        alias: &str,
+        private_key: &Secp256k1PrivateKey,
+        cipher: &KeystoreCipher,
+    ) -> Result<(), Error> {
+        validate_key_alias(alias)?;
+        match self.public_key(alias) {
+            Some(public_key) if public_key == private_key.public_key() => return Ok(()),
+            Some(_) => return Err(Error::KeyAliasAlreadyExists(alias.to_owned())),
+            None => {}
+        }
+        if self.password_check.is_none() {
+            let nonce = rand::thread_rng().gen::<[u8; 12]>();
+            let encrypted_message = cipher
+                .0
+                .encrypt(Nonce::from_slice(&nonce), Payload { msg: &[], aad: &[] })
+                .expect("Encryption of the password check can't fail");
+            self.password_check = Some(PasswordCheck {
+                nonce: nonce.to_vec(),
+                encrypted_message,
+            });
+        }
+        let nonce = rand::thread_rng().gen::<[u8; 12]>();
+        let encrypted_private_key = cipher
+            .0
+            .encrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: &private_key.to_bytes(),
+                    aad: alias.as_bytes(),
+                },
+            )
+            .expect("Encryption of a private key can't fail");
+        self.keys.insert(
+            alias.to_owned(),
+            KeystoreEntry {
+                public_key: private_key.public_key(),
+                nonce: nonce.to_vec(),
+                encrypted_private_key,
+            },
+        );
+        Ok(())
+    }
+}
+
+/// The keystore is kept next to the data directory rather than inside it, so that the keys survive
+/// a `resim reset` and aren't rolled back by `resim snapshot restore`.
+pub fn get_keystore_path() -> Result<PathBuf, Error> {
+    let data_dir = get_data_dir()?;
+    let mut file_name = data_dir
+        .file_name()
+        .map(|name| name.to_os_string())
+        .unwrap_or_default();
+    file_name.push("-keystore.sbor");
+    Ok(data_dir.with_file_name(file_name))
+}
+
+pub fn get_keystore() -> Result<Keystore, Error> {
+    let path = get_keystore_path()?;
+    if path.exists() {
+        scrypto_decode(&fs::read(&path).map_err(|err| Error::IOErrorAtPath(err, path))?)
+            .map_err(Error::SborDecodeError)
+    } else {
+        Ok(Keystore::default())
+    }
+}
+
+pub fn set_keystore(keystore: &Keystore) -> Result<(), Error> {
+    let path = get_keystore_path()?;
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    options
+        .open(&path)
+        .and_then(|mut file| file.write_all(&scrypto_encode(keystore).unwrap()))
+        .map_err(|err| Error::IOErrorAtPath(err, path))
+}
+
+/// Reads the keystore password from the `KEYSTORE_PASSWORD` environment variable, or prompts for
+/// it if that isn't set.
+pub fn get_keystore_password() -> Result<String, Error> {
+    match env::var(ENV_KEYSTORE_PASSWORD) {
+        Ok(password) => Ok(password),
+        Err(..) => rpassword::prompt_password("Keystore password: ").map_err(Error::IOError),
+    }
+}
+
+/// Reads a private key from the standard input if it is piped, or else prompts for it without
+/// echoing it - so that it doesn't end up in the shell history.
+pub fn read_private_key() -> Result<Secp256k1PrivateKey, Error> {
+    let stdin = std::io::stdin();
+    let private_key = if stdin.is_terminal() {
+        rpassword::prompt_password("Private key: ").map_err(Error::IOError)?
+    } else {
+        let mut line = String::new();
+        stdin.lock().read_line(&mut line).map_err(Error::IOError)?;
+        line
+    };
+    parse_private_key_from_str(private_key.trim())
+}
+
+/// Loads and unlocks the keystore, prompting for the password if needed.
+pub fn unlock_keystore() -> Result<(Keystore, KeystoreCipher), Error> {
+    let keystore = get_keystore()?;
+    let cipher = keystore.unlock(&get_keystore_password()?)?;
+    Ok((keystore, cipher))
+}
+
+/// Resolves keys given either as a keystore alias or as a hex encoded private key, only unlocking
+/// the keystore if an alias is given.
+pub fn resolve_private_keys(keys: &[&str]) -> Result<Vec<Secp256k1PrivateKey>, Error> {
+    let keystore = get_keystore()?;
+    let mut cipher = None;
+    let mut private_keys = Vec::new();
+    for key in keys {
+        let private_key = if keystore.keys.contains_key(*key) {
+            if cipher.is_none() {
+                cipher = Some(keystore.unlock(&get_keystore_password()?)?);
+            }
+            keystore.private_key(key, cipher.as_ref().unwrap())?
+        } else {
+            parse_private_key_or_unknown_alias(key)?
+        };
+        private_keys.push(private_key);
+    }
+    Ok(private_keys)
+}
+
+impl UnlockedKeystore {
+    /// Loads the keystore, prompting for the password if it has any keys.
+    pub fn load() -> Result<Self, Error> {
+        let keystore = get_keystore()?;
+        let cipher = if keystore.keys.is_empty() {
+            None
+        } else {
+            Some(keystore.unlock(&get_keystore_password()?)?)
+        };
+        Ok(Self { keystore, cipher })
+    }
+
+    /// Same as [`resolve_private_keys`], without ever prompting for the password.
+    pub fn resolve_private_keys(&self, keys: &[&str]) -> Result<Vec<Secp256k1PrivateKey>, Error> {
+        keys.iter()
+            .map(|key| match &self.cipher {
+                Some(cipher) if self.keystore.keys.contains_key(*key) => {
+                    self.keystore.private_key(key, cipher)
+                }
+                _ => parse_private_key_or_unknown_alias(key),
+            })
+            .collect()
+    }
+}
+
+/// Parses a hex encoded private key, reporting a key which looks like an alias as an unknown one.
+fn parse_private_key_or_unknown_alias(key: &str) -> Result<Secp256k1PrivateKey, Error> {
+    parse_private_key_from_str(key).map_err(|err| {
+        if is_valid_key_alias(key) && hex::decode(key).is_err() {
+            Error::KeyAliasNotFound(key.to_string())
+        } else {
+            err
+        }
+    })
+}
+
+/// Aliases may only contain letters, digits, `-` and `_`, so that they can't be confused with the
+/// separators of `--signing-keys`.
+fn is_valid_key_alias(alias: &str) -> bool {
+    !alias.is_empty()
+        && alias
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+pub fn validate_key_alias(alias: &str) -> Result<(), Error> {
+    if is_valid_key_alias(alias) {
+        Ok(())
+    } else {
+        Err(Error::InvalidKeyAlias(alias.to_owned()))
+    }
+}
@@ -1,8 +1,13 @@
 mod addressing;
+mod aliases;
+mod cmd_alias;
 mod cmd_call_function;
 mod cmd_call_method;
+mod cmd_events;
 mod cmd_export_package_definition;
 mod cmd_generate_key_pair;
+mod cmd_history;
+mod cmd_keys;
 mod cmd_mint;
 mod cmd_new_account;
 mod cmd_new_badge_fixed;
@@ -13,22 +18,33 @@ mod cmd_new_token_mutable;
 mod cmd_publish;
 mod cmd_reset;
 mod cmd_run;
+mod cmd_serve;
 mod cmd_set_current_epoch;
 mod cmd_set_current_time;
 mod cmd_set_default_account;
 mod cmd_show;
 mod cmd_show_configs;
 mod cmd_show_ledger;
+mod cmd_show_transaction;
+mod cmd_snapshot;
 mod cmd_transfer;
 mod config;
+mod cost_report;
 mod dumper;
 mod error;
+mod keystore;
+mod transaction_log;
 
 pub use addressing::*;
+pub use aliases::*;
+pub use cmd_alias::*;
 pub use cmd_call_function::CallFunction;
 pub use cmd_call_method::CallMethod;
+pub use cmd_events::*;
 pub use cmd_export_package_definition::*;
 pub use cmd_generate_key_pair::*;
+pub use cmd_history::*;
+pub use cmd_keys::*;
 pub use cmd_new_account::*;
 pub use cmd_new_badge_fixed::*;
 pub use cmd_new_badge_mutable::*;
@@ -38,20 +54,30 @@ pub use cmd_new_token_mutable::*;
 pub use cmd_publish::*;
 pub use cmd_reset::*;
 pub use cmd_run::*;
+pub use cmd_serve::*;
 pub use cmd_set_current_epoch::*;
 pub use cmd_set_current_time::*;
 pub use cmd_set_default_account::*;
 pub use cmd_show::*;
 pub use cmd_show_configs::*;
 pub use cmd_show_ledger::*;
+pub use cmd_show_transaction::*;
+pub use cmd_snapshot::*;
 pub use cmd_transfer::*;
 pub use config::*;
+pub use cost_report::*;
 pub use dumper::*;
 pub use error::*;
+pub use keystore::*;
+pub use transaction_log::*;
 
 pub const DEFAULT_SCRYPTO_DIR_UNDER_HOME: &'static str = ".scrypto";
 pub const ENV_DATA_DIR: &'static str = "DATA_DIR";
 pub const ENV_DISABLE_MANIFEST_OUTPUT: &'static str = "DISABLE_MANIFEST_OUTPUT";
+/// The password of the keystore - which is prompted for when it is needed, if not set.
+pub const ENV_KEYSTORE_PASSWORD: &'static str = "KEYSTORE_PASSWORD";
+/// The folder to cache the code prepared for the published packages in - nothing is cached if not set.
+pub const ENV_PREPARED_CODE_CACHE_DIR: &'static str = "PREPARED_CODE_CACHE_DIR";
 
 use crate::prelude::*;
 use clap::{Parser, Subcommand};
@@ -70,6 +96,10 @@ use radix_transactions::validation::TransactionValidator;
 pub struct ResimCli {
     #[clap(subcommand)]
     pub(crate) command: Command,
+
+    /// The format in which errors are reported [text | json]
+    #[clap(long, global = true, default_value = "text")]
+    pub(crate) error_format: ErrorFormat,
 }
 
 impl ResimCli {
@@ -80,10 +110,14 @@ impl ResimCli {
 
 #[derive(Subcommand, Debug)]
 pub enum Command {
+    Alias(Alias),
     CallFunction(CallFunction),
     CallMethod(CallMethod),
+    Events(Events),
     ExportPackageDefinition(ExportPackageDefinition),
     GenerateKeyPair(GenerateKeyPair),
+    History(History),
+    Keys(Keys),
     Mint(crate::resim::cmd_mint::Mint),
     NewAccount(NewAccount),
     NewSimpleBadge(NewSimpleBadge),
@@ -94,25 +128,33 @@ pub enum Command {
     Publish(Publish),
     Reset(Reset),
     Run(Run),
+    Serve(Serve),
     SetCurrentEpoch(SetCurrentEpoch),
     SetCurrentTime(SetCurrentTime),
     SetDefaultAccount(SetDefaultAccount),
     ShowConfigs(ShowConfigs),
     ShowLedger(ShowLedger),
+    ShowTransaction(ShowTransaction),
     Show(Show),
+    Snapshot(Snapshot),
     Transfer(Transfer),
 }
 
 pub fn run() -> Result<(), String> {
     let cli = ResimCli::parse();
+    set_error_format(cli.error_format);
 
     let mut out = std::io::stdout();
 
     match cli.command {
+        Command::Alias(cmd) => cmd.run(&mut out),
         Command::CallFunction(cmd) => cmd.run(&mut out),
         Command::CallMethod(cmd) => cmd.run(&mut out),
+        Command::Events(cmd) => cmd.run(&mut out),
         Command::ExportPackageDefinition(cmd) => cmd.run(&mut out),
         Command::GenerateKeyPair(cmd) => cmd.run(&mut out),
+        Command::History(cmd) => cmd.run(&mut out),
+        Command::Keys(cmd) => cmd.run(&mut out),
         Command::Mint(cmd) => cmd.run(&mut out),
         Command::NewAccount(cmd) => cmd.run(&mut out),
         Command::NewSimpleBadge(cmd) => cmd.run(&mut out).map(|_| ()),
@@ -123,12 +165,15 @@ pub fn run() -> Result<(), String> {
         Command::Publish(cmd) => cmd.run(&mut out),
         Command::Reset(cmd) => cmd.run(&mut out),
         Command::Run(cmd) => cmd.run(&mut out),
+        Command::Serve(cmd) => cmd.run(&mut out),
         Command::SetCurrentEpoch(cmd) => cmd.run(&mut out),
         Command::SetCurrentTime(cmd) => cmd.run(&mut out),
         Command::SetDefaultAccount(cmd) => cmd.run(&mut out),
         Command::ShowConfigs(cmd) => cmd.run(&mut out),
         Command::ShowLedger(cmd) => cmd.run(&mut out),
+        Command::ShowTransaction(cmd) => cmd.run(&mut out),
         Command::Show(cmd) => cmd.run(&mut out),
+        Command::Snapshot(cmd) => cmd.run(&mut out),
         Command::Transfer(cmd) => cmd.run(&mut out),
     }
 }
@@ -181,6 +226,33 @@ pub fn handle_manifest<O: std::io::Write>(
     trace: bool,
     print_receipt: bool,
     out: &mut O,
+) -> Result<Option<TransactionReceipt>, String> {
+    handle_manifest_with_cost_report(
+        manifest,
+        signing_keys,
+        network,
+        write_manifest,
+        trace,
+        print_receipt,
+        &CostReport::default(),
+        "",
+        out,
+    )
+}
+
+/// Same as [`handle_manifest`], but also writes the requested reports of the transaction costs,
+/// with the given title, after the receipt - whatever the transaction outcome.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_manifest_with_cost_report<O: std::io::Write>(
+    manifest: AnyManifest,
+    signing_keys: &Option<String>,
+    network: &Option<String>,
+    write_manifest: &Option<PathBuf>,
+    trace: bool,
+    print_receipt: bool,
+    cost_report: &CostReport,
+    title: &str,
+    out: &mut O,
 ) -> Result<Option<TransactionReceipt>, String> {
     let network = match network {
         Some(n) => NetworkDefinition::from_str(&n).map_err(Error::ParseNetworkError)?,
@@ -209,38 +281,28 @@ pub fn handle_manifest<O: std::io::Write>(
             Ok(None)
         }
         None => {
-            let SimulatorEnvironment {
-                mut db, vm_modules, ..
-            } = SimulatorEnvironment::new()?;
-
-            let sks = get_signing_keys(signing_keys)?;
-            let initial_proofs = sks
-                .into_iter()
-                .map(|e| NonFungibleGlobalId::from_public_key(&e.public_key()))
-                .collect::<BTreeSet<NonFungibleGlobalId>>();
-            let nonce = get_nonce()?;
-            let validator = TransactionValidator::new(&db, &NetworkDefinition::simulator());
-            let transaction =
-                TestTransaction::new_from_any_manifest(manifest, nonce, initial_proofs)?;
-
-            let receipt = execute_and_commit_transaction(
-                &mut db,
-                &vm_modules,
-                &ExecutionConfig::for_test_transaction().with_kernel_trace(trace),
-                transaction
-                    .into_executable(&validator)
-                    .map_err(Error::TransactionPrepareError)?,
-            );
+            let mut env = SimulatorEnvironment::new()?;
+
+            let receipt = execute_manifest(
+                &mut env,
+                manifest,
+                &get_signing_keys(signing_keys)?,
+                &cost_report.update_execution_config(
+                    ExecutionConfig::for_test_transaction().with_kernel_trace(trace),
+                ),
+                true,
+            )?;
 
             if print_receipt {
                 let encoder = AddressBech32Encoder::for_simulator();
                 let display_context = TransactionReceiptDisplayContextBuilder::new()
                     .encoder(&encoder)
-                    .schema_lookup_from_db(&db)
+                    .schema_lookup_from_db(&env.db)
                     .build();
                 writeln!(out, "{}", receipt.display(display_context)).map_err(Error::IOError)?;
             }
-            drop(db);
+            cost_report.write(&receipt, title, &network, out)?;
+            drop(env);
 
             process_receipt(receipt)
                 .map(Option::Some)
@@ -249,12 +311,42 @@ pub fn handle_manifest<O: std::io::Write>(
     }
 }
 
+/// Executes a manifest against the simulator ledger, signed by the given keys.
+///
+/// The result is only written to the database if `commit` is set. Unlike [`handle_manifest`],
+/// the receipt is returned as-is, whatever the transaction outcome, and the nonce is not bumped.
+pub fn execute_manifest(
+    env: &mut SimulatorEnvironment,
+    manifest: AnyManifest,
+    signing_keys: &[Secp256k1PrivateKey],
+    execution_config: &ExecutionConfig,
+    commit: bool,
+) -> Result<TransactionReceipt, String> {
+    let initial_proofs = signing_keys
+        .iter()
+        .map(|e| NonFungibleGlobalId::from_public_key(&e.public_key()))
+        .collect::<BTreeSet<NonFungibleGlobalId>>();
+    let nonce = get_nonce()?;
+    let validator = TransactionValidator::new(&env.db, &NetworkDefinition::simulator());
+    let transaction = TestTransaction::new_from_any_manifest(manifest, nonce, initial_proofs)?;
+    let executable = transaction
+        .into_executable(&validator)
+        .map_err(Error::TransactionPrepareError)?;
+
+    let receipt = if commit {
+        execute_and_commit_transaction(&mut env.db, &env.vm_modules, execution_config, executable)
+    } else {
+        execute_transaction(&env.db, &env.vm_modules, execution_config, executable)
+    };
+
+    Ok(receipt)
+}
+
 pub fn process_receipt(receipt: TransactionReceipt) -> Result<TransactionReceipt, Error> {
     match &receipt.result {
         TransactionResult::Commit(commit) => {
-            let mut configs = get_configs()?;
-            configs.nonce = get_nonce()? + 1;
-            set_configs(&configs)?;
+            append_to_transaction_log(&receipt)?;
+            increment_nonce()?;
 
             match &commit.outcome {
                 TransactionOutcome::Failure(error) => Err(Error::TransactionFailed(error.clone())),
@@ -278,13 +370,11 @@ pub fn parse_private_key_from_str(key: &str) -> Result<Secp256k1PrivateKey, Erro
         .and_then(|bytes| parse_private_key_from_bytes(&bytes))
 }
 
+/// Parses the comma separated signing keys, each either the alias of a key in the keystore or a
+/// hex encoded private key - or uses the default account key if none are given.
 pub fn get_signing_keys(signing_keys: &Option<String>) -> Result<Vec<Secp256k1PrivateKey>, Error> {
     let private_keys = if let Some(keys) = signing_keys {
-        keys.split(",")
-            .map(str::trim)
-            .filter(|s: &&str| !s.is_empty())
-            .map(parse_private_key_from_str)
-            .collect::<Result<Vec<Secp256k1PrivateKey>, Error>>()?
+        resolve_private_keys(&split_signing_keys(keys))?
     } else {
         vec![get_default_private_key()?]
     };
@@ -292,6 +382,29 @@ pub fn get_signing_keys(signing_keys: &Option<String>) -> Result<Vec<Secp256k1Pr
     Ok(private_keys)
 }
 
+/// Same as [`get_signing_keys`], resolving the key aliases with a keystore unlocked up front.
+pub fn get_signing_keys_from(
+    signing_keys: &Option<String>,
+    keystore: &UnlockedKeystore,
+) -> Result<Vec<Secp256k1PrivateKey>, Error> {
+    match signing_keys {
+        Some(keys) => keystore.resolve_private_keys(&split_signing_keys(keys)),
+        None => {
+            let default_key = get_configs()?
+                .default_key
+                .ok_or(Error::NoDefaultPrivateKey)?;
+            keystore.resolve_private_keys(&[default_key.as_str()])
+        }
+    }
+}
+
+fn split_signing_keys(keys: &str) -> Vec<&str> {
+    keys.split(",")
+        .map(str::trim)
+        .filter(|s: &&str| !s.is_empty())
+        .collect()
+}
+
 pub fn export_package_schema(
     package_address: PackageAddress,
 ) -> Result<BTreeMap<BlueprintVersionKey, BlueprintDefinition>, Error> {
@@ -434,9 +547,13 @@ mod tests {
             network: None,
             manifest: None,
             trace: false,
+            alias: None,
         };
         assert!(new_account.run(&mut out).is_ok());
-        let cmd = Show { address: None };
+        let cmd = Show {
+            address: None,
+            at_version: None,
+        };
         assert!(cmd.run(&mut out).is_ok());
     }
 
@@ -474,7 +591,7 @@ mod tests {
                     "account_sim1c9yeaya6pehau0fn7vgavuggeev64gahsh05dauae2uu25njk224xz",
                 )
                 .unwrap(),
-                private_key: key_string,
+                key: key_string,
                 owner_badge: SimulatorNonFungibleGlobalId::from_str(
                     "resource_sim1ngvrads4uj3rgq2v9s78fzhvry05dw95wzf3p9r8skhqusf44dlvmr:#1#",
                 )
@@ -486,10 +603,171 @@ mod tests {
         assert!(make_cmd(public_key.to_string()).run(&mut out).is_err());
     }
 
+    fn test_transaction_log() {
+        let mut out = std::io::stdout();
+        assert!(Reset {}.run(&mut out).is_ok());
+        assert_eq!(get_transaction_log_len().unwrap(), 0);
+        let new_account = NewAccount {
+            network: None,
+            manifest: None,
+            trace: false,
+            alias: None,
+        };
+        assert!(new_account.run(&mut out).is_ok());
+        let len = get_transaction_log_len().unwrap();
+        assert!(len > 0);
+
+        assert!(ShowTransaction { index: 0 }.run(&mut out).is_ok());
+        assert!(ShowTransaction { index: len }.run(&mut out).is_err());
+        let account = get_default_account().unwrap();
+        let log_index = get_transaction_log_index().unwrap();
+        assert_eq!(log_index.len, len);
+        assert_eq!(
+            log_index.by_account.get(account.as_node_id()),
+            Some(&vec![len - 1])
+        );
+        let history = History {
+            account: SimulatorComponentAddress(account),
+        };
+        assert!(history.run(&mut out).is_ok());
+        let events = Events {
+            emitter: None,
+            name: Some("DepositEvent".to_owned()),
+        };
+        assert!(events.run(&mut out).is_ok());
+    }
+
+    fn test_address_aliases() {
+        let mut out = std::io::stdout();
+        let account = get_default_account().unwrap();
+        let account_string = account
+            .display(&AddressBech32Encoder::for_simulator())
+            .to_string();
+        let set = Alias {
+            command: AliasCommand::Set {
+                name: "@resim-test".to_owned(),
+                address: account_string.clone(),
+            },
+        };
+        assert!(set.run(&mut out).is_ok());
+        assert_eq!(
+            SimulatorComponentAddress::from_str("@resim-test")
+                .unwrap()
+                .0,
+            account
+        );
+        assert!(SimulatorComponentAddress::from_str("@unknown-alias").is_err());
+        let aliases = get_address_aliases().unwrap();
+        assert_eq!(
+            aliases.resolve_in_manifest(
+                r#"CALL_METHOD Address("@resim-test") "@resim-test" # Address("@resim-test")"#
+            ),
+            Ok(format!(
+                r#"CALL_METHOD Address("{}") "@resim-test" # Address("@resim-test")"#,
+                account_string
+            ))
+        );
+        assert_eq!(
+            aliases.resolve_in_manifest(r#"Address("@unknown-alias")"#),
+            Err(AddressError::UnknownAlias("@unknown-alias".to_owned()))
+        );
+
+        let mut shown = Vec::new();
+        let show = Show {
+            address: Some("@resim-test".to_owned()),
+            at_version: None,
+        };
+        assert!(show.run(&mut shown).is_ok());
+        assert!(String::from_utf8(shown).unwrap().contains("(@resim-test)"));
+    }
+
+    fn test_snapshot() {
+        let mut out = std::io::stdout();
+        let snapshot = |command| Snapshot { command };
+        assert!(Reset {}.run(&mut out).is_ok());
+        let save = snapshot(SnapshotCommand::Save {
+            name: "resim-test".to_owned(),
+        });
+        assert!(save.run(&mut out).is_ok());
+
+        let new_account = NewAccount {
+            network: None,
+            manifest: None,
+            trace: false,
+            alias: None,
+        };
+        assert!(new_account.run(&mut out).is_ok());
+        assert!(get_configs().unwrap().default_account.is_some());
+
+        let restore = snapshot(SnapshotCommand::Restore {
+            name: "resim-test".to_owned(),
+        });
+        assert!(restore.run(&mut out).is_ok());
+        assert!(get_configs().unwrap().default_account.is_none());
+        assert_eq!(get_transaction_log_len().unwrap(), 0);
+        assert!(snapshot(SnapshotCommand::List).run(&mut out).is_ok());
+
+        let invalid = snapshot(SnapshotCommand::Save {
+            name: "../outside".to_owned(),
+        });
+        assert!(invalid.run(&mut out).is_err());
+    }
+
+    fn test_keystore() {
+        let mut out = std::io::stdout();
+        let private_key = Secp256k1PrivateKey::from_u64(1).unwrap();
+        assert!(get_keystore().unwrap().password_check.is_none());
+        assert!(import_key("resim-test", &private_key, &mut out).is_ok());
+        assert!(import_key(
+            "resim-test",
+            &Secp256k1PrivateKey::from_u64(2).unwrap(),
+            &mut out
+        )
+        .is_err());
+
+        let signing_keys = get_signing_keys(&Some("resim-test".to_owned())).unwrap();
+        assert_eq!(signing_keys[0].public_key(), private_key.public_key());
+        assert!(get_signing_keys(&Some("unknown-alias".to_owned())).is_err());
+
+        let unlocked_keystore = UnlockedKeystore::load().unwrap();
+        let signing_keys =
+            get_signing_keys_from(&Some("resim-test".to_owned()), &unlocked_keystore).unwrap();
+        assert_eq!(signing_keys[0].public_key(), private_key.public_key());
+        assert!(matches!(
+            get_signing_keys_from(&Some("unknown-alias".to_owned()), &unlocked_keystore),
+            Err(Error::KeyAliasNotFound(_))
+        ));
+
+        let mut exported = Vec::new();
+        let export = Keys {
+            command: KeysCommand::Export {
+                alias: "resim-test".to_owned(),
+            },
+        };
+        assert!(export.run(&mut exported).is_ok());
+        assert_eq!(
+            String::from_utf8(exported).unwrap().trim(),
+            private_key.to_hex()
+        );
+        assert!(matches!(
+            get_keystore().unwrap().unlock("wrong-password"),
+            Err(Error::InvalidKeystorePassword)
+        ));
+    }
+
     #[test]
     fn serial_resim_command_tests() {
+        // The tests share a data directory - and the keystore and snapshots next to it - which is
+        // removed afterwards
+        let temp_dir = tempfile::tempdir().unwrap();
+        env::set_var(ENV_DATA_DIR, temp_dir.path().join("data"));
+        env::set_var(ENV_KEYSTORE_PASSWORD, "resim-test");
         test_no_value();
         test_pre_process_manifest();
         test_set_default_account_validation();
+        test_transaction_log();
+        test_address_aliases();
+        test_snapshot();
+        test_keystore();
     }
 }
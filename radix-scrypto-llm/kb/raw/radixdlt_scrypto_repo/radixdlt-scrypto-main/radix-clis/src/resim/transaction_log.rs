@@ -0,0 +1,234 @@
+use crate::resim::*;
+use radix_common::prelude::*;
+use radix_engine::system::system_db_reader::SystemDatabaseReader;
+use radix_engine::transaction::*;
+use radix_engine_interface::prelude::*;
+use radix_substate_store_impls::rocks_db::RocksdbSubstateStore;
+use radix_substate_store_queries::typed_native_events::to_typed_native_event;
+use radix_transactions::model::TransactionCostingParametersReceiptV2;
+use sbor::representations::{DisplayMode, PrintMode, RustLikeOptions, ValueDisplayParameters};
+use std::fs;
+
+/// A committed transaction, as recorded in the resim transaction log.
+#[derive(Debug, Clone, ScryptoSbor)]
+pub struct TransactionLogEntry {
+    pub costing_parameters: CostingParameters,
+    pub transaction_costing_parameters: TransactionCostingParametersReceiptV2,
+    pub fee_summary: TransactionFeeSummary,
+    pub fee_details: Option<TransactionFeeDetails>,
+    pub commit_result: CommitResult,
+}
+
+impl TransactionLogEntry {
+    pub fn into_receipt(self) -> TransactionReceipt {
+        TransactionReceipt {
+            costing_parameters: self.costing_parameters,
+            transaction_costing_parameters: self.transaction_costing_parameters,
+            fee_summary: self.fee_summary,
+            fee_details: self.fee_details,
+            result: TransactionResult::Commit(self.commit_result),
+            resources_usage: None,
+            debug_information: None,
+            read_set: None,
+        }
+    }
+}
+
+/// The length of the transaction log, and the transactions involving each entity, so that the log
+/// doesn't have to be scanned to append to it or to find the transactions of an entity.
+#[derive(Debug, Clone, Default, ScryptoSbor)]
+pub struct TransactionLogIndex {
+    pub len: u64,
+    /// The transactions in which each entity emitted events (for function events, the package).
+    pub by_emitter: BTreeMap<NodeId, Vec<u64>>,
+    /// The transactions which created each account, or in which it emitted events.
+    pub by_account: BTreeMap<NodeId, Vec<u64>>,
+}
+
+impl TransactionLogIndex {
+    fn record(&mut self, index: u64, commit_result: &CommitResult) {
+        let emitters = commit_result
+            .application_events
+            .iter()
+            .map(|(EventTypeIdentifier(emitter, _), _)| get_emitter_node_id(emitter))
+            .collect::<BTreeSet<_>>();
+        let new_accounts = commit_result
+            .state_update_summary
+            .new_components
+            .iter()
+            .map(|address| address.into_node_id())
+            .filter(|node_id| node_id.is_global_account());
+        let accounts = emitters
+            .iter()
+            .copied()
+            .filter(|node_id| node_id.is_global_account())
+            .chain(new_accounts)
+            .collect::<BTreeSet<_>>();
+
+        for emitter in emitters {
+            self.by_emitter.entry(emitter).or_default().push(index);
+        }
+        for account in accounts {
+            self.by_account.entry(account).or_default().push(index);
+        }
+        self.len = index + 1;
+    }
+}
+
+fn get_transaction_log_entry_path(index: u64) -> Result<PathBuf, Error> {
+    let mut path = get_transaction_log_dir()?;
+    path.push(index.to_string());
+    Ok(path.with_extension("sbor"))
+}
+
+fn get_transaction_log_index_path() -> Result<PathBuf, Error> {
+    let mut path = get_transaction_log_dir()?;
+    path.push("index");
+    Ok(path.with_extension("sbor"))
+}
+
+/// Reads the index of the transaction log, rebuilding it from the entries if it's missing.
+pub fn get_transaction_log_index() -> Result<TransactionLogIndex, Error> {
+    let path = get_transaction_log_index_path()?;
+    if path.exists() {
+        let bytes = fs::read(&path).map_err(|err| Error::IOErrorAtPath(err, path))?;
+        return scrypto_decode(&bytes).map_err(Error::SborDecodeError);
+    }
+
+    let mut index = TransactionLogIndex::default();
+    while get_transaction_log_entry_path(index.len)?.exists() {
+        let entry = read_transaction_log_entry(index.len)?;
+        index.record(index.len, &entry.commit_result);
+    }
+    Ok(index)
+}
+
+fn set_transaction_log_index(index: &TransactionLogIndex) -> Result<(), Error> {
+    let path = get_transaction_log_index_path()?;
+    let bytes = scrypto_encode(index).map_err(Error::SborEncodeError)?;
+    fs::write(&path, bytes).map_err(|err| Error::IOErrorAtPath(err, path))
+}
+
+/// Returns the number of transactions in the log.
+pub fn get_transaction_log_len() -> Result<u64, Error> {
+    Ok(get_transaction_log_index()?.len)
+}
+
+/// Appends the transaction to the log and returns its index.
+///
+/// Only committed transactions are recorded, as rejected and aborted transactions leave the
+/// ledger untouched.
+pub fn append_to_transaction_log(receipt: &TransactionReceipt) -> Result<Option<u64>, Error> {
+    let commit_result = match &receipt.result {
+        TransactionResult::Commit(commit_result) => commit_result,
+        TransactionResult::Reject(_) | TransactionResult::Abort(_) => return Ok(None),
+    };
+    let entry = TransactionLogEntry {
+        costing_parameters: receipt.costing_parameters,
+        transaction_costing_parameters: receipt.transaction_costing_parameters.clone(),
+        fee_summary: receipt.fee_summary.clone(),
+        fee_details: receipt.fee_details.clone(),
+        commit_result: commit_result.clone(),
+    };
+
+    let mut log_index = get_transaction_log_index()?;
+    let index = log_index.len;
+    let path = get_transaction_log_entry_path(index)?;
+    let bytes = scrypto_encode(&entry).map_err(Error::SborEncodeError)?;
+    fs::write(&path, bytes).map_err(|err| Error::IOErrorAtPath(err, path))?;
+    log_index.record(index, commit_result);
+    set_transaction_log_index(&log_index)?;
+    Ok(Some(index))
+}
+
+pub fn read_transaction_log_entry(index: u64) -> Result<TransactionLogEntry, Error> {
+    let path = get_transaction_log_entry_path(index)?;
+    if !path.exists() {
+        return Err(Error::TransactionNotFound(index));
+    }
+    let bytes = fs::read(&path).map_err(|err| Error::IOErrorAtPath(err, path))?;
+    scrypto_decode(&bytes).map_err(Error::SborDecodeError)
+}
+
+/// Iterates through the transaction log, from the oldest transaction to the newest.
+pub fn iter_transaction_log(
+) -> Result<impl Iterator<Item = Result<(u64, TransactionLogEntry), Error>>, Error> {
+    let len = get_transaction_log_len()?;
+    Ok(iter_transaction_log_entries(0..len))
+}
+
+/// Iterates through the given transactions of the log, such as those of an index entry.
+pub fn iter_transaction_log_entries<I: IntoIterator<Item = u64>>(
+    indices: I,
+) -> impl Iterator<Item = Result<(u64, TransactionLogEntry), Error>> {
+    indices
+        .into_iter()
+        .map(|index| read_transaction_log_entry(index).map(|entry| (index, entry)))
+}
+
+/// Parses the address of an event emitter, which may be an `@<name>` alias.
+pub fn parse_emitter_address(address: &str) -> Result<NodeId, Error> {
+    AddressBech32Decoder::for_simulator()
+        .validate_and_decode(&resolve_address_alias(address).map_err(Error::AddressError)?)
+        .ok()
+        .and_then(|(_, bytes)| <[u8; NodeId::LENGTH]>::try_from(bytes.as_slice()).ok())
+        .map(NodeId::from)
+        .ok_or_else(|| Error::InvalidId(address.to_owned()))
+}
+
+/// Returns the node which emitted the event - for function events, this is the package.
+pub fn get_emitter_node_id(emitter: &Emitter) -> NodeId {
+    match emitter {
+        Emitter::Function(blueprint_id) => blueprint_id.package_address.into_node_id(),
+        Emitter::Method(node_id, _) => *node_id,
+    }
+}
+
+/// Formats the event data, decoding it as a typed native event where possible, and otherwise
+/// using the event schema from the ledger.
+pub fn format_event_data(
+    db: &RocksdbSubstateStore,
+    commit_result: &CommitResult,
+    event_type_identifier: &EventTypeIdentifier,
+    event_data: &[u8],
+) -> String {
+    if let Ok(event) = to_typed_native_event(event_type_identifier, event_data) {
+        return format!("{:?}", event);
+    }
+
+    let raw_value = match scrypto_decode::<ScryptoRawValue>(event_data) {
+        Ok(raw_value) => raw_value,
+        Err(err) => return format!("<undecodable event: {:?}>", err),
+    };
+    let encoder = AddressBech32Encoder::for_simulator();
+    let custom_context = ScryptoValueDisplayContext::with_optional_bech32(Some(&encoder));
+    let schema = commit_result
+        .system_structure
+        .event_system_structures
+        .get(event_type_identifier)
+        .and_then(|structure| {
+            let FullyScopedTypeId(package_address, schema_hash, type_id) =
+                &structure.package_type_reference.full_type_id;
+            SystemDatabaseReader::new(db)
+                .get_schema(package_address.as_node_id(), schema_hash)
+                .ok()
+                .map(|schema| (*type_id, schema))
+        });
+    let display_parameters = match &schema {
+        Some((type_id, schema)) => ValueDisplayParameters::Annotated {
+            display_mode: DisplayMode::RustLike(RustLikeOptions::full()),
+            print_mode: PrintMode::SingleLine,
+            custom_context,
+            schema: schema.v1(),
+            type_id: *type_id,
+            depth_limit: SCRYPTO_SBOR_V1_MAX_DEPTH,
+        },
+        None => ValueDisplayParameters::Schemaless {
+            display_mode: DisplayMode::RustLike(RustLikeOptions::full()),
+            print_mode: PrintMode::SingleLine,
+            custom_context,
+            depth_limit: SCRYPTO_SBOR_V1_MAX_DEPTH,
+        },
+    };
+    raw_value.display(display_parameters).to_string()
+}
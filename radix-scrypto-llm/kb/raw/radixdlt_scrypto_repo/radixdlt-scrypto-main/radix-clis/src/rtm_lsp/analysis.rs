@@ -0,0 +1,893 @@
+use super::protocol::*;
+use crate::prelude::*;
+use core::ops::ControlFlow;
+use radix_blueprint_schema_init::{FunctionSchemaInit, RefTypes, TypeRef};
+use radix_engine_interface::api::ModuleId;
+use radix_engine_interface::blueprints::package::BlueprintDefinitionInit;
+use radix_transactions::manifest::ast;
+use radix_transactions::manifest::lexer::tokenize;
+use radix_transactions::manifest::parser::{Parser, PARSER_MAX_DEPTH};
+use radix_transactions::manifest::token::{Position, Span, Token, TokenWithSpan};
+
+/// The value kinds which refer to a value by a name declared earlier in the manifest.
+const NAMED_VALUE_KINDS: [&str; 5] = [
+    "Bucket",
+    "Proof",
+    "AddressReservation",
+    "NamedAddress",
+    "NamedIntent",
+];
+
+/// The maximum depth to which nested types are spelled out in signatures.
+const MAX_TYPE_NAME_DEPTH: usize = 3;
+
+/// All instructions accepted by the manifest parser, including the aliases.
+pub const INSTRUCTION_IDENTS: [&str; 70] = [
+    // Pseudo-instructions
+    "USE_CHILD",
+    "USE_PREALLOCATED_ADDRESS",
+    // Bucket Lifecycle
+    TakeFromWorktop::IDENT,
+    TakeNonFungiblesFromWorktop::IDENT,
+    TakeAllFromWorktop::IDENT,
+    ReturnToWorktop::IDENT,
+    BurnResource::IDENT,
+    // Resource Assertions
+    AssertWorktopContains::IDENT,
+    AssertWorktopContainsNonFungibles::IDENT,
+    AssertWorktopContainsAny::IDENT,
+    "ASSERT_WORKTOP_IS_EMPTY",
+    AssertWorktopResourcesOnly::IDENT,
+    AssertWorktopResourcesInclude::IDENT,
+    AssertNextCallReturnsOnly::IDENT,
+    AssertNextCallReturnsInclude::IDENT,
+    AssertBucketContents::IDENT,
+    // Proof Lifecycle
+    CreateProofFromBucketOfAmount::IDENT,
+    CreateProofFromBucketOfNonFungibles::IDENT,
+    CreateProofFromBucketOfAll::IDENT,
+    CreateProofFromAuthZoneOfAmount::IDENT,
+    CreateProofFromAuthZoneOfNonFungibles::IDENT,
+    CreateProofFromAuthZoneOfAll::IDENT,
+    CloneProof::IDENT,
+    DropProof::IDENT,
+    PushToAuthZone::IDENT,
+    PopFromAuthZone::IDENT,
+    DropAuthZoneProofs::IDENT,
+    DropAuthZoneSignatureProofs::IDENT,
+    DropAuthZoneRegularProofs::IDENT,
+    DropNamedProofs::IDENT,
+    DropAllProofs::IDENT,
+    // Invocation
+    CallFunction::IDENT,
+    CallMethod::IDENT,
+    CallRoyaltyMethod::IDENT,
+    CallMetadataMethod::IDENT,
+    CallRoleAssignmentMethod::IDENT,
+    CallDirectVaultMethod::IDENT,
+    // Address Allocation
+    AllocateGlobalAddress::IDENT,
+    // Interaction with other intents
+    YieldToParent::IDENT,
+    YieldToChild::IDENT,
+    VerifyParent::IDENT,
+    // Call direct vault method aliases
+    "RECALL_FROM_VAULT",
+    "FREEZE_VAULT",
+    "UNFREEZE_VAULT",
+    "RECALL_NON_FUNGIBLES_FROM_VAULT",
+    // Call function aliases
+    "PUBLISH_PACKAGE",
+    "PUBLISH_PACKAGE_ADVANCED",
+    "CREATE_FUNGIBLE_RESOURCE",
+    "CREATE_FUNGIBLE_RESOURCE_WITH_INITIAL_SUPPLY",
+    "CREATE_NON_FUNGIBLE_RESOURCE",
+    "CREATE_NON_FUNGIBLE_RESOURCE_WITH_INITIAL_SUPPLY",
+    "CREATE_IDENTITY",
+    "CREATE_IDENTITY_ADVANCED",
+    "CREATE_ACCOUNT",
+    "CREATE_ACCOUNT_ADVANCED",
+    "CREATE_ACCESS_CONTROLLER",
+    // Call non-main-method aliases
+    "SET_METADATA",
+    "REMOVE_METADATA",
+    "LOCK_METADATA",
+    "SET_COMPONENT_ROYALTY",
+    "LOCK_COMPONENT_ROYALTY",
+    "CLAIM_COMPONENT_ROYALTIES",
+    "SET_OWNER_ROLE",
+    "LOCK_OWNER_ROLE",
+    "SET_ROLE",
+    // Call main-method aliases
+    "MINT_FUNGIBLE",
+    "MINT_NON_FUNGIBLE",
+    "MINT_RUID_NON_FUNGIBLE",
+    "CLAIM_PACKAGE_ROYALTIES",
+    "CREATE_VALIDATOR",
+];
+
+/// Converts between the character based positions of the manifest lexer and the UTF-16 based
+/// positions of the language server protocol.
+pub struct TextIndex {
+    lines: Vec<Vec<char>>,
+}
+
+impl TextIndex {
+    pub fn new(text: &str) -> Self {
+        Self {
+            lines: text
+                .split('\n')
+                .map(|line| line.chars().collect())
+                .collect(),
+        }
+    }
+
+    pub fn to_lsp_position(&self, position: Position) -> LspPosition {
+        let character: usize = self
+            .lines
+            .get(position.line_idx)
+            .map(|line| {
+                line.iter()
+                    .take(position.line_char_index)
+                    .map(|c| c.len_utf16())
+                    .sum()
+            })
+            .unwrap_or_default();
+        LspPosition {
+            line: position.line_idx as u32,
+            character: character as u32,
+        }
+    }
+
+    pub fn to_lsp_range(&self, span: Span) -> LspRange {
+        LspRange {
+            start: self.to_lsp_position(span.start),
+            end: self.to_lsp_position(span.end),
+        }
+    }
+
+    /// Returns the lexer position of the LSP position, clamped to the end of the document.
+    pub fn to_position(&self, position: LspPosition) -> Position {
+        let line_idx = (position.line as usize).min(self.lines.len() - 1);
+        let mut utf16_index = 0;
+        let mut line_char_index = 0;
+        for c in &self.lines[line_idx] {
+            if utf16_index >= position.character as usize {
+                break;
+            }
+            utf16_index += c.len_utf16();
+            line_char_index += 1;
+        }
+        let line_start: usize = self.lines[..line_idx]
+            .iter()
+            .map(|line| line.len() + 1)
+            .sum();
+        Position {
+            full_index: line_start + line_char_index,
+            line_idx,
+            line_char_index,
+        }
+    }
+}
+
+/// Provides the editor features of the language server for a single manifest.
+pub struct ManifestAnalyzer {
+    manifest_kind: ManifestKind,
+    network: NetworkDefinition,
+    address_bech32_decoder: AddressBech32Decoder,
+}
+
+impl ManifestAnalyzer {
+    pub fn new(manifest_kind: ManifestKind, network: NetworkDefinition) -> Self {
+        let address_bech32_decoder = AddressBech32Decoder::new(&network);
+        Self {
+            manifest_kind,
+            network,
+            address_bech32_decoder,
+        }
+    }
+
+    /// Compiles and validates the manifest, as `rtmc` would.
+    ///
+    /// Blobs are not available to the editor, so any blob hash is accepted.
+    pub fn diagnostics(&self, text: &str) -> Vec<LspDiagnostic> {
+        let index = TextIndex::new(text);
+        let manifest = match compile_any_manifest(
+            text,
+            self.manifest_kind,
+            &self.network,
+            MockBlobProvider::new(),
+        ) {
+            Ok(manifest) => manifest,
+            Err(err) => {
+                let (title, _) = err.title_and_label();
+                return vec![LspDiagnostic::error(index.to_lsp_range(err.span()), title)];
+            }
+        };
+
+        // The pseudo-instructions are compiled into the preamble of the manifest, so they are
+        // skipped when mapping instruction indices back to the source.
+        let instruction_spans = parse_instructions(text)
+            .into_iter()
+            .filter(|instruction| !is_pseudo_instruction(&instruction.instruction))
+            .map(|instruction| instruction.span)
+            .collect::<Vec<_>>();
+        let locate = |instruction_index: Option<usize>| {
+            instruction_index
+                .and_then(|instruction_index| instruction_spans.get(instruction_index))
+                .map(|span| index.to_lsp_range(*span))
+                .unwrap_or_default()
+        };
+
+        let mut diagnostics = Vec::new();
+        let mut locator = InstructionLocator::default();
+        if let Err(err) = StaticManifestInterpreter::new(ValidationRuleset::all(), &manifest)
+            .validate_and_apply_visitor(&mut locator)
+        {
+            let diagnostic = diagnose_manifest_validation_error(&err);
+            let message = match diagnostic.help {
+                Some(help) => format!("{}\n{}", diagnostic.message, help),
+                None => diagnostic.message,
+            };
+            diagnostics.push(LspDiagnostic::error(
+                locate(locator.current_instruction),
+                message,
+            ));
+        }
+        if let Err(err) = validate_call_arguments_to_native_components(&manifest) {
+            diagnostics.push(LspDiagnostic::error(
+                locate(Some(err.instruction_index)),
+                describe_instruction_schema_validation_error(&err.cause),
+            ));
+        }
+        diagnostics
+    }
+
+    /// Completes instruction names at the start of an instruction, the names of functions and
+    /// methods of native blueprints, and the names of buckets, proofs and other named values.
+    pub fn completions(&self, text: &str, position: LspPosition) -> Vec<LspCompletionItem> {
+        let cursor = TextIndex::new(text).to_position(position);
+        let prefix = text.chars().take(cursor.full_index).collect::<String>();
+
+        match find_open_string_start(&prefix) {
+            Some(string_start) => {
+                let before_string = prefix.chars().take(string_start).collect::<String>();
+                match tokenize(&before_string) {
+                    Ok(tokens) => self.string_completions(&tokens),
+                    Err(_) => Vec::new(),
+                }
+            }
+            None => {
+                let before_word =
+                    prefix.trim_end_matches(|c: char| c.is_ascii_alphanumeric() || c == '_');
+                let at_instruction_start = match tokenize(before_word) {
+                    Ok(tokens) => matches!(
+                        tokens.last().map(|token| &token.token),
+                        None | Some(Token::Semicolon)
+                    ),
+                    Err(_) => false,
+                };
+                if at_instruction_start {
+                    INSTRUCTION_IDENTS
+                        .iter()
+                        .map(|ident| LspCompletionItem {
+                            label: ident.to_string(),
+                            kind: COMPLETION_ITEM_KIND_KEYWORD,
+                            detail: None,
+                        })
+                        .collect()
+                } else {
+                    Vec::new()
+                }
+            }
+        }
+    }
+
+    fn string_completions(&self, tokens: &[TokenWithSpan]) -> Vec<LspCompletionItem> {
+        let token_kinds = tokens.iter().map(|token| &token.token).collect::<Vec<_>>();
+
+        if let [.., Token::Ident(kind), Token::OpenParenthesis] = token_kinds.as_slice() {
+            if NAMED_VALUE_KINDS.contains(&kind.as_str()) {
+                let mut names = index_set_new();
+                for reference in named_references(tokens) {
+                    if reference.kind == kind {
+                        names.insert(reference.name);
+                    }
+                }
+                return names
+                    .into_iter()
+                    .map(|name| LspCompletionItem {
+                        label: name.to_owned(),
+                        kind: COMPLETION_ITEM_KIND_VARIABLE,
+                        detail: Some(kind.clone()),
+                    })
+                    .collect();
+            }
+        }
+
+        let instruction_start = token_kinds
+            .iter()
+            .rposition(|token| **token == Token::Semicolon)
+            .map_or(0, |index| index + 1);
+        let (instruction, blueprint) = match &token_kinds[instruction_start..] {
+            [Token::Ident(instruction), Token::Ident(address_kind), Token::OpenParenthesis, Token::StringLiteral(address), Token::CloseParenthesis]
+                if address_kind == "Address" =>
+            {
+                (
+                    instruction.as_str(),
+                    self.resolve_method_blueprint(instruction, address),
+                )
+            }
+            [Token::Ident(instruction), Token::Ident(address_kind), Token::OpenParenthesis, Token::StringLiteral(package_address), Token::CloseParenthesis, Token::StringLiteral(blueprint)]
+                if instruction == CallFunction::IDENT && address_kind == "Address" =>
+            {
+                (
+                    instruction.as_str(),
+                    self.resolve_function_blueprint(package_address, blueprint),
+                )
+            }
+            _ => return Vec::new(),
+        };
+        let Some(blueprint) = blueprint else {
+            return Vec::new();
+        };
+
+        let kind = if instruction == CallFunction::IDENT {
+            COMPLETION_ITEM_KIND_FUNCTION
+        } else {
+            COMPLETION_ITEM_KIND_METHOD
+        };
+        let schema = blueprint.schema.schema.v1();
+        blueprint
+            .schema
+            .functions
+            .functions
+            .iter()
+            .filter(|(_, function_schema)| is_callable_by(function_schema, instruction))
+            .map(|(name, function_schema)| LspCompletionItem {
+                label: name.clone(),
+                kind,
+                detail: Some(function_signature(name, function_schema, schema)),
+            })
+            .collect()
+    }
+
+    /// Shows the signature of the native function or method called by the instruction.
+    pub fn hover(&self, text: &str, position: LspPosition) -> Option<LspHover> {
+        let index = TextIndex::new(text);
+        let cursor = index.to_position(position);
+        let instruction = parse_instructions(text)
+            .into_iter()
+            .find(|instruction| span_contains(&instruction.span, &cursor))?;
+        let signature = self.invocation_signature(&instruction.instruction)?;
+        Some(LspHover {
+            contents: LspMarkupContent {
+                kind: "markdown",
+                value: format!("```\n{}\n```", signature),
+            },
+            range: index.to_lsp_range(instruction.span),
+        })
+    }
+
+    /// Finds where the bucket, proof or other named value under the cursor is declared.
+    pub fn definition(&self, text: &str, position: LspPosition) -> Option<LspRange> {
+        let index = TextIndex::new(text);
+        let cursor = index.to_position(position);
+        let tokens = tokenize(text).ok()?;
+        let references = named_references(&tokens);
+        let reference = references
+            .iter()
+            .find(|reference| span_contains(&reference.span, &cursor))?;
+        // Names can't be used before they are declared, so the first reference is the declaration
+        let declaration = references
+            .iter()
+            .find(|other| other.kind == reference.kind && other.name == reference.name)?;
+        Some(index.to_lsp_range(declaration.name_span))
+    }
+
+    fn invocation_signature(&self, instruction: &ast::Instruction) -> Option<String> {
+        let (blueprint, name) = match instruction {
+            ast::Instruction::CallFunction {
+                package_address,
+                blueprint_name,
+                function_name,
+                ..
+            } => (
+                self.resolve_function_blueprint(
+                    static_address(package_address)?,
+                    string_value(blueprint_name)?,
+                )?,
+                string_value(function_name)?,
+            ),
+            ast::Instruction::CallMethod {
+                address,
+                method_name,
+                ..
+            } => (
+                self.resolve_method_blueprint(CallMethod::IDENT, static_address(address)?)?,
+                string_value(method_name)?,
+            ),
+            ast::Instruction::CallRoyaltyMethod {
+                address,
+                method_name,
+                ..
+            } => (
+                self.resolve_method_blueprint(CallRoyaltyMethod::IDENT, static_address(address)?)?,
+                string_value(method_name)?,
+            ),
+            ast::Instruction::CallMetadataMethod {
+                address,
+                method_name,
+                ..
+            } => (
+                self.resolve_method_blueprint(CallMetadataMethod::IDENT, static_address(address)?)?,
+                string_value(method_name)?,
+            ),
+            ast::Instruction::CallRoleAssignmentMethod {
+                address,
+                method_name,
+                ..
+            } => (
+                self.resolve_method_blueprint(
+                    CallRoleAssignmentMethod::IDENT,
+                    static_address(address)?,
+                )?,
+                string_value(method_name)?,
+            ),
+            ast::Instruction::CallDirectVaultMethod {
+                address,
+                method_name,
+                ..
+            } => (
+                self.resolve_method_blueprint(
+                    CallDirectVaultMethod::IDENT,
+                    static_address(address)?,
+                )?,
+                string_value(method_name)?,
+            ),
+            _ => return None,
+        };
+        let function_schema = blueprint.schema.functions.functions.get(name)?;
+        Some(function_signature(
+            name,
+            function_schema,
+            blueprint.schema.schema.v1(),
+        ))
+    }
+
+    /// Returns the native blueprint which handles the methods called by the instruction on the
+    /// address, if any.
+    fn resolve_method_blueprint(
+        &self,
+        instruction: &str,
+        address: &str,
+    ) -> Option<&'static BlueprintDefinitionInit> {
+        let module_id = match instruction {
+            CallMethod::IDENT | CallDirectVaultMethod::IDENT => ModuleId::Main,
+            CallRoyaltyMethod::IDENT => ModuleId::Royalty,
+            CallMetadataMethod::IDENT => ModuleId::Metadata,
+            CallRoleAssignmentMethod::IDENT => ModuleId::RoleAssignment,
+            _ => return None,
+        };
+        let entity_type = if instruction == CallDirectVaultMethod::IDENT {
+            InternalAddress::try_from_bech32(&self.address_bech32_decoder, address)?
+                .as_node_id()
+                .entity_type()?
+        } else {
+            GlobalAddress::try_from_bech32(&self.address_bech32_decoder, address)?
+                .as_node_id()
+                .entity_type()?
+        };
+        get_native_method_blueprint_definition(entity_type, module_id)
+    }
+
+    fn resolve_function_blueprint(
+        &self,
+        package_address: &str,
+        blueprint: &str,
+    ) -> Option<&'static BlueprintDefinitionInit> {
+        let package_address =
+            PackageAddress::try_from_bech32(&self.address_bech32_decoder, package_address)?;
+        get_native_function_blueprint_definition(package_address, blueprint)
+            .ok()
+            .flatten()
+    }
+}
+
+/// Tracks the instruction being validated, to locate validation errors in the source.
+#[derive(Default)]
+struct InstructionLocator {
+    current_instruction: Option<usize>,
+}
+
+impl ManifestInterpretationVisitor for InstructionLocator {
+    type Output = ManifestValidationError;
+
+    fn on_start_instruction(&mut self, details: OnStartInstruction) -> ControlFlow<Self::Output> {
+        self.current_instruction = Some(details.index);
+        ControlFlow::Continue(())
+    }
+}
+
+/// A reference to a named value, e.g. `Bucket("my_bucket")`.
+struct NamedReference<'t> {
+    kind: &'t str,
+    name: &'t str,
+    span: Span,
+    name_span: Span,
+}
+
+/// Finds the references to named values, in the order in which they appear in the manifest.
+fn named_references(tokens: &[TokenWithSpan]) -> Vec<NamedReference> {
+    tokens
+        .windows(4)
+        .filter_map(|window| match window {
+            [TokenWithSpan {
+                token: Token::Ident(kind),
+                span: kind_span,
+            }, TokenWithSpan {
+                token: Token::OpenParenthesis,
+                ..
+            }, TokenWithSpan {
+                token: Token::StringLiteral(name),
+                span: name_span,
+            }, TokenWithSpan {
+                token: Token::CloseParenthesis,
+                span: close_span,
+            }] if NAMED_VALUE_KINDS.contains(&kind.as_str()) => Some(NamedReference {
+                kind,
+                name,
+                span: Span {
+                    start: kind_span.start,
+                    end: close_span.end,
+                },
+                name_span: *name_span,
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Parses the instructions of the manifest up to the first error, so that hovering keeps working
+/// on the valid part of a manifest which is being edited.
+fn parse_instructions(text: &str) -> Vec<ast::InstructionWithSpan> {
+    let mut instructions = Vec::new();
+    let Ok(tokens) = tokenize(text) else {
+        return instructions;
+    };
+    let Ok(mut parser) = Parser::new(tokens, PARSER_MAX_DEPTH) else {
+        return instructions;
+    };
+    while !parser.is_eof() {
+        match parser.parse_instruction() {
+            Ok(instruction) => instructions.push(instruction),
+            Err(_) => break,
+        }
+    }
+    instructions
+}
+
+/// Returns the index of the quote opening the string which the text ends in, if any.
+fn find_open_string_start(text: &str) -> Option<usize> {
+    let mut string_start = None;
+    let mut in_comment = false;
+    let mut escaped = false;
+    for (index, c) in text.chars().enumerate() {
+        if in_comment {
+            in_comment = c != '\n';
+        } else if string_start.is_some() {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                string_start = None;
+            }
+        } else if c == '#' {
+            in_comment = true;
+        } else if c == '"' {
+            string_start = Some(index);
+        }
+    }
+    string_start
+}
+
+fn is_pseudo_instruction(instruction: &ast::Instruction) -> bool {
+    matches!(
+        instruction,
+        ast::Instruction::UseChild { .. } | ast::Instruction::UsePreallocatedAddress { .. }
+    )
+}
+
+fn span_contains(span: &Span, position: &Position) -> bool {
+    span.start.full_index <= position.full_index && position.full_index <= span.end.full_index
+}
+
+fn string_value(value: &ast::ValueWithSpan) -> Option<&str> {
+    match &value.value {
+        ast::Value::String(value) => Some(value.as_str()),
+        _ => None,
+    }
+}
+
+fn static_address(value: &ast::ValueWithSpan) -> Option<&str> {
+    match &value.value {
+        ast::Value::Address(inner) => string_value(inner),
+        _ => None,
+    }
+}
+
+fn is_callable_by(function_schema: &FunctionSchemaInit, instruction: &str) -> bool {
+    match (&function_schema.receiver, instruction) {
+        (None, CallFunction::IDENT) => true,
+        (Some(receiver), CallDirectVaultMethod::IDENT) => {
+            receiver.ref_types == RefTypes::DIRECT_ACCESS
+        }
+        (Some(receiver), instruction) => {
+            instruction != CallFunction::IDENT && receiver.ref_types == RefTypes::NORMAL
+        }
+        (None, _) => false,
+    }
+}
+
+fn describe_instruction_schema_validation_error(
+    error: &InstructionSchemaValidationError,
+) -> String {
+    match error {
+        InstructionSchemaValidationError::MethodNotFound(method) => {
+            format!(
+                "the native blueprint has no function or method '{}'",
+                method
+            )
+        }
+        InstructionSchemaValidationError::SchemaValidationError(message) => {
+            format!("the arguments do not match the schema: {}", message)
+        }
+        InstructionSchemaValidationError::InvalidAddress(_) => {
+            "the address is not valid for this call".to_owned()
+        }
+        InstructionSchemaValidationError::InvalidBlueprint(_, blueprint) => {
+            format!("the native package has no blueprint '{}'", blueprint)
+        }
+        InstructionSchemaValidationError::InvalidReceiver => {
+            "a function is called as a method, or a method is called as a function".to_owned()
+        }
+    }
+}
+
+/// Renders the signature of a function from its schema, e.g.
+/// `withdraw(resource_address: ResourceAddress, amount: Decimal) -> Bucket`.
+fn function_signature(
+    name: &str,
+    function_schema: &FunctionSchemaInit,
+    schema: &Schema<ScryptoCustomSchema>,
+) -> String {
+    let arguments = match function_schema.input {
+        TypeRef::Static(type_id) => {
+            let field_names = schema
+                .resolve_type_metadata(type_id)
+                .and_then(|metadata| metadata.get_field_names());
+            match schema.resolve_type_kind(type_id) {
+                Some(TypeKind::Tuple { field_types }) => field_types
+                    .iter()
+                    .enumerate()
+                    .map(|(index, field_type)| {
+                        let type_name = type_name(schema, *field_type, 0);
+                        match field_names.and_then(|field_names| field_names.get(index)) {
+                            Some(field_name) => format!("{}: {}", field_name, type_name),
+                            None => type_name,
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                _ => type_name(schema, type_id, 0),
+            }
+        }
+        TypeRef::Generic(_) => "..".to_owned(),
+    };
+    let output = match function_schema.output {
+        TypeRef::Static(type_id) => type_name(schema, type_id, 0),
+        TypeRef::Generic(_) => "Any".to_owned(),
+    };
+    format!("{}({}) -> {}", name, arguments, output)
+}
+
+fn type_name(schema: &Schema<ScryptoCustomSchema>, type_id: LocalTypeId, depth: usize) -> String {
+    if let Some(name) = schema.resolve_type_name_from_metadata(type_id) {
+        return name.to_owned();
+    }
+    match schema.resolve_type_kind(type_id) {
+        Some(TypeKind::Array { element_type }) if depth < MAX_TYPE_NAME_DEPTH => {
+            format!("Array<{}>", type_name(schema, *element_type, depth + 1))
+        }
+        Some(TypeKind::Map {
+            key_type,
+            value_type,
+        }) if depth < MAX_TYPE_NAME_DEPTH => format!(
+            "Map<{}, {}>",
+            type_name(schema, *key_type, depth + 1),
+            type_name(schema, *value_type, depth + 1)
+        ),
+        Some(TypeKind::Tuple { field_types }) if depth < MAX_TYPE_NAME_DEPTH => format!(
+            "({})",
+            field_types
+                .iter()
+                .map(|field_type| type_name(schema, *field_type, depth + 1))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Some(type_kind) => type_kind.category_name().to_owned(),
+        None => "Any".to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyzer() -> ManifestAnalyzer {
+        ManifestAnalyzer::new(ManifestKind::V2, NetworkDefinition::simulator())
+    }
+
+    fn account_address() -> String {
+        let public_key = Secp256k1PrivateKey::from_u64(1).unwrap().public_key();
+        ComponentAddress::preallocated_account_from_public_key(&public_key)
+            .to_string(&AddressBech32Encoder::for_simulator())
+    }
+
+    /// Returns the LSP position at the end of the text.
+    fn end_of(text: &str) -> LspPosition {
+        let index = TextIndex::new(text);
+        index.to_lsp_position(index.to_position(LspPosition {
+            line: u32::MAX,
+            character: u32::MAX,
+        }))
+    }
+
+    #[test]
+    fn test_text_index_round_trip() {
+        let text = "CALL_METHOD\n  \"\u{1F600}x\";";
+        let index = TextIndex::new(text);
+        let position = index.to_position(LspPosition {
+            line: 1,
+            character: 5,
+        });
+        assert_eq!(position.full_index, 16);
+        assert_eq!(position.line_char_index, 4);
+        assert_eq!(
+            index.to_lsp_position(position),
+            LspPosition {
+                line: 1,
+                character: 5
+            }
+        );
+    }
+
+    #[test]
+    fn test_diagnostics() {
+        assert_eq!(analyzer().diagnostics("DROP_ALL_PROOFS;"), vec![]);
+
+        let diagnostics = analyzer().diagnostics("DROP_ALL_PROOFS;\nTAKE_FROM_WORKTOP;");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].range.start.line, 1);
+
+        let manifest = format!(
+            "CALL_METHOD Address(\"{}\") \"withdraw\";\nRETURN_TO_WORKTOP Bucket(\"missing\");",
+            account_address()
+        );
+        let diagnostics = analyzer().diagnostics(&manifest);
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("missing"));
+    }
+
+    #[test]
+    fn test_native_call_diagnostics_are_located() {
+        let manifest = format!(
+            "DROP_ALL_PROOFS;\nCALL_METHOD Address(\"{}\") \"no_such_method\";",
+            account_address()
+        );
+        let diagnostics = analyzer().diagnostics(&manifest);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].range.start.line, 1);
+        assert!(diagnostics[0].message.contains("no_such_method"));
+    }
+
+    #[test]
+    fn test_instruction_completions() {
+        let text = "DROP_ALL_PROOFS;\nCALL_";
+        let labels = analyzer()
+            .completions(text, end_of(text))
+            .into_iter()
+            .map(|item| item.label)
+            .collect::<Vec<_>>();
+        assert!(labels.contains(&CallMethod::IDENT.to_owned()));
+
+        let text = "CALL_METHOD ";
+        assert_eq!(analyzer().completions(text, end_of(text)), vec![]);
+    }
+
+    #[test]
+    fn test_all_instruction_idents_are_parsed() {
+        for ident in INSTRUCTION_IDENTS {
+            assert!(
+                radix_transactions::manifest::parser::InstructionIdent::from_ident(ident).is_some(),
+                "{}",
+                ident
+            );
+        }
+    }
+
+    #[test]
+    fn test_method_completions() {
+        let text = format!("CALL_METHOD Address(\"{}\") \"wi", account_address());
+        let items = analyzer().completions(&text, end_of(&text));
+        let withdraw = items
+            .iter()
+            .find(|item| item.label == ACCOUNT_WITHDRAW_IDENT)
+            .unwrap();
+        assert_eq!(withdraw.kind, COMPLETION_ITEM_KIND_METHOD);
+        assert!(withdraw
+            .detail
+            .as_ref()
+            .unwrap()
+            .starts_with("withdraw(resource_address:"));
+        assert!(items.iter().all(|item| item.label != ACCOUNT_CREATE_IDENT));
+    }
+
+    #[test]
+    fn test_named_value_completions() {
+        let text = "TAKE_ALL_FROM_WORKTOP Address(\"x\") Bucket(\"first\");\nTAKE_ALL_FROM_WORKTOP Address(\"x\") Bucket(\"second\");\nRETURN_TO_WORKTOP Bucket(\"";
+        let labels = analyzer()
+            .completions(text, end_of(text))
+            .into_iter()
+            .map(|item| item.label)
+            .collect::<Vec<_>>();
+        assert_eq!(labels, vec!["first".to_owned(), "second".to_owned()]);
+    }
+
+    #[test]
+    fn test_hover() {
+        let text = format!(
+            "CALL_METHOD\n    Address(\"{}\")\n    \"lock_fee\"\n    Decimal(\"10\");",
+            account_address()
+        );
+        let hover = analyzer()
+            .hover(
+                &text,
+                LspPosition {
+                    line: 2,
+                    character: 7,
+                },
+            )
+            .unwrap();
+        assert!(hover.contents.value.contains("lock_fee(amount: Decimal)"));
+        assert_eq!(hover.range.start, LspPosition::default());
+    }
+
+    #[test]
+    fn test_definition() {
+        let text = "TAKE_ALL_FROM_WORKTOP Address(\"x\") Bucket(\"my_bucket\");\nRETURN_TO_WORKTOP Bucket(\"my_bucket\");";
+        let range = analyzer()
+            .definition(
+                text,
+                LspPosition {
+                    line: 1,
+                    character: 30,
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            range,
+            LspRange {
+                start: LspPosition {
+                    line: 0,
+                    character: 42
+                },
+                end: LspPosition {
+                    line: 0,
+                    character: 53
+                },
+            }
+        );
+    }
+}
@@ -0,0 +1,80 @@
+mod analysis;
+mod protocol;
+mod server;
+
+pub use analysis::*;
+pub use protocol::*;
+pub use server::*;
+
+use crate::prelude::*;
+
+/// Radix transaction manifest language server
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None, name = "rtm-lsp")]
+pub struct Args {
+    /// Network to Use [Simulator | Alphanet | Mainnet]
+    #[clap(short, long)]
+    network: Option<String>,
+
+    /// The manifest type [V1 | SystemV1 | V2 | SubintentV2], defaults to V2
+    #[clap(short, long)]
+    kind: Option<String>,
+
+    /// Communicate over stdin and stdout, which is the only supported transport. Accepted
+    /// because language clients pass it by default.
+    #[clap(long = "stdio")]
+    _stdio: bool,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    IOError(std::io::Error),
+    JsonError(serde_json::Error),
+    InvalidHeader(String),
+    MissingContentLength,
+    ParseNetworkError(ParseNetworkError),
+}
+
+impl Error {
+    pub fn diagnostic(&self) -> Diagnostic {
+        match self {
+            Error::IOError(err) => Diagnostic::new("IOError", err.to_string()),
+            Error::JsonError(err) => Diagnostic::new("JsonError", err.to_string()),
+            Error::InvalidHeader(header) => {
+                Diagnostic::new("InvalidHeader", format!("Invalid header: {}", header))
+            }
+            Error::MissingContentLength => Diagnostic::new(
+                "MissingContentLength",
+                "A message was sent without a Content-Length header",
+            ),
+            Error::ParseNetworkError(err) => Diagnostic::from_debug("ParseNetworkError", err)
+                .with_help("Supported networks include simulator, stokenet and mainnet."),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.diagnostic())
+    }
+}
+
+impl From<Error> for String {
+    fn from(err: Error) -> String {
+        err.diagnostic().render()
+    }
+}
+
+pub fn run() -> Result<(), String> {
+    let args = Args::parse();
+
+    let network = match args.network {
+        Some(n) => NetworkDefinition::from_str(&n).map_err(Error::ParseNetworkError)?,
+        None => NetworkDefinition::simulator(),
+    };
+    let manifest_kind = ManifestKind::parse_or_latest(args.kind.as_ref().map(|x| x.as_str()))?;
+
+    let mut server = Server::new(ManifestAnalyzer::new(manifest_kind, network));
+    server.serve(&mut std::io::stdin().lock(), &mut std::io::stdout().lock())?;
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use super::Error;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::io::{BufRead, Write};
+
+pub const COMPLETION_ITEM_KIND_METHOD: u32 = 2;
+pub const COMPLETION_ITEM_KIND_FUNCTION: u32 = 3;
+pub const COMPLETION_ITEM_KIND_VARIABLE: u32 = 6;
+pub const COMPLETION_ITEM_KIND_KEYWORD: u32 = 14;
+
+pub const DIAGNOSTIC_SEVERITY_ERROR: u32 = 1;
+
+pub const ERROR_CODE_INVALID_PARAMS: i64 = -32602;
+pub const ERROR_CODE_METHOD_NOT_FOUND: i64 = -32601;
+
+/// A position in a text document, where `character` counts UTF-16 code units.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LspPosition {
+    pub line: u32,
+    pub character: u32,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LspRange {
+    pub start: LspPosition,
+    pub end: LspPosition,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LspDiagnostic {
+    pub range: LspRange,
+    pub severity: u32,
+    pub source: &'static str,
+    pub message: String,
+}
+
+impl LspDiagnostic {
+    pub fn error(range: LspRange, message: String) -> Self {
+        Self {
+            range,
+            severity: DIAGNOSTIC_SEVERITY_ERROR,
+            source: "rtm-lsp",
+            message,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LspCompletionItem {
+    pub label: String,
+    pub kind: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LspMarkupContent {
+    pub kind: &'static str,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LspHover {
+    pub contents: LspMarkupContent,
+    pub range: LspRange,
+}
+
+/// Reads a JSON-RPC message framed by a `Content-Length` header, or returns `None` once the
+/// client has closed the input.
+pub fn read_message<R: BufRead>(reader: &mut R) -> Result<Option<Value>, Error> {
+    let mut content_length = None;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).map_err(Error::IOError)? == 0 {
+            return Ok(None);
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("Content-Length") {
+                let length = value
+                    .trim()
+                    .parse::<usize>()
+                    .map_err(|_| Error::InvalidHeader(line.to_owned()))?;
+                content_length = Some(length);
+            }
+        }
+    }
+
+    let content_length = content_length.ok_or(Error::MissingContentLength)?;
+    let mut content = vec![0u8; content_length];
+    reader.read_exact(&mut content).map_err(Error::IOError)?;
+    serde_json::from_slice(&content)
+        .map(Some)
+        .map_err(Error::JsonError)
+}
+
+pub fn write_message<W: Write>(writer: &mut W, message: &Value) -> Result<(), Error> {
+    let content = message.to_string();
+    write!(
+        writer,
+        "Content-Length: {}\r\n\r\n{}",
+        content.len(),
+        content
+    )
+    .map_err(Error::IOError)?;
+    writer.flush().map_err(Error::IOError)
+}
@@ -0,0 +1,181 @@
+use super::analysis::*;
+use super::protocol::*;
+use super::Error;
+use crate::prelude::*;
+use serde_json::{json, Value};
+use std::io::{BufRead, Write};
+
+/// `TextDocumentSyncKind.Full` - the client sends the whole document on every change.
+const TEXT_DOCUMENT_SYNC_FULL: u32 = 1;
+
+/// A language server for transaction manifests, speaking JSON-RPC over a reader and writer.
+pub struct Server {
+    analyzer: ManifestAnalyzer,
+    documents: IndexMap<String, String>,
+}
+
+impl Server {
+    pub fn new(analyzer: ManifestAnalyzer) -> Self {
+        Self {
+            analyzer,
+            documents: index_map_new(),
+        }
+    }
+
+    /// Serves requests until the client sends `exit` or closes the input.
+    pub fn serve<R: BufRead, W: Write>(
+        &mut self,
+        reader: &mut R,
+        writer: &mut W,
+    ) -> Result<(), Error> {
+        while let Some(message) = read_message(reader)? {
+            let Some(method) = message.get("method").and_then(Value::as_str) else {
+                // Responses to requests sent by the server - none are sent, so none are expected
+                continue;
+            };
+            if method == "exit" {
+                break;
+            }
+            let params = message.get("params").cloned().unwrap_or(Value::Null);
+
+            match message.get("id").cloned() {
+                Some(id) => {
+                    let response = match self.handle_request(method, &params) {
+                        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+                        Err((code, message)) => json!({
+                            "jsonrpc": "2.0",
+                            "id": id,
+                            "error": { "code": code, "message": message },
+                        }),
+                    };
+                    write_message(writer, &response)?;
+                }
+                None => {
+                    for notification in self.handle_notification(method, &params) {
+                        write_message(writer, &notification)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_request(&mut self, method: &str, params: &Value) -> Result<Value, (i64, String)> {
+        match method {
+            "initialize" => Ok(json!({
+                "capabilities": {
+                    "textDocumentSync": TEXT_DOCUMENT_SYNC_FULL,
+                    "completionProvider": { "triggerCharacters": ["\"", "("] },
+                    "hoverProvider": true,
+                    "definitionProvider": true,
+                },
+                "serverInfo": { "name": "rtm-lsp", "version": env!("CARGO_PKG_VERSION") },
+            })),
+            "shutdown" => Ok(Value::Null),
+            "textDocument/completion" => {
+                let (uri, position) = text_document_position(params)?;
+                let items = match self.documents.get(&uri) {
+                    Some(text) => self.analyzer.completions(text, position),
+                    None => Vec::new(),
+                };
+                Ok(json!(items))
+            }
+            "textDocument/hover" => {
+                let (uri, position) = text_document_position(params)?;
+                let hover = self
+                    .documents
+                    .get(&uri)
+                    .and_then(|text| self.analyzer.hover(text, position));
+                Ok(json!(hover))
+            }
+            "textDocument/definition" => {
+                let (uri, position) = text_document_position(params)?;
+                let location = self
+                    .documents
+                    .get(&uri)
+                    .and_then(|text| self.analyzer.definition(text, position))
+                    .map(|range| json!({ "uri": uri, "range": range }));
+                Ok(json!(location))
+            }
+            _ => Err((
+                ERROR_CODE_METHOD_NOT_FOUND,
+                format!("Unsupported method: {}", method),
+            )),
+        }
+    }
+
+    /// Handles a notification, returning the notifications to send back to the client.
+    fn handle_notification(&mut self, method: &str, params: &Value) -> Vec<Value> {
+        let uri = params
+            .pointer("/textDocument/uri")
+            .and_then(Value::as_str)
+            .map(str::to_owned);
+        match (method, uri) {
+            ("textDocument/didOpen", Some(uri)) => {
+                let text = params
+                    .pointer("/textDocument/text")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default();
+                self.documents.insert(uri.clone(), text.to_owned());
+                vec![self.publish_diagnostics(&uri)]
+            }
+            ("textDocument/didChange", Some(uri)) => {
+                // With full sync, the last change holds the whole document
+                let text = params
+                    .get("contentChanges")
+                    .and_then(Value::as_array)
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change.get("text"))
+                    .and_then(Value::as_str);
+                match text {
+                    Some(text) => {
+                        self.documents.insert(uri.clone(), text.to_owned());
+                        vec![self.publish_diagnostics(&uri)]
+                    }
+                    None => Vec::new(),
+                }
+            }
+            ("textDocument/didClose", Some(uri)) => {
+                self.documents.swap_remove(&uri);
+                // Clear the diagnostics of the closed document
+                vec![json!({
+                    "jsonrpc": "2.0",
+                    "method": "textDocument/publishDiagnostics",
+                    "params": { "uri": uri, "diagnostics": [] },
+                })]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn publish_diagnostics(&self, uri: &str) -> Value {
+        let diagnostics = self
+            .documents
+            .get(uri)
+            .map(|text| self.analyzer.diagnostics(text))
+            .unwrap_or_default();
+        json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/publishDiagnostics",
+            "params": { "uri": uri, "diagnostics": diagnostics },
+        })
+    }
+}
+
+fn text_document_position(params: &Value) -> Result<(String, LspPosition), (i64, String)> {
+    let uri = params
+        .pointer("/textDocument/uri")
+        .and_then(Value::as_str)
+        .ok_or_else(|| {
+            (
+                ERROR_CODE_INVALID_PARAMS,
+                "Missing textDocument.uri".to_owned(),
+            )
+        })?;
+    let position = params
+        .get("position")
+        .cloned()
+        .and_then(|position| serde_json::from_value(position).ok())
+        .ok_or_else(|| (ERROR_CODE_INVALID_PARAMS, "Missing position".to_owned()))?;
+    Ok((uri.to_owned(), position))
+}
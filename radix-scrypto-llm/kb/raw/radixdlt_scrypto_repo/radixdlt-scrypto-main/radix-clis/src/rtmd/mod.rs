@@ -19,6 +19,20 @@ pub struct Args {
     /// Input file
     #[clap(required = true)]
     input: PathBuf,
+
+    /// The format in which errors are reported [text | json]
+    #[clap(long, default_value = "text")]
+    error_format: ErrorFormat,
+
+    /// Print a summary of what the manifest does: the account withdrawals and deposits, the
+    /// assertions, the entities called, the proofs created and any resource flows which can't be
+    /// determined statically
+    #[clap(long)]
+    summary: bool,
+
+    /// The format in which the summary is printed [text | json]
+    #[clap(long, default_value = "text")]
+    summary_format: SummaryFormat,
 }
 
 #[derive(Debug)]
@@ -29,23 +43,46 @@ pub enum Error {
     ParseNetworkError(ParseNetworkError),
     ManifestValidationError(ManifestValidationError),
     InstructionSchemaValidationError(radix_engine::utils::LocatedInstructionSchemaValidationError),
+    StaticResourceMovementsError(
+        radix_transactions::manifest::static_resource_movements::StaticResourceMovementsError,
+    ),
+}
+
+impl Error {
+    pub fn diagnostic(&self) -> Diagnostic {
+        match self {
+            Error::IoError(err) => Diagnostic::new("IOError", err.to_string()),
+            Error::DecodeError(err) => Diagnostic::from_debug("DecodeError", err)
+                .with_help("Check the input is a compiled manifest, e.g. produced by `rtmc`."),
+            Error::DecompileError(err) => Diagnostic::from_debug("DecompileError", err),
+            Error::ParseNetworkError(err) => Diagnostic::from_debug("ParseNetworkError", err)
+                .with_help("Supported networks include simulator, stokenet and mainnet."),
+            Error::ManifestValidationError(err) => diagnose_manifest_validation_error(err),
+            Error::InstructionSchemaValidationError(err) => {
+                Diagnostic::from_debug("InstructionSchemaValidationError", err)
+            }
+            Error::StaticResourceMovementsError(err) => {
+                Diagnostic::from_debug("StaticResourceMovementsError", err)
+            }
+        }
+    }
 }
 
 impl fmt::Display for Error {
-    // TODO Implement pretty error printing
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:?}", self)
+        write!(f, "{}", self.diagnostic())
     }
 }
 
 impl From<Error> for String {
     fn from(err: Error) -> String {
-        err.to_string()
+        err.diagnostic().render()
     }
 }
 
 pub fn run() -> Result<(), String> {
     let args = Args::parse();
+    set_error_format(args.error_format);
 
     let content = std::fs::read(&args.input).map_err(Error::IoError)?;
     let network = match args.network {
@@ -62,6 +99,12 @@ pub fn run() -> Result<(), String> {
     validate_call_arguments_to_native_components(&manifest)
         .map_err(Error::InstructionSchemaValidationError)?;
 
+    if args.summary {
+        let summary = ManifestSummary::new(&manifest, &network)
+            .map_err(Error::StaticResourceMovementsError)?;
+        print!("{}", render_manifest_summary(&summary, args.summary_format));
+    }
+
     let decompiled = decompile_any(&manifest, &network).map_err(Error::DecompileError)?;
 
     write_ensuring_folder_exists(&args.output, &decompiled).map_err(Error::IoError)?;
@@ -0,0 +1,141 @@
+use crate::prelude::*;
+
+/// Radix transaction manifest formatter
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None, name = "rtmfmt")]
+pub struct Args {
+    /// The manifest files to format in place
+    #[clap(required = true, multiple = true)]
+    inputs: Vec<PathBuf>,
+
+    /// Check that the manifests are formatted without changing them, failing if any is not
+    #[clap(long)]
+    check: bool,
+
+    /// Name the addresses allocated by the manifests, and their address reservations, after their
+    /// blueprint, e.g. `NamedAddress("account")`
+    #[clap(long)]
+    name_addresses: bool,
+
+    /// Replace the names of buckets, proofs, address reservations, named addresses and named
+    /// intents with the default names given by the decompiler, e.g. `bucket1`
+    #[clap(long)]
+    reset_object_names: bool,
+
+    /// Network to Use [Simulator | Alphanet | Mainnet]
+    #[clap(short, long)]
+    network: Option<String>,
+
+    /// The manifest type [V1 | SystemV1 | V2 | SubintentV2], defaults to V2
+    #[clap(short, long)]
+    kind: Option<String>,
+
+    /// The format in which errors are reported [text | json]
+    #[clap(long, default_value = "text")]
+    error_format: ErrorFormat,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    IOErrorAtPath(std::io::Error, PathBuf),
+    ParseNetworkError(ParseNetworkError),
+    /// The compile error, rendered against the manifest it occurred in
+    CompileError(PathBuf, String),
+    DecompileError(PathBuf, DecompileError),
+    UnformattedManifests(Vec<PathBuf>),
+}
+
+impl Error {
+    pub fn diagnostic(&self) -> Diagnostic {
+        match self {
+            Error::IOErrorAtPath(err, path) => {
+                Diagnostic::new("IOError", format!("{}: {}", path.display(), err))
+            }
+            Error::ParseNetworkError(err) => Diagnostic::from_debug("ParseNetworkError", err)
+                .with_help("Supported networks include simulator, stokenet and mainnet."),
+            Error::CompileError(path, rendered) => Diagnostic::new(
+                "CompileError",
+                format!("Failed to compile {}", path.display()),
+            )
+            .with_details(rendered.clone()),
+            Error::DecompileError(path, err) => Diagnostic::new(
+                "DecompileError",
+                format!("Failed to format {}: {:?}", path.display(), err),
+            ),
+            Error::UnformattedManifests(paths) => Diagnostic::new(
+                "UnformattedManifests",
+                format!("{} manifest(s) are not formatted", paths.len()),
+            )
+            .with_details(
+                paths
+                    .iter()
+                    .map(|path| path.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            )
+            .with_help("Run rtmfmt without --check to format them."),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.diagnostic())
+    }
+}
+
+impl From<Error> for String {
+    fn from(err: Error) -> String {
+        err.diagnostic().render()
+    }
+}
+
+pub fn run() -> Result<(), String> {
+    let args = Args::parse();
+    set_error_format(args.error_format);
+
+    let network = match args.network {
+        Some(n) => NetworkDefinition::from_str(&n).map_err(Error::ParseNetworkError)?,
+        None => NetworkDefinition::simulator(),
+    };
+    let manifest_kind = ManifestKind::parse_or_latest(args.kind.as_ref().map(|x| x.as_str()))?;
+    let options = ManifestFormatterOptions {
+        name_addresses: args.name_addresses,
+        reset_object_names: args.reset_object_names,
+    };
+
+    let mut unformatted = Vec::new();
+    for input in args.inputs {
+        let content =
+            fs::read_to_string(&input).map_err(|err| Error::IOErrorAtPath(err, input.clone()))?;
+        let formatted =
+            format_any_manifest(&content, manifest_kind, &network, &options).map_err(|err| {
+                match err {
+                    ManifestFormatError::CompileError(err) => Error::CompileError(
+                        input.clone(),
+                        compile_error_diagnostics(
+                            &content,
+                            err,
+                            CompileErrorDiagnosticsStyle::PlainText,
+                        ),
+                    ),
+                    ManifestFormatError::DecompileError(err) => {
+                        Error::DecompileError(input.clone(), err)
+                    }
+                }
+            })?;
+        if formatted == content {
+            continue;
+        }
+        if args.check {
+            unformatted.push(input);
+        } else {
+            fs::write(&input, formatted).map_err(|err| Error::IOErrorAtPath(err, input.clone()))?;
+        }
+    }
+
+    if !unformatted.is_empty() {
+        return Err(Error::UnformattedManifests(unformatted).into());
+    }
+    Ok(())
+}
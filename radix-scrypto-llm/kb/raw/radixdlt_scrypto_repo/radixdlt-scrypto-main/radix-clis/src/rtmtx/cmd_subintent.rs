@@ -0,0 +1,291 @@
+use crate::prelude::*;
+use crate::rtmtx::*;
+use clap::Subcommand;
+use radix_transactions::signing::{RemoteSigner, SigningContext};
+use radix_transactions::validation::verify_and_recover;
+
+/// Build, sign or inspect a subintent, as a signed partial transaction
+#[derive(Parser, Debug)]
+pub struct Subintent {
+    #[clap(subcommand)]
+    pub command: SubintentCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum SubintentCommand {
+    Build(SubintentBuild),
+    Sign(SubintentSign),
+    Inspect(SubintentInspect),
+}
+
+impl Subintent {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), String> {
+        match &self.command {
+            SubintentCommand::Build(cmd) => cmd.run(out),
+            SubintentCommand::Sign(cmd) => cmd.run(out),
+            SubintentCommand::Inspect(cmd) => cmd.run(out),
+        }
+    }
+}
+
+/// Compile a subintent manifest into a signed partial transaction without root signatures,
+/// including the signed partial transactions of its children
+#[derive(Parser, Debug)]
+pub struct SubintentBuild {
+    /// The subintent manifest
+    pub input: PathBuf,
+
+    /// Path to the output file
+    #[clap(short, long)]
+    pub output: PathBuf,
+
+    #[clap(flatten)]
+    pub intent: IntentArgs,
+}
+
+impl SubintentBuild {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), String> {
+        let network = self.intent.network()?;
+        let content = fs::read_to_string(&self.input)
+            .map_err(|err| Error::IOErrorAtPath(err, self.input.clone()))?;
+        let manifest: SubintentManifestV2 = compile_manifest_with_pretty_error(
+            &content,
+            &network,
+            self.intent.blob_provider()?,
+            CompileErrorDiagnosticsStyle::TextTerminalColors,
+        )?;
+        manifest
+            .validate(ValidationRuleset::all())
+            .map_err(Error::ManifestValidationError)?;
+
+        let mut builder = PartialTransactionV2Builder::new();
+        for (path, child) in self.intent.children(&manifest.children, &network)? {
+            builder = builder.add_signed_child(path.display().to_string(), child);
+        }
+        let partial_transaction = builder
+            .intent_header(self.intent.intent_header(&network))
+            .message(self.intent.message())
+            .manifest(manifest)
+            .build_minimal();
+        let validated = validate_signed_partial_transaction(&partial_transaction, &network)?;
+
+        write_ensuring_folder_exists(
+            &self.output,
+            partial_transaction.to_raw().map_err(Error::EncodeError)?,
+        )
+        .map_err(|err| Error::IOErrorAtPath(err, self.output.clone()))?;
+        writeln!(
+            out,
+            "Subintent {} built.",
+            TransactionHashBech32Encoder::new(&network)
+                .encode(&validated.prepared.subintent_hash())
+                .unwrap()
+        )
+        .map_err(Error::IOError)?;
+        Ok(())
+    }
+}
+
+/// Sign the root subintent of a signed partial transaction, appending to its signatures
+#[derive(Parser, Debug)]
+pub struct SubintentSign {
+    /// The signed partial transaction
+    pub input: PathBuf,
+
+    /// The file with the hex encoded Secp256k1 private key to sign with
+    #[clap(short, long)]
+    pub key_file: Option<PathBuf>,
+
+    /// The Unix socket of a signing daemon to sign with, instead of a key file
+    #[clap(short, long)]
+    pub remote_signer: Option<PathBuf>,
+
+    /// Path to the output file, the input file by default
+    #[clap(short, long)]
+    pub output: Option<PathBuf>,
+
+    /// Network to Use [Simulator | Alphanet | Mainnet]
+    #[clap(short, long)]
+    pub network: Option<String>,
+}
+
+impl SubintentSign {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), String> {
+        let network = parse_network(&self.network)?;
+        let mut partial_transaction = read_signed_partial_transaction(&self.input)?;
+
+        let subintent_hash = partial_transaction
+            .prepare(PreparationSettings::latest_ref())
+            .map_err(Error::PrepareError)?
+            .subintent_hash();
+        let signature = match (&self.key_file, &self.remote_signer) {
+            (Some(key_file), None) => {
+                read_private_key(key_file)?.sign_with_public_key(&subintent_hash)
+            }
+            (None, Some(socket)) => RemoteSigner::connect(socket)
+                .and_then(|signer| {
+                    signer.sign_with_context(
+                        &subintent_hash,
+                        &SigningContext::for_subintent(
+                            &partial_transaction.partial_transaction.root_subintent,
+                            &network,
+                        ),
+                    )
+                })
+                .map_err(Error::RemoteSignerError)?,
+            _ => return Err(Error::MissingSigner.into()),
+        };
+        let signer = match verify_and_recover(subintent_hash.as_hash(), &signature) {
+            Some(PublicKey::Secp256k1(public_key)) => public_key.to_string(),
+            Some(PublicKey::Ed25519(public_key)) => public_key.to_string(),
+            None => return Err(Error::InvalidSignature.into()),
+        };
+        partial_transaction
+            .root_subintent_signatures
+            .signatures
+            .push(IntentSignatureV1(signature));
+        validate_signed_partial_transaction(&partial_transaction, &network)?;
+
+        let output = self.output.as_ref().unwrap_or(&self.input);
+        write_ensuring_folder_exists(
+            output,
+            partial_transaction.to_raw().map_err(Error::EncodeError)?,
+        )
+        .map_err(|err| Error::IOErrorAtPath(err, output.clone()))?;
+        writeln!(
+            out,
+            "Subintent {} signed by {}.",
+            TransactionHashBech32Encoder::new(&network)
+                .encode(&subintent_hash)
+                .unwrap(),
+            signer
+        )
+        .map_err(Error::IOError)?;
+        Ok(())
+    }
+}
+
+/// Print the tree of subintents of a signed partial transaction, with their signers, and whether
+/// it passes validation
+#[derive(Parser, Debug)]
+pub struct SubintentInspect {
+    /// The signed partial transaction
+    pub input: PathBuf,
+
+    /// Network to Use [Simulator | Alphanet | Mainnet]
+    #[clap(short, long)]
+    pub network: Option<String>,
+}
+
+impl SubintentInspect {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), String> {
+        let network = parse_network(&self.network)?;
+        let partial_transaction = read_signed_partial_transaction(&self.input)?;
+        let prepared = partial_transaction
+            .prepare(PreparationSettings::latest_ref())
+            .map_err(Error::PrepareError)?;
+
+        let mut subintents = index_map_new();
+        subintents.insert(
+            prepared.subintent_hash(),
+            (
+                &partial_transaction.partial_transaction.root_subintent,
+                Some(&partial_transaction.root_subintent_signatures),
+            ),
+        );
+        for (index, hash) in prepared.non_root_subintent_hashes().enumerate() {
+            subintents.insert(
+                hash,
+                (
+                    &partial_transaction
+                        .partial_transaction
+                        .non_root_subintents
+                        .0[index],
+                    partial_transaction
+                        .non_root_subintent_signatures
+                        .by_subintent
+                        .get(index),
+                ),
+            );
+        }
+
+        let tree = SubintentTree {
+            subintents,
+            encoder: TransactionHashBech32Encoder::new(&network),
+        };
+        tree.write(out, &prepared.subintent_hash(), "")
+            .map_err(Error::IOError)?;
+        match validate_signed_partial_transaction(&partial_transaction, &network) {
+            Ok(_) => writeln!(out, "Validation: OK"),
+            Err(err) => writeln!(out, "Validation: {}", err.diagnostic().message),
+        }
+        .map_err(Error::IOError)?;
+        Ok(())
+    }
+}
+
+struct SubintentTree<'a> {
+    subintents: IndexMap<SubintentHash, (&'a SubintentV2, Option<&'a IntentSignaturesV2>)>,
+    encoder: TransactionHashBech32Encoder,
+}
+
+impl<'a> SubintentTree<'a> {
+    fn write<O: std::io::Write>(
+        &self,
+        out: &mut O,
+        hash: &SubintentHash,
+        indent: &str,
+    ) -> std::io::Result<()> {
+        writeln!(out, "Subintent {}", self.encoder.encode(hash).unwrap())?;
+        let Some((subintent, signatures)) = self.subintents.get(hash) else {
+            return writeln!(
+                out,
+                "{}{} Missing from the partial transaction",
+                indent,
+                list_item_prefix(true)
+            );
+        };
+        let intent_core = &subintent.intent_core;
+
+        writeln!(
+            out,
+            "{}{} Epochs: [{}, {})",
+            indent,
+            list_item_prefix(false),
+            intent_core.header.start_epoch_inclusive.number(),
+            intent_core.header.end_epoch_exclusive.number()
+        )?;
+
+        let signatures = signatures.map_or(&[][..], |signatures| &signatures.signatures[..]);
+        writeln!(
+            out,
+            "{}{} Signers: {}",
+            indent,
+            list_item_prefix(false),
+            signatures.len()
+        )?;
+        for (last, signature) in signatures.iter().identify_last() {
+            let signer = match verify_and_recover(hash.as_hash(), &signature.0) {
+                Some(PublicKey::Secp256k1(public_key)) => format!("Secp256k1 {}", public_key),
+                Some(PublicKey::Ed25519(public_key)) => format!("Ed25519 {}", public_key),
+                None => "Invalid signature".to_owned(),
+            };
+            writeln!(out, "{}│  {} {}", indent, list_item_prefix(last), signer)?;
+        }
+
+        let children = &intent_core.children.children;
+        writeln!(
+            out,
+            "{}{} Children: {}",
+            indent,
+            list_item_prefix(true),
+            children.len()
+        )?;
+        for (last, child) in children.iter().identify_last() {
+            write!(out, "{}   {} ", indent, list_item_prefix(last))?;
+            let child_indent = format!("{}   {}", indent, if last { "   " } else { "│  " });
+            self.write(out, &child.hash, &child_indent)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,161 @@
+use crate::prelude::*;
+use crate::rtmtx::*;
+use clap::Subcommand;
+use radix_transactions::validation::TransactionValidator;
+
+/// Compose a V2 transaction
+#[derive(Parser, Debug)]
+pub struct Transaction {
+    #[clap(subcommand)]
+    pub command: TransactionCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum TransactionCommand {
+    Compose(TransactionCompose),
+}
+
+impl Transaction {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), String> {
+        match &self.command {
+            TransactionCommand::Compose(cmd) => cmd.run(out),
+        }
+    }
+}
+
+/// Compile a transaction manifest into a transaction intent, including the signed partial
+/// transactions of its children, and sign it.
+///
+/// With the notary key file, the transaction is notarized and written as a raw notarized
+/// transaction. With only the notary public key, the signed transaction intent is written for the
+/// notary to notarize.
+#[derive(Parser, Debug)]
+pub struct TransactionCompose {
+    /// The transaction manifest
+    pub input: PathBuf,
+
+    /// Path to the output file
+    #[clap(short, long)]
+    pub output: PathBuf,
+
+    #[clap(flatten)]
+    pub intent: IntentArgs,
+
+    /// The files with the hex encoded Secp256k1 private keys to sign the transaction intent with
+    #[clap(short, long, multiple = true)]
+    pub signer_key_files: Option<Vec<PathBuf>>,
+
+    /// The file with the hex encoded Secp256k1 private key of the notary
+    #[clap(long)]
+    pub notary_key_file: Option<PathBuf>,
+
+    /// The hex encoded Secp256k1 public key of the notary, if its private key isn't available
+    #[clap(long)]
+    pub notary_public_key: Option<String>,
+
+    /// Whether the notary's signature counts as a signature of the transaction intent
+    #[clap(long)]
+    pub notary_is_signatory: bool,
+
+    /// The tip to the validator, in basis points of the execution and finalization costs
+    #[clap(long, default_value = "0")]
+    pub tip_basis_points: u32,
+}
+
+impl TransactionCompose {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), String> {
+        let network = self.intent.network()?;
+        let content = fs::read_to_string(&self.input)
+            .map_err(|err| Error::IOErrorAtPath(err, self.input.clone()))?;
+        let manifest: TransactionManifestV2 = compile_manifest_with_pretty_error(
+            &content,
+            &network,
+            self.intent.blob_provider()?,
+            CompileErrorDiagnosticsStyle::TextTerminalColors,
+        )?;
+        manifest
+            .validate(ValidationRuleset::all())
+            .map_err(Error::ManifestValidationError)?;
+
+        let notary_private_key = match &self.notary_key_file {
+            Some(path) => Some(read_private_key(path)?),
+            None => None,
+        };
+        let notary_public_key = match (&notary_private_key, &self.notary_public_key) {
+            (Some(private_key), None) => private_key.public_key(),
+            (None, Some(public_key)) => Secp256k1PublicKey::from_str(public_key)
+                .map_err(|_| Error::InvalidPublicKey(public_key.clone()))?,
+            _ => return Err(Error::MissingNotary.into()),
+        };
+        let signers = self
+            .signer_key_files
+            .iter()
+            .flatten()
+            .map(|path| read_private_key(path))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut builder = TransactionV2Builder::new();
+        for (path, child) in self.intent.children(&manifest.children, &network)? {
+            builder = builder.add_signed_child(path.display().to_string(), child);
+        }
+        let mut builder = builder
+            .transaction_header(TransactionHeaderV2 {
+                notary_public_key: notary_public_key.into(),
+                notary_is_signatory: self.notary_is_signatory,
+                tip_basis_points: self.tip_basis_points,
+            })
+            .intent_header(self.intent.intent_header(&network))
+            .message(self.intent.message())
+            .manifest(manifest)
+            .multi_sign(&signers);
+        let encoder = TransactionHashBech32Encoder::new(&network);
+        let intent_hash = encoder.encode(&builder.intent_hash()).unwrap();
+        let validator = TransactionValidator::new_with_latest_config(&network);
+
+        match notary_private_key {
+            Some(notary_private_key) => {
+                let transaction = builder
+                    .notarize(&notary_private_key)
+                    .build_minimal_no_validate();
+                transaction
+                    .prepare_and_validate(&validator)
+                    .map_err(Error::TransactionValidationError)?;
+                write_ensuring_folder_exists(
+                    &self.output,
+                    transaction.to_raw().map_err(Error::EncodeError)?,
+                )
+                .map_err(|err| Error::IOErrorAtPath(err, self.output.clone()))?;
+                writeln!(out, "Transaction {} composed and notarized.", intent_hash)
+                    .map_err(Error::IOError)?;
+            }
+            None => {
+                // Without the notary signature, the transaction is validated as a preview - with
+                // the children validated when they were read.
+                let mut signer_public_keys: Vec<PublicKey> = signers
+                    .iter()
+                    .map(|signer| signer.public_key().into())
+                    .collect();
+                if self.notary_is_signatory {
+                    signer_public_keys.push(notary_public_key.into());
+                }
+                builder
+                    .build_preview_transaction_no_validate(signer_public_keys)
+                    .prepare_and_validate(&validator)
+                    .map_err(Error::TransactionValidationError)?;
+                let signed_intent = builder.create_signed_transaction_intent();
+                write_ensuring_folder_exists(
+                    &self.output,
+                    signed_intent.to_raw().map_err(Error::EncodeError)?,
+                )
+                .map_err(|err| Error::IOErrorAtPath(err, self.output.clone()))?;
+                writeln!(
+                    out,
+                    "Transaction {} composed, to be notarized by {}.",
+                    intent_hash, notary_public_key
+                )
+                .map_err(Error::IOError)?;
+            }
+        }
+        Ok(())
+    }
+}
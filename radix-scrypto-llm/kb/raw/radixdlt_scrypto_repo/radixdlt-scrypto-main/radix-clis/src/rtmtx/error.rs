@@ -0,0 +1,102 @@
+use crate::prelude::*;
+use radix_transactions::errors::*;
+use radix_transactions::signing::RemoteSignerError;
+
+#[derive(Debug)]
+pub enum Error {
+    IOError(std::io::Error),
+    IOErrorAtPath(std::io::Error, PathBuf),
+    ParseNetworkError(ParseNetworkError),
+    EncodeError(sbor::EncodeError),
+    DecodeError(PathBuf, sbor::DecodeError),
+    PrepareError(PrepareError),
+    InvalidPrivateKey(PathBuf),
+    InvalidPublicKey(String),
+    ManifestValidationError(ManifestValidationError),
+    TransactionValidationError(TransactionValidationError),
+    /// A child used by the manifest, given as its bech32 encoded subintent hash, has no signed
+    /// partial transaction.
+    MissingChild(String),
+    /// A signed partial transaction is not a child used by the manifest.
+    UnusedChild(PathBuf),
+    MissingNotary,
+    MissingSigner,
+    InvalidSignature,
+    RemoteSignerError(RemoteSignerError),
+}
+
+impl Error {
+    pub fn diagnostic(&self) -> Diagnostic {
+        match self {
+            Error::IOError(err) => Diagnostic::new("IOError", err.to_string()),
+            Error::IOErrorAtPath(err, path) => {
+                Diagnostic::new("IOError", format!("{}: {}", path.display(), err))
+            }
+            Error::ParseNetworkError(err) => Diagnostic::from_debug("ParseNetworkError", err)
+                .with_help("Supported networks include simulator, stokenet and mainnet."),
+            Error::EncodeError(err) => Diagnostic::from_debug("EncodeError", err),
+            Error::DecodeError(path, err) => Diagnostic::new(
+                "DecodeError",
+                format!("{} could not be decoded: {:?}", path.display(), err),
+            )
+            .with_help("Check the file was written by `rtmtx`, and is of the expected kind."),
+            Error::PrepareError(err) => Diagnostic::from_debug("PrepareError", err),
+            Error::InvalidPrivateKey(path) => Diagnostic::new(
+                "InvalidPrivateKey",
+                format!("The key file {} has no valid private key.", path.display()),
+            )
+            .with_help(
+                "Key files hold a Secp256k1 private key as 64 hex characters, e.g. from `resim generate-key-pair`.",
+            ),
+            Error::InvalidPublicKey(key) => Diagnostic::new(
+                "InvalidPublicKey",
+                format!("The public key {} is invalid.", key),
+            )
+            .with_help("Public keys are given as 66 hex characters of a compressed Secp256k1 key."),
+            Error::ManifestValidationError(err) => diagnose_manifest_validation_error(err),
+            Error::TransactionValidationError(err) => diagnose_transaction_validation_error(err),
+            Error::MissingChild(hash) => Diagnostic::new(
+                "MissingChild",
+                format!("The manifest uses the child {}, which wasn't given.", hash),
+            )
+            .with_help("Pass the signed partial transaction of every child with `--children`."),
+            Error::UnusedChild(path) => Diagnostic::new(
+                "UnusedChild",
+                format!("The child {} is not used by the manifest.", path.display()),
+            )
+            .with_help("Add a `USE_CHILD` instruction with its subintent hash to the manifest."),
+            Error::MissingNotary => Diagnostic::new(
+                "MissingNotary",
+                "Exactly one of the notary key file and the notary public key must be given.",
+            )
+            .with_help(
+                "Pass `--notary-key-file` to notarize the transaction, or `--notary-public-key` to leave it for the notary.",
+            ),
+            Error::MissingSigner => Diagnostic::new(
+                "MissingSigner",
+                "Exactly one of the key file and the remote signer must be given.",
+            )
+            .with_help(
+                "Pass `--key-file` to sign with a local key, or `--remote-signer` to sign with a signing daemon.",
+            ),
+            Error::InvalidSignature => Diagnostic::new(
+                "InvalidSignature",
+                "The signature doesn't match the subintent hash.",
+            ),
+            Error::RemoteSignerError(err) => Diagnostic::new("RemoteSignerError", err.to_string())
+                .with_help("Check the signing daemon is running, and listening on the socket."),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.diagnostic())
+    }
+}
+
+impl From<Error> for String {
+    fn from(err: Error) -> String {
+        err.diagnostic().render()
+    }
+}
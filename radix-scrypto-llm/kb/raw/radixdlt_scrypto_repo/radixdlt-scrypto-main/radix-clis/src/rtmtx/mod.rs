@@ -0,0 +1,278 @@
+mod cmd_subintent;
+mod cmd_transaction;
+mod error;
+
+pub use cmd_subintent::*;
+pub use cmd_transaction::*;
+pub use error::*;
+
+use crate::prelude::*;
+use clap::Subcommand;
+use radix_transactions::validation::TransactionValidator;
+
+/// Build, sign and inspect subintents, and compose them into V2 transactions
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None, name = "rtmtx")]
+pub struct RtmtxCli {
+    #[clap(subcommand)]
+    command: Command,
+
+    /// The format in which errors are reported [text | json]
+    #[clap(long, global = true, default_value = "text")]
+    error_format: ErrorFormat,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    Subintent(Subintent),
+    Transaction(Transaction),
+}
+
+pub fn run() -> Result<(), String> {
+    let cli = RtmtxCli::parse();
+    set_error_format(cli.error_format);
+
+    let mut out = std::io::stdout();
+    match cli.command {
+        Command::Subintent(cmd) => cmd.run(&mut out),
+        Command::Transaction(cmd) => cmd.run(&mut out),
+    }
+}
+
+/// The header and message of the root intent being built.
+#[derive(Parser, Debug)]
+pub struct IntentArgs {
+    /// Network to Use [Simulator | Alphanet | Mainnet]
+    #[clap(short, long)]
+    network: Option<String>,
+
+    /// The first epoch in which the intent can be committed
+    #[clap(long)]
+    start_epoch: u64,
+
+    /// The epoch from which the intent can no longer be committed
+    #[clap(long)]
+    end_epoch: u64,
+
+    /// The earliest proposer timestamp, in seconds, at which the intent can be committed
+    #[clap(long)]
+    min_timestamp: Option<i64>,
+
+    /// The proposer timestamp, in seconds, from which the intent can no longer be committed
+    #[clap(long)]
+    max_timestamp: Option<i64>,
+
+    /// The discriminator which makes otherwise identical intents distinct, random by default
+    #[clap(long)]
+    discriminator: Option<u64>,
+
+    /// A plaintext message to attach to the intent
+    #[clap(short, long)]
+    message: Option<String>,
+
+    /// The paths to blobs
+    #[clap(short, long, multiple = true)]
+    blobs: Option<Vec<String>>,
+
+    /// The paths to the signed partial transactions of the children used by the manifest
+    #[clap(short, long, multiple = true)]
+    children: Option<Vec<PathBuf>>,
+}
+
+impl IntentArgs {
+    pub fn network(&self) -> Result<NetworkDefinition, Error> {
+        parse_network(&self.network)
+    }
+
+    pub fn intent_header(&self, network: &NetworkDefinition) -> IntentHeaderV2 {
+        IntentHeaderV2 {
+            network_id: network.id,
+            start_epoch_inclusive: Epoch::of(self.start_epoch),
+            end_epoch_exclusive: Epoch::of(self.end_epoch),
+            min_proposer_timestamp_inclusive: self.min_timestamp.map(Instant::new),
+            max_proposer_timestamp_exclusive: self.max_timestamp.map(Instant::new),
+            intent_discriminator: self.discriminator.unwrap_or_else(rand::random),
+        }
+    }
+
+    pub fn message(&self) -> MessageV2 {
+        match &self.message {
+            Some(message) => MessageV2::Plaintext(PlaintextMessageV1::text(message)),
+            None => MessageV2::None,
+        }
+    }
+
+    pub fn blob_provider(&self) -> Result<BlobProvider, Error> {
+        let mut blobs = Vec::new();
+        for path in self.blobs.iter().flatten() {
+            blobs.push(fs::read(path).map_err(|err| Error::IOErrorAtPath(err, path.into()))?);
+        }
+        Ok(BlobProvider::new_with_blobs(blobs))
+    }
+
+    /// Reads and validates the children, and checks they are exactly the ones used by the
+    /// manifest - which `add_signed_child` would otherwise panic on.
+    pub fn children(
+        &self,
+        manifest_children: &IndexSet<ChildSubintentSpecifier>,
+        network: &NetworkDefinition,
+    ) -> Result<Vec<(PathBuf, SignedPartialTransactionV2)>, Error> {
+        let mut children = index_map_new();
+        for path in self.children.iter().flatten() {
+            let child = read_signed_partial_transaction(path)?;
+            let validated = validate_signed_partial_transaction(&child, network)?;
+            let specifier = ChildSubintentSpecifier {
+                hash: validated.prepared.subintent_hash(),
+            };
+            if !manifest_children.contains(&specifier) {
+                return Err(Error::UnusedChild(path.clone()));
+            }
+            children.insert(specifier, (path.clone(), child));
+        }
+        let encoder = TransactionHashBech32Encoder::new(network);
+        for specifier in manifest_children {
+            if !children.contains_key(specifier) {
+                return Err(Error::MissingChild(
+                    encoder.encode(&specifier.hash).unwrap(),
+                ));
+            }
+        }
+        Ok(children.into_values().collect())
+    }
+}
+
+pub fn parse_network(network: &Option<String>) -> Result<NetworkDefinition, Error> {
+    match network {
+        Some(n) => NetworkDefinition::from_str(n).map_err(Error::ParseNetworkError),
+        None => Ok(NetworkDefinition::simulator()),
+    }
+}
+
+/// Reads a key file, which holds a hex encoded Secp256k1 private key like the ones printed by
+/// `resim generate-key-pair`.
+pub fn read_private_key(path: &Path) -> Result<Secp256k1PrivateKey, Error> {
+    let content =
+        fs::read_to_string(path).map_err(|err| Error::IOErrorAtPath(err, path.to_owned()))?;
+    hex::decode(content.trim())
+        .ok()
+        .and_then(|bytes| Secp256k1PrivateKey::from_bytes(&bytes).ok())
+        .ok_or_else(|| Error::InvalidPrivateKey(path.to_owned()))
+}
+
+pub fn read_signed_partial_transaction(path: &Path) -> Result<SignedPartialTransactionV2, Error> {
+    let raw = RawSignedPartialTransaction::from_vec(
+        fs::read(path).map_err(|err| Error::IOErrorAtPath(err, path.to_owned()))?,
+    );
+    SignedPartialTransactionV2::from_raw(&raw)
+        .map_err(|err| Error::DecodeError(path.to_owned(), err))
+}
+
+pub fn validate_signed_partial_transaction(
+    partial_transaction: &SignedPartialTransactionV2,
+    network: &NetworkDefinition,
+) -> Result<ValidatedSignedPartialTransactionV2, Error> {
+    partial_transaction
+        .prepare_and_validate(&TransactionValidator::new_with_latest_config(network))
+        .map_err(Error::TransactionValidationError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn intent_args(children: Vec<PathBuf>) -> IntentArgs {
+        IntentArgs {
+            network: None,
+            start_epoch: 0,
+            end_epoch: 100,
+            min_timestamp: None,
+            max_timestamp: None,
+            discriminator: Some(1),
+            message: None,
+            blobs: None,
+            children: Some(children),
+        }
+    }
+
+    #[test]
+    fn signed_subintent_can_be_composed_into_a_notarized_transaction() {
+        // Arrange
+        let folder = tempfile::tempdir().unwrap();
+        let path = |name: &str| folder.path().join(name);
+        fs::write(path("child.rtm"), "YIELD_TO_PARENT;").unwrap();
+        fs::write(
+            path("child.key"),
+            Secp256k1PrivateKey::from_u64(1).unwrap().to_hex(),
+        )
+        .unwrap();
+        fs::write(
+            path("notary.key"),
+            Secp256k1PrivateKey::from_u64(2).unwrap().to_hex(),
+        )
+        .unwrap();
+        let mut out = Vec::new();
+
+        // Act
+        SubintentBuild {
+            input: path("child.rtm"),
+            output: path("child.bin"),
+            intent: intent_args(vec![]),
+        }
+        .run(&mut out)
+        .unwrap();
+        SubintentSign {
+            input: path("child.bin"),
+            key_file: Some(path("child.key")),
+            remote_signer: None,
+            output: None,
+            network: None,
+        }
+        .run(&mut out)
+        .unwrap();
+        let child = read_signed_partial_transaction(&path("child.bin")).unwrap();
+        let child_hash = TransactionHashBech32Encoder::for_simulator()
+            .encode(
+                &validate_signed_partial_transaction(&child, &NetworkDefinition::simulator())
+                    .unwrap()
+                    .prepared
+                    .subintent_hash(),
+            )
+            .unwrap();
+        fs::write(
+            path("transaction.rtm"),
+            format!(
+                r#"USE_CHILD NamedIntent("child") Intent("{}"); YIELD_TO_CHILD NamedIntent("child");"#,
+                child_hash
+            ),
+        )
+        .unwrap();
+        TransactionCompose {
+            input: path("transaction.rtm"),
+            output: path("transaction.bin"),
+            intent: intent_args(vec![path("child.bin")]),
+            signer_key_files: None,
+            notary_key_file: Some(path("notary.key")),
+            notary_public_key: None,
+            notary_is_signatory: false,
+            tip_basis_points: 0,
+        }
+        .run(&mut out)
+        .unwrap();
+
+        // Assert
+        assert_eq!(child.root_subintent_signatures.signatures.len(), 1);
+        let transaction = NotarizedTransactionV2::from_raw(&RawNotarizedTransaction::from_vec(
+            fs::read(path("transaction.bin")).unwrap(),
+        ))
+        .unwrap();
+        let signed_intent = transaction.signed_transaction_intent;
+        assert_eq!(
+            signed_intent.transaction_intent.non_root_subintents.0,
+            vec![child.partial_transaction.root_subintent]
+        );
+        assert_eq!(
+            signed_intent.non_root_subintent_signatures.by_subintent,
+            vec![child.root_subintent_signatures]
+        );
+    }
+}
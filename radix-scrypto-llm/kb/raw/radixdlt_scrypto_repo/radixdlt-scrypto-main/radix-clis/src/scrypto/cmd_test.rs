@@ -1,5 +1,6 @@
 use clap::Parser;
 use scrypto_compiler::is_scrypto_cargo_locked_env_var_active;
+use std::env;
 use std::env::current_dir;
 use std::path::PathBuf;
 
@@ -21,10 +22,29 @@ pub struct Test {
     /// The package directory
     #[clap(long)]
     path: Option<PathBuf>,
+
+    /// Overwrites the fee baselines with the costs of this run, instead of checking them.
+    /// Equivalent to setting the `SCRYPTO_UPDATE_FEE_BASELINES` environment variable.
+    #[clap(long)]
+    update_fee_baselines: bool,
+
+    /// The percentage by which the execution or finalization cost units may go up before a fee
+    /// baseline check fails. Equivalent to setting the `SCRYPTO_FEE_BASELINE_TOLERANCE`
+    /// environment variable.
+    #[clap(long)]
+    fee_tolerance: Option<f64>,
 }
 
 impl Test {
     pub fn run(&self) -> Result<(), String> {
+        // Read by the fee baseline checks of `scrypto-test`
+        if self.update_fee_baselines {
+            env::set_var("SCRYPTO_UPDATE_FEE_BASELINES", "true");
+        }
+        if let Some(fee_tolerance) = self.fee_tolerance {
+            env::set_var("SCRYPTO_FEE_BASELINE_TOLERANCE", fee_tolerance.to_string());
+        }
+
         test_package(
             self.path.clone().unwrap_or(current_dir().unwrap()),
             self.arguments.clone(),
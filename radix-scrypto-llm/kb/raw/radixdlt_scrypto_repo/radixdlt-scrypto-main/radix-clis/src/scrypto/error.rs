@@ -17,15 +17,63 @@ pub enum Error {
     CoverageError(CoverageError),
 }
 
+impl Error {
+    pub fn diagnostic(&self) -> Diagnostic {
+        match self {
+            Error::IOError(err) => Diagnostic::new("IOError", err.to_string()),
+            Error::BuildError(err) => diagnose_build_error(err),
+            Error::TestError(TestError::BuildError(err)) => diagnose_build_error(err),
+            Error::TestError(TestError::NotCargoPackage) => Diagnostic::new(
+                "NotCargoPackage",
+                "The path does not point to a Cargo package.",
+            )
+            .with_help("Run the command from a Scrypto package, or pass its path."),
+            Error::TestError(TestError::CargoFailure(status)) => Diagnostic::new(
+                "CargoFailure",
+                format!("`cargo test` failed with {}.", status),
+            ),
+            Error::TestError(TestError::IOError(err)) => {
+                Diagnostic::new("IOError", err.to_string())
+            }
+            Error::FormatError(FormatError::BuildError(err)) => diagnose_build_error(err),
+            Error::FormatError(FormatError::CargoFailure(status)) => Diagnostic::new(
+                "CargoFailure",
+                format!("`cargo fmt` failed with {}.", status),
+            ),
+            Error::FormatError(FormatError::IOError(err)) => {
+                Diagnostic::new("IOError", err.to_string())
+            }
+            Error::PackageAlreadyExists => Diagnostic::new(
+                "PackageAlreadyExists",
+                "A package with the same name already exists.",
+            )
+            .with_help("Choose a different package name, or remove the existing directory."),
+            Error::CoverageError(err) => {
+                let diagnostic = Diagnostic::from_debug("CoverageError", err);
+                match err {
+                    CoverageError::MissingWasm32Target => {
+                        diagnostic.with_help("Run `rustup target add wasm32-unknown-unknown`.")
+                    }
+                    CoverageError::IncorrectRustVersion => {
+                        diagnostic.with_help("Coverage requires a nightly Rust toolchain.")
+                    }
+                    CoverageError::MissingLLVM => diagnostic
+                        .with_help("Install LLVM, including `llvm-profdata` and `llvm-cov`."),
+                    _ => diagnostic,
+                }
+            }
+        }
+    }
+}
+
 impl fmt::Display for Error {
-    // TODO Implement pretty error printing
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:?}", self)
+        write!(f, "{}", self.diagnostic())
     }
 }
 
 impl From<Error> for String {
     fn from(err: Error) -> String {
-        err.to_string()
+        err.diagnostic().render()
     }
 }
@@ -12,6 +12,7 @@ pub use cmd_new_package::*;
 pub use cmd_test::*;
 pub use error::*;
 
+use crate::utils::{set_error_format, ErrorFormat};
 use clap::{Parser, Subcommand};
 
 /// Create, build and test Scrypto code
@@ -20,6 +21,10 @@ use clap::{Parser, Subcommand};
 pub struct ScryptoCli {
     #[clap(subcommand)]
     command: Command,
+
+    /// The format in which errors are reported [text | json]
+    #[clap(long, global = true, default_value = "text")]
+    error_format: ErrorFormat,
 }
 
 #[derive(Subcommand, Debug)]
@@ -33,6 +38,7 @@ pub enum Command {
 
 pub fn run() -> Result<(), String> {
     let cli = ScryptoCli::parse();
+    set_error_format(cli.error_format);
 
     match cli.command {
         Command::Build(cmd) => cmd.run(),
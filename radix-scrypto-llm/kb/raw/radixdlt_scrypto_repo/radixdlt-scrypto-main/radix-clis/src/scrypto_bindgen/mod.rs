@@ -1,14 +1,27 @@
 use scrypto_bindgen::schema;
 use scrypto_bindgen::translation;
 use scrypto_bindgen::types;
+use scrypto_bindgen::typescript;
 
 use clap::Parser;
 use radix_common::prelude::*;
+use radix_engine::blueprints::models::KeyValueEntryPayload;
+use radix_engine::blueprints::package::{
+    PackageNativePackage, PackageSchemaEntryPayload, PackageStructure,
+};
+use radix_engine::errors::RuntimeError;
 use radix_engine::system::system_db_reader::SystemDatabaseReader;
+use radix_engine::vm::{ScryptoVmVersion, VmBoot};
+use radix_engine_interface::blueprints::package::*;
+use radix_engine_interface::types::Level;
 use radix_substate_store_interface::interface::SubstateDatabase;
+use std::ffi::OsStr;
+use std::fs;
 use std::io::Write;
+use std::path::{Path, PathBuf};
 
 use crate::resim::*;
+use crate::utils::{build_package, BuildError};
 
 use self::schema::*;
 
@@ -16,16 +29,37 @@ use self::schema::*;
 #[derive(Parser, Debug)]
 #[clap(author, version, about, long_about = None, name = "scrypto-bindgen")]
 pub struct Args {
-    /// The address of the package to generate the bindings for.
+    /// The address of the package to generate the bindings for. When generating the bindings from
+    /// a local package, this is the address the bindings will call.
     package_address: String,
 
+    /// The path to a Scrypto package or a .wasm file (with the .rpd file next to it) to generate
+    /// the bindings from, instead of the package published at the address in the resim ledger.
+    #[clap(short, long)]
+    package: Option<PathBuf>,
+
+    /// When passed, this argument disables wasm-opt from running on the built wasm.
+    #[clap(long, requires = "package")]
+    disable_wasm_opt: bool,
+
+    /// The max log level of the build, such as ERROR, WARN, INFO, DEBUG and TRACE.
+    /// The default is INFO.
+    #[clap(long, requires = "package")]
+    log_level: Option<Level>,
+
     /// When enabled, the ledger will be cleared and bootstrapped again before being used to obtain
     /// the bindings.
-    #[clap(short, long)]
+    #[clap(short, long, conflicts_with = "package")]
     reset_ledger: bool,
 
-    #[clap(short, long)]
+    #[clap(short, long, conflicts_with = "typescript")]
     func_sig_change: Vec<types::FunctionSignatureReplacementsInput>,
+
+    /// When enabled, a TypeScript module is generated instead of the Scrypto stubs: with the types
+    /// of the package interface, and functions building the manifest instructions calling the
+    /// blueprints and decoding their outputs and events from programmatic JSON.
+    #[clap(long)]
+    typescript: bool,
 }
 
 #[derive(Debug)]
@@ -35,6 +69,10 @@ pub enum Error {
     ResimError(crate::resim::Error),
     SchemaError(SchemaError),
     IOError(std::io::Error),
+    IOErrorAtPath(std::io::Error, PathBuf),
+    SborDecodeError(DecodeError),
+    BuildError(BuildError),
+    InvalidPackage(RuntimeError),
 }
 
 pub fn run() -> Result<(), Error> {
@@ -43,15 +81,6 @@ pub fn run() -> Result<(), Error> {
     // Everything will be written to the std-out
     let mut out = std::io::stdout();
 
-    let env = if args.reset_ledger {
-        SimulatorEnvironment::new_reset().map_err(Error::ResimError)?
-    } else {
-        SimulatorEnvironment::new().map_err(Error::ResimError)?
-    };
-    let db = env.db;
-
-    let blueprint_replacement_map = types::prepare_replacement_map(&args.func_sig_change);
-
     // Decode the package address without network context.
     let package_address = {
         let (_, _, bytes) =
@@ -61,31 +90,121 @@ pub fn run() -> Result<(), Error> {
     };
 
     // Generating the bindings
-    let bindings = {
+    let bindings = if let Some(path) = &args.package {
+        let package_structure = load_package_structure(
+            path,
+            args.disable_wasm_opt,
+            args.log_level.unwrap_or(Level::default()),
+        )?;
+        let definition = package_structure
+            .definitions
+            .into_iter()
+            .map(|(blueprint_name, definition)| {
+                (
+                    BlueprintVersionKey::new_default(blueprint_name),
+                    definition.fully_update_and_into_latest_version(),
+                )
+            })
+            .collect();
+        let schema_resolver =
+            PackageStructureSchemaResolver::new(package_address, package_structure.schemas);
+
+        generate_bindings(&args, definition, &schema_resolver, package_address)?
+    } else {
+        let env = if args.reset_ledger {
+            SimulatorEnvironment::new_reset().map_err(Error::ResimError)?
+        } else {
+            SimulatorEnvironment::new().map_err(Error::ResimError)?
+        };
+        let db = env.db;
+
         let reader = SystemDatabaseReader::new(&db);
         let definition = reader.get_package_definition(package_address);
         let schema_resolver = SchemaResolver::new(package_address, &db);
 
-        let package_interface =
-            schema::package_interface_from_package_definition(definition, &schema_resolver)
-                .map_err(Error::SchemaError)?;
-        let mut ast_package_interface = translation::package_schema_interface_to_ast_interface(
+        generate_bindings(&args, definition, &schema_resolver, package_address)?
+    };
+
+    writeln!(&mut out, "{}", bindings).map_err(Error::IOError)?;
+
+    Ok(())
+}
+
+fn generate_bindings<S: PackageSchemaResolver>(
+    args: &Args,
+    definition: BTreeMap<BlueprintVersionKey, BlueprintDefinition>,
+    schema_resolver: &S,
+    package_address: PackageAddress,
+) -> Result<String, Error> {
+    let package_interface =
+        schema::package_interface_from_package_definition(definition, schema_resolver)
+            .map_err(Error::SchemaError)?;
+
+    if args.typescript {
+        return typescript::package_schema_interface_to_typescript(
             package_interface,
-            package_address,
-            &schema_resolver,
-            &blueprint_replacement_map,
+            &args.package_address,
+            schema_resolver,
         )
-        .map_err(Error::SchemaError)?;
+        .map_err(Error::SchemaError);
+    }
+
+    let blueprint_replacement_map = types::prepare_replacement_map(&args.func_sig_change);
+    let mut ast_package_interface = translation::package_schema_interface_to_ast_interface(
+        package_interface,
+        package_address,
+        schema_resolver,
+        &blueprint_replacement_map,
+    )
+    .map_err(Error::SchemaError)?;
+
+    // Scrypto-bindgen does not generate the aux-types. Only ledger-tools does.
+    ast_package_interface.auxiliary_types = Default::default();
 
-        // Scrypto-bindgen does not generate the aux-types. Only ledger-tools does.
-        ast_package_interface.auxiliary_types = Default::default();
+    Ok(quote::quote!(#ast_package_interface).to_string())
+}
 
-        ast_package_interface
+/// Builds the package (if the path isn't a .wasm file), then validates it the same way as it would
+/// be on publishing, without touching any ledger.
+fn load_package_structure(
+    path: &Path,
+    disable_wasm_opt: bool,
+    log_level: Level,
+) -> Result<PackageStructure, Error> {
+    let (code_path, definition_path) = if path.extension() != Some(OsStr::new("wasm")) {
+        let build_artifacts = build_package(path, disable_wasm_opt, log_level, false, &[])
+            .map_err(Error::BuildError)?;
+        if build_artifacts.len() > 1 {
+            return Err(Error::BuildError(BuildError::WorkspaceNotSupported));
+        } else {
+            build_artifacts
+                .first()
+                .ok_or(Error::BuildError(BuildError::BuildArtifactsEmpty))?
+                .to_owned()
+        }
+    } else {
+        (path.to_path_buf(), path.with_extension("rpd"))
     };
 
-    writeln!(&mut out, "{}", quote::quote!(#bindings)).map_err(Error::IOError)?;
+    let code = fs::read(&code_path).map_err(|err| Error::IOErrorAtPath(err, code_path))?;
+    let package_definition: PackageDefinition = manifest_decode(
+        &fs::read(&definition_path).map_err(|err| Error::IOErrorAtPath(err, definition_path))?,
+    )
+    .map_err(Error::SborDecodeError)?;
 
-    Ok(())
+    // Accept everything the Scrypto VM supports, even if not yet enabled on any network.
+    let vm_boot = VmBoot::V1 {
+        scrypto_version: ScryptoVmVersion::highest_supported().into(),
+    };
+    PackageNativePackage::validate_and_build_package_structure(
+        package_definition,
+        VmType::ScryptoV1,
+        code,
+        Default::default(),
+        false,
+        &vm_boot,
+    )
+    .map_err(Error::InvalidPackage)
 }
 
 pub struct SchemaResolver<'s, S>(PackageAddress, SystemDatabaseReader<'s, S>)
@@ -110,52 +229,39 @@ where
         self.1.get_schema(self.0.as_node_id(), schema_hash).ok()
     }
 
-    fn resolve_type_kind(
-        &self,
-        type_identifier: &ScopedTypeId,
-    ) -> Result<LocalTypeKind<ScryptoCustomSchema>, schema::SchemaError> {
-        self.lookup_schema(&type_identifier.0)
-            .ok_or(schema::SchemaError::FailedToGetSchemaFromSchemaHash)?
-            .as_latest_version()
-            .ok_or(schema::SchemaError::FailedToGetSchemaFromSchemaHash)?
-            .resolve_type_kind(type_identifier.1)
-            .ok_or(schema::SchemaError::NonExistentLocalTypeIndex(
-                type_identifier.1,
-            ))
-            .cloned()
+    fn package_address(&self) -> PackageAddress {
+        self.0
     }
+}
+
+/// Resolves the schemas of a package which was built and validated locally, see
+/// [`load_package_structure`].
+pub struct PackageStructureSchemaResolver {
+    package_address: PackageAddress,
+    schemas: IndexMap<SchemaHash, Rc<VersionedScryptoSchema>>,
+}
 
-    fn resolve_type_metadata(
-        &self,
-        type_identifier: &ScopedTypeId,
-    ) -> Result<TypeMetadata, schema::SchemaError> {
-        self.lookup_schema(&type_identifier.0)
-            .ok_or(schema::SchemaError::FailedToGetSchemaFromSchemaHash)?
-            .as_latest_version()
-            .ok_or(schema::SchemaError::FailedToGetSchemaFromSchemaHash)?
-            .resolve_type_metadata(type_identifier.1)
-            .ok_or(schema::SchemaError::NonExistentLocalTypeIndex(
-                type_identifier.1,
-            ))
-            .cloned()
+impl PackageStructureSchemaResolver {
+    pub fn new(
+        package_address: PackageAddress,
+        schemas: IndexMap<SchemaHash, PackageSchemaEntryPayload>,
+    ) -> Self {
+        Self {
+            package_address,
+            schemas: schemas
+                .into_iter()
+                .map(|(schema_hash, schema)| (schema_hash, Rc::new(schema.into_content())))
+                .collect(),
+        }
     }
+}
 
-    fn resolve_type_validation(
-        &self,
-        type_identifier: &ScopedTypeId,
-    ) -> Result<TypeValidation<ScryptoCustomTypeValidation>, schema::SchemaError> {
-        self.lookup_schema(&type_identifier.0)
-            .ok_or(schema::SchemaError::FailedToGetSchemaFromSchemaHash)?
-            .as_latest_version()
-            .ok_or(schema::SchemaError::FailedToGetSchemaFromSchemaHash)?
-            .resolve_type_validation(type_identifier.1)
-            .ok_or(schema::SchemaError::NonExistentLocalTypeIndex(
-                type_identifier.1,
-            ))
-            .cloned()
+impl PackageSchemaResolver for PackageStructureSchemaResolver {
+    fn lookup_schema(&self, schema_hash: &SchemaHash) -> Option<Rc<VersionedScryptoSchema>> {
+        self.schemas.get(schema_hash).cloned()
     }
 
     fn package_address(&self) -> PackageAddress {
-        self.0
+        self.package_address
     }
 }
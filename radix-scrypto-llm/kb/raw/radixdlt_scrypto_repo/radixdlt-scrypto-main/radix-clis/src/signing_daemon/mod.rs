@@ -0,0 +1,76 @@
+use crate::prelude::*;
+use radix_transactions::signing::FileSigningDaemon;
+
+/// Reference signing daemon for the `RemoteSigner`, with the key in a file - for tests and local
+/// development only
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None, name = "signing-daemon")]
+pub struct Args {
+    /// The file with the hex encoded Secp256k1 private key to sign with
+    #[clap(short, long)]
+    key_file: PathBuf,
+
+    /// The Unix socket to listen on, instead of serving a single connection over stdin and stdout
+    #[clap(short, long)]
+    socket: Option<PathBuf>,
+
+    /// The format in which errors are reported [text | json]
+    #[clap(long, default_value = "text")]
+    error_format: ErrorFormat,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    IOError(std::io::Error),
+    UnixSocketsNotSupported,
+}
+
+impl Error {
+    pub fn diagnostic(&self) -> Diagnostic {
+        match self {
+            Error::IOError(err) => Diagnostic::new("IOError", err.to_string()),
+            Error::UnixSocketsNotSupported => Diagnostic::new(
+                "UnixSocketsNotSupported",
+                "Unix sockets are not supported on this platform.",
+            )
+            .with_help("Serve over stdin and stdout instead, by leaving out `--socket`."),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.diagnostic())
+    }
+}
+
+impl From<Error> for String {
+    fn from(err: Error) -> String {
+        err.diagnostic().render()
+    }
+}
+
+pub fn run() -> Result<(), String> {
+    let args = Args::parse();
+    set_error_format(args.error_format);
+
+    let daemon = FileSigningDaemon::from_key_file(&args.key_file).map_err(Error::IOError)?;
+    match args.socket {
+        #[cfg(unix)]
+        Some(socket) => {
+            // A socket left behind by a previous run would fail the bind
+            if socket.exists() {
+                fs::remove_file(&socket).map_err(Error::IOError)?;
+            }
+            let listener =
+                std::os::unix::net::UnixListener::bind(&socket).map_err(Error::IOError)?;
+            daemon.serve_unix_socket(listener);
+        }
+        #[cfg(not(unix))]
+        Some(_) => return Err(Error::UnixSocketsNotSupported.into()),
+        None => daemon
+            .serve(std::io::stdin().lock(), std::io::stdout().lock())
+            .map_err(Error::IOError)?,
+    }
+    Ok(())
+}
@@ -0,0 +1,632 @@
+use crate::utils::BuildError;
+use colored::*;
+use radix_common::prelude::*;
+use radix_engine::errors::*;
+use radix_engine::system::system_modules::auth::AuthError;
+use radix_engine::system::system_modules::costing::{CostingError, FeeReserveError};
+use radix_rust::ContextualDisplay;
+use radix_transactions::errors::*;
+use radix_transactions::manifest::ManifestValidationError;
+use scrypto_compiler::ScryptoCompilerError;
+use serde::Serialize;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// The format in which the CLIs report errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorFormat {
+    /// Human readable text, for terminals.
+    Text,
+    /// A single line JSON object, for CI tooling.
+    Json,
+}
+
+impl FromStr for ErrorFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err(format!("Unknown error format {}, expected text or json", s)),
+        }
+    }
+}
+
+static ERROR_FORMAT: AtomicU8 = AtomicU8::new(0);
+
+/// Sets the error format for the rest of the process, typically from a `--error-format` flag.
+pub fn set_error_format(format: ErrorFormat) {
+    let value = match format {
+        ErrorFormat::Text => 0,
+        ErrorFormat::Json => 1,
+    };
+    ERROR_FORMAT.store(value, Ordering::Relaxed);
+}
+
+pub fn get_error_format() -> ErrorFormat {
+    match ERROR_FORMAT.load(Ordering::Relaxed) {
+        1 => ErrorFormat::Json,
+        _ => ErrorFormat::Text,
+    }
+}
+
+/// A readable description of an error, which can be rendered as text or JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Diagnostic {
+    /// A stable identifier for the kind of error, e.g. `InsufficientFee`.
+    pub code: String,
+    /// A one-line description of what went wrong.
+    pub message: String,
+    /// A suggestion on how to resolve the error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub help: Option<String>,
+    /// The underlying error in full, for debugging.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+}
+
+impl Diagnostic {
+    pub fn new(code: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            code: code.into(),
+            message: message.into(),
+            help: None,
+            details: None,
+        }
+    }
+
+    /// Creates a diagnostic for errors without a dedicated message, from their debug output.
+    pub fn from_debug<E: fmt::Debug>(code: impl Into<String>, error: &E) -> Self {
+        Self::new(code, format!("{:?}", error))
+    }
+
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    pub fn with_details(mut self, details: impl Into<String>) -> Self {
+        self.details = Some(details.into());
+        self
+    }
+
+    /// Renders the diagnostic in the format configured with [`set_error_format`].
+    pub fn render(&self) -> String {
+        match get_error_format() {
+            ErrorFormat::Text => self.to_string(),
+            ErrorFormat::Json => serde_json::to_string(self).expect("Diagnostic is serializable"),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {}",
+            format!("error[{}]:", self.code).red().bold(),
+            self.message
+        )?;
+        if let Some(details) = &self.details {
+            write!(f, "\n{} {}", "details:".bold(), details)?;
+        }
+        if let Some(help) = &self.help {
+            write!(f, "\n{} {}", "help:".cyan().bold(), help)?;
+        }
+        Ok(())
+    }
+}
+
+/// Renders an error message which did not originate from a diagnostic, such as a manifest
+/// compilation error, in the configured format.
+pub fn render_error_message(message: String) -> String {
+    match get_error_format() {
+        ErrorFormat::Text => message,
+        ErrorFormat::Json => {
+            let is_json_object = serde_json::from_str::<serde_json::Value>(&message)
+                .map_or(false, |value| value.is_object());
+            if is_json_object {
+                message
+            } else {
+                Diagnostic::new("Error", message).render()
+            }
+        }
+    }
+}
+
+pub fn diagnose_runtime_error(
+    error: &RuntimeError,
+    address_encoder: &AddressBech32Encoder,
+) -> Diagnostic {
+    let lock_more_fees_help = "Lock a larger fee, e.g. with the `lock_fee` method of an account.";
+    let diagnostic = match error {
+        RuntimeError::ApplicationError(ApplicationError::PanicMessage(message)) => {
+            Diagnostic::new("Panic", format!("The blueprint code panicked: {}", message))
+                .with_help("Check the assertions in the blueprint and the arguments passed to it.")
+        }
+        RuntimeError::SystemModuleError(SystemModuleError::AuthError(AuthError::Unauthorized(
+            _,
+        ))) => Diagnostic::new(
+            "Unauthorized",
+            "The auth zone does not satisfy the access rule of a called function or method.",
+        )
+        .with_help("Sign with the required badges, or create proofs of them in the manifest."),
+        RuntimeError::SystemModuleError(SystemModuleError::CostingError(
+            CostingError::FeeReserveError(FeeReserveError::InsufficientBalance {
+                required,
+                remaining,
+            }),
+        )) => Diagnostic::new(
+            "InsufficientFee",
+            format!(
+                "The locked fee was used up: {} XRD was required, but only {} XRD remained.",
+                required, remaining
+            ),
+        )
+        .with_help(lock_more_fees_help),
+        RuntimeError::SystemModuleError(SystemModuleError::CostingError(
+            CostingError::FeeReserveError(FeeReserveError::LimitExceeded { limit, .. }),
+        )) => Diagnostic::new(
+            "CostUnitLimitExceeded",
+            format!(
+                "The transaction exceeded its limit of {} cost units.",
+                limit
+            ),
+        )
+        .with_help("Split the work across several transactions."),
+        RuntimeError::SystemModuleError(SystemModuleError::TransactionLimitsError(_)) => {
+            Diagnostic::new(
+                "TransactionLimitExceeded",
+                "The transaction exceeded one of the limits enforced by the engine.",
+            )
+            .with_help("Split the work across several transactions.")
+        }
+        RuntimeError::SystemModuleError(_) => Diagnostic::new(
+            "SystemModuleError",
+            "A system module rejected an operation of the transaction.",
+        ),
+        RuntimeError::KernelError(_) => Diagnostic::new(
+            "KernelError",
+            "The kernel rejected an operation of the transaction.",
+        ),
+        RuntimeError::SystemError(_) => Diagnostic::new(
+            "SystemError",
+            "The system rejected an operation of the transaction.",
+        ),
+        RuntimeError::SystemUpstreamError(_) => Diagnostic::new(
+            "SystemUpstreamError",
+            "A call could not be dispatched to its blueprint.",
+        )
+        .with_help("Check the function or method exists and its arguments match its schema."),
+        RuntimeError::VmError(_) => Diagnostic::new(
+            "VmError",
+            "The virtual machine failed to execute the blueprint code.",
+        ),
+        RuntimeError::ApplicationError(_) => Diagnostic::new(
+            "ApplicationError",
+            "A blueprint rejected an operation of the transaction.",
+        ),
+        RuntimeError::FinalizationCostingError(_) => Diagnostic::new(
+            "FinalizationCostingError",
+            "The locked fee could not cover the finalization costs of the transaction.",
+        )
+        .with_help(lock_more_fees_help),
+    };
+    diagnostic.with_details(error.to_string(address_encoder))
+}
+
+pub fn diagnose_rejection(
+    reason: &RejectionReason,
+    address_encoder: &AddressBech32Encoder,
+) -> Diagnostic {
+    let diagnostic = match reason {
+        RejectionReason::ErrorBeforeLoanAndDeferredCostsRepaid(error) => {
+            let cause = diagnose_runtime_error(error, address_encoder);
+            let mut diagnostic = Diagnostic::new(
+                "TransactionRejected",
+                format!(
+                    "The transaction failed before it locked enough fees: {}",
+                    cause.message
+                ),
+            );
+            diagnostic.help = cause.help;
+            diagnostic
+        }
+        RejectionReason::SuccessButFeeLoanNotRepaid => Diagnostic::new(
+            "TransactionRejected",
+            "The transaction did not lock enough fees to repay the fee loan.",
+        )
+        .with_help("Lock a fee, e.g. with the `lock_fee` method of an account."),
+        _ => Diagnostic::new("TransactionRejected", "The transaction was rejected."),
+    };
+    diagnostic.with_details(reason.to_string(address_encoder))
+}
+
+pub fn diagnose_manifest_validation_error(error: &ManifestValidationError) -> Diagnostic {
+    let (message, help) = match error {
+        ManifestValidationError::DuplicateBlob(blob) => (
+            format!("The blob {:?} is provided more than once.", blob),
+            None,
+        ),
+        ManifestValidationError::BlobNotRegistered(blob) => (
+            format!("The blob {:?} is referenced but was not provided.", blob),
+            Some("Pass the blob files to the command, e.g. with `--blobs`."),
+        ),
+        ManifestValidationError::BucketNotYetCreated(bucket) => {
+            (format!("{:?} is used before it is created.", bucket), None)
+        }
+        ManifestValidationError::BucketAlreadyUsed(bucket, state) => (
+            format!("{:?} is used after it was consumed ({}).", bucket, state),
+            Some("A bucket can only be consumed once."),
+        ),
+        ManifestValidationError::BucketConsumedWhilstLockedByProof(bucket, state) => (
+            format!(
+                "{:?} is consumed while a proof of it exists ({}).",
+                bucket, state
+            ),
+            Some("Drop the proofs of the bucket with `DROP_PROOF` before consuming it."),
+        ),
+        ManifestValidationError::ProofNotYetCreated(proof) => {
+            (format!("{:?} is used before it is created.", proof), None)
+        }
+        ManifestValidationError::ProofAlreadyUsed(proof, state) => (
+            format!("{:?} is used after it was consumed ({}).", proof, state),
+            Some("Use `CLONE_PROOF` to use a proof more than once."),
+        ),
+        ManifestValidationError::AddressReservationNotYetCreated(reservation) => (
+            format!("{:?} is used before it is created.", reservation),
+            None,
+        ),
+        ManifestValidationError::AddressReservationAlreadyUsed(reservation, state) => (
+            format!(
+                "{:?} is used after it was consumed ({}).",
+                reservation, state
+            ),
+            None,
+        ),
+        ManifestValidationError::NamedAddressNotYetCreated(address) => {
+            (format!("{:?} is used before it is created.", address), None)
+        }
+        ManifestValidationError::ChildIntentNotRegistered(intent) => (
+            format!("{:?} is used but was not registered.", intent),
+            Some("Declare the child subintents with `USE_CHILD` at the start of the manifest."),
+        ),
+        ManifestValidationError::DanglingBucket(bucket, state) => (
+            format!(
+                "{:?} still exists at the end of the manifest ({}).",
+                bucket, state
+            ),
+            Some("Deposit or burn the bucket, e.g. with the `deposit_batch` method of an account."),
+        ),
+        ManifestValidationError::DanglingAddressReservation(reservation, state) => (
+            format!("{:?} is never used ({}).", reservation, state),
+            Some("Pass the address reservation to the function which creates the global entity."),
+        ),
+        ManifestValidationError::ArgsEncodeError(error) => (
+            format!("Instruction arguments could not be encoded: {:?}", error),
+            None,
+        ),
+        ManifestValidationError::ArgsDecodeError(error) => (
+            format!("Instruction arguments could not be decoded: {:?}", error),
+            None,
+        ),
+        ManifestValidationError::InstructionNotSupportedInTransactionIntent => (
+            "The manifest uses an instruction which is only supported in subintents.".to_owned(),
+            Some("Check that the manifest kind is correct, e.g. with `--kind`."),
+        ),
+        ManifestValidationError::SubintentDoesNotEndWithYieldToParent => (
+            "The subintent manifest does not end with `YIELD_TO_PARENT`.".to_owned(),
+            Some("Add a `YIELD_TO_PARENT` instruction at the end of the manifest."),
+        ),
+        ManifestValidationError::ProofCannotBePassedToAnotherIntent => (
+            "A proof is passed to another intent, which is not allowed.".to_owned(),
+            None,
+        ),
+        ManifestValidationError::TooManyInstructions => (
+            "The manifest has more instructions than allowed.".to_owned(),
+            Some("Split the manifest across several transactions."),
+        ),
+        ManifestValidationError::InvalidResourceConstraint => (
+            "The manifest contains an invalid resource constraint.".to_owned(),
+            None,
+        ),
+        ManifestValidationError::InstructionFollowingNextCallAssertionWasNotInvocation => (
+            "An assertion on the next call is not followed by an invocation.".to_owned(),
+            None,
+        ),
+        ManifestValidationError::ManifestEndedWhilstExpectingNextCallAssertion => (
+            "The manifest ends with an assertion on the next call.".to_owned(),
+            None,
+        ),
+    };
+    let diagnostic = Diagnostic::new("ManifestValidationError", message);
+    match help {
+        Some(help) => diagnostic.with_help(help),
+        None => diagnostic,
+    }
+    .with_details(format!("{:?}", error))
+}
+
+pub fn diagnose_transaction_validation_error(error: &TransactionValidationError) -> Diagnostic {
+    let diagnostic = match error {
+        TransactionValidationError::TransactionVersionNotPermitted(version) => Diagnostic::new(
+            "TransactionValidationError",
+            format!("Transactions of version {} are not permitted.", version),
+        ),
+        TransactionValidationError::TransactionTooLarge => Diagnostic::new(
+            "TransactionValidationError",
+            "The transaction is larger than the maximum transaction size.",
+        ),
+        TransactionValidationError::IntentValidationError(
+            _,
+            IntentValidationError::ManifestValidationError(error),
+        ) => diagnose_manifest_validation_error(error),
+        TransactionValidationError::IntentValidationError(
+            _,
+            IntentValidationError::HeaderValidationError(HeaderValidationError::InvalidNetwork),
+        ) => Diagnostic::new(
+            "TransactionValidationError",
+            "The transaction was built for a different network.",
+        )
+        .with_help("Check the network of the transaction, e.g. with `--network`."),
+        TransactionValidationError::IntentValidationError(
+            _,
+            IntentValidationError::HeaderValidationError(HeaderValidationError::InvalidEpochRange),
+        ) => Diagnostic::new(
+            "TransactionValidationError",
+            "The epoch range of the transaction header is invalid.",
+        ),
+        TransactionValidationError::SignatureValidationError(_, error) => Diagnostic::new(
+            "TransactionValidationError",
+            format!("The signatures of the transaction are invalid: {:?}", error),
+        ),
+        TransactionValidationError::SubintentStructureError(_, error) => Diagnostic::new(
+            "TransactionValidationError",
+            format!("The subintents of the transaction are invalid: {:?}", error),
+        ),
+        _ => Diagnostic::new("TransactionValidationError", "The transaction is invalid."),
+    };
+    diagnostic.with_details(format!("{:?}", error))
+}
+
+pub fn diagnose_scrypto_compiler_error(error: &ScryptoCompilerError) -> Diagnostic {
+    let diagnostic = match error {
+        ScryptoCompilerError::IOError(error, context) => Diagnostic::new(
+            "IOError",
+            match context {
+                Some(context) => format!("{}: {}", context, error),
+                None => error.to_string(),
+            },
+        ),
+        ScryptoCompilerError::IOErrorWithPath(error, path, context) => Diagnostic::new(
+            "IOError",
+            match context {
+                Some(context) => format!("{} ({}): {}", context, path.display(), error),
+                None => format!("{}: {}", path.display(), error),
+            },
+        ),
+        ScryptoCompilerError::CargoBuildFailure(status) => Diagnostic::new(
+            "CargoBuildFailure",
+            format!("`cargo build` failed with {}.", status),
+        )
+        .with_help("See the compiler output above for the cause."),
+        ScryptoCompilerError::CargoMetadataFailure(stderr, path, status) => Diagnostic::new(
+            "CargoMetadataFailure",
+            format!(
+                "`cargo metadata` failed for {} with {}: {}",
+                path.display(),
+                status,
+                stderr.trim()
+            ),
+        ),
+        ScryptoCompilerError::CargoManifestFileNotFound(path) => Diagnostic::new(
+            "CargoManifestFileNotFound",
+            format!("No Cargo.toml was found at {}.", path),
+        )
+        .with_help("Run the command from a Scrypto package, or pass its path."),
+        ScryptoCompilerError::CargoWrongPackageId(package) => Diagnostic::new(
+            "CargoWrongPackageId",
+            format!("{} is not a member of the workspace.", package),
+        ),
+        ScryptoCompilerError::NothingToCompile => Diagnostic::new(
+            "NothingToCompile",
+            "The workspace does not contain any Scrypto packages.",
+        ),
+        ScryptoCompilerError::SchemaExtractionError(_) => Diagnostic::new(
+            "SchemaExtractionError",
+            "The package schema could not be extracted from the compiled WASM.",
+        )
+        .with_help("Check the package defines its blueprints with `#[blueprint]`."),
+        _ => Diagnostic::new(
+            "ScryptoCompilerError",
+            "The Scrypto package failed to compile.",
+        ),
+    };
+    diagnostic.with_details(format!("{:?}", error))
+}
+
+pub fn diagnose_build_error(error: &BuildError) -> Diagnostic {
+    match error {
+        BuildError::ScryptoCompilerError(error) => diagnose_scrypto_compiler_error(error),
+        BuildError::IOErrorAtPath(error, path) => {
+            Diagnostic::new("IOError", format!("{}: {}", path.display(), error))
+        }
+        BuildError::BuildArtifactsEmpty => Diagnostic::new(
+            "BuildArtifactsEmpty",
+            "The build did not produce any WASM or package definition files.",
+        ),
+        BuildError::WorkspaceNotSupported => Diagnostic::new(
+            "WorkspaceNotSupported",
+            "Workspaces with several packages are not supported here.",
+        )
+        .with_help("Pass the path of a single Scrypto package instead."),
+        BuildError::EnvParsingError => Diagnostic::new(
+            "EnvParsingError",
+            "An environment variable could not be parsed.",
+        )
+        .with_help("Environment variables are passed as `NAME=value`, e.g. with `--env`."),
+        BuildError::SchemaExtractionError(_) | BuildError::SchemaEncodeError(_) => {
+            Diagnostic::from_debug("SchemaError", error)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value as JsonValue};
+
+    #[test]
+    fn diagnostics_and_error_messages_are_rendered_as_json() {
+        // Arrange
+        // The error format is process wide, so everything which depends on it is tested here.
+        let diagnostic = Diagnostic::new("InsufficientFee", "The locked fee was used up.")
+            .with_help("Lock a larger fee.");
+
+        // Act
+        set_error_format(ErrorFormat::Json);
+        let rendered_diagnostic = diagnostic.render();
+        let rendered_message = render_error_message("Invalid manifest".to_owned());
+        let passed_through_message = render_error_message(rendered_diagnostic.clone());
+        set_error_format(ErrorFormat::Text);
+
+        // Assert
+        assert!(!rendered_diagnostic.contains('\n'));
+        assert_eq!(
+            serde_json::from_str::<JsonValue>(&rendered_diagnostic).unwrap(),
+            json!({
+                "code": "InsufficientFee",
+                "message": "The locked fee was used up.",
+                "help": "Lock a larger fee.",
+            })
+        );
+        assert_eq!(
+            serde_json::from_str::<JsonValue>(&rendered_message).unwrap(),
+            json!({
+                "code": "Error",
+                "message": "Invalid manifest",
+            })
+        );
+        assert_eq!(passed_through_message, rendered_diagnostic);
+    }
+
+    #[test]
+    fn error_message_is_rendered_unchanged_as_text() {
+        // Act
+        let rendered_message = render_error_message("{\"code\":\"Error\"}".to_owned());
+
+        // Assert
+        assert_eq!(rendered_message, "{\"code\":\"Error\"}");
+    }
+
+    #[test]
+    fn error_format_is_parsed_case_insensitively() {
+        assert_eq!(ErrorFormat::from_str("JSON"), Ok(ErrorFormat::Json));
+        assert_eq!(ErrorFormat::from_str("text"), Ok(ErrorFormat::Text));
+        assert!(ErrorFormat::from_str("yaml").is_err());
+    }
+
+    #[test]
+    fn panic_is_diagnosed_with_its_message() {
+        // Arrange
+        let error = RuntimeError::ApplicationError(ApplicationError::PanicMessage(
+            "Not enough gumballs".to_owned(),
+        ));
+
+        // Act
+        let diagnostic = diagnose_runtime_error(&error, &AddressBech32Encoder::for_simulator());
+
+        // Assert
+        assert_eq!(diagnostic.code, "Panic");
+        assert_eq!(
+            diagnostic.message,
+            "The blueprint code panicked: Not enough gumballs"
+        );
+        assert!(diagnostic.help.is_some());
+        assert!(diagnostic.details.unwrap().contains("Not enough gumballs"));
+    }
+
+    #[test]
+    fn insufficient_fee_is_diagnosed_with_the_amounts() {
+        // Arrange
+        let error = RuntimeError::SystemModuleError(SystemModuleError::CostingError(
+            CostingError::FeeReserveError(FeeReserveError::InsufficientBalance {
+                required: dec!("1.5"),
+                remaining: dec!("0.5"),
+            }),
+        ));
+
+        // Act
+        let diagnostic = diagnose_runtime_error(&error, &AddressBech32Encoder::for_simulator());
+
+        // Assert
+        assert_eq!(diagnostic.code, "InsufficientFee");
+        assert_eq!(
+            diagnostic.message,
+            "The locked fee was used up: 1.5 XRD was required, but only 0.5 XRD remained."
+        );
+        assert_eq!(
+            diagnostic.help.as_deref(),
+            Some("Lock a larger fee, e.g. with the `lock_fee` method of an account.")
+        );
+    }
+
+    #[test]
+    fn cost_unit_limit_is_diagnosed_with_the_limit() {
+        // Arrange
+        let error = RuntimeError::SystemModuleError(SystemModuleError::CostingError(
+            CostingError::FeeReserveError(FeeReserveError::LimitExceeded {
+                limit: 100,
+                committed: 90,
+                new: 20,
+            }),
+        ));
+
+        // Act
+        let diagnostic = diagnose_runtime_error(&error, &AddressBech32Encoder::for_simulator());
+
+        // Assert
+        assert_eq!(diagnostic.code, "CostUnitLimitExceeded");
+        assert_eq!(
+            diagnostic.message,
+            "The transaction exceeded its limit of 100 cost units."
+        );
+    }
+
+    #[test]
+    fn other_application_errors_fall_back_to_their_category() {
+        // Arrange
+        let error = RuntimeError::ApplicationError(ApplicationError::ExportDoesNotExist(
+            "missing_function".to_owned(),
+        ));
+
+        // Act
+        let diagnostic = diagnose_runtime_error(&error, &AddressBech32Encoder::for_simulator());
+
+        // Assert
+        assert_eq!(diagnostic.code, "ApplicationError");
+        assert!(diagnostic.help.is_none());
+        assert!(diagnostic.details.unwrap().contains("missing_function"));
+    }
+
+    #[test]
+    fn rejection_before_fee_lock_is_diagnosed_with_its_cause() {
+        // Arrange
+        let reason = RejectionReason::ErrorBeforeLoanAndDeferredCostsRepaid(
+            RuntimeError::ApplicationError(ApplicationError::PanicMessage("Oops".to_owned())),
+        );
+
+        // Act
+        let diagnostic = diagnose_rejection(&reason, &AddressBech32Encoder::for_simulator());
+
+        // Assert
+        assert_eq!(diagnostic.code, "TransactionRejected");
+        assert_eq!(
+            diagnostic.message,
+            "The transaction failed before it locked enough fees: The blueprint code panicked: Oops"
+        );
+        assert!(diagnostic.help.is_some());
+    }
+}
@@ -0,0 +1,130 @@
+use colored::*;
+use radix_transactions::manifest::{AccountMovementSummary, ManifestSummary};
+use std::fmt::Write;
+use std::io::IsTerminal;
+use std::str::FromStr;
+
+use super::{get_error_format, list_item_prefix, ErrorFormat, IdentifyLast};
+
+/// The format in which `--summary` reports are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SummaryFormat {
+    Text,
+    Json,
+}
+
+impl FromStr for SummaryFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err(format!(
+                "Unknown summary format {}, expected text or json",
+                s
+            )),
+        }
+    }
+}
+
+/// Renders a summary in the given format, to be printed to the standard output - which is only
+/// colored if it is a terminal, and errors aren't reported as JSON.
+pub fn render_manifest_summary(summary: &ManifestSummary, format: SummaryFormat) -> String {
+    match format {
+        SummaryFormat::Text => {
+            let colored =
+                std::io::stdout().is_terminal() && get_error_format() == ErrorFormat::Text;
+            render_text(summary, colored)
+        }
+        SummaryFormat::Json => serde_json::to_string_pretty(summary).unwrap(),
+    }
+}
+
+fn render_text(summary: &ManifestSummary, colored: bool) -> String {
+    let mut out = String::new();
+    let account_withdrawals = summary
+        .account_withdrawals
+        .iter()
+        .map(|movement| describe_account_movement(movement, "from"))
+        .collect::<Vec<_>>();
+    let account_deposits = summary
+        .account_deposits
+        .iter()
+        .map(|movement| describe_account_movement(movement, "into"))
+        .collect::<Vec<_>>();
+    write_section(
+        &mut out,
+        "Account Withdrawals",
+        account_withdrawals,
+        colored,
+    );
+    write_section(&mut out, "Account Deposits", account_deposits, colored);
+    for (title, items) in [
+        ("Assertions", &summary.assertions),
+        ("Entities Called", &summary.entities_called),
+        ("Proofs Created", &summary.proofs_created),
+        ("Unknown Resource Flows", &summary.unknown_resource_flows),
+    ] {
+        write_section(
+            &mut out,
+            title,
+            items
+                .iter()
+                .map(|item| format!("[{}] {}", item.instruction_index, item.description))
+                .collect(),
+            colored,
+        );
+    }
+    out
+}
+
+fn describe_account_movement(movement: &AccountMovementSummary, preposition: &str) -> String {
+    let resource = match &movement.resource {
+        Some(resource) => resource.as_str(),
+        None => "resources which can't be determined statically",
+    };
+    format!(
+        "{} of {} {} {}",
+        movement.bounds, resource, preposition, movement.account
+    )
+}
+
+fn write_section(out: &mut String, title: &str, items: Vec<String>, colored: bool) {
+    if colored {
+        writeln!(out, "{}:", title.green().bold()).unwrap();
+    } else {
+        writeln!(out, "{}:", title).unwrap();
+    }
+    if items.is_empty() {
+        writeln!(out, "{} None", list_item_prefix(true)).unwrap();
+    }
+    for (last, item) in items.iter().identify_last() {
+        writeln!(out, "{} {}", list_item_prefix(last), item).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use radix_transactions::manifest::InstructionSummary;
+
+    #[test]
+    fn uncolored_text_summary_has_no_escape_codes() {
+        // Arrange
+        let summary = ManifestSummary {
+            entities_called: vec![InstructionSummary {
+                instruction_index: 0,
+                description: "account_sim1 lock_fee".to_owned(),
+            }],
+            ..Default::default()
+        };
+
+        // Act
+        let text = render_text(&summary, false);
+
+        // Assert
+        assert!(!text.contains('\u{1b}'));
+        assert!(text.contains("Entities Called:\n└─ [0] account_sim1 lock_fee\n"));
+    }
+}
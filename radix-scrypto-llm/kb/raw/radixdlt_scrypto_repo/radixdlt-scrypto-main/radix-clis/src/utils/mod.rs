@@ -1,15 +1,19 @@
 mod cargo;
 mod common_instructions;
 mod coverage;
+mod diagnostics;
 mod display;
 mod file;
 mod iter;
+mod manifest_summary;
 mod resource_specifier;
 
 pub use cargo::*;
 pub use common_instructions::*;
 pub use coverage::*;
+pub use diagnostics::*;
 pub use display::list_item_prefix;
 pub use file::*;
 pub use iter::{IdentifyLast, Iter};
+pub use manifest_summary::*;
 pub use resource_specifier::*;
@@ -6,6 +6,7 @@ set -e
 cd "$(dirname "$0")/.."
 
 resim="cargo run --bin resim $@ --"
+export KEYSTORE_PASSWORD="resim-test"
 
 $resim reset
 
@@ -6,11 +6,13 @@ set -e
 cd "$(dirname "$0")/.."
 
 resim="cargo run --bin resim $@ --"
+export KEYSTORE_PASSWORD="resim-test"
 
 # Create test accounts and public keys
 $resim reset
-temp=`$resim new-account | awk '/Account component address:/ {print $NF}'`
-account=`echo $temp | cut -d " " -f1`
+temp=`$resim new-account`
+account=`echo "$temp" | awk '/Account component address:/ {print $NF}'`
+account_key=`echo "$temp" | awk '/Key alias:/ {print $NF}'`
 account2=`$resim new-account | awk '/Account component address:/ {print $NF}'`
 
 # Dump each entity in the ledger
@@ -45,6 +47,12 @@ if [[ ${account_dump} != *"XRD"* ]];then
     exit 1
 fi
 
+# Test - show account before it was created
+if $resim show $account --at-version 0; then
+    echo "Account present at state version 0!"
+    exit 1
+fi
+
 # Test - create fixed supply badge
 minter_badge=`$resim new-badge-fixed 1 --name 'MinterBadge' | awk '/Resource:/ {print $NF}'`
 
@@ -66,6 +74,18 @@ package=`$resim publish ../examples/hello-world --owner-badge $owner_badge | awk
 component=`$resim call-function $package Hello instantiate_hello | awk '/Component:/ {print $NF}'`
 $resim call-method $component free_token
 
+# Test - address aliases
+$resim publish ../examples/hello-world --owner-badge $owner_badge --alias hello_pkg
+$resim call-function @hello_pkg Hello instantiate_hello
+$resim new-token-fixed 1000 --alias test_token
+$resim transfer @test_token:10 $account2
+$resim show $account | grep "(@test_token)"
+$resim alias list | grep "@hello_pkg"
+
+# Test - flamegraph and cost report
+$resim call-method $component free_token --flamegraph ../examples/hello-world/target/free_token.svg --cost-report | grep "Execution Cost Breakdown"
+test -f ../examples/hello-world/target/free_token.svg
+
 # Test - publish wasm file
 $resim publish ../examples/hello-world/target/wasm32-unknown-unknown/release/hello_world.wasm
 
@@ -92,7 +112,12 @@ $resim run ./target/temp3.rtm
 
 # Test - run manifest with a given set of signing keys
 $resim generate-key-pair
-$resim run ./target/temp2.rtm --blobs $blobs
+$resim run ./target/temp2.rtm --blobs $blobs --signing-keys $account_key
+
+# Test - keystore
+$resim keys list | grep "$account_key"
+account_private_key=`$resim keys export $account_key`
+$resim run ./target/temp2.rtm --blobs $blobs --signing-keys $account_private_key
 
 # Test - nft
 package=`$resim publish ./tests/blueprints --owner-badge $owner_badge | awk '/Package:/ {print $NF}'`
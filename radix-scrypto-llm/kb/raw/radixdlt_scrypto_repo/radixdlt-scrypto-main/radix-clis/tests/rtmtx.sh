@@ -0,0 +1,40 @@
+#!/bin/bash
+
+set -x
+set -e
+
+cd "$(dirname "$0")/.."
+
+rtmtx="cargo run --bin rtmtx -- $@ "
+
+mkdir -p ./tests/out
+echo "0000000000000000000000000000000000000000000000000000000000000001" > ./tests/out/child.key
+echo "0000000000000000000000000000000000000000000000000000000000000002" > ./tests/out/notary.key
+
+# Build, sign and inspect a subintent
+$rtmtx subintent build ./tests/subintent.rtm --output ./tests/out/child.bin --start-epoch 0 --end-epoch 100
+child_public_key=`$rtmtx subintent sign ./tests/out/child.bin --key-file ./tests/out/child.key | cut -d " " -f 5 | tr -d "."`
+$rtmtx subintent inspect ./tests/out/child.bin | grep "Secp256k1 $child_public_key"
+$rtmtx subintent inspect ./tests/out/child.bin | grep "Validation: OK"
+child_hash=`$rtmtx subintent inspect ./tests/out/child.bin | head -n 1 | cut -d " " -f 2`
+
+# Sign a subintent with a signing daemon, which holds the same key
+cargo build --bin signing-daemon
+rm -f ./tests/out/signing_daemon.sock
+cargo run --bin signing-daemon -- --key-file ./tests/out/child.key --socket ./tests/out/signing_daemon.sock &
+signing_daemon_pid=$!
+trap "kill $signing_daemon_pid" EXIT
+while [ ! -S ./tests/out/signing_daemon.sock ]; do sleep 1; done
+$rtmtx subintent build ./tests/subintent.rtm --output ./tests/out/remote_child.bin --start-epoch 0 --end-epoch 100
+$rtmtx subintent sign ./tests/out/remote_child.bin --remote-signer ./tests/out/signing_daemon.sock | grep "signed by $child_public_key"
+$rtmtx subintent inspect ./tests/out/remote_child.bin | grep "Validation: OK"
+
+# Compose a transaction which yields to the subintent, with and without notarizing it
+sed "s/\${child_hash}/$child_hash/" ./tests/transaction_with_child.rtm > ./tests/out/transaction_with_child.rtm
+$rtmtx transaction compose ./tests/out/transaction_with_child.rtm --output ./tests/out/transaction.bin --start-epoch 0 --end-epoch 100 --children ./tests/out/child.bin --notary-key-file ./tests/out/notary.key
+$rtmtx transaction compose ./tests/out/transaction_with_child.rtm --output ./tests/out/signed_intent.bin --start-epoch 0 --end-epoch 100 --children ./tests/out/child.bin --notary-public-key $child_public_key --notary-is-signatory
+
+# A transaction without its child fails validation
+if $rtmtx transaction compose ./tests/out/transaction_with_child.rtm --output ./tests/out/transaction.bin --start-epoch 0 --end-epoch 100 --notary-key-file ./tests/out/notary.key; then
+    exit 1
+fi
@@ -6,6 +6,7 @@ set -e
 cd "$(dirname "$0")/.."
 
 scrypto="cargo run --bin scrypto $@ --"
+scrypto_bindgen="cargo run --bin scrypto-bindgen $@ --"
 test_pkg="./target/temp/hello-world"
 
 # Create package
@@ -33,6 +34,12 @@ $scrypto test --path $test_pkg --locked -- --nocapture
 # Check envs parsing
 $scrypto build --path $test_pkg --locked --env ENV_NAME=foo=bar
 
+# Generate bindings without publishing the package into the ledger
+package_address="package_sim1pkgxxxxxxxxxfaucetxxxxxxxxx000034355863xxxxxxxxxhkrefh"
+$scrypto_bindgen $package_address --package $test_pkg | grep "instantiate_hello"
+$scrypto_bindgen $package_address --package $test_pkg/target/wasm32-unknown-unknown/release/hello_world.wasm | grep "instantiate_hello"
+$scrypto_bindgen $package_address --package $test_pkg --typescript | grep "buildHelloFreeTokenCall"
+
 # Logging
 $scrypto build --path ../examples/everything --log-level ERROR --locked
 size1=$(ls -la ../examples/everything/target/wasm32-unknown-unknown/release/everything.wasm | cut -d ' ' -f 5)
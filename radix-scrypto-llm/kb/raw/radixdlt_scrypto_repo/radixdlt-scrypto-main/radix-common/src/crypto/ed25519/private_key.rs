@@ -1,7 +1,7 @@
 use super::Ed25519Signature;
 use crate::internal_prelude::*;
 use core::pin::Pin;
-use ed25519_dalek::{Signer, SigningKey};
+use ed25519_dalek::{Signer, SigningKey, VerifyingKey};
 use zeroize::Zeroize;
 
 // Pin<Box<>> assures the memory location of secret key is fixed preventing
@@ -41,6 +41,16 @@ impl Ed25519PrivateKey {
         Ed25519Signature(self.signing_key().sign(msg.as_ref()).to_bytes())
     }
 
+    /// The shared secret of a static Diffie-Hellman key exchange (X25519) with the given public
+    /// key: the `u` coordinate of the shared point on the birationally equivalent Curve25519.
+    pub fn ecdh_shared_secret(&self, public_key: &Ed25519PublicKey) -> Result<[u8; 32], ()> {
+        let verifying_key = VerifyingKey::from_bytes(&public_key.0).map_err(|_| ())?;
+        Ok(verifying_key
+            .to_montgomery()
+            .mul_clamped(self.signing_key().to_scalar_bytes())
+            .to_bytes())
+    }
+
     pub fn to_bytes(&self) -> Vec<u8> {
         self.signing_key().to_bytes().to_vec()
     }
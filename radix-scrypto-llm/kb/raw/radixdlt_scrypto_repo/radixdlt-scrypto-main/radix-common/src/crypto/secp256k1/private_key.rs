@@ -41,6 +41,15 @@ impl Secp256k1PrivateKey {
         Secp256k1Signature(buf)
     }
 
+    /// The shared secret of a static Diffie-Hellman key exchange (ECDH) with the given public
+    /// key: the unhashed `x` coordinate of the shared point, as in ASN1 X9.63 - rather than the
+    /// hashed variant of libsecp256k1's `SharedSecret`.
+    pub fn ecdh_shared_secret(&self, public_key: &Secp256k1PublicKey) -> Result<[u8; 32], ()> {
+        let public_key = PublicKey::from_slice(&public_key.0).map_err(|_| ())?;
+        let shared_point = secp256k1::ecdh::shared_secret_point(&public_key, &self.0 .0);
+        Ok(copy_u8_array(&shared_point[..32]))
+    }
+
     pub fn to_bytes(&self) -> Vec<u8> {
         self.0 .0.secret_bytes().to_vec()
     }
@@ -40,18 +40,161 @@ impl SerializableCustomExtension for ScryptoCustomExtension {
     }
 }
 
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ScryptoValueDeserializationContext<'a> {
+    pub address_bech32_decoder: Option<&'a AddressBech32Decoder>,
+}
+
+impl<'a> ScryptoValueDeserializationContext<'a> {
+    pub fn no_context() -> Self {
+        Self {
+            address_bech32_decoder: None,
+        }
+    }
+
+    pub fn with_optional_bech32(address_bech32_decoder: Option<&'a AddressBech32Decoder>) -> Self {
+        Self {
+            address_bech32_decoder,
+        }
+    }
+}
+
+impl<'a> Into<ScryptoValueDeserializationContext<'a>> for &'a AddressBech32Decoder {
+    fn into(self) -> ScryptoValueDeserializationContext<'a> {
+        ScryptoValueDeserializationContext::with_optional_bech32(Some(self))
+    }
+}
+
+impl<'a> Into<ScryptoValueDeserializationContext<'a>> for Option<&'a AddressBech32Decoder> {
+    fn into(self) -> ScryptoValueDeserializationContext<'a> {
+        ScryptoValueDeserializationContext::with_optional_bech32(self)
+    }
+}
+
+/// Parses a node id as serialized - either a bech32m address, or `NodeId(<hex>)` if the
+/// node id has no address or no encoder was available.
+fn parse_serialized_node_id(
+    context: &ScryptoValueDeserializationContext,
+    value: &str,
+) -> Result<NodeId, String> {
+    if let Some(hex) = value
+        .strip_prefix("NodeId(")
+        .and_then(|rest| rest.strip_suffix(")"))
+    {
+        return NodeId::try_from_hex(hex).ok_or_else(|| format!("Invalid node id hex: {}", hex));
+    }
+    let decoder = context
+        .address_bech32_decoder
+        .ok_or_else(|| "An address decoder is required to parse addresses".to_string())?;
+    let (_, bytes) = decoder
+        .validate_and_decode(value)
+        .map_err(|err| format!("{:?}", err))?;
+    Ok(NodeId(bytes.try_into().map_err(|_| {
+        format!("Address does not have {} bytes", NodeId::LENGTH)
+    })?))
+}
+
+impl DeserializableCustomExtension for ScryptoCustomExtension {
+    type CustomValue = ScryptoCustomValue;
+    type CustomDeserializationContext<'a> = ScryptoValueDeserializationContext<'a>;
+
+    fn parse_custom_value_kind(kind_name: &str) -> Option<Self::CustomValueKind> {
+        let custom_value_kind = match kind_name {
+            "Reference" => ScryptoCustomValueKind::Reference,
+            "Own" => ScryptoCustomValueKind::Own,
+            "Decimal" => ScryptoCustomValueKind::Decimal,
+            "PreciseDecimal" => ScryptoCustomValueKind::PreciseDecimal,
+            "NonFungibleLocalId" => ScryptoCustomValueKind::NonFungibleLocalId,
+            _ => return None,
+        };
+        Some(custom_value_kind)
+    }
+
+    fn parse_custom_value(
+        context: &Self::CustomDeserializationContext<'_>,
+        custom_value_kind: Self::CustomValueKind,
+        value: &serde_json::Value,
+    ) -> Result<Self::CustomValue, String> {
+        let value = value
+            .as_str()
+            .ok_or_else(|| "Expected a JSON string".to_string())?;
+        let custom_value = match custom_value_kind {
+            ScryptoCustomValueKind::Reference => {
+                ScryptoCustomValue::Reference(Reference(parse_serialized_node_id(context, value)?))
+            }
+            ScryptoCustomValueKind::Own => {
+                ScryptoCustomValue::Own(Own(parse_serialized_node_id(context, value)?))
+            }
+            ScryptoCustomValueKind::Decimal => ScryptoCustomValue::Decimal(
+                Decimal::from_str(value).map_err(|err| format!("{:?}", err))?,
+            ),
+            ScryptoCustomValueKind::PreciseDecimal => ScryptoCustomValue::PreciseDecimal(
+                PreciseDecimal::from_str(value).map_err(|err| format!("{:?}", err))?,
+            ),
+            ScryptoCustomValueKind::NonFungibleLocalId => ScryptoCustomValue::NonFungibleLocalId(
+                NonFungibleLocalId::from_str(value).map_err(|err| format!("{:?}", err))?,
+            ),
+        };
+        Ok(custom_value)
+    }
+}
+
+/// Parses a [`ScryptoValue`] from its programmatic JSON representation.
+///
+/// The schema is only used to resolve enum variant names and annotate errors, the value isn't
+/// validated against it - use [`scrypto_payload_from_programmatic_json`] for that.
+pub fn scrypto_value_from_programmatic_json<'a>(
+    json: &serde_json::Value,
+    schema: &VersionedScryptoSchema,
+    type_id: LocalTypeId,
+    context: impl Into<ScryptoValueDeserializationContext<'a>>,
+) -> Result<ScryptoValue, LocatedDeserializationError> {
+    programmatic_json_to_value::<ScryptoCustomExtension>(
+        json,
+        &DeserializationParameters::WithSchema {
+            custom_context: context.into(),
+            schema: schema.v1(),
+            type_id,
+            depth_limit: SCRYPTO_SBOR_V1_MAX_DEPTH,
+        },
+    )
+}
+
+/// Parses a Scrypto SBOR payload from its programmatic JSON representation, and validates it
+/// against the given type in the schema.
+pub fn scrypto_payload_from_programmatic_json<'a>(
+    json: &serde_json::Value,
+    schema: &VersionedScryptoSchema,
+    type_id: LocalTypeId,
+    context: impl Into<ScryptoValueDeserializationContext<'a>>,
+) -> Result<Vec<u8>, LocatedDeserializationError> {
+    programmatic_json_to_payload::<ScryptoCustomExtension, ()>(
+        json,
+        &DeserializationParameters::WithSchema {
+            custom_context: context.into(),
+            schema: schema.v1(),
+            type_id,
+            depth_limit: SCRYPTO_SBOR_V1_MAX_DEPTH,
+        },
+        &(),
+    )
+}
+
 #[cfg(test)]
 #[cfg(feature = "serde")] // Ensures that VS Code runs this module with the features serde tag!
 mod tests {
     use super::*;
     use crate::address::test_addresses::*;
-    use crate::address::AddressBech32Encoder;
+    use crate::address::{AddressBech32Decoder, AddressBech32Encoder};
+    use crate::constants::*;
     use crate::data::scrypto::model::*;
-    use crate::data::scrypto::{scrypto_encode, ScryptoValue};
+    use crate::data::scrypto::{scrypto_encode, ScryptoCustomSchema, ScryptoValue};
     use crate::math::*;
     use crate::types::*;
     use radix_rust::ContextualSerialize;
+    use sbor::representations::*;
     use sbor::rust::vec;
+    use sbor::*;
     use serde::Serialize;
     use serde_json::{json, to_string, to_value, Value as JsonValue};
 
@@ -236,6 +379,99 @@ mod tests {
         assert_programmatic_json_matches(&value, context, expected_programmatic);
     }
 
+    #[test]
+    #[cfg(feature = "serde")] // Workaround for VS Code "Run Test" feature
+    fn test_programmatic_json_round_trip_with_network() {
+        let encoder = AddressBech32Encoder::for_simulator();
+        let decoder = AddressBech32Decoder::for_simulator();
+        let value = (
+            FUNGIBLE_RESOURCE,
+            Decimal::ONE.checked_div(100).unwrap(),
+            PreciseDecimal::ZERO,
+            NonFungibleLocalId::string("hello").unwrap(),
+            vec![NonFungibleLocalId::integer(123)],
+        );
+        let (type_id, schema) = generate_full_schema_from_single_type::<
+            (
+                ResourceAddress,
+                Decimal,
+                PreciseDecimal,
+                NonFungibleLocalId,
+                Vec<NonFungibleLocalId>,
+            ),
+            ScryptoCustomSchema,
+        >();
+        let payload = scrypto_encode(&value).unwrap();
+        let json = to_value(
+            &ScryptoRawPayload::new_from_valid_slice(&payload).serializable(
+                SerializationParameters::WithSchema {
+                    mode: SerializationMode::Programmatic,
+                    custom_context: ScryptoValueDisplayContext::with_optional_bech32(Some(
+                        &encoder,
+                    )),
+                    schema: schema.v1(),
+                    type_id,
+                    depth_limit: SCRYPTO_SBOR_V1_MAX_DEPTH,
+                },
+            ),
+        )
+        .unwrap();
+
+        let parsed_payload =
+            scrypto_payload_from_programmatic_json(&json, &schema, type_id, &decoder).unwrap();
+        assert_eq!(parsed_payload, payload);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")] // Workaround for VS Code "Run Test" feature
+    fn test_programmatic_json_rejects_value_not_matching_schema() {
+        let decoder = AddressBech32Decoder::for_simulator();
+        let (type_id, schema) =
+            generate_full_schema_from_single_type::<Sample, ScryptoCustomSchema>();
+        // A package address isn't a valid ResourceAddress
+        let json = json!({
+            "kind": "Tuple",
+            "fields": [
+                {
+                    "kind": "Reference",
+                    "value": AddressBech32Encoder::for_simulator()
+                        .encode(PACKAGE_PACKAGE.as_node_id().as_bytes())
+                        .unwrap()
+                }
+            ]
+        });
+
+        let error =
+            scrypto_payload_from_programmatic_json(&json, &schema, type_id, &decoder).unwrap_err();
+        assert!(matches!(
+            error.error,
+            DeserializationError::PayloadValidationError(_)
+        ));
+        assert!(error.format_path().starts_with("Sample.[0|a]"));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")] // Workaround for VS Code "Run Test" feature
+    fn test_programmatic_json_without_decoder_accepts_node_id_hex() {
+        let value = Reference(FUNGIBLE_RESOURCE_NODE_ID);
+        let json = json!({
+            "kind": "Reference",
+            "value": format!("NodeId({})", FUNGIBLE_RESOURCE_NODE_ID.to_hex())
+        });
+        let parsed = programmatic_json_to_value::<ScryptoCustomExtension>(
+            &json,
+            &DeserializationParameters::Schemaless {
+                custom_context: ScryptoValueDeserializationContext::no_context(),
+                depth_limit: SCRYPTO_SBOR_V1_MAX_DEPTH,
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            scrypto_encode(&parsed).unwrap(),
+            scrypto_encode(&value).unwrap()
+        );
+    }
+
     fn assert_natural_json_matches<
         'a,
         T: ScryptoEncode,
@@ -28,6 +28,8 @@ pub use custom_extension::*;
 pub use custom_formatting::*;
 pub use custom_payload_wrappers::*;
 pub use custom_schema::*;
+#[cfg(feature = "serde")]
+pub use custom_serde::*;
 pub use custom_traversal::*;
 pub use custom_value::*;
 pub use custom_value_kind::*;
@@ -46,6 +48,8 @@ pub mod prelude {
     pub use super::custom_formatting::*;
     pub use super::custom_payload_wrappers::*;
     pub use super::custom_schema::*;
+    #[cfg(feature = "serde")]
+    pub use super::custom_serde::*;
     pub use super::custom_traversal::*;
     pub use super::custom_value::*;
     pub use super::custom_value_kind::*;
@@ -13,6 +13,7 @@ use crate::data::scrypto::*;
 use crate::math::bnum_integer::*;
 use crate::math::rounding_mode::*;
 use crate::math::traits::*;
+use crate::math::transcendental;
 use crate::math::PreciseDecimal;
 use crate::well_known_scrypto_custom_type;
 use crate::*;
@@ -295,6 +296,45 @@ impl Decimal {
             Some(Decimal(nth_root))
         }
     }
+
+    /// Natural logarithm of a Decimal
+    ///
+    /// Returns `None` if the Decimal is not positive. The result is within one subunit
+    /// (`10^-18`) of the exact value.
+    pub fn checked_ln(&self) -> Option<Self> {
+        let ln = transcendental::ln(BigInt::from(self.0), Self::SCALE)?;
+        I192::try_from(ln).ok().map(Self)
+    }
+
+    /// Exponential function of a Decimal, `e^self`
+    ///
+    /// Returns `None` if the result overflows. The result is within one subunit (`10^-18`) of the
+    /// exact value, so it is zero if the exact value is less than half a subunit.
+    pub fn checked_exp(&self) -> Option<Self> {
+        let exp = transcendental::exp(BigInt::from(self.0), Self::SCALE)?;
+        I192::try_from(exp).ok().map(Self)
+    }
+
+    /// Logarithm of a Decimal to the given base
+    ///
+    /// Returns `None` if the Decimal or the base is not positive, or the base is one. The result
+    /// is within one subunit (`10^-18`) of the exact value.
+    pub fn checked_log<T: Into<Self>>(&self, base: T) -> Option<Self> {
+        let base = base.into();
+        let log = transcendental::log(BigInt::from(self.0), BigInt::from(base.0), Self::SCALE)?;
+        I192::try_from(log).ok().map(Self)
+    }
+
+    /// Raises a Decimal to a possibly fractional power, `self^exp`
+    ///
+    /// Returns `None` if the result overflows, if a negative Decimal is raised to a non-integer
+    /// power, or if zero is raised to a negative power. `0^0` is one. The result is within one
+    /// subunit (`10^-18`) of the exact value.
+    pub fn checked_pow<T: Into<Self>>(&self, exp: T) -> Option<Self> {
+        let exp = exp.into();
+        let pow = transcendental::pow(BigInt::from(self.0), BigInt::from(exp.0), Self::SCALE)?;
+        I192::try_from(pow).ok().map(Self)
+    }
 }
 
 macro_rules! from_primitive_type {
@@ -947,6 +987,9 @@ mod tests {
     use super::*;
     use crate::internal_prelude::*;
     use paste::paste;
+    use rand::Rng;
+    use rand_chacha::rand_core::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
 
     macro_rules! test_dec {
         // NOTE: Decimal arithmetic operation safe unwrap.
@@ -1972,6 +2015,166 @@ mod tests {
         assert_eq!(root_0, None);
     }
 
+    #[test]
+    fn test_ln() {
+        assert_eq!(
+            test_dec!(42).checked_ln().unwrap(),
+            test_dec!("3.737669618283368306")
+        );
+        assert_eq!(test_dec!(1).checked_ln().unwrap(), test_dec!(0));
+        assert_eq!(
+            Decimal::ONE_ATTO.checked_ln().unwrap(),
+            test_dec!("-41.446531673892822312")
+        );
+        assert_eq!(
+            Decimal::MAX.checked_ln().unwrap(),
+            test_dec!("90.944579813056731786")
+        );
+        assert_eq!(test_dec!(0).checked_ln(), None);
+        assert_eq!(test_dec!("-1").checked_ln(), None);
+    }
+
+    #[test]
+    fn test_exp() {
+        assert_eq!(test_dec!(0).checked_exp().unwrap(), test_dec!(1));
+        assert_eq!(
+            test_dec!(1).checked_exp().unwrap(),
+            test_dec!("2.718281828459045235")
+        );
+        assert_eq!(
+            test_dec!("-1").checked_exp().unwrap(),
+            test_dec!("0.367879441171442322")
+        );
+        assert_eq!(
+            test_dec!(42).checked_exp().unwrap(),
+            test_dec!("1739274941520501047.394681303611235226")
+        );
+        assert_eq!(
+            test_dec!("-42").checked_exp().unwrap(),
+            test_dec!("0.000000000000000001")
+        );
+        assert_eq!(test_dec!("-100").checked_exp().unwrap(), test_dec!(0));
+        assert_eq!(test_dec!(100).checked_exp(), None);
+        assert_eq!(Decimal::MAX.checked_exp(), None);
+        assert_eq!(Decimal::MIN.checked_exp().unwrap(), test_dec!(0));
+    }
+
+    #[test]
+    fn test_log() {
+        assert_eq!(
+            test_dec!(42).checked_log(10).unwrap(),
+            test_dec!("1.623249290397900463")
+        );
+        assert_eq!(test_dec!("0.001").checked_log(10).unwrap(), test_dec!("-3"));
+        assert_eq!(
+            test_dec!(2).checked_log(test_dec!("0.5")).unwrap(),
+            test_dec!("-1")
+        );
+        assert_eq!(test_dec!(42).checked_log(1), None);
+        assert_eq!(test_dec!(42).checked_log(0), None);
+        assert_eq!(test_dec!(42).checked_log(-10), None);
+        assert_eq!(test_dec!(0).checked_log(10), None);
+    }
+
+    #[test]
+    fn test_pow() {
+        assert_eq!(
+            test_dec!(42).checked_pow(test_dec!("0.5")).unwrap(),
+            test_dec!("6.480740698407860231")
+        );
+        assert_eq!(
+            test_dec!(42).checked_pow(test_dec!("1.5")).unwrap(),
+            test_dec!("272.191109333130129701")
+        );
+        assert_eq!(
+            test_dec!(2).checked_pow(test_dec!("-0.5")).unwrap(),
+            test_dec!("0.707106781186547524")
+        );
+        assert_eq!(test_dec!("-3").checked_pow(3).unwrap(), test_dec!("-27"));
+        assert_eq!(
+            test_dec!("-3").checked_pow(-2).unwrap(),
+            test_dec!("0.111111111111111111")
+        );
+        assert_eq!(test_dec!("-3").checked_pow(test_dec!("0.5")), None);
+        assert_eq!(test_dec!(0).checked_pow(0).unwrap(), test_dec!(1));
+        assert_eq!(
+            test_dec!(0).checked_pow(test_dec!("0.5")).unwrap(),
+            test_dec!(0)
+        );
+        assert_eq!(test_dec!(0).checked_pow(-1), None);
+        assert_eq!(
+            test_dec!(10).checked_pow(39).unwrap(),
+            test_dec!("1000000000000000000000000000000000000000")
+        );
+        assert_eq!(test_dec!(10).checked_pow(40), None);
+    }
+
+    fn random_decimal(rng: &mut ChaCha8Rng) -> Decimal {
+        // Between one attosubunit and 10^12
+        Decimal::from_attos(I192::from(rng.gen_range(1..10u128.pow(30))))
+    }
+
+    #[test]
+    fn test_exp_inverts_ln() {
+        let mut rng = ChaCha8Rng::seed_from_u64(1234);
+        for _ in 0..100 {
+            let x = random_decimal(&mut rng);
+            let exp_ln_x = x.checked_ln().unwrap().checked_exp().unwrap();
+            // The error of `ln(x)` is scaled by `x` in `exp(ln(x))`
+            let tolerance = x * Decimal::ONE_ATTO + Decimal::ONE_ATTO * 2;
+            assert!(
+                (exp_ln_x - x).checked_abs().unwrap() <= tolerance,
+                "exp(ln({})) = {}",
+                x,
+                exp_ln_x
+            );
+        }
+    }
+
+    #[test]
+    fn test_ln_of_product_is_sum_of_ln() {
+        let mut rng = ChaCha8Rng::seed_from_u64(1234);
+        for _ in 0..100 {
+            let x = Decimal::from(rng.gen_range(1..1_000_000_000u64));
+            let y = Decimal::from(rng.gen_range(1..1_000_000_000u64));
+            let ln_xy = (x * y).checked_ln().unwrap();
+            let sum = x.checked_ln().unwrap() + y.checked_ln().unwrap();
+            assert!(
+                (ln_xy - sum).checked_abs().unwrap() <= Decimal::ONE_ATTO * 2,
+                "ln({} * {})",
+                x,
+                y
+            );
+        }
+    }
+
+    #[test]
+    fn test_pow_of_half_is_sqrt() {
+        let mut rng = ChaCha8Rng::seed_from_u64(1234);
+        for _ in 0..100 {
+            let x = random_decimal(&mut rng);
+            let pow = x.checked_pow(test_dec!("0.5")).unwrap();
+            let sqrt = x.checked_sqrt().unwrap();
+            assert!(
+                (pow - sqrt).checked_abs().unwrap() <= Decimal::ONE_ATTO * 2,
+                "{}^0.5 = {}",
+                x,
+                pow
+            );
+        }
+    }
+
+    #[test]
+    fn test_integer_pow_and_log_are_exact() {
+        for base in 2..=10u32 {
+            for exp in 0..=12u32 {
+                let pow = Decimal::from(base).checked_pow(exp).unwrap();
+                assert_eq!(pow, Decimal::from(u64::from(base).pow(exp)));
+                assert_eq!(pow.checked_log(base).unwrap(), Decimal::from(exp));
+            }
+        }
+    }
+
     #[test]
     fn no_panic_with_18_decimal_places() {
         // Arrange
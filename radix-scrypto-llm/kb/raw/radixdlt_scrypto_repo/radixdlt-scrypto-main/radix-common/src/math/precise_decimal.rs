@@ -12,6 +12,7 @@ use crate::math::bnum_integer::*;
 use crate::math::decimal::*;
 use crate::math::rounding_mode::*;
 use crate::math::traits::*;
+use crate::math::transcendental;
 use crate::well_known_scrypto_custom_type;
 use crate::*;
 
@@ -307,6 +308,45 @@ impl PreciseDecimal {
             Some(Self(nth_root))
         }
     }
+
+    /// Natural logarithm of a PreciseDecimal
+    ///
+    /// Returns `None` if the PreciseDecimal is not positive. The result is within one subunit
+    /// (`10^-36`) of the exact value.
+    pub fn checked_ln(&self) -> Option<Self> {
+        let ln = transcendental::ln(BigInt::from(self.0), Self::SCALE)?;
+        I256::try_from(ln).ok().map(Self)
+    }
+
+    /// Exponential function of a PreciseDecimal, `e^self`
+    ///
+    /// Returns `None` if the result overflows. The result is within one subunit (`10^-36`) of the
+    /// exact value, so it is zero if the exact value is less than half a subunit.
+    pub fn checked_exp(&self) -> Option<Self> {
+        let exp = transcendental::exp(BigInt::from(self.0), Self::SCALE)?;
+        I256::try_from(exp).ok().map(Self)
+    }
+
+    /// Logarithm of a PreciseDecimal to the given base
+    ///
+    /// Returns `None` if the PreciseDecimal or the base is not positive, or the base is one. The result
+    /// is within one subunit (`10^-36`) of the exact value.
+    pub fn checked_log<T: Into<Self>>(&self, base: T) -> Option<Self> {
+        let base = base.into();
+        let log = transcendental::log(BigInt::from(self.0), BigInt::from(base.0), Self::SCALE)?;
+        I256::try_from(log).ok().map(Self)
+    }
+
+    /// Raises a PreciseDecimal to a possibly fractional power, `self^exp`
+    ///
+    /// Returns `None` if the result overflows, if a negative PreciseDecimal is raised to a non-integer
+    /// power, or if zero is raised to a negative power. `0^0` is one. The result is within one
+    /// subunit (`10^-36`) of the exact value.
+    pub fn checked_pow<T: Into<Self>>(&self, exp: T) -> Option<Self> {
+        let exp = exp.into();
+        let pow = transcendental::pow(BigInt::from(self.0), BigInt::from(exp.0), Self::SCALE)?;
+        I256::try_from(pow).ok().map(Self)
+    }
 }
 
 macro_rules! from_primitive_type {
@@ -979,6 +1019,9 @@ mod tests {
     use super::*;
     use crate::math::precise_decimal::RoundingMode;
     use paste::paste;
+    use rand::Rng;
+    use rand_chacha::rand_core::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
 
     macro_rules! test_dec {
         // NOTE: Decimal arithmetic operation safe unwrap.
@@ -2160,6 +2203,172 @@ mod tests {
         assert_eq!(root_0, None);
     }
 
+    #[test]
+    fn test_ln() {
+        assert_eq!(
+            test_pdec!(42).checked_ln().unwrap(),
+            test_pdec!("3.737669618283368305917830101823882002")
+        );
+        assert_eq!(test_pdec!(1).checked_ln().unwrap(), test_pdec!(0));
+        assert_eq!(
+            PreciseDecimal::ONE_PRECISE_SUBUNIT.checked_ln().unwrap(),
+            test_pdec!("-82.893063347785644624647692368637111474")
+        );
+        assert_eq!(
+            PreciseDecimal::MAX.checked_ln().unwrap(),
+            test_pdec!("93.859467695000409276746498603197913386")
+        );
+        assert_eq!(test_pdec!(0).checked_ln(), None);
+        assert_eq!(test_pdec!("-1").checked_ln(), None);
+    }
+
+    #[test]
+    fn test_exp() {
+        assert_eq!(test_pdec!(0).checked_exp().unwrap(), test_pdec!(1));
+        assert_eq!(
+            test_pdec!(1).checked_exp().unwrap(),
+            test_pdec!("2.718281828459045235360287471352662498")
+        );
+        assert_eq!(
+            test_pdec!("-1").checked_exp().unwrap(),
+            test_pdec!("0.367879441171442321595523770161460867")
+        );
+        assert_eq!(
+            test_pdec!(42).checked_exp().unwrap(),
+            test_pdec!("1739274941520501047.394681303611235226147984057725008401")
+        );
+        assert_eq!(
+            test_pdec!("-42").checked_exp().unwrap(),
+            test_pdec!("0.000000000000000000574952226429355981")
+        );
+        assert_eq!(test_pdec!("-100").checked_exp().unwrap(), test_pdec!(0));
+        assert_eq!(test_pdec!(100).checked_exp(), None);
+        assert_eq!(PreciseDecimal::MAX.checked_exp(), None);
+        assert_eq!(PreciseDecimal::MIN.checked_exp().unwrap(), test_pdec!(0));
+    }
+
+    #[test]
+    fn test_log() {
+        assert_eq!(
+            test_pdec!(42).checked_log(10).unwrap(),
+            test_pdec!("1.623249290397900463220983056572244529")
+        );
+        assert_eq!(
+            test_pdec!("0.001").checked_log(10).unwrap(),
+            test_pdec!("-3")
+        );
+        assert_eq!(
+            test_pdec!(2).checked_log(test_pdec!("0.5")).unwrap(),
+            test_pdec!("-1")
+        );
+        assert_eq!(test_pdec!(42).checked_log(1), None);
+        assert_eq!(test_pdec!(42).checked_log(0), None);
+        assert_eq!(test_pdec!(42).checked_log(-10), None);
+        assert_eq!(test_pdec!(0).checked_log(10), None);
+    }
+
+    #[test]
+    fn test_pow() {
+        assert_eq!(
+            test_pdec!(42).checked_pow(test_pdec!("0.5")).unwrap(),
+            test_pdec!("6.480740698407860230965967436087996658")
+        );
+        assert_eq!(
+            test_pdec!(42).checked_pow(test_pdec!("1.5")).unwrap(),
+            test_pdec!("272.191109333130129700570632315695859624")
+        );
+        assert_eq!(
+            test_pdec!(2).checked_pow(test_pdec!("-0.5")).unwrap(),
+            test_pdec!("0.707106781186547524400844362104849039")
+        );
+        assert_eq!(test_pdec!("-3").checked_pow(3).unwrap(), test_pdec!("-27"));
+        assert_eq!(
+            test_pdec!("-3").checked_pow(-2).unwrap(),
+            test_pdec!("0.111111111111111111111111111111111111")
+        );
+        assert_eq!(test_pdec!("-3").checked_pow(test_pdec!("0.5")), None);
+        assert_eq!(test_pdec!(0).checked_pow(0).unwrap(), test_pdec!(1));
+        assert_eq!(
+            test_pdec!(0).checked_pow(test_pdec!("0.5")).unwrap(),
+            test_pdec!(0)
+        );
+        assert_eq!(test_pdec!(0).checked_pow(-1), None);
+        assert_eq!(
+            test_pdec!(10).checked_pow(40).unwrap(),
+            test_pdec!("10000000000000000000000000000000000000000")
+        );
+        assert_eq!(test_pdec!(10).checked_pow(41), None);
+    }
+
+    fn random_precise_decimal(rng: &mut ChaCha8Rng) -> PreciseDecimal {
+        // Between one precise subunit and 10^12
+        let high = I256::from(rng.gen_range(0..10u128.pow(24)));
+        let low = I256::from(rng.gen_range(1..10u128.pow(24)));
+        PreciseDecimal::from_precise_subunits(high * I256::from(10u128.pow(24)) + low)
+    }
+
+    #[test]
+    fn test_exp_inverts_ln() {
+        let mut rng = ChaCha8Rng::seed_from_u64(1234);
+        for _ in 0..100 {
+            let x = random_precise_decimal(&mut rng);
+            let exp_ln_x = x.checked_ln().unwrap().checked_exp().unwrap();
+            // The error of `ln(x)` is scaled by `x` in `exp(ln(x))`
+            let tolerance =
+                x * PreciseDecimal::ONE_PRECISE_SUBUNIT + PreciseDecimal::ONE_PRECISE_SUBUNIT * 2;
+            assert!(
+                (exp_ln_x - x).checked_abs().unwrap() <= tolerance,
+                "exp(ln({})) = {}",
+                x,
+                exp_ln_x
+            );
+        }
+    }
+
+    #[test]
+    fn test_ln_of_product_is_sum_of_ln() {
+        let mut rng = ChaCha8Rng::seed_from_u64(1234);
+        for _ in 0..100 {
+            let x = PreciseDecimal::from(rng.gen_range(1..1_000_000_000u64));
+            let y = PreciseDecimal::from(rng.gen_range(1..1_000_000_000u64));
+            let ln_xy = (x * y).checked_ln().unwrap();
+            let sum = x.checked_ln().unwrap() + y.checked_ln().unwrap();
+            assert!(
+                (ln_xy - sum).checked_abs().unwrap() <= PreciseDecimal::ONE_PRECISE_SUBUNIT * 2,
+                "ln({} * {})",
+                x,
+                y
+            );
+        }
+    }
+
+    #[test]
+    fn test_pow_of_half_is_sqrt() {
+        let mut rng = ChaCha8Rng::seed_from_u64(1234);
+        for _ in 0..100 {
+            let x = random_precise_decimal(&mut rng);
+            let pow = x.checked_pow(test_pdec!("0.5")).unwrap();
+            let sqrt = x.checked_sqrt().unwrap();
+            assert!(
+                (pow - sqrt).checked_abs().unwrap() <= PreciseDecimal::ONE_PRECISE_SUBUNIT * 2,
+                "{}^0.5 = {}",
+                x,
+                pow
+            );
+        }
+    }
+
+    #[test]
+    fn test_integer_pow_and_log_are_exact() {
+        for base in 2..=10u32 {
+            for exp in 0..=12u32 {
+                let pow = PreciseDecimal::from(base).checked_pow(exp).unwrap();
+                assert_eq!(pow, PreciseDecimal::from(u64::from(base).pow(exp)));
+                assert_eq!(pow.checked_log(base).unwrap(), PreciseDecimal::from(exp));
+            }
+        }
+    }
+
     #[test]
     fn no_panic_with_36_decimal_places() {
         // Arrange
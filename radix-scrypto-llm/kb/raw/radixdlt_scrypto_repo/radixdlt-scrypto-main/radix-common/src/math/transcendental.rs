@@ -0,0 +1,192 @@
+//! Natural logarithms and exponentials of fixed-point numbers, shared by [`Decimal`] and
+//! [`PreciseDecimal`].
+//!
+//! A fixed-point number with `scale` decimal places is held in a [`BigInt`] as `value * 10^scale`.
+//! The computations are carried out with [`GUARD_DIGITS`] more decimal places than the result,
+//! and only use integer arithmetic, so they are deterministic across platforms.
+//!
+//! [`Decimal`]: crate::math::Decimal
+//! [`PreciseDecimal`]: crate::math::PreciseDecimal
+
+use num_bigint::BigInt;
+use num_traits::{Pow, Signed, Zero};
+use sbor::rust::string::ToString;
+
+/// The number of decimal places carried beyond those of the result.
+///
+/// Exponentials are computed as `2^k * exp(r)`, which scales the error of `exp(r)` by up to
+/// `2^136` (~`10^41`) for results within the range of a `PreciseDecimal`. The remaining guard
+/// digits keep the error of the result well below half of its last place.
+const GUARD_DIGITS: u32 = 48;
+
+/// `exp(x)` overflows every fixed-point type above this bound, and rounds to zero below its
+/// negation.
+const MAX_EXP_ARGUMENT: u32 = 200;
+
+/// Computes `ln(x)`, or returns `None` if `x` isn't positive.
+pub(crate) fn ln(x: BigInt, scale: u32) -> Option<BigInt> {
+    if !x.is_positive() {
+        return None;
+    }
+    let digits = scale + GUARD_DIGITS;
+    let ln = ln_fixed(&rescale(x, scale, digits), digits);
+    Some(rescale(ln, digits, scale))
+}
+
+/// Computes `exp(x)`, or returns `None` if it's too large for any fixed-point type.
+pub(crate) fn exp(x: BigInt, scale: u32) -> Option<BigInt> {
+    let digits = scale + GUARD_DIGITS;
+    let exp = exp_fixed(&rescale(x, scale, digits), digits)?;
+    Some(rescale(exp, digits, scale))
+}
+
+/// Computes `log_base(x)`, or returns `None` if `x` or `base` isn't positive, or `base` is one.
+pub(crate) fn log(x: BigInt, base: BigInt, scale: u32) -> Option<BigInt> {
+    if !x.is_positive() || !base.is_positive() || base == ten_pow(scale) {
+        return None;
+    }
+    // The base can be as close to one as `1 + 10^-scale`, in which case its logarithm is
+    // around `10^-scale` - dividing by it scales up the error by as much.
+    let digits = 2 * scale + GUARD_DIGITS;
+    let ln_x = ln_fixed(&rescale(x, scale, digits), digits);
+    let ln_base = ln_fixed(&rescale(base, scale, digits), digits);
+    Some(rescale(ln_x * ten_pow(digits) / ln_base, digits, scale))
+}
+
+/// Computes `x^y`, or returns `None` if it's undefined or too large for any fixed-point type.
+///
+/// Negative bases are only supported with integer exponents. `0^0` is one.
+pub(crate) fn pow(x: BigInt, y: BigInt, scale: u32) -> Option<BigInt> {
+    let one = ten_pow(scale);
+    if y.is_zero() {
+        return Some(one);
+    }
+    if x.is_zero() {
+        return if y.is_positive() {
+            Some(BigInt::zero())
+        } else {
+            None
+        };
+    }
+    let is_negative = if x.is_negative() {
+        if !(&y % &one).is_zero() {
+            return None;
+        }
+        !(&y / &one % 2u32).is_zero()
+    } else {
+        false
+    };
+
+    // `x^y = exp(y * ln(x))`, where the error of `ln(x)` is scaled by `y` - so carry as many
+    // more decimal places as `y` has integer digits.
+    let integer_digits = (y.magnitude() / one.magnitude()).to_string().len() as u32;
+    let digits = scale + GUARD_DIGITS + integer_digits;
+    let ln_x = ln_fixed(&rescale(x.abs(), scale, digits), digits);
+    let exp = exp_fixed(&(ln_x * y / one), digits)?;
+    let pow = rescale(exp, digits, scale);
+    Some(if is_negative { -pow } else { pow })
+}
+
+fn ten_pow(digits: u32) -> BigInt {
+    BigInt::from(10u32).pow(digits)
+}
+
+/// Changes the number of decimal places of `x`, rounding half away from zero if any are dropped.
+fn rescale(x: BigInt, from_digits: u32, to_digits: u32) -> BigInt {
+    if to_digits >= from_digits {
+        return x * ten_pow(to_digits - from_digits);
+    }
+    let divisor = ten_pow(from_digits - to_digits);
+    let half = &divisor / 2u32;
+    if x.is_negative() {
+        -((-x + half) / divisor)
+    } else {
+        (x + half) / divisor
+    }
+}
+
+/// `ln(x)` for a positive `x` with `digits` decimal places.
+fn ln_fixed(x: &BigInt, digits: u32) -> BigInt {
+    let one = ten_pow(digits);
+
+    // Reduce to `x = m * 2^k` with `1 <= m < 2`
+    let mut k = x.bits() as i64 - one.bits() as i64;
+    let mut m = if k >= 0 {
+        x >> (k as usize)
+    } else {
+        x << (-k as usize)
+    };
+    while m >= &one * 2u32 {
+        m >>= 1;
+        k += 1;
+    }
+    while m < one {
+        m <<= 1;
+        k -= 1;
+    }
+
+    // `ln(m) = 2 * atanh((m - 1) / (m + 1))`, where `0 <= (m - 1) / (m + 1) < 1/3`
+    let z = (&m - &one) * &one / (&m + &one);
+    two_atanh(&z, &one) + ln_2(&one) * k
+}
+
+/// `exp(x)` for an `x` with `digits` decimal places.
+fn exp_fixed(x: &BigInt, digits: u32) -> Option<BigInt> {
+    let one = ten_pow(digits);
+    let bound = &one * MAX_EXP_ARGUMENT;
+    if *x > bound {
+        return None;
+    }
+    if *x < -bound {
+        return Some(BigInt::zero());
+    }
+
+    // Reduce to `x = k * ln(2) + r` with `|r| <= ln(2) / 2`
+    let ln_2 = ln_2(&one);
+    let k = if x.is_negative() {
+        (x * 2u32 - &ln_2) / (&ln_2 * 2u32)
+    } else {
+        (x * 2u32 + &ln_2) / (&ln_2 * 2u32)
+    };
+    let r = x - &ln_2 * &k;
+
+    // `exp(r) = 1 + r + r^2/2! + r^3/3! + ...`
+    let mut sum = one.clone();
+    let mut term = one.clone();
+    let mut n = 1u32;
+    loop {
+        term = &term * &r / &one / n;
+        if term.is_zero() {
+            break;
+        }
+        sum += &term;
+        n += 1;
+    }
+
+    let k = i64::try_from(&k).expect("Bounded by MAX_EXP_ARGUMENT");
+    Some(if k >= 0 {
+        sum << (k as usize)
+    } else {
+        sum >> (-k as usize)
+    })
+}
+
+/// `2 * atanh(z) = 2 * (z + z^3/3 + z^5/5 + ...)` for `|z| <= 1/3`, where `one` is the scale
+/// of `z`.
+fn two_atanh(z: &BigInt, one: &BigInt) -> BigInt {
+    let z_squared = z * z / one;
+    let mut sum = BigInt::zero();
+    let mut term = z.clone();
+    let mut n = 1u32;
+    while !term.is_zero() {
+        sum += &term / n;
+        term = &term * &z_squared / one;
+        n += 2;
+    }
+    sum * 2u32
+}
+
+/// `ln(2) = 2 * atanh(1/3)`, where `one` is the scale of the result.
+fn ln_2(one: &BigInt) -> BigInt {
+    two_atanh(&(one / 3u32), one)
+}
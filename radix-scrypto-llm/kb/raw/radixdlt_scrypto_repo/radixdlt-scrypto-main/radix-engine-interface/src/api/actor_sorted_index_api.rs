@@ -60,6 +60,15 @@ pub trait SystemActorSortedIndexApi<E> {
         count: u32,
     ) -> Result<Vec<(SortedKey, Vec<u8>)>, E>;
 
+    /// Scans the first elements of count from a sorted index, starting from the given sort prefix
+    fn actor_sorted_index_scan_from(
+        &mut self,
+        object_handle: ActorStateHandle,
+        collection_index: CollectionIndex,
+        from_sort_prefix: [u8; 2],
+        count: u32,
+    ) -> Result<Vec<(SortedKey, Vec<u8>)>, E>;
+
     /// Scans the first elements of count from a sorted index
     fn actor_sorted_index_scan_typed<K: ScryptoDecode, V: ScryptoDecode>(
         &mut self,
@@ -0,0 +1,80 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+#[collections(
+    orders: Index<u64, String>,
+    bids: SortedIndex<u64, String>,
+)]
+mod index_collections {
+    struct OrderBook {}
+
+    impl OrderBook {
+        pub fn new() -> Global<OrderBook> {
+            Self {}
+                .instantiate()
+                .prepare_to_globalize(OwnerRole::None)
+                .globalize()
+        }
+
+        /// Scans the bids while the component is still owned, so that they are read from the heap.
+        pub fn new_with_bids(
+            bids: Vec<(u16, u64, String)>,
+            min_price: u16,
+            max_price: u16,
+            limit: u32,
+        ) -> (
+            Global<OrderBook>,
+            Vec<(u16, u64, String)>,
+            Vec<(u16, u64, String)>,
+        ) {
+            let order_book = Self {}.instantiate();
+            for (price, id, bid) in bids {
+                order_book.insert_bid(price, id, bid);
+            }
+            let lowest = order_book.scan_bids(limit);
+            let in_range = order_book.bids_in_range(min_price, max_price, limit);
+            (
+                order_book.prepare_to_globalize(OwnerRole::None).globalize(),
+                lowest,
+                in_range,
+            )
+        }
+
+        pub fn insert_order(&self, id: u64, order: String) {
+            self.orders().insert(id, order);
+        }
+
+        pub fn remove_order(&self, id: u64) -> Option<String> {
+            self.orders().remove(&id)
+        }
+
+        pub fn order_ids(&self, limit: u32) -> Vec<u64> {
+            self.orders().scan_keys(limit)
+        }
+
+        pub fn drain_orders(&self, limit: u32) -> Vec<(u64, String)> {
+            self.orders().drain(limit)
+        }
+
+        pub fn insert_bid(&self, price: u16, id: u64, bid: String) {
+            self.bids().insert(price, id, bid);
+        }
+
+        pub fn remove_bid(&self, price: u16, id: u64) -> Option<String> {
+            self.bids().remove(price, &id)
+        }
+
+        pub fn scan_bids(&self, limit: u32) -> Vec<(u16, u64, String)> {
+            self.bids().scan(limit)
+        }
+
+        pub fn bids_in_range(
+            &self,
+            min_price: u16,
+            max_price: u16,
+            limit: u32,
+        ) -> Vec<(u16, u64, String)> {
+            self.bids().range(min_price..=max_price, limit)
+        }
+    }
+}
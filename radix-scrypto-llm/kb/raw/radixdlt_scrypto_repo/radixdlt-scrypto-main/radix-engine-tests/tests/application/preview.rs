@@ -1,6 +1,7 @@
 use radix_common::prelude::*;
 use radix_engine::system::system_modules::costing::FeeTable;
-use radix_engine::transaction::ExecutionConfig;
+use radix_engine::transaction::{execute_preview, execute_previews_in_parallel, ExecutionConfig};
+use radix_engine::vm::VmModules;
 use radix_engine_interface::rule;
 use scrypto_test::prelude::*;
 
@@ -324,6 +325,83 @@ fn notary_key_is_not_in_initial_proofs_when_notary_as_signatory_is_false() {
         });
 }
 
+#[test]
+fn test_parallel_previews_match_sequential_previews() {
+    // Arrange
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let network = NetworkDefinition::simulator();
+    let (_, _, account) = ledger.new_allocated_account();
+    let preview_flags = PreviewFlags {
+        use_free_credit: true,
+        assume_all_signature_proofs: true,
+        skip_epoch_check: false,
+        disable_auth: false,
+    };
+    let preview_intents: Vec<PreviewIntentV1> = (0..16)
+        .map(|i| {
+            let manifest = if i % 4 == 3 {
+                // Fails, as the account can't cover the withdrawal
+                ManifestBuilder::new()
+                    .lock_fee_from_faucet()
+                    .withdraw_from_account(account, XRD, dec!("1000000000"))
+                    .try_deposit_entire_worktop_or_abort(account, None)
+                    .build()
+            } else {
+                ManifestBuilder::new()
+                    .lock_fee_from_faucet()
+                    .get_free_xrd_from_faucet()
+                    .take_from_worktop(XRD, Decimal::from(i + 1), "bucket")
+                    .try_deposit_or_abort(account, None, "bucket")
+                    .try_deposit_entire_worktop_or_abort(account, None)
+                    .build()
+            };
+            prepare_matching_test_tx_and_preview_intent(
+                &mut ledger,
+                &network,
+                manifest,
+                &preview_flags,
+            )
+            .1
+        })
+        .collect();
+    let vm_modules = VmModules::default();
+
+    // Act
+    let sequential_receipts: Vec<_> = preview_intents
+        .iter()
+        .map(|preview_intent| {
+            execute_preview(
+                ledger.substate_db(),
+                &vm_modules,
+                &network,
+                preview_intent.clone(),
+                false,
+            )
+        })
+        .collect();
+    let parallel_receipts = execute_previews_in_parallel(
+        ledger.substate_db(),
+        &vm_modules,
+        &network,
+        preview_intents,
+        false,
+    );
+
+    // Assert
+    assert_eq!(parallel_receipts.len(), sequential_receipts.len());
+    for (parallel, sequential) in parallel_receipts.iter().zip(sequential_receipts.iter()) {
+        assert_eq!(parallel, sequential);
+    }
+    parallel_receipts[0]
+        .as_ref()
+        .unwrap()
+        .expect_commit_success();
+    parallel_receipts[3]
+        .as_ref()
+        .unwrap()
+        .expect_commit_failure();
+}
+
 fn prepare_matching_test_tx_and_preview_intent(
     ledger: &mut DefaultLedgerSimulator,
     network: &NetworkDefinition,
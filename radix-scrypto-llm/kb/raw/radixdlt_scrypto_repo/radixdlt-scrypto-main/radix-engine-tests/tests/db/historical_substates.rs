@@ -0,0 +1,119 @@
+use radix_common::prelude::*;
+use radix_engine::system::system_db_reader::SystemDatabaseReader;
+use radix_substate_store_impls::state_tree_support::*;
+use radix_substate_store_interface::interface::*;
+use radix_transaction_scenarios::executor::*;
+use scrypto_test::prelude::*;
+
+type Database = StateTreeUpdatingDatabase<InMemorySubstateDatabase>;
+
+#[derive(Default)]
+struct SnapshotHooks {
+    transaction_count: usize,
+    snapshots: Vec<(u64, InMemorySubstateDatabase)>,
+}
+
+impl ScenarioExecutionHooks<Database> for SnapshotHooks {
+    fn on_transaction_executed(&mut self, event: OnScenarioTransactionExecuted<Database>) {
+        self.transaction_count += 1;
+        if self.transaction_count % 25 == 0 {
+            self.snapshots.push((
+                event.database.get_current_state_version(),
+                event.database.underlying().clone(),
+            ));
+        }
+    }
+}
+
+fn read_epoch<S: SubstateDatabase>(database: &S) -> Epoch {
+    SystemDatabaseReader::new(database)
+        .read_typed_object_field::<ConsensusManagerStateFieldPayload>(
+            CONSENSUS_MANAGER.as_node_id(),
+            ModuleId::Main,
+            ConsensusManagerField::State.field_index(),
+        )
+        .unwrap()
+        .fully_update_and_into_latest_version()
+        .epoch
+}
+
+#[test]
+fn substates_at_previous_versions_match_snapshots_taken_at_those_versions() {
+    // Arrange
+    let mut hooks = SnapshotHooks::default();
+    let mut executor = TransactionScenarioExecutor::new(
+        StateTreeUpdatingDatabase::new(InMemorySubstateDatabase::standard())
+            .with_historical_substates(),
+        NetworkDefinition::simulator(),
+    );
+
+    // Act
+    executor
+        .execute_every_protocol_update_and_scenario(&mut hooks)
+        .expect("Must succeed!");
+    let database = executor.into_database();
+
+    // Assert
+    assert!(!hooks.snapshots.is_empty());
+    for (state_version, snapshot) in hooks.snapshots {
+        let database_at_version = SubstateDatabaseAtVersion::new(&database, state_version).unwrap();
+        let partition_keys: IndexSet<DbPartitionKey> = snapshot
+            .list_partition_keys()
+            .chain(database.list_partition_keys())
+            .collect();
+        for partition_key in partition_keys {
+            assert_eq!(
+                database_at_version
+                    .list_raw_values_from_db_key(&partition_key, None)
+                    .collect::<Vec<_>>(),
+                snapshot
+                    .list_raw_values_from_db_key(&partition_key, None)
+                    .collect::<Vec<_>>(),
+                "Partition {:?} differs at state version {}",
+                partition_key,
+                state_version
+            );
+        }
+        assert_eq!(read_epoch(&database_at_version), read_epoch(&snapshot));
+        assert_eq!(
+            SystemDatabaseReader::new_at_version(&database, state_version)
+                .unwrap()
+                .read_typed_object_field::<ConsensusManagerStateFieldPayload>(
+                    CONSENSUS_MANAGER.as_node_id(),
+                    ModuleId::Main,
+                    ConsensusManagerField::State.field_index(),
+                )
+                .unwrap()
+                .fully_update_and_into_latest_version()
+                .epoch,
+            read_epoch(&snapshot)
+        );
+    }
+}
+
+#[test]
+fn only_the_current_version_is_available_without_historical_substates() {
+    // Arrange
+    let mut executor = TransactionScenarioExecutor::new(
+        StateTreeUpdatingDatabase::new(InMemorySubstateDatabase::standard()),
+        NetworkDefinition::simulator(),
+    );
+
+    // Act
+    executor
+        .execute_every_protocol_update_and_scenario(&mut ())
+        .expect("Must succeed!");
+    let database = executor.into_database();
+
+    // Assert
+    let current_state_version = database.get_current_state_version();
+    assert!(SubstateDatabaseAtVersion::new(&database, current_state_version).is_ok());
+    assert_eq!(
+        SubstateDatabaseAtVersion::new(&database, current_state_version - 1).err(),
+        Some(StateVersionNotAvailableError {
+            state_version: current_state_version - 1,
+            first_historical_state_version: current_state_version,
+            current_state_version,
+        })
+    );
+}
@@ -24,3 +24,58 @@ fn substate_store_matches_state_tree_after_each_scenario() {
         .execute_every_protocol_update_and_scenario(&mut Hooks)
         .expect("Must succeed!");
 }
+
+#[test]
+fn substates_are_proven_against_the_current_root_hash() {
+    // Arrange
+    let mut executor = TransactionScenarioExecutor::new(
+        StateTreeUpdatingDatabase::new(InMemorySubstateDatabase::standard()),
+        NetworkDefinition::simulator(),
+    );
+    executor
+        .execute_every_protocol_update_and_scenario(&mut ())
+        .expect("Must succeed!");
+    let database = executor.into_database();
+    let version = database.get_current_version();
+    let root_hash = database.get_current_root_hash();
+
+    // Act
+    let included = database
+        .get_substate_with_proof(
+            CONSENSUS_MANAGER,
+            MAIN_BASE_PARTITION,
+            ConsensusManagerField::State,
+            version,
+        )
+        .unwrap();
+    let absent = database
+        .get_substate_with_proof(
+            CONSENSUS_MANAGER,
+            PartitionNumber(200),
+            SubstateKey::Field(0),
+            version,
+        )
+        .unwrap();
+    let previous_version =
+        database.get_substate_with_proof(CONSENSUS_MANAGER, MAIN_BASE_PARTITION, 0u8, version - 1);
+
+    // Assert
+    let partition_key = SpreadPrefixKeyMapper::to_db_partition_key(
+        CONSENSUS_MANAGER.as_node_id(),
+        MAIN_BASE_PARTITION,
+    );
+    let sort_key = SpreadPrefixKeyMapper::to_db_sort_key(&ConsensusManagerField::State.into());
+    assert!(included.value.is_some());
+    assert_eq!(
+        included.verify(&root_hash, &partition_key, &sort_key),
+        Ok(())
+    );
+    let partition_key = SpreadPrefixKeyMapper::to_db_partition_key(
+        CONSENSUS_MANAGER.as_node_id(),
+        PartitionNumber(200),
+    );
+    let sort_key = SpreadPrefixKeyMapper::to_db_sort_key(&SubstateKey::Field(0));
+    assert_eq!(absent.value, None);
+    assert_eq!(absent.verify(&root_hash, &partition_key, &sort_key), Ok(()));
+    assert!(previous_version.is_none());
+}
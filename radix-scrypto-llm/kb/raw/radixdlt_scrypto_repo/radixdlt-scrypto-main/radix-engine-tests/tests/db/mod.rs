@@ -1,4 +1,5 @@
 // We used to use automod, but it breaks various tools
 // such as cargo fmt, so let's just list them explicitly.
+mod historical_substates;
 mod jmt_consistency;
 mod substate_database_overlay;
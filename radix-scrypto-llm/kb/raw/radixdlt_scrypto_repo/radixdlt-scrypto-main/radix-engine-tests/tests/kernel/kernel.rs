@@ -430,3 +430,72 @@ fn kernel_close_substate_should_fail_if_opened_child_exists() {
         ))
     );
 }
+
+#[test]
+fn track_scan_sorted_substates_starts_from_the_given_sort_prefix() {
+    // Arrange
+    let mut database = InMemorySubstateDatabase::standard();
+    let node_id = NodeId::new(
+        EntityType::InternalKeyValueStore as u8,
+        &[7u8; NodeId::RID_LENGTH],
+    );
+    let partition_num = PartitionNumber(64u8);
+    let sorted_key = |sort_prefix: u16, key: u8| (sort_prefix.to_be_bytes(), vec![key]);
+    for (sort_prefix, key) in [(1u16, 10u8), (2, 20), (3, 30)] {
+        database.update_substate(
+            node_id,
+            partition_num,
+            SubstateKey::Sorted(sorted_key(sort_prefix, key)),
+            key,
+        );
+    }
+    let mut track = Track::new(&database);
+    for (sort_prefix, key) in [(0u16, 0u8), (4, 40)] {
+        track
+            .set_substate(
+                node_id,
+                partition_num,
+                SubstateKey::Sorted(sorted_key(sort_prefix, key)),
+                IndexedScryptoValue::from_typed(&key),
+                &mut |_| Ok::<(), ()>(()),
+            )
+            .unwrap();
+    }
+
+    // Act
+    let mut keys_read_from_db = Vec::new();
+    let substates = track
+        .scan_sorted_substates(
+            &node_id,
+            partition_num,
+            Some(2u16.to_be_bytes()),
+            10,
+            &mut |io_access| {
+                if let IOAccess::ReadFromDb(canonical_substate_key, _) = io_access {
+                    keys_read_from_db.push(canonical_substate_key.substate_key);
+                }
+                Ok::<(), ()>(())
+            },
+        )
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        substates
+            .into_iter()
+            .map(|(sorted_key, value)| (sorted_key, value.as_typed::<u8>().unwrap()))
+            .collect::<Vec<_>>(),
+        vec![
+            (sorted_key(2, 20), 20),
+            (sorted_key(3, 30), 30),
+            (sorted_key(4, 40), 40),
+        ]
+    );
+    assert_eq!(
+        keys_read_from_db,
+        vec![
+            SubstateKey::Sorted(sorted_key(2, 20)),
+            SubstateKey::Sorted(sorted_key(3, 30)),
+        ]
+    );
+}
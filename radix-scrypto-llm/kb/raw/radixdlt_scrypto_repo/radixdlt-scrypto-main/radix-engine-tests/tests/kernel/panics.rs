@@ -163,6 +163,7 @@ impl<E: KernelTransactionExecutor> KernelSubstateApi<E::LockData> for MockKernel
         &mut self,
         _: &NodeId,
         _: PartitionNumber,
+        _: Option<[u8; 2]>,
         _: u32,
     ) -> Result<Vec<(SortedKey, IndexedScryptoValue)>, RuntimeError> {
         panic1!()
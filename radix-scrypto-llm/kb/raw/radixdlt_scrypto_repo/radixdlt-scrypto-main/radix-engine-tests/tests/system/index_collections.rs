@@ -0,0 +1,259 @@
+use radix_common::prelude::*;
+use radix_engine::blueprints::package::PackageError;
+use radix_engine::errors::{ApplicationError, RuntimeError};
+use radix_engine::vm::{ScryptoVmVersion, VmBoot};
+use radix_engine_tests::common::*;
+use radix_substate_store_interface::interface::*;
+use scrypto_test::prelude::*;
+
+fn enable_actor_collections(ledger: &mut DefaultLedgerSimulator) {
+    let database_updates = StateUpdates::empty()
+        .set_substate(
+            TRANSACTION_TRACKER,
+            BOOT_LOADER_PARTITION,
+            BootLoaderField::VmBoot,
+            VmBoot::V1 {
+                scrypto_version: ScryptoVmVersion::actor_collections_v1().into(),
+            },
+        )
+        .create_database_updates();
+    ledger.substate_db_mut().commit(&database_updates);
+}
+
+fn create_order_book() -> (DefaultLedgerSimulator, PackageAddress, ComponentAddress) {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    enable_actor_collections(&mut ledger);
+    let package_address = ledger.publish_package_simple(PackageLoader::get("index_collections"));
+
+    let receipt = ledger.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(package_address, "OrderBook", "new", manifest_args!())
+            .build(),
+        vec![],
+    );
+    let component_address = receipt.expect_commit_success().new_component_addresses()[0];
+
+    (ledger, package_address, component_address)
+}
+
+fn call_method<T: ScryptoDecode>(
+    ledger: &mut DefaultLedgerSimulator,
+    component_address: ComponentAddress,
+    method_name: &str,
+    args: impl ResolvableArguments,
+) -> T {
+    let receipt = ledger.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(component_address, method_name, args)
+            .build(),
+        vec![],
+    );
+    receipt.expect_commit_success().output(1)
+}
+
+#[test]
+fn publishing_index_collections_without_vm_boot_flash_should_fail() {
+    // Arrange
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+
+    // Act
+    let receipt = ledger.try_publish_package(PackageLoader::get("index_collections"));
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::PackageError(
+                PackageError::InvalidWasm(..)
+            ))
+        )
+    });
+}
+
+#[test]
+fn index_can_be_scanned_and_drained() {
+    // Arrange
+    let (mut ledger, _, component_address) = create_order_book();
+    for id in 1u64..=5 {
+        let _: () = call_method(
+            &mut ledger,
+            component_address,
+            "insert_order",
+            manifest_args!(id, format!("order {}", id)),
+        );
+    }
+
+    // Act
+    let removed: Option<String> = call_method(
+        &mut ledger,
+        component_address,
+        "remove_order",
+        manifest_args!(3u64),
+    );
+    let mut ids: Vec<u64> = call_method(
+        &mut ledger,
+        component_address,
+        "order_ids",
+        manifest_args!(10u32),
+    );
+    let drained: Vec<(u64, String)> = call_method(
+        &mut ledger,
+        component_address,
+        "drain_orders",
+        manifest_args!(3u32),
+    );
+    let remaining: Vec<(u64, String)> = call_method(
+        &mut ledger,
+        component_address,
+        "drain_orders",
+        manifest_args!(10u32),
+    );
+
+    // Assert
+    assert_eq!(removed, Some("order 3".to_string()));
+    ids.sort();
+    assert_eq!(ids, vec![1, 2, 4, 5]);
+    assert_eq!(drained.len(), 3);
+    assert_eq!(remaining.len(), 1);
+    let mut all: Vec<_> = drained.into_iter().chain(remaining).collect();
+    all.sort();
+    assert_eq!(
+        all,
+        vec![1u64, 2, 4, 5]
+            .into_iter()
+            .map(|id| (id, format!("order {}", id)))
+            .collect::<Vec<_>>()
+    );
+    let ids: Vec<u64> = call_method(
+        &mut ledger,
+        component_address,
+        "order_ids",
+        manifest_args!(10u32),
+    );
+    assert!(ids.is_empty());
+}
+
+#[test]
+fn sorted_index_is_scanned_in_sort_prefix_order() {
+    // Arrange
+    let (mut ledger, _, component_address) = create_order_book();
+    for (price, id) in [(300u16, 1u64), (100, 2), (200, 3), (100, 4), (500, 5)] {
+        let _: () = call_method(
+            &mut ledger,
+            component_address,
+            "insert_bid",
+            manifest_args!(price, id, format!("bid {}", id)),
+        );
+    }
+
+    // Act
+    let removed: Option<String> = call_method(
+        &mut ledger,
+        component_address,
+        "remove_bid",
+        manifest_args!(500u16, 5u64),
+    );
+    let lowest: Vec<(u16, u64, String)> = call_method(
+        &mut ledger,
+        component_address,
+        "scan_bids",
+        manifest_args!(3u32),
+    );
+    let in_range: Vec<(u16, u64, String)> = call_method(
+        &mut ledger,
+        component_address,
+        "bids_in_range",
+        manifest_args!(150u16, 400u16, 10u32),
+    );
+    let limited_range: Vec<(u16, u64, String)> = call_method(
+        &mut ledger,
+        component_address,
+        "bids_in_range",
+        manifest_args!(200u16, 400u16, 1u32),
+    );
+
+    // Assert
+    assert_eq!(removed, Some("bid 5".to_string()));
+    assert_eq!(
+        lowest,
+        vec![
+            (100, 2, "bid 2".to_string()),
+            (100, 4, "bid 4".to_string()),
+            (200, 3, "bid 3".to_string()),
+        ]
+    );
+    assert_eq!(
+        in_range,
+        vec![(200, 3, "bid 3".to_string()), (300, 1, "bid 1".to_string())]
+    );
+    assert_eq!(limited_range, vec![(200, 3, "bid 3".to_string())]);
+}
+
+#[test]
+fn sorted_index_of_a_component_can_be_scanned_before_it_is_globalized() {
+    // Arrange
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    enable_actor_collections(&mut ledger);
+    let package_address = ledger.publish_package_simple(PackageLoader::get("index_collections"));
+    let bids: Vec<(u16, u64, String)> = [(300u16, 1u64), (100, 2), (200, 3), (100, 4), (500, 5)]
+        .into_iter()
+        .map(|(price, id)| (price, id, format!("bid {}", id)))
+        .collect();
+
+    // Act
+    let receipt = ledger.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(
+                package_address,
+                "OrderBook",
+                "new_with_bids",
+                manifest_args!(bids, 150u16, 400u16, 3u32),
+            )
+            .build(),
+        vec![],
+    );
+
+    // Assert
+    let commit = receipt.expect_commit_success();
+    let component_address = commit.new_component_addresses()[0];
+    let (_, lowest, in_range): (
+        ComponentAddress,
+        Vec<(u16, u64, String)>,
+        Vec<(u16, u64, String)>,
+    ) = commit.output(1);
+    assert_eq!(
+        in_range,
+        vec![(200, 3, "bid 3".to_string()), (300, 1, "bid 1".to_string())]
+    );
+    let globalized_lowest: Vec<(u16, u64, String)> = call_method(
+        &mut ledger,
+        component_address,
+        "scan_bids",
+        manifest_args!(3u32),
+    );
+    assert_eq!(lowest, globalized_lowest);
+}
+
+#[test]
+fn index_collections_are_in_blueprint_schema() {
+    // Arrange
+    let (ledger, package_address, _) = create_order_book();
+
+    // Act
+    let definitions = ledger.get_package_blueprint_definitions(&package_address);
+    let definition = definitions
+        .get(&BlueprintVersionKey::new_default("OrderBook"))
+        .unwrap();
+
+    // Assert
+    assert!(matches!(
+        definition.interface.state.collections.as_slice(),
+        [
+            (_, BlueprintCollectionSchema::Index(..)),
+            (_, BlueprintCollectionSchema::SortedIndex(..))
+        ]
+    ));
+}
@@ -32,6 +32,7 @@ mod faucet;
 mod fee;
 mod fee_reserve_states;
 mod identity;
+mod index_collections;
 mod instructions;
 mod invalid_stored_values;
 mod kv_store;
@@ -56,6 +57,7 @@ mod royalty;
 mod royalty_auth;
 mod royalty_edge_cases;
 mod schema_sanity_check;
+mod speculative_execution;
 mod subintent_auth;
 mod subintent_leaks;
 mod subintent_lock_fee;
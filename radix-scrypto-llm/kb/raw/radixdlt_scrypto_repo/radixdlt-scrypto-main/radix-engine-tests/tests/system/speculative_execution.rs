@@ -0,0 +1,175 @@
+use radix_engine::transaction::*;
+use radix_engine::vm::{DefaultVmModules, VmModules};
+use radix_substate_store_impls::substate_database_overlay::SubstateDatabaseOverlay;
+use scrypto_test::prelude::*;
+
+#[test]
+fn speculative_parallel_execution_commits_the_same_state_updates_as_sequential_execution() {
+    // Arrange
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let network = NetworkDefinition::simulator();
+    let accounts: Vec<ComponentAddress> =
+        (0..4).map(|_| ledger.new_allocated_account().2).collect();
+    let transactions: Vec<(TransactionManifestV1, u32)> = (0..12)
+        .map(|i| {
+            let account = accounts[i % accounts.len()];
+            let manifest = if i % 5 == 4 {
+                // Fails, as the account can't cover the withdrawal
+                ManifestBuilder::new()
+                    .lock_fee_from_faucet()
+                    .withdraw_from_account(account, XRD, dec!("1000000000"))
+                    .try_deposit_entire_worktop_or_abort(account, None)
+                    .build()
+            } else {
+                ManifestBuilder::new()
+                    .lock_fee_from_faucet()
+                    .get_free_xrd_from_faucet()
+                    .try_deposit_entire_worktop_or_abort(account, None)
+                    .build()
+            };
+            (manifest, ledger.next_transaction_nonce())
+        })
+        .collect();
+    let vm_modules = VmModules::default();
+
+    // Act
+    let mut sequential_database = SubstateDatabaseOverlay::new_unmergeable(ledger.substate_db());
+    let mut sequential_receipts = Vec::new();
+    for transaction in &transactions {
+        let receipt = execute(&sequential_database, &vm_modules, &network, transaction);
+        sequential_database.commit(&database_updates(&receipt));
+        sequential_receipts.push(receipt);
+    }
+    let speculative = execute_speculatively_in_parallel(
+        ledger.substate_db(),
+        &transactions,
+        |database, transaction| execute(database, &vm_modules, &network, transaction),
+    );
+
+    // Assert
+    assert_same_receipts(&speculative.results, &sequential_receipts);
+    sequential_receipts[0].expect_commit_success();
+    sequential_receipts[4].expect_commit_failure();
+    // Each transaction locks its fee from the vault of the faucet, which the earlier ones updated
+    assert_eq!(speculative.re_executed_count, transactions.len() - 1);
+}
+
+#[test]
+fn speculative_parallel_execution_does_not_re_execute_independent_transactions() {
+    // Arrange
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let network = NetworkDefinition::simulator();
+    let transactions: Vec<(TransactionManifestV1, u32, Secp256k1PublicKey)> = (0..4)
+        .map(|_| {
+            let (public_key, _, account) = ledger.new_allocated_account();
+            let manifest = ManifestBuilder::new()
+                .lock_fee(account, 500)
+                .withdraw_from_account(account, XRD, 1)
+                .try_deposit_entire_worktop_or_abort(account, None)
+                .build();
+            (manifest, ledger.next_transaction_nonce(), public_key)
+        })
+        .collect();
+    let vm_modules = VmModules::default();
+
+    // Act
+    let mut sequential_database = SubstateDatabaseOverlay::new_unmergeable(ledger.substate_db());
+    let mut sequential_receipts = Vec::new();
+    for (manifest, nonce, public_key) in &transactions {
+        let receipt = execute_signed(
+            &sequential_database,
+            &vm_modules,
+            &network,
+            manifest,
+            *nonce,
+            public_key,
+        );
+        sequential_database.commit(&database_updates(&receipt));
+        sequential_receipts.push(receipt);
+    }
+    let speculative = execute_speculatively_in_parallel(
+        ledger.substate_db(),
+        &transactions,
+        |database, (manifest, nonce, public_key)| {
+            execute_signed(
+                database,
+                &vm_modules,
+                &network,
+                manifest,
+                *nonce,
+                public_key,
+            )
+        },
+    );
+
+    // Assert
+    assert_same_receipts(&speculative.results, &sequential_receipts);
+    for receipt in &sequential_receipts {
+        receipt.expect_commit_success();
+    }
+    // The fee distribution updates of the earlier transactions are merged instead
+    assert_eq!(speculative.re_executed_count, 0);
+}
+
+fn execute(
+    database: &impl SubstateDatabase,
+    vm_modules: &DefaultVmModules,
+    network: &NetworkDefinition,
+    (manifest, nonce): &(TransactionManifestV1, u32),
+) -> TransactionReceipt {
+    let validator = TransactionValidator::new(database, network);
+    let executable = manifest
+        .clone()
+        .into_executable_with_proofs(*nonce, btreeset!(), &validator)
+        .unwrap();
+    execute_transaction(
+        database,
+        vm_modules,
+        &ExecutionConfig::for_test_transaction().with_read_set(true),
+        executable,
+    )
+}
+
+fn execute_signed(
+    database: &impl SubstateDatabase,
+    vm_modules: &DefaultVmModules,
+    network: &NetworkDefinition,
+    manifest: &TransactionManifestV1,
+    nonce: u32,
+    public_key: &Secp256k1PublicKey,
+) -> TransactionReceipt {
+    let validator = TransactionValidator::new(database, network);
+    let executable = manifest
+        .clone()
+        .into_executable_with_proofs(
+            nonce,
+            btreeset!(NonFungibleGlobalId::from_public_key(public_key)),
+            &validator,
+        )
+        .unwrap();
+    execute_transaction(
+        database,
+        vm_modules,
+        &ExecutionConfig::for_test_transaction().with_read_set(true),
+        executable,
+    )
+}
+
+fn database_updates(receipt: &TransactionReceipt) -> DatabaseUpdates {
+    match &receipt.result {
+        TransactionResult::Commit(commit) => commit.state_updates.create_database_updates(),
+        _ => DatabaseUpdates::default(),
+    }
+}
+
+/// The read sets can differ, as the speculative executions read an outdated state.
+fn assert_same_receipts(speculative: &[TransactionReceipt], sequential: &[TransactionReceipt]) {
+    assert_eq!(speculative.len(), sequential.len());
+    for (speculative, sequential) in speculative.iter().zip(sequential.iter()) {
+        let mut speculative = speculative.clone();
+        speculative.read_set = None;
+        let mut sequential = sequential.clone();
+        sequential.read_set = None;
+        assert_eq!(speculative, sequential);
+    }
+}
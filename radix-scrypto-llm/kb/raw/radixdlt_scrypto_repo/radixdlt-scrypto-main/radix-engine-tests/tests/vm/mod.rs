@@ -19,3 +19,5 @@ mod wasm_memory;
 mod wasm_metering;
 mod wasm_non_mvp;
 mod wasm_validation;
+#[cfg(feature = "wasmtime")]
+mod wasmtime_engine;
@@ -0,0 +1,82 @@
+use radix_common::prelude::*;
+use radix_engine::transaction::*;
+use radix_engine::vm::wasm::*;
+use radix_engine::vm::*;
+use radix_substate_store_impls::memory_db::*;
+use radix_substate_store_interface::interface::*;
+use radix_transaction_scenarios::executor::*;
+
+struct ExecutedTransaction {
+    name: String,
+    receipt: TransactionReceipt,
+}
+
+#[derive(Default)]
+struct ReceiptCollector {
+    transactions: Vec<ExecutedTransaction>,
+}
+
+impl<S: SubstateDatabase> ScenarioExecutionHooks<S> for ReceiptCollector {
+    fn adapt_execution_config(&mut self, config: ExecutionConfig) -> ExecutionConfig {
+        config.with_cost_breakdown(true)
+    }
+
+    fn on_transaction_executed(&mut self, event: OnScenarioTransactionExecuted<S>) {
+        self.transactions.push(ExecutedTransaction {
+            name: format!(
+                "{}/{}",
+                event.metadata.logical_name, event.transaction.logical_name
+            ),
+            receipt: event.receipt.clone(),
+        });
+    }
+}
+
+fn execute_every_scenario(modules: &impl VmInitialize) -> Vec<ExecutedTransaction> {
+    let mut collector = ReceiptCollector::default();
+    TransactionScenarioExecutor::new(
+        InMemorySubstateDatabase::standard(),
+        NetworkDefinition::simulator(),
+    )
+    .execute_protocol_updates_and_scenarios(
+        |builder| builder.from_bootstrap_to_latest(),
+        ScenarioTrigger::AtStartOfEveryProtocolVersion,
+        ScenarioFilter::AllScenariosFirstValidAtProtocolVersion,
+        &mut collector,
+        &mut (),
+        modules,
+    )
+    .expect("Must succeed!");
+    collector.transactions
+}
+
+#[test]
+fn wasmtime_engine_executes_every_scenario_identically_to_wasmi_engine() {
+    // Act
+    let wasmi_transactions = execute_every_scenario(&VmModules::default());
+    let wasmtime_transactions = execute_every_scenario(&VmModules::new(
+        ScryptoVm::<WasmtimeEngine>::default(),
+        NoExtension,
+    ));
+
+    // Assert
+    assert_eq!(wasmi_transactions.len(), wasmtime_transactions.len());
+    for (wasmi, wasmtime) in wasmi_transactions.iter().zip(wasmtime_transactions.iter()) {
+        assert_eq!(wasmi.name, wasmtime.name);
+        assert_eq!(
+            wasmi.receipt.fee_summary, wasmtime.receipt.fee_summary,
+            "Fee summary differs in {}",
+            wasmi.name
+        );
+        assert_eq!(
+            wasmi.receipt.fee_details, wasmtime.receipt.fee_details,
+            "Cost breakdown differs in {}",
+            wasmi.name
+        );
+        assert_eq!(
+            wasmi.receipt.result, wasmtime.receipt.result,
+            "Result differs in {}",
+            wasmi.name
+        );
+    }
+}
@@ -1334,6 +1334,7 @@ impl<C, L: Clone> CallFrame<C, L> {
         substate_io: &'f mut SubstateIO<S>,
         node_id: &NodeId,
         partition_num: PartitionNumber,
+        from_sort_prefix: Option<[u8; 2]>,
         count: u32,
         handler: &mut impl CallFrameIOAccessHandler<C, L, E>,
     ) -> Result<
@@ -1353,8 +1354,14 @@ impl<C, L: Clone> CallFrame<C, L> {
             phantom: PhantomData::default(),
         };
 
-        let substates =
-            substate_io.scan_sorted(device, node_id, partition_num, count, &mut adapter)?;
+        let substates = substate_io.scan_sorted(
+            device,
+            node_id,
+            partition_num,
+            from_sort_prefix,
+            count,
+            &mut adapter,
+        )?;
 
         for (key, substate) in &substates {
             self.process_output_substate_key(&SubstateKey::Sorted(key.clone()))
@@ -1,6 +1,7 @@
 use crate::internal_prelude::*;
 use crate::track::interface::IOAccess;
 use crate::track::interface::{CallbackError, CanonicalSubstateKey, NodeSubstates};
+use radix_substate_store_interface::db_key_mapper::{DatabaseKeyMapper, SpreadPrefixKeyMapper};
 
 pub struct Heap {
     nodes: NonIterMap<NodeId, NodeSubstates>,
@@ -180,6 +181,49 @@ impl Heap {
         }
     }
 
+    /// Scans the sorted substates of a node's partition, in the order the store would list them,
+    /// starting from the given sort prefix. On an non-existing node/partition, this will return an
+    /// empty vector
+    pub fn scan_sorted(
+        &self,
+        node_id: &NodeId,
+        partition_num: PartitionNumber,
+        from_sort_prefix: Option<[u8; 2]>,
+        count: u32,
+    ) -> Vec<(SortedKey, IndexedScryptoValue)> {
+        let node_substates = self.nodes.get(node_id).and_then(|n| n.get(&partition_num));
+        if let Some(substates) = node_substates {
+            let from_sort_key = from_sort_prefix
+                .as_ref()
+                .map(SpreadPrefixKeyMapper::sort_prefix_to_db_sort_key);
+            let mut sorted_substates: Vec<(DbSortKey, SortedKey, &IndexedScryptoValue)> = substates
+                .iter()
+                .filter_map(|(key, value)| match key {
+                    SubstateKey::Sorted(sorted_key) => Some((
+                        SpreadPrefixKeyMapper::sorted_to_db_sort_key(sorted_key),
+                        sorted_key.clone(),
+                        value,
+                    )),
+                    _ => None,
+                })
+                .filter(|(db_sort_key, _, _)| {
+                    from_sort_key
+                        .as_ref()
+                        .map_or(true, |from_sort_key| db_sort_key >= from_sort_key)
+                })
+                .collect();
+            sorted_substates.sort_by(|(a, _, _), (b, _, _)| a.cmp(b));
+
+            sorted_substates
+                .into_iter()
+                .map(|(_, sorted_key, value)| (sorted_key, value.clone()))
+                .take(count.try_into().unwrap())
+                .collect()
+        } else {
+            vec![]
+        }
+    }
+
     /// Drains the substates from a node's partition. On an non-existing node/partition, this
     /// will return an empty vector
     pub fn drain_substates<E, F: FnMut(&Heap, IOAccess) -> Result<(), E>>(
@@ -373,4 +417,49 @@ mod tests {
         heap.remove_node(&node_id, &mut on_io_access).unwrap();
         assert_eq!(total_size, 0);
     }
+
+    #[test]
+    fn test_heap_scan_sorted_follows_the_store_order_from_the_sort_prefix() {
+        // Arrange
+        let mut heap = Heap::new();
+        let node_id = NodeId([0u8; NodeId::LENGTH]);
+        let partition_number = PartitionNumber(5);
+        let sorted_keys: Vec<SortedKey> = vec![
+            ([0, 3], scrypto_encode(&"a").unwrap()),
+            ([0, 1], scrypto_encode(&"b").unwrap()),
+            ([0, 2], scrypto_encode(&"c").unwrap()),
+            ([0, 2], scrypto_encode(&"d").unwrap()),
+        ];
+        heap.create_node(
+            node_id,
+            btreemap!(
+                partition_number => sorted_keys
+                    .iter()
+                    .map(|sorted_key| (
+                        SubstateKey::Sorted(sorted_key.clone()),
+                        IndexedScryptoValue::from_typed(&sorted_key.1),
+                    ))
+                    .collect(),
+            ),
+            &mut |_: &_, _| Result::<(), ()>::Ok(()),
+        )
+        .unwrap();
+
+        // Act
+        let scanned = heap.scan_sorted(&node_id, partition_number, Some([0, 2]), 2);
+
+        // Assert
+        let mut expected: Vec<SortedKey> = sorted_keys[2..].to_vec();
+        expected.sort_by_key(SpreadPrefixKeyMapper::sorted_to_db_sort_key);
+        assert_eq!(
+            scanned
+                .into_iter()
+                .map(|(sorted_key, _)| sorted_key)
+                .collect::<Vec<_>>(),
+            expected
+        );
+        assert!(heap
+            .scan_sorted(&node_id, partition_number, Some([0, 4]), 10)
+            .is_empty());
+    }
 }
@@ -1126,6 +1126,7 @@ where
         &mut self,
         node_id: &NodeId,
         partition_num: PartitionNumber,
+        from_sort_prefix: Option<[u8; 2]>,
         limit: u32,
     ) -> Result<Vec<(SortedKey, IndexedScryptoValue)>, RuntimeError> {
         M::on_scan_sorted_substates(ScanSortedSubstatesEvent::Start, &mut as_read_only!(self))?;
@@ -1145,6 +1146,7 @@ where
                 &mut self.substate_io,
                 node_id,
                 partition_num,
+                from_sort_prefix,
                 limit,
                 &mut handler,
             )
@@ -124,7 +124,8 @@ pub trait KernelSubstateApi<L> {
         substate_key: &SubstateKey,
     ) -> Result<Option<IndexedScryptoValue>, RuntimeError>;
 
-    /// Reads substates under a node in sorted lexicographical order
+    /// Reads substates under a node in sorted lexicographical order, starting from the given sort
+    /// prefix if there is one
     ///
     /// Clients must ensure that this isn't used in conjunction with virtualized
     /// substates; otherwise, the behavior is undefined
@@ -132,6 +133,7 @@ pub trait KernelSubstateApi<L> {
         &mut self,
         node_id: &NodeId,
         partition_num: PartitionNumber,
+        from_sort_prefix: Option<[u8; 2]>,
         count: u32,
     ) -> Result<Vec<(SortedKey, IndexedScryptoValue)>, RuntimeError>;
 
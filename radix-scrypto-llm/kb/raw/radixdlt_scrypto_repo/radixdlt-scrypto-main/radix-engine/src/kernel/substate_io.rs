@@ -650,6 +650,7 @@ impl<'g, S: CommitableSubstateStore + 'g> SubstateIO<'g, S> {
         device: SubstateDevice,
         node_id: &NodeId,
         partition_num: PartitionNumber,
+        from_sort_prefix: Option<[u8; 2]>,
         count: u32,
         handler: &mut impl IOAccessHandler<E>,
     ) -> Result<
@@ -658,15 +659,18 @@ impl<'g, S: CommitableSubstateStore + 'g> SubstateIO<'g, S> {
     > {
         let substates = match device {
             SubstateDevice::Heap => {
-                // This should never be triggered because sorted index store is
-                // used by consensus manager only.
-                panic!("Unexpected code path")
+                self.heap
+                    .scan_sorted(node_id, partition_num, from_sort_prefix, count)
             }
             SubstateDevice::Store => self
                 .store
-                .scan_sorted_substates(node_id, partition_num, count, &mut |io_access| {
-                    handler.on_io_access(&self.heap, io_access)
-                })
+                .scan_sorted_substates(
+                    node_id,
+                    partition_num,
+                    from_sort_prefix,
+                    count,
+                    &mut |io_access| handler.on_io_access(&self.heap, io_access),
+                )
                 .map_err(|e| CallbackError::CallbackError(e))?,
         };
 
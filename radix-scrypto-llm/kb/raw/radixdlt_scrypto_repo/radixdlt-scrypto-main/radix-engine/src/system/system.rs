@@ -773,6 +773,36 @@ impl<'a, Y: SystemBasedKernelApi> SystemService<'a, Y> {
         Ok((node_id, blueprint_info, partition_num))
     }
 
+    fn scan_actor_sorted_index(
+        &mut self,
+        object_handle: ActorStateHandle,
+        collection_index: CollectionIndex,
+        from_sort_prefix: Option<[u8; 2]>,
+        limit: u32,
+    ) -> Result<Vec<(SortedKey, Vec<u8>)>, RuntimeError> {
+        let actor_object_type: ActorStateRef = object_handle.try_into()?;
+
+        let (node_id, _info, partition_num) = self.get_actor_collection_partition_info(
+            actor_object_type,
+            collection_index,
+            &BlueprintPartitionType::SortedIndexCollection,
+        )?;
+
+        let substates = self
+            .api
+            .kernel_scan_sorted_substates(&node_id, partition_num, from_sort_prefix, limit)?
+            .into_iter()
+            .map(|(key, value)| {
+                let value: SortedIndexEntrySubstate<ScryptoValue> = value.as_typed().unwrap();
+                let value = scrypto_encode(value.value()).unwrap();
+
+                (key, value)
+            })
+            .collect();
+
+        Ok(substates)
+    }
+
     fn get_actor_info(
         &mut self,
         actor_object_type: ActorStateRef,
@@ -2084,27 +2114,24 @@ impl<'a, Y: SystemBasedKernelApi> SystemActorSortedIndexApi<RuntimeError> for Sy
         collection_index: CollectionIndex,
         limit: u32,
     ) -> Result<Vec<(SortedKey, Vec<u8>)>, RuntimeError> {
-        let actor_object_type: ActorStateRef = object_handle.try_into()?;
+        self.scan_actor_sorted_index(object_handle, collection_index, None, limit)
+    }
 
-        let (node_id, _info, partition_num) = self.get_actor_collection_partition_info(
-            actor_object_type,
+    // Costing through kernel
+    #[trace_resources]
+    fn actor_sorted_index_scan_from(
+        &mut self,
+        object_handle: ActorStateHandle,
+        collection_index: CollectionIndex,
+        from_sort_prefix: [u8; 2],
+        limit: u32,
+    ) -> Result<Vec<(SortedKey, Vec<u8>)>, RuntimeError> {
+        self.scan_actor_sorted_index(
+            object_handle,
             collection_index,
-            &BlueprintPartitionType::SortedIndexCollection,
-        )?;
-
-        let substates = self
-            .api
-            .kernel_scan_sorted_substates(&node_id, partition_num, limit)?
-            .into_iter()
-            .map(|(key, value)| {
-                let value: SortedIndexEntrySubstate<ScryptoValue> = value.as_typed().unwrap();
-                let value = scrypto_encode(value.value()).unwrap();
-
-                (key, value)
-            })
-            .collect();
-
-        Ok(substates)
+            Some(from_sort_prefix),
+            limit,
+        )
     }
 }
 
@@ -2980,10 +3007,11 @@ impl<'a, Y: SystemBasedKernelApi> KernelSubstateApi<SystemLockData> for SystemSe
         &mut self,
         node_id: &NodeId,
         partition_num: PartitionNumber,
+        from_sort_prefix: Option<[u8; 2]>,
         limit: u32,
     ) -> Result<Vec<(SortedKey, IndexedScryptoValue)>, RuntimeError> {
         self.api
-            .kernel_scan_sorted_substates(node_id, partition_num, limit)
+            .kernel_scan_sorted_substates(node_id, partition_num, from_sort_prefix, limit)
     }
 
     fn kernel_scan_keys<K: SubstateKeyContent>(
@@ -43,6 +43,7 @@ use radix_engine_interface::blueprints::hooks::*;
 use radix_engine_interface::blueprints::identity::IDENTITY_BLUEPRINT;
 use radix_engine_interface::blueprints::package::*;
 use radix_engine_interface::blueprints::transaction_processor::*;
+use radix_substate_store_interface::db_key_mapper::{DatabaseKeyMapper, SpreadPrefixKeyMapper};
 use radix_substate_store_interface::interface::*;
 use radix_transactions::model::*;
 
@@ -384,6 +385,7 @@ pub struct SystemSelfInit {
     pub enable_cost_breakdown: bool,
     pub execution_trace: Option<usize>,
     pub enable_debug_information: bool,
+    pub enable_read_set: bool,
 
     // Configuration
     pub system_parameters: SystemParameters,
@@ -401,6 +403,7 @@ impl SystemSelfInit {
             enable_kernel_trace: execution_config.enable_kernel_trace,
             enable_cost_breakdown: execution_config.enable_cost_breakdown,
             enable_debug_information: execution_config.enable_debug_information,
+            enable_read_set: execution_config.enable_read_set,
             execution_trace: execution_config.execution_trace,
             system_overrides: execution_config.system_overrides,
             system_logic_version,
@@ -432,12 +435,14 @@ impl<V: SystemCallbackObject> HasModules for System<V> {
 
 pub struct SystemFinalization {
     pub intent_nullifications: Vec<IntentHashNullification>,
+    pub record_read_set: bool,
 }
 
 impl SystemFinalization {
     pub fn no_nullifications() -> Self {
         Self {
             intent_nullifications: vec![],
+            record_read_set: false,
         }
     }
 }
@@ -1268,6 +1273,7 @@ impl<V: SystemCallbackObject> System<V> {
         mut track: Track<S>,
         modules: SystemModuleMixer,
         system_finalization: SystemFinalization,
+        read_set: Option<ReadSet>,
     ) -> TransactionReceipt {
         let print_execution_summary = modules.is_kernel_trace_enabled();
         let execution_trace_enabled = modules.is_execution_trace_enabled();
@@ -1311,6 +1317,19 @@ impl<V: SystemCallbackObject> System<V> {
                 vec![]
             };
 
+        // Separate the substates only read by the finalization, which are mostly the same for all
+        // transactions
+        let read_set = read_set.map(|mut read_set| {
+            let finalization_substates = track
+                .read_set()
+                .substates
+                .into_iter()
+                .filter(|key| !read_set.substates.contains(key))
+                .collect();
+            read_set.finalization_substates = finalization_substates;
+            read_set
+        });
+
         // Finalize events and logs
         let (mut application_events, application_logs) = runtime_module.finalize(is_success);
         application_events.extend(finalization_events);
@@ -1367,7 +1386,7 @@ impl<V: SystemCallbackObject> System<V> {
             performed_nullifications,
         });
 
-        Self::create_receipt_internal(
+        let mut receipt = Self::create_receipt_internal(
             print_execution_summary,
             costing_parameters,
             cost_breakdown,
@@ -1375,7 +1394,22 @@ impl<V: SystemCallbackObject> System<V> {
             transaction_costing_parameters,
             fee_summary,
             result,
-        )
+        );
+        receipt.read_set = read_set;
+        receipt
+    }
+
+    /// The keys read by the execution of the transaction - the boot substates are read from the
+    /// database before the track exists, so their whole partition is added.
+    fn execution_read_set<S: SubstateDatabase>(track: &Track<S>) -> ReadSet {
+        let mut read_set = track.read_set();
+        read_set
+            .partitions
+            .insert(SpreadPrefixKeyMapper::to_db_partition_key(
+                TRANSACTION_TRACKER.as_node_id(),
+                BOOT_LOADER_PARTITION,
+            ));
+        read_set
     }
 
     fn create_receipt_internal(
@@ -1409,6 +1443,7 @@ impl<V: SystemCallbackObject> System<V> {
             result,
             resources_usage: None,
             debug_information,
+            read_set: None,
         };
 
         // Dump summary
@@ -1549,6 +1584,7 @@ impl<V: SystemCallbackObject> KernelTransactionExecutor for System<V> {
         }
 
         let logic_version = init_input.self_init.system_logic_version;
+        let record_read_set = init_input.self_init.enable_read_set;
         let mut modules = Self::resolve_modules(executable, init_input.self_init)?;
 
         // NOTE: Have to use match pattern rather than map_err to appease the borrow checker
@@ -1717,6 +1753,7 @@ impl<V: SystemCallbackObject> KernelTransactionExecutor for System<V> {
                     .iter()
                     .cloned()
                     .collect(),
+                record_read_set,
             },
         );
 
@@ -1851,16 +1888,29 @@ impl<V: SystemCallbackObject> KernelTransactionExecutor for System<V> {
             &mut self.modules.costing_mut_even_if_disabled().fee_reserve,
         );
 
+        let read_set = self
+            .finalization
+            .record_read_set
+            .then(|| Self::execution_read_set(&track));
+
         match result_type {
             TransactionResultType::Reject(reason) => {
-                Self::create_rejection_receipt(reason, self.modules)
+                let mut receipt = Self::create_rejection_receipt(reason, self.modules);
+                receipt.read_set = read_set;
+                receipt
             }
             TransactionResultType::Abort(reason) => {
-                Self::create_abort_receipt(reason, self.modules)
-            }
-            TransactionResultType::Commit(outcome) => {
-                Self::create_commit_receipt(outcome, track, self.modules, self.finalization)
+                let mut receipt = Self::create_abort_receipt(reason, self.modules);
+                receipt.read_set = read_set;
+                receipt
             }
+            TransactionResultType::Commit(outcome) => Self::create_commit_receipt(
+                outcome,
+                track,
+                self.modules,
+                self.finalization,
+                read_set,
+            ),
         }
     }
 }
@@ -1,4 +1,5 @@
 use crate::internal_prelude::*;
+use core::ops::Deref;
 use radix_engine_interface::api::{AttachedModuleId, CollectionIndex, ModuleId};
 use radix_engine_interface::blueprints::package::*;
 use radix_engine_interface::types::*;
@@ -84,9 +85,30 @@ pub enum SystemReaderError {
     BlueprintTypeNotFound(String),
 }
 
+/// The database of a [`SystemDatabaseReader`], which is only owned by the reader if it is a view
+/// created for it, such as a [`SubstateDatabaseAtVersion`].
+enum SubstateDatabaseRef<'a, S: ?Sized> {
+    Borrowed(&'a S),
+    Owned(Box<S>),
+}
+
+impl<'a, S: ?Sized> Deref for SubstateDatabaseRef<'a, S> {
+    type Target = S;
+
+    fn deref(&self) -> &S {
+        match self {
+            SubstateDatabaseRef::Borrowed(substate_db) => substate_db,
+            SubstateDatabaseRef::Owned(substate_db) => substate_db,
+        }
+    }
+}
+
 /// A System Layer (Layer 2) abstraction over an underlying substate database
+///
+/// A previous state of a [`HistoricalSubstateDatabase`] can be read through
+/// [`SystemDatabaseReader::new_at_version`].
 pub struct SystemDatabaseReader<'a, S: SubstateDatabase + ?Sized> {
-    substate_db: &'a S,
+    substate_db: SubstateDatabaseRef<'a, S>,
     state_updates: Option<&'a StateUpdates>,
 
     blueprint_cache: RefCell<NonIterMap<CanonicalBlueprintId, Rc<BlueprintDefinition>>>,
@@ -96,7 +118,7 @@ pub struct SystemDatabaseReader<'a, S: SubstateDatabase + ?Sized> {
 impl<'a, S: SubstateDatabase + ?Sized> SystemDatabaseReader<'a, S> {
     pub fn new_with_overlay(substate_db: &'a S, state_updates: &'a StateUpdates) -> Self {
         Self {
-            substate_db,
+            substate_db: SubstateDatabaseRef::Borrowed(substate_db),
             state_updates: Some(state_updates),
             blueprint_cache: RefCell::new(NonIterMap::new()),
             schema_cache: RefCell::new(NonIterMap::new()),
@@ -105,7 +127,7 @@ impl<'a, S: SubstateDatabase + ?Sized> SystemDatabaseReader<'a, S> {
 
     pub fn new(substate_db: &'a S) -> Self {
         Self {
-            substate_db,
+            substate_db: SubstateDatabaseRef::Borrowed(substate_db),
             state_updates: None,
             blueprint_cache: RefCell::new(NonIterMap::new()),
             schema_cache: RefCell::new(NonIterMap::new()),
@@ -1147,6 +1169,27 @@ struct ValidationPayloadCheckerContext<'a, S: SubstateDatabase + ?Sized> {
     allow_ownership: bool,
 }
 
+impl<'a, D: HistoricalSubstateDatabase + ?Sized>
+    SystemDatabaseReader<'a, SubstateDatabaseAtVersion<'a, D>>
+{
+    /// Reads the entities as they were at a previous state version, e.g. before a given
+    /// transaction.
+    pub fn new_at_version(
+        substate_db: &'a D,
+        state_version: u64,
+    ) -> Result<Self, StateVersionNotAvailableError> {
+        Ok(Self {
+            substate_db: SubstateDatabaseRef::Owned(Box::new(SubstateDatabaseAtVersion::new(
+                substate_db,
+                state_version,
+            )?)),
+            state_updates: None,
+            blueprint_cache: RefCell::new(NonIterMap::new()),
+            schema_cache: RefCell::new(NonIterMap::new()),
+        })
+    }
+}
+
 impl<'a, S: SubstateDatabase + ?Sized> ValidationContext
     for ValidationPayloadCheckerContext<'a, S>
 {
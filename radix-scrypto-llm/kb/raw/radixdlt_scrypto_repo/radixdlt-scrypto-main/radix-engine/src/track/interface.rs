@@ -160,10 +160,14 @@ pub trait CommitableSubstateStore {
     ) -> Result<Vec<(SubstateKey, IndexedScryptoValue)>, E>;
 
     /// Returns tuple of substate vector and boolean which is true for the first database access.
+    ///
+    /// If a sort prefix is given, the scan starts from the first substate with that sort prefix
+    /// or a higher one.
     fn scan_sorted_substates<E, F: FnMut(IOAccess) -> Result<(), E>>(
         &mut self,
         node_id: &NodeId,
         partition_num: PartitionNumber,
+        from_sort_prefix: Option<[u8; 2]>,
         count: u32,
         on_io_access: &mut F,
     ) -> Result<Vec<(SortedKey, IndexedScryptoValue)>, E>;
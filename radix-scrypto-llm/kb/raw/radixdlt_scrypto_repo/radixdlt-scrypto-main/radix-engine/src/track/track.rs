@@ -6,7 +6,7 @@ use crate::track::interface::{
 use crate::track::state_updates::*;
 use radix_engine_interface::types::*;
 use radix_substate_store_interface::db_key_mapper::{SpreadPrefixKeyMapper, SubstateKeyContent};
-use radix_substate_store_interface::interface::DbPartitionKey;
+use radix_substate_store_interface::interface::{DbPartitionKey, DbSubstateKey};
 use radix_substate_store_interface::{
     db_key_mapper::DatabaseKeyMapper,
     interface::{DbSortKey, PartitionEntry, SubstateDatabase},
@@ -14,6 +14,7 @@ use radix_substate_store_interface::{
 use sbor::rust::collections::btree_map::Entry;
 use sbor::rust::iter::empty;
 use sbor::rust::mem;
+use sbor::rust::ops::Bound;
 
 use super::interface::{CanonicalPartition, CanonicalSubstateKey, StoreCommit, StoreCommitInfo};
 
@@ -33,6 +34,8 @@ pub struct MappedTrack<'s, S: SubstateDatabase, M: DatabaseKeyMapper + 'static>
     force_write_tracked_nodes: IndexMap<NodeId, TrackedNode>,
     /// TODO: if time allows, consider merging into tracked nodes.
     deleted_partitions: IndexSet<(NodeId, PartitionNumber)>,
+    /// The partitions iterated from the database, whose substates are only partially tracked.
+    iterated_partitions: IndexSet<(NodeId, PartitionNumber)>,
 
     transient_substates: TransientSubstates,
 
@@ -47,6 +50,19 @@ pub struct TrackedSubstates {
     pub deleted_partitions: IndexSet<(NodeId, PartitionNumber)>,
 }
 
+/// The keys a transaction read from the database, which its result depends on.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReadSet {
+    /// The substates read while executing the transaction, including the ones which didn't exist.
+    pub substates: IndexSet<DbSubstateKey>,
+    /// The partitions iterated while executing the transaction, which depend on all of their
+    /// substates.
+    pub partitions: IndexSet<DbPartitionKey>,
+    /// The substates only read when finalizing a committed transaction, to distribute its fees and
+    /// royalties and to update the transaction tracker.
+    pub finalization_substates: IndexSet<DbSubstateKey>,
+}
+
 impl TrackedSubstates {
     pub fn to_state_updates(self) -> (IndexSet<NodeId>, StateUpdates) {
         let mut new_nodes = index_set_new();
@@ -110,6 +126,7 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper> MappedTrack<'s, S, M> {
             force_write_tracked_nodes: index_map_new(),
             tracked_nodes: index_map_new(),
             deleted_partitions: index_set_new(),
+            iterated_partitions: index_set_new(),
             transient_substates: TransientSubstates::new(),
             phantom_data: PhantomData::default(),
         }
@@ -143,6 +160,7 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper> MappedTrack<'s, S, M> {
     >(
         substate_db: &'x S,
         partition_key: &DbPartitionKey,
+        from_sort_key: Option<&DbSortKey>,
         on_io_access: &'x mut F,
         canonical_partition: CanonicalPartition,
     ) -> Box<dyn Iterator<Item = Result<(DbSortKey, (SubstateKey, IndexedScryptoValue)), E>> + 'x>
@@ -201,7 +219,7 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper> MappedTrack<'s, S, M> {
         }
 
         Box::new(TracedIterator {
-            iterator: substate_db.list_raw_values_from_db_key(partition_key, None),
+            iterator: substate_db.list_raw_values_from_db_key(partition_key, from_sort_key),
             on_io_access,
             canonical_partition,
             errored_out: false,
@@ -210,6 +228,41 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper> MappedTrack<'s, S, M> {
         })
     }
 
+    /// The keys read from the database so far - the substates go into [`ReadSet::substates`],
+    /// whatever stage of the transaction they were read in.
+    pub fn read_set(&self) -> ReadSet {
+        let mut read_set = ReadSet::default();
+        for (node_id, tracked_node) in &self.tracked_nodes {
+            // The substates of a new node are never read from the database
+            if tracked_node.is_new {
+                continue;
+            }
+            for (partition_num, tracked_partition) in &tracked_node.tracked_partitions {
+                let partition_key = M::to_db_partition_key(node_id, *partition_num);
+                for (db_sort_key, tracked_substate) in &tracked_partition.substates {
+                    match tracked_substate.substate_value {
+                        TrackedSubstateValue::ReadOnly(..)
+                        | TrackedSubstateValue::ReadExistAndWrite(..)
+                        | TrackedSubstateValue::ReadNonExistAndWrite(..) => {
+                            read_set
+                                .substates
+                                .insert((partition_key.clone(), db_sort_key.clone()));
+                        }
+                        TrackedSubstateValue::New(..)
+                        | TrackedSubstateValue::WriteOnly(..)
+                        | TrackedSubstateValue::Garbage => {}
+                    }
+                }
+            }
+        }
+        for (node_id, partition_num) in &self.iterated_partitions {
+            read_set
+                .partitions
+                .insert(M::to_db_partition_key(node_id, *partition_num));
+        }
+        read_set
+    }
+
     /// Reverts all non force write changes.
     ///
     /// Note that dependencies will never be reverted.
@@ -647,10 +700,13 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper> CommitableSubstateStore
             return Ok(items);
         }
 
+        self.iterated_partitions
+            .insert((*node_id, partition_number));
         let db_partition_key = M::to_db_partition_key(node_id, partition_number);
         let mut tracked_iter = IterationCountedIter::new(Self::list_entries_from_db::<E, F, K>(
             self.substate_db,
             &db_partition_key,
+            None,
             on_io_access,
             CanonicalPartition {
                 node_id: *node_id,
@@ -735,6 +791,8 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper> CommitableSubstateStore
         }
 
         // Read from database
+        self.iterated_partitions
+            .insert((*node_id, partition_number));
         let db_partition_key = M::to_db_partition_key(node_id, partition_number);
 
         let (new_updates, num_iterations) = {
@@ -742,6 +800,7 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper> CommitableSubstateStore
                 IterationCountedIter::new(Self::list_entries_from_db::<E, F, K>(
                     self.substate_db,
                     &db_partition_key,
+                    None,
                     on_io_access,
                     CanonicalPartition {
                         node_id: *node_id,
@@ -816,6 +875,7 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper> CommitableSubstateStore
         &mut self,
         node_id: &NodeId,
         partition_number: PartitionNumber,
+        from_sort_prefix: Option<[u8; 2]>,
         limit: u32,
         on_io_access: &mut F,
     ) -> Result<Vec<(SortedKey, IndexedScryptoValue)>, E> {
@@ -832,6 +892,11 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper> CommitableSubstateStore
             .entry(partition_number)
             .or_insert(TrackedPartition::new());
 
+        // the lowest sort key to read, on both the database and the track side
+        let from_sort_key = from_sort_prefix
+            .as_ref()
+            .map(|sort_prefix| M::sort_prefix_to_db_sort_key(sort_prefix));
+
         // initialize the "from db" iterator: use `dyn`, since we want to skip it altogether if the node is marked as `is_new` in our track
         let mut db_values_count = 0u32;
         let raw_db_entries: Box<
@@ -839,10 +904,13 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper> CommitableSubstateStore
         > = if tracked_node.is_new {
             Box::new(empty()) // optimization: avoid touching the database altogether
         } else {
+            self.iterated_partitions
+                .insert((*node_id, partition_number));
             let partition_key = M::to_db_partition_key(node_id, partition_number);
             Box::new(Self::list_entries_from_db::<E, F, SortedKey>(
                 self.substate_db,
                 &partition_key,
+                from_sort_key.as_ref(),
                 on_io_access,
                 CanonicalPartition {
                     node_id: *node_id,
@@ -855,21 +923,25 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper> CommitableSubstateStore
         });
 
         // initialize the "from track" iterator
-        let tracked_entry_changes =
-            tracked_partition
-                .substates
-                .iter()
-                .map(|(db_sort_key, tracked_substate)| {
-                    // TODO: ensure we abort if any substates are write locked.
-                    if let Some(value) = tracked_substate.substate_value.get() {
-                        (
-                            db_sort_key.clone(),
-                            Some((tracked_substate.substate_key.clone(), value.clone())),
-                        )
-                    } else {
-                        (db_sort_key.clone(), None)
-                    }
-                });
+        let tracked_entry_changes = tracked_partition
+            .substates
+            .range::<DbSortKey, _>((
+                from_sort_key
+                    .as_ref()
+                    .map_or(Bound::Unbounded, Bound::Included),
+                Bound::Unbounded,
+            ))
+            .map(|(db_sort_key, tracked_substate)| {
+                // TODO: ensure we abort if any substates are write locked.
+                if let Some(value) = tracked_substate.substate_value.get() {
+                    (
+                        db_sort_key.clone(),
+                        Some((tracked_substate.substate_key.clone(), value.clone())),
+                    )
+                } else {
+                    (db_sort_key.clone(), None)
+                }
+            });
 
         let mut items = Vec::new();
         // construct the composite iterator, which applies changes read from our track on top of db values
@@ -1,4 +1,6 @@
 mod preview_executor;
+#[cfg(feature = "parallel_execution")]
+mod speculative_executor;
 mod state_update_summary;
 mod system_structure;
 mod transaction_executor;
@@ -6,6 +8,8 @@ mod transaction_receipt;
 mod transaction_reconciler;
 
 pub use preview_executor::*;
+#[cfg(feature = "parallel_execution")]
+pub use speculative_executor::*;
 pub use state_update_summary::*;
 pub use system_structure::*;
 pub use transaction_executor::*;
@@ -2,10 +2,14 @@ use crate::transaction::TransactionReceipt;
 use crate::transaction::*;
 use crate::vm::VmInitialize;
 use radix_common::network::NetworkDefinition;
+#[cfg(feature = "parallel_execution")]
+use radix_substate_store_impls::substate_database_overlay::SubstateDatabaseOverlay;
 use radix_substate_store_interface::interface::*;
 use radix_transactions::errors::TransactionValidationError;
 use radix_transactions::model::PreviewIntentV1;
 use radix_transactions::validation::*;
+#[cfg(feature = "parallel_execution")]
+use rayon::prelude::*;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PreviewError {
@@ -43,3 +47,32 @@ pub fn execute_preview(
         validated.create_executable(),
     ))
 }
+
+/// Executes the previews concurrently on the current rayon thread pool - use
+/// [`rayon::ThreadPool::install`] to run them on a dedicated one.
+///
+/// All previews read the same snapshot of the database, each through its own
+/// [`SubstateDatabaseOverlay`], and share the caches of the VM modules. The receipts, returned in
+/// the order of the intents, are identical to those of [`execute_preview`] called for each intent
+/// in turn.
+#[cfg(feature = "parallel_execution")]
+pub fn execute_previews_in_parallel<S: SubstateDatabase + Sync, V: VmInitialize + Sync>(
+    substate_db: &S,
+    vm_modules: &V,
+    network: &NetworkDefinition,
+    preview_intents: Vec<PreviewIntentV1>,
+    with_kernel_trace: bool,
+) -> Vec<Result<TransactionReceipt, PreviewError>> {
+    preview_intents
+        .into_par_iter()
+        .map(|preview_intent| {
+            execute_preview(
+                &SubstateDatabaseOverlay::new_unmergeable(substate_db),
+                vm_modules,
+                network,
+                preview_intent,
+                with_kernel_trace,
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,340 @@
+use crate::blueprints::consensus_manager::*;
+use crate::blueprints::resource::*;
+use crate::internal_prelude::*;
+use crate::track::ReadSet;
+use crate::transaction::{TransactionReceipt, TransactionResult};
+use radix_substate_store_impls::substate_database_overlay::*;
+use radix_substate_store_interface::db_key_mapper::{DatabaseKeyMapper, SpreadPrefixKeyMapper};
+use radix_substate_store_interface::interface::*;
+use rayon::prelude::*;
+
+/// The result of executing a transaction speculatively, with what is needed to check it against
+/// the transactions before it in the batch.
+pub trait SpeculativeExecutionResult {
+    /// The keys read by the transaction, if they were recorded (see `ExecutionConfig::with_read_set`)
+    /// - without them, the transaction conflicts with any earlier transaction which wrote anything.
+    fn read_set(&self) -> Option<&ReadSet>;
+
+    /// The state updates to commit, if there are any.
+    fn state_updates(&self) -> Option<&StateUpdates>;
+
+    fn state_updates_mut(&mut self) -> Option<&mut StateUpdates>;
+}
+
+impl SpeculativeExecutionResult for TransactionReceipt {
+    fn read_set(&self) -> Option<&ReadSet> {
+        self.read_set.as_ref()
+    }
+
+    fn state_updates(&self) -> Option<&StateUpdates> {
+        match &self.result {
+            TransactionResult::Commit(commit) => Some(&commit.state_updates),
+            TransactionResult::Reject(..) | TransactionResult::Abort(..) => None,
+        }
+    }
+
+    fn state_updates_mut(&mut self) -> Option<&mut StateUpdates> {
+        match &mut self.result {
+            TransactionResult::Commit(commit) => Some(&mut commit.state_updates),
+            TransactionResult::Reject(..) | TransactionResult::Abort(..) => None,
+        }
+    }
+}
+
+impl<R: SpeculativeExecutionResult, E> SpeculativeExecutionResult for Result<R, E> {
+    fn read_set(&self) -> Option<&ReadSet> {
+        self.as_ref().ok().and_then(|result| result.read_set())
+    }
+
+    fn state_updates(&self) -> Option<&StateUpdates> {
+        self.as_ref().ok().and_then(|result| result.state_updates())
+    }
+
+    fn state_updates_mut(&mut self) -> Option<&mut StateUpdates> {
+        self.as_mut()
+            .ok()
+            .and_then(|result| result.state_updates_mut())
+    }
+}
+
+/// The keys written by transactions: single substates, and whole partitions for any reset.
+#[derive(Debug, Clone, Default)]
+pub struct WrittenKeys {
+    pub substates: IndexMap<DbPartitionKey, IndexSet<DbSortKey>>,
+    pub partitions: IndexSet<DbPartitionKey>,
+}
+
+impl WrittenKeys {
+    pub fn record(&mut self, database_updates: &DatabaseUpdates) {
+        for (node_key, node_updates) in &database_updates.node_updates {
+            for (partition_num, partition_updates) in &node_updates.partition_updates {
+                let partition_key = DbPartitionKey {
+                    node_key: node_key.clone(),
+                    partition_num: *partition_num,
+                };
+                match partition_updates {
+                    PartitionDatabaseUpdates::Delta { substate_updates } => {
+                        self.substates
+                            .entry(partition_key)
+                            .or_default()
+                            .extend(substate_updates.keys().cloned());
+                    }
+                    PartitionDatabaseUpdates::Reset { .. } => {
+                        self.partitions.insert(partition_key);
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.substates.is_empty() && self.partitions.is_empty()
+    }
+
+    pub fn contains_substate(&self, (partition_key, sort_key): &DbSubstateKey) -> bool {
+        self.partitions.contains(partition_key)
+            || self
+                .substates
+                .get(partition_key)
+                .is_some_and(|sort_keys| sort_keys.contains(sort_key))
+    }
+
+    pub fn contains_partition(&self, partition_key: &DbPartitionKey) -> bool {
+        self.partitions.contains(partition_key) || self.substates.contains_key(partition_key)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SpeculativeExecutionResults<R> {
+    /// The results of the transactions, in their order.
+    pub results: Vec<R>,
+    /// The number of transactions which conflicted with an earlier one, and were executed again.
+    pub re_executed_count: usize,
+}
+
+/// Executes a batch of transactions optimistically in parallel on the current rayon thread pool,
+/// each over its own overlay of the given database, and returns the same results as executing them
+/// in order, each on the database with the updates of the previous ones committed.
+///
+/// The results are checked in order against the substates written by the earlier transactions of
+/// the batch, using the keys which their `Track` read from the database:
+/// * A transaction is executed again, over the updates of all the earlier transactions, if any
+///   substate read by its execution has a different value since, or if any partition it iterated
+///   was written to.
+/// * The substates only read to distribute the fees and royalties - the validator rewards and the
+///   vaults receiving them - are written by every committed transaction, so their updates are
+///   merged into the result instead, by adding the amounts the transaction deposited to the latest
+///   values.
+///
+/// The results are only identical if `execute` is deterministic and reads nothing but the given
+/// database, through the `Track` of the transactions.
+///
+/// The state updates of each result are committed to the overlay before the next transaction;
+/// the caller remains responsible for committing them to the database itself.
+pub fn execute_speculatively_in_parallel<D, T, R, E>(
+    database: &D,
+    transactions: &[T],
+    execute: E,
+) -> SpeculativeExecutionResults<R>
+where
+    D: SubstateDatabase + Sync,
+    T: Sync,
+    R: SpeculativeExecutionResult + Send,
+    E: Fn(&UnmergeableSubstateDatabaseOverlay<D>, &T) -> R + Sync,
+{
+    let speculative_results: Vec<R> = transactions
+        .par_iter()
+        .map(|transaction| {
+            execute(
+                &SubstateDatabaseOverlay::new_unmergeable(database),
+                transaction,
+            )
+        })
+        .collect();
+
+    let mut staged_database = SubstateDatabaseOverlay::new_unmergeable(database);
+    let mut written_keys = WrittenKeys::default();
+    let mut results = Vec::with_capacity(transactions.len());
+    let mut re_executed_count = 0;
+    for (transaction, mut result) in transactions.iter().zip(speculative_results) {
+        if !reconcile(&mut result, database, &staged_database, &written_keys) {
+            re_executed_count += 1;
+            result = execute(&staged_database, transaction);
+        }
+        if let Some(state_updates) = result.state_updates() {
+            let updates = state_updates.create_database_updates();
+            written_keys.record(&updates);
+            staged_database.commit(&updates);
+        }
+        results.push(result);
+    }
+
+    SpeculativeExecutionResults {
+        results,
+        re_executed_count,
+    }
+}
+
+/// Checks a result executed on the given database against the updates of the earlier transactions,
+/// merging their fee distribution updates into it - returns whether it is still valid.
+fn reconcile<D: SubstateDatabase, R: SpeculativeExecutionResult>(
+    result: &mut R,
+    database: &D,
+    staged_database: &UnmergeableSubstateDatabaseOverlay<D>,
+    written_keys: &WrittenKeys,
+) -> bool {
+    let Some(read_set) = result.read_set() else {
+        return written_keys.is_empty();
+    };
+    let is_changed = |key: &DbSubstateKey| {
+        written_keys.contains_substate(key)
+            && staged_database.get_raw_substate_by_db_key(&key.0, &key.1)
+                != database.get_raw_substate_by_db_key(&key.0, &key.1)
+    };
+    if read_set
+        .partitions
+        .iter()
+        .any(|partition_key| written_keys.contains_partition(partition_key))
+        || read_set.substates.iter().any(is_changed)
+    {
+        return false;
+    }
+    let changed_finalization_substates: Vec<DbSubstateKey> = read_set
+        .finalization_substates
+        .iter()
+        .filter(|key| is_changed(key))
+        .cloned()
+        .collect();
+    if changed_finalization_substates.is_empty() {
+        return true;
+    }
+
+    let Some(state_updates) = result.state_updates_mut() else {
+        return false;
+    };
+    changed_finalization_substates.iter().all(|key| {
+        merge_finalization_update(
+            state_updates,
+            key,
+            database.get_raw_substate_by_db_key(&key.0, &key.1),
+            staged_database.get_raw_substate_by_db_key(&key.0, &key.1),
+        )
+    })
+}
+
+/// Rebases the update of a substate written by the fee distribution of a transaction on its latest
+/// value, if it is one which only gets amounts added - returns whether it could.
+fn merge_finalization_update(
+    state_updates: &mut StateUpdates,
+    (partition_key, sort_key): &DbSubstateKey,
+    read: Option<DbSubstateValue>,
+    current: Option<DbSubstateValue>,
+) -> bool {
+    let (node_id, partition_num) = SpreadPrefixKeyMapper::from_db_partition_key(partition_key);
+    let substate_key: SubstateKey = if node_id.is_internal_fungible_vault()
+        && partition_num == MAIN_BASE_PARTITION
+        && *sort_key == SpreadPrefixKeyMapper::to_db_sort_key(&FungibleVaultField::Balance.into())
+    {
+        FungibleVaultField::Balance.into()
+    } else if node_id == *CONSENSUS_MANAGER.as_node_id()
+        && partition_num == MAIN_BASE_PARTITION
+        && *sort_key
+            == SpreadPrefixKeyMapper::to_db_sort_key(
+                &ConsensusManagerField::ValidatorRewards.into(),
+            )
+    {
+        ConsensusManagerField::ValidatorRewards.into()
+    } else {
+        return false;
+    };
+
+    let written = match state_updates
+        .by_node
+        .get(&node_id)
+        .and_then(|node_updates| node_updates.of_partition_ref(partition_num))
+    {
+        Some(PartitionStateUpdates::Delta { by_substate }) => {
+            match by_substate.get(&substate_key) {
+                Some(DatabaseUpdate::Set(written)) => written,
+                _ => return false,
+            }
+        }
+        _ => return false,
+    };
+    let (Some(read), Some(current)) = (read, current) else {
+        return false;
+    };
+    let merged = if node_id.is_internal_fungible_vault() {
+        merge_fungible_vault_balance(&read, written, &current)
+    } else {
+        merge_validator_rewards(&read, written, &current)
+    };
+    let Some(merged) = merged else {
+        return false;
+    };
+    state_updates
+        .of_node(node_id)
+        .of_partition(partition_num)
+        .mut_update_substate(substate_key, DatabaseUpdate::Set(merged));
+    true
+}
+
+fn merge_fungible_vault_balance(
+    read: &[u8],
+    written: &[u8],
+    current: &[u8],
+) -> Option<DbSubstateValue> {
+    let amount = |value: &[u8]| {
+        scrypto_decode::<FungibleVaultBalanceFieldSubstate>(value)
+            .ok()
+            .map(|substate| substate.into_payload().into_unique_version().amount())
+    };
+    let deposited = amount(written)?.checked_sub(amount(read)?)?;
+    if deposited.is_negative() {
+        return None;
+    }
+    let balance = LiquidFungibleResource::new(amount(current)?.checked_add(deposited)?);
+    Some(
+        scrypto_encode(
+            &FungibleVaultBalanceFieldPayload::from_content_source(balance)
+                .into_unlocked_substate(),
+        )
+        .unwrap(),
+    )
+}
+
+fn merge_validator_rewards(read: &[u8], written: &[u8], current: &[u8]) -> Option<DbSubstateValue> {
+    let rewards = |value: &[u8]| {
+        scrypto_decode::<FieldSubstate<ConsensusManagerValidatorRewardsFieldPayload>>(value)
+            .ok()
+            .map(|substate| substate.into_payload().into_unique_version())
+    };
+    let (read, written, mut current) = (rewards(read)?, rewards(written)?, rewards(current)?);
+    if read.rewards_vault != written.rewards_vault
+        || current.rewards_vault != written.rewards_vault
+        || !read
+            .proposer_rewards
+            .keys()
+            .all(|index| written.proposer_rewards.contains_key(index))
+    {
+        return None;
+    }
+    for (index, amount) in written.proposer_rewards {
+        let read_amount = read.proposer_rewards.get(&index).cloned();
+        let added = amount.checked_sub(read_amount.unwrap_or_default())?;
+        if added.is_negative() {
+            return None;
+        }
+        if added.is_zero() && read_amount.is_some() {
+            continue;
+        }
+        let entry = current.proposer_rewards.entry(index).or_default();
+        *entry = entry.checked_add(added)?;
+    }
+    Some(
+        scrypto_encode(&FieldSubstate::new_unlocked_field(
+            ConsensusManagerValidatorRewardsFieldPayload::from_content_source(current),
+        ))
+        .unwrap(),
+    )
+}
@@ -185,6 +185,7 @@ pub struct ExecutionConfig {
     pub enable_cost_breakdown: bool,
     pub execution_trace: Option<usize>,
     pub enable_debug_information: bool,
+    pub enable_read_set: bool,
 
     pub system_overrides: Option<SystemOverrides>,
 }
@@ -199,6 +200,7 @@ impl ExecutionConfig {
             execution_trace: None,
             system_overrides: None,
             enable_debug_information: false,
+            enable_read_set: false,
         }
     }
 
@@ -316,6 +318,16 @@ impl ExecutionConfig {
         self.enable_cost_breakdown = enabled;
         self
     }
+
+    pub fn with_debug_information(mut self, enabled: bool) -> Self {
+        self.enable_debug_information = enabled;
+        self
+    }
+
+    pub fn with_read_set(mut self, enabled: bool) -> Self {
+        self.enable_read_set = enabled;
+        self
+    }
 }
 
 pub fn execute_transaction<'v, V: VmInitialize>(
@@ -7,6 +7,7 @@ use crate::system::system_db_reader::SystemDatabaseReader;
 use crate::system::system_modules::costing::*;
 use crate::system::system_modules::execution_trace::*;
 use crate::system::system_substate_schemas::*;
+use crate::track::ReadSet;
 use crate::transaction::SystemStructure;
 use colored::*;
 use radix_engine_interface::blueprints::transaction_processor::InstructionOutput;
@@ -34,6 +35,10 @@ pub struct TransactionReceipt {
     /// This field contains debug information about the transaction which is extracted during the
     /// transaction execution.
     pub debug_information: Option<TransactionDebugInformation>,
+    /// The keys read from the database by the transaction, which its result depends on.
+    /// Available if `ExecutionConfig::enable_read_set` is enabled, except for transactions rejected
+    /// before their execution started.
+    pub read_set: Option<ReadSet>,
 }
 
 // Type for backwards compatibility to avoid integrator compile errors
@@ -589,6 +594,7 @@ impl TransactionReceipt {
             result: TransactionResult::Commit(commit_result),
             resources_usage: Default::default(),
             debug_information: Default::default(),
+            read_set: Default::default(),
         }
     }
 
@@ -81,6 +81,111 @@ fn validate_instruction_call_arguments_to_native_components(
     Ok(())
 }
 
+/// Returns the definition of a blueprint of a native package.
+///
+/// * An `Err` is returned if the package is native, but does not define the blueprint.
+/// * A `None` is returned if the package is not a native package.
+pub fn get_native_function_blueprint_definition(
+    package_address: PackageAddress,
+    blueprint: &str,
+) -> Result<Option<&'static BlueprintDefinitionInit>, InstructionSchemaValidationError> {
+    let package_definition: &'static PackageDefinition = match package_address {
+        PACKAGE_PACKAGE => &PACKAGE_PACKAGE_DEFINITION,
+        RESOURCE_PACKAGE => &RESOURCE_PACKAGE_DEFINITION,
+        ACCOUNT_PACKAGE => &ACCOUNT_PACKAGE_DEFINITION,
+        IDENTITY_PACKAGE => &IDENTITY_PACKAGE_DEFINITION,
+        CONSENSUS_MANAGER_PACKAGE => &CONSENSUS_MANAGER_PACKAGE_DEFINITION,
+        ACCESS_CONTROLLER_PACKAGE => &ACCESS_CONTROLLER_PACKAGE_DEFINITION_V1_0,
+        POOL_PACKAGE => &POOL_PACKAGE_DEFINITION_V1_0,
+        TRANSACTION_PROCESSOR_PACKAGE => &TRANSACTION_PROCESSOR_PACKAGE_DEFINITION,
+        METADATA_MODULE_PACKAGE => &METADATA_PACKAGE_DEFINITION,
+        ROYALTY_MODULE_PACKAGE => &ROYALTY_PACKAGE_DEFINITION,
+        ROLE_ASSIGNMENT_MODULE_PACKAGE => &ROLE_ASSIGNMENT_PACKAGE_DEFINITION,
+        _ => return Ok(None),
+    };
+    get_blueprint_schema(package_definition, package_address, blueprint).map(Some)
+}
+
+/// Returns the definition of the native blueprint which handles calls to methods of the given
+/// module on entities of the given type, or `None` if the entity is not a native one.
+pub fn get_native_method_blueprint_definition(
+    entity_type: EntityType,
+    module_id: ModuleId,
+) -> Option<&'static BlueprintDefinitionInit> {
+    match module_id {
+        ModuleId::Main => match entity_type {
+            EntityType::GlobalPackage => {
+                PACKAGE_PACKAGE_DEFINITION.blueprints.get(PACKAGE_BLUEPRINT)
+            }
+
+            EntityType::GlobalConsensusManager => CONSENSUS_MANAGER_PACKAGE_DEFINITION
+                .blueprints
+                .get(CONSENSUS_MANAGER_BLUEPRINT),
+            EntityType::GlobalValidator => CONSENSUS_MANAGER_PACKAGE_DEFINITION
+                .blueprints
+                .get(VALIDATOR_BLUEPRINT),
+
+            EntityType::GlobalAccount
+            | EntityType::GlobalPreallocatedEd25519Account
+            | EntityType::GlobalPreallocatedSecp256k1Account => {
+                ACCOUNT_PACKAGE_DEFINITION.blueprints.get(ACCOUNT_BLUEPRINT)
+            }
+
+            EntityType::GlobalIdentity
+            | EntityType::GlobalPreallocatedEd25519Identity
+            | EntityType::GlobalPreallocatedSecp256k1Identity => IDENTITY_PACKAGE_DEFINITION
+                .blueprints
+                .get(IDENTITY_BLUEPRINT),
+
+            EntityType::GlobalAccessController => ACCESS_CONTROLLER_PACKAGE_DEFINITION_V1_0
+                .blueprints
+                .get(ACCESS_CONTROLLER_BLUEPRINT),
+
+            EntityType::GlobalOneResourcePool => POOL_PACKAGE_DEFINITION_V1_0
+                .blueprints
+                .get(ONE_RESOURCE_POOL_BLUEPRINT_IDENT),
+            EntityType::GlobalTwoResourcePool => POOL_PACKAGE_DEFINITION_V1_0
+                .blueprints
+                .get(TWO_RESOURCE_POOL_BLUEPRINT_IDENT),
+            EntityType::GlobalMultiResourcePool => POOL_PACKAGE_DEFINITION_V1_0
+                .blueprints
+                .get(MULTI_RESOURCE_POOL_BLUEPRINT_IDENT),
+
+            EntityType::GlobalTransactionTracker => TRANSACTION_TRACKER_PACKAGE_DEFINITION
+                .blueprints
+                .get(TRANSACTION_TRACKER_BLUEPRINT),
+
+            EntityType::GlobalFungibleResourceManager => RESOURCE_PACKAGE_DEFINITION
+                .blueprints
+                .get(FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT),
+            EntityType::GlobalNonFungibleResourceManager => RESOURCE_PACKAGE_DEFINITION
+                .blueprints
+                .get(NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT),
+            EntityType::InternalFungibleVault => RESOURCE_PACKAGE_DEFINITION
+                .blueprints
+                .get(FUNGIBLE_VAULT_BLUEPRINT),
+            EntityType::InternalNonFungibleVault => RESOURCE_PACKAGE_DEFINITION
+                .blueprints
+                .get(NON_FUNGIBLE_VAULT_BLUEPRINT),
+            EntityType::GlobalAccountLocker => LOCKER_PACKAGE_DEFINITION
+                .blueprints
+                .get(ACCOUNT_LOCKER_BLUEPRINT),
+            EntityType::GlobalGenericComponent
+            | EntityType::InternalGenericComponent
+            | EntityType::InternalKeyValueStore => None,
+        },
+        ModuleId::Metadata => METADATA_PACKAGE_DEFINITION
+            .blueprints
+            .get(METADATA_BLUEPRINT),
+        ModuleId::RoleAssignment => ROLE_ASSIGNMENT_PACKAGE_DEFINITION
+            .blueprints
+            .get(ROLE_ASSIGNMENT_BLUEPRINT),
+        ModuleId::Royalty => ROYALTY_PACKAGE_DEFINITION
+            .blueprints
+            .get(COMPONENT_ROYALTY_BLUEPRINT),
+    }
+}
+
 fn get_blueprint_schema<'p>(
     package_definition: &'p PackageDefinition,
     package_address: PackageAddress,
@@ -104,141 +209,15 @@ fn get_arguments_schema<'s>(
     let entity_type = invocation.entity_type();
 
     let blueprint_schema = match invocation {
-        Invocation::Function(package_address @ PACKAGE_PACKAGE, ref blueprint, _) => {
-            get_blueprint_schema(&PACKAGE_PACKAGE_DEFINITION, package_address, &blueprint)
-                .map(Some)?
+        Invocation::Function(package_address, ref blueprint, _) => {
+            get_native_function_blueprint_definition(package_address, blueprint)?
         }
-        Invocation::Function(package_address @ RESOURCE_PACKAGE, ref blueprint, _) => {
-            get_blueprint_schema(&RESOURCE_PACKAGE_DEFINITION, package_address, blueprint)
-                .map(Some)?
+        Invocation::Method(_, module_id, _) => {
+            get_native_method_blueprint_definition(entity_type, module_id)
         }
-        Invocation::Function(package_address @ ACCOUNT_PACKAGE, ref blueprint, _) => {
-            get_blueprint_schema(&ACCOUNT_PACKAGE_DEFINITION, package_address, blueprint)
-                .map(Some)?
+        Invocation::DirectMethod(..) => {
+            get_native_method_blueprint_definition(entity_type, ModuleId::Main)
         }
-        Invocation::Function(package_address @ IDENTITY_PACKAGE, ref blueprint, _) => {
-            get_blueprint_schema(&IDENTITY_PACKAGE_DEFINITION, package_address, blueprint)
-                .map(Some)?
-        }
-        Invocation::Function(package_address @ CONSENSUS_MANAGER_PACKAGE, ref blueprint, _) => {
-            get_blueprint_schema(
-                &CONSENSUS_MANAGER_PACKAGE_DEFINITION,
-                package_address,
-                blueprint,
-            )
-            .map(Some)?
-        }
-        Invocation::Function(package_address @ ACCESS_CONTROLLER_PACKAGE, ref blueprint, _) => {
-            get_blueprint_schema(
-                &ACCESS_CONTROLLER_PACKAGE_DEFINITION_V1_0,
-                package_address,
-                blueprint,
-            )
-            .map(Some)?
-        }
-        Invocation::Function(package_address @ POOL_PACKAGE, ref blueprint, _) => {
-            get_blueprint_schema(&POOL_PACKAGE_DEFINITION_V1_0, package_address, blueprint)
-                .map(Some)?
-        }
-        Invocation::Function(package_address @ TRANSACTION_PROCESSOR_PACKAGE, ref blueprint, _) => {
-            get_blueprint_schema(
-                &TRANSACTION_PROCESSOR_PACKAGE_DEFINITION,
-                package_address,
-                blueprint,
-            )
-            .map(Some)?
-        }
-        Invocation::Function(package_address @ METADATA_MODULE_PACKAGE, ref blueprint, _) => {
-            get_blueprint_schema(&METADATA_PACKAGE_DEFINITION, package_address, blueprint)
-                .map(Some)?
-        }
-        Invocation::Function(package_address @ ROYALTY_MODULE_PACKAGE, ref blueprint, _) => {
-            get_blueprint_schema(&ROYALTY_PACKAGE_DEFINITION, package_address, blueprint)
-                .map(Some)?
-        }
-        Invocation::Function(
-            package_address @ ROLE_ASSIGNMENT_MODULE_PACKAGE,
-            ref blueprint,
-            _,
-        ) => get_blueprint_schema(
-            &ROLE_ASSIGNMENT_PACKAGE_DEFINITION,
-            package_address,
-            blueprint,
-        )
-        .map(Some)?,
-        Invocation::Function(..) => None,
-        Invocation::Method(_, ModuleId::Main, _) | Invocation::DirectMethod(..) => {
-            match entity_type {
-                EntityType::GlobalPackage => {
-                    PACKAGE_PACKAGE_DEFINITION.blueprints.get(PACKAGE_BLUEPRINT)
-                }
-
-                EntityType::GlobalConsensusManager => CONSENSUS_MANAGER_PACKAGE_DEFINITION
-                    .blueprints
-                    .get(CONSENSUS_MANAGER_BLUEPRINT),
-                EntityType::GlobalValidator => CONSENSUS_MANAGER_PACKAGE_DEFINITION
-                    .blueprints
-                    .get(VALIDATOR_BLUEPRINT),
-
-                EntityType::GlobalAccount
-                | EntityType::GlobalPreallocatedEd25519Account
-                | EntityType::GlobalPreallocatedSecp256k1Account => {
-                    ACCOUNT_PACKAGE_DEFINITION.blueprints.get(ACCOUNT_BLUEPRINT)
-                }
-
-                EntityType::GlobalIdentity
-                | EntityType::GlobalPreallocatedEd25519Identity
-                | EntityType::GlobalPreallocatedSecp256k1Identity => IDENTITY_PACKAGE_DEFINITION
-                    .blueprints
-                    .get(IDENTITY_BLUEPRINT),
-
-                EntityType::GlobalAccessController => ACCESS_CONTROLLER_PACKAGE_DEFINITION_V1_0
-                    .blueprints
-                    .get(ACCESS_CONTROLLER_BLUEPRINT),
-
-                EntityType::GlobalOneResourcePool => POOL_PACKAGE_DEFINITION_V1_0
-                    .blueprints
-                    .get(ONE_RESOURCE_POOL_BLUEPRINT_IDENT),
-                EntityType::GlobalTwoResourcePool => POOL_PACKAGE_DEFINITION_V1_0
-                    .blueprints
-                    .get(TWO_RESOURCE_POOL_BLUEPRINT_IDENT),
-                EntityType::GlobalMultiResourcePool => POOL_PACKAGE_DEFINITION_V1_0
-                    .blueprints
-                    .get(MULTI_RESOURCE_POOL_BLUEPRINT_IDENT),
-
-                EntityType::GlobalTransactionTracker => TRANSACTION_TRACKER_PACKAGE_DEFINITION
-                    .blueprints
-                    .get(TRANSACTION_TRACKER_BLUEPRINT),
-
-                EntityType::GlobalFungibleResourceManager => RESOURCE_PACKAGE_DEFINITION
-                    .blueprints
-                    .get(FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT),
-                EntityType::GlobalNonFungibleResourceManager => RESOURCE_PACKAGE_DEFINITION
-                    .blueprints
-                    .get(NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT),
-                EntityType::InternalFungibleVault => RESOURCE_PACKAGE_DEFINITION
-                    .blueprints
-                    .get(FUNGIBLE_VAULT_BLUEPRINT),
-                EntityType::InternalNonFungibleVault => RESOURCE_PACKAGE_DEFINITION
-                    .blueprints
-                    .get(NON_FUNGIBLE_VAULT_BLUEPRINT),
-                EntityType::GlobalAccountLocker => LOCKER_PACKAGE_DEFINITION
-                    .blueprints
-                    .get(ACCOUNT_LOCKER_BLUEPRINT),
-                EntityType::GlobalGenericComponent
-                | EntityType::InternalGenericComponent
-                | EntityType::InternalKeyValueStore => None,
-            }
-        }
-        Invocation::Method(_, ModuleId::Metadata, _) => METADATA_PACKAGE_DEFINITION
-            .blueprints
-            .get(METADATA_BLUEPRINT),
-        Invocation::Method(_, ModuleId::RoleAssignment, _) => ROLE_ASSIGNMENT_PACKAGE_DEFINITION
-            .blueprints
-            .get(ROLE_ASSIGNMENT_BLUEPRINT),
-        Invocation::Method(_, ModuleId::Royalty, _) => ROYALTY_PACKAGE_DEFINITION
-            .blueprints
-            .get(COMPONENT_ROYALTY_BLUEPRINT),
     };
 
     if let Some(blueprint_schema) = blueprint_schema {
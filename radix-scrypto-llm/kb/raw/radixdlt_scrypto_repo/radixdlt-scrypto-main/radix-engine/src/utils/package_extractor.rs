@@ -28,8 +28,9 @@ pub fn extract_definition(code: &[u8]) -> Result<PackageDefinition, ExtractSchem
         .into_iter()
         .filter(|s| s.ends_with("_schema"));
 
-    // Validate WASM
-    let validator = ScryptoV1WasmValidator::new(ScryptoVmVersion::latest());
+    // Validate WASM, accepting everything the Scrypto VM supports - whether a network has enabled
+    // it is only checked when the package is published
+    let validator = ScryptoV1WasmValidator::new(ScryptoVmVersion::highest_supported());
     let code_hash = CodeHash(Hash([0u8; 32]));
     let instrumented_code = validator
         .validate(&code, iter::empty())
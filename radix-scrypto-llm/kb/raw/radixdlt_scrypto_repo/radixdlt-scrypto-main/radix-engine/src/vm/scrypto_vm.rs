@@ -11,6 +11,9 @@ use radix_engine_profiling_derive::trace_resources;
 pub struct ScryptoVm<W: WasmEngine> {
     pub wasm_engine: W,
     pub wasm_validator_config: WasmValidatorConfigV1,
+    /// The on-disk cache of the code prepared when packages are published, if any.
+    #[cfg(feature = "std")]
+    pub prepared_code_cache: Option<PreparedCodeCache>,
 }
 
 impl<W: WasmEngine + Default> Default for ScryptoVm<W> {
@@ -18,11 +21,19 @@ impl<W: WasmEngine + Default> Default for ScryptoVm<W> {
         Self {
             wasm_engine: W::default(),
             wasm_validator_config: WasmValidatorConfigV1::new(),
+            #[cfg(feature = "std")]
+            prepared_code_cache: None,
         }
     }
 }
 
 impl<W: WasmEngine> ScryptoVm<W> {
+    #[cfg(feature = "std")]
+    pub fn with_prepared_code_cache(mut self, prepared_code_cache: PreparedCodeCache) -> Self {
+        self.prepared_code_cache = Some(prepared_code_cache);
+        self
+    }
+
     pub fn create_instance(
         &self,
         package_address: &PackageAddress,
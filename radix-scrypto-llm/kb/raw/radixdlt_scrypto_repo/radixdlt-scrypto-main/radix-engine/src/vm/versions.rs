@@ -6,6 +6,7 @@ pub enum ScryptoVmVersion {
     V1_0,
     V1_1,
     V1_2,
+    V1_3,
 }
 
 impl ScryptoVmVersion {
@@ -13,6 +14,12 @@ impl ScryptoVmVersion {
         Self::cuttlefish()
     }
 
+    /// The highest version which the VM supports - which is ahead of [`Self::latest`] while no
+    /// protocol update enables it yet.
+    pub const fn highest_supported() -> ScryptoVmVersion {
+        Self::actor_collections_v1()
+    }
+
     pub const fn babylon_genesis() -> ScryptoVmVersion {
         Self::V1_0
     }
@@ -32,6 +39,17 @@ impl ScryptoVmVersion {
     pub const fn crypto_utils_v2() -> ScryptoVmVersion {
         Self::V1_2
     }
+
+    /// Adds the actor index and sorted index functions, which give Scrypto blueprints access to
+    /// their `Index` and `SortedIndex` collections.
+    ///
+    /// Enabling it on a network is deliberately left to the protocol update which will ship it -
+    /// that update needs a `VmBoot` flash to this version, like the one of Cuttlefish to
+    /// [`Self::crypto_utils_v2`]. Until then, it can only be used by flashing the `VmBoot` substate
+    /// of a ledger directly, as the tests do.
+    pub const fn actor_collections_v1() -> ScryptoVmVersion {
+        Self::V1_3
+    }
 }
 
 impl From<ScryptoVmVersion> for u64 {
@@ -48,6 +66,7 @@ impl TryFrom<u64> for ScryptoVmVersion {
             0 => Ok(Self::V1_0),
             1 => Ok(Self::V1_1),
             2 => Ok(Self::V1_2),
+            3 => Ok(Self::V1_3),
             v => Err(Self::Error::FromIntError(v)),
         }
     }
@@ -66,6 +85,7 @@ mod test {
     fn test_scrypto_vm_version() {
         let v = ScryptoVmVersion::latest();
         assert_eq!(v, ScryptoVmVersion::V1_2);
+        assert_eq!(ScryptoVmVersion::highest_supported(), ScryptoVmVersion::V1_3);
         assert_eq!(ScryptoVmVersion::crypto_utils_v1(), ScryptoVmVersion::V1_1);
     }
 
@@ -77,9 +97,9 @@ mod test {
         let v: ScryptoVmVersion = 1u64.try_into().unwrap();
         assert_eq!(v, ScryptoVmVersion::V1_1);
 
-        let e = ScryptoVmVersion::try_from(3u64).unwrap_err();
+        let e = ScryptoVmVersion::try_from(4u64).unwrap_err();
 
-        assert_eq!(e, ScryptoVmVersionError::FromIntError(3u64));
+        assert_eq!(e, ScryptoVmVersionError::FromIntError(4u64));
     }
 
     #[test]
@@ -87,5 +107,6 @@ mod test {
         assert!(ScryptoVmVersion::crypto_utils_v1() == ScryptoVmVersion::V1_1);
         assert!(ScryptoVmVersion::crypto_utils_v1() > ScryptoVmVersion::V1_0);
         assert!(ScryptoVmVersion::crypto_utils_v1() < ScryptoVmVersion::crypto_utils_v2());
+        assert!(ScryptoVmVersion::crypto_utils_v2() < ScryptoVmVersion::actor_collections_v1());
     }
 }
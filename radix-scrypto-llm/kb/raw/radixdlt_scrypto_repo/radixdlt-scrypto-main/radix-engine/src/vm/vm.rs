@@ -5,6 +5,8 @@ use crate::kernel::kernel_api::{KernelNodeApi, KernelSubstateApi};
 use crate::system::system_callback::*;
 use crate::system::system_callback_api::SystemCallbackObject;
 use crate::system::system_substates::KeyValueEntrySubstate;
+#[cfg(feature = "std")]
+use crate::vm::wasm::PreparedCodeCache;
 use crate::vm::wasm::{ScryptoV1WasmValidator, WasmEngine};
 use crate::vm::{NativeVm, NativeVmExtension, ScryptoVm};
 use radix_engine_interface::api::field_api::LockFlags;
@@ -54,6 +56,12 @@ impl VmBoot {
 
 pub trait VmApi {
     fn get_scrypto_version(&self) -> ScryptoVmVersion;
+
+    /// The cache of the Scrypto code prepared when packages are published, if any.
+    #[cfg(feature = "std")]
+    fn get_prepared_code_cache(&self) -> Option<&PreparedCodeCache> {
+        None
+    }
 }
 
 impl VmApi for VmBoot {
@@ -65,6 +73,24 @@ impl VmApi for VmBoot {
     }
 }
 
+/// The [`VmApi`] of the invoked packages: the [`VmBoot`] along with the caches of the
+/// [`ScryptoVm`].
+pub struct VmBootWithCaches<'g, W: WasmEngine> {
+    pub vm_boot: VmBoot,
+    pub scrypto_vm: &'g ScryptoVm<W>,
+}
+
+impl<'g, W: WasmEngine> VmApi for VmBootWithCaches<'g, W> {
+    fn get_scrypto_version(&self) -> ScryptoVmVersion {
+        self.vm_boot.get_scrypto_version()
+    }
+
+    #[cfg(feature = "std")]
+    fn get_prepared_code_cache(&self) -> Option<&PreparedCodeCache> {
+        self.scrypto_vm.prepared_code_cache.as_ref()
+    }
+}
+
 /// This trait is intended to encapsulate the data and types required to
 /// initalize the VMs in the engine.
 ///
@@ -201,7 +227,13 @@ impl<'g, W: WasmEngine + 'g, E: NativeVmExtension> SystemCallbackObject for Vm<'
                 .unwrap_or_else(|| panic!("Vm type not found: {:?}", export))
         };
 
-        let vm_api = api.kernel_get_system().callback.vm_boot.clone();
+        let vm_api = {
+            let callback = &api.kernel_get_system().callback;
+            VmBootWithCaches {
+                vm_boot: callback.vm_boot.clone(),
+                scrypto_vm: callback.scrypto_vm,
+            }
+        };
 
         let output = match vm_type.fully_update_and_into_latest_version().vm_type {
             VmType::Native => {
@@ -315,8 +347,17 @@ impl VmPackageValidation {
                 let version = vm_api.get_scrypto_version();
 
                 // Validate WASM
-                let instrumented_code = ScryptoV1WasmValidator::new(version)
-                    .validate(&code, definition.blueprints.values())
+                let validator = ScryptoV1WasmValidator::new(version);
+                #[cfg(feature = "std")]
+                let prepared_code = match vm_api.get_prepared_code_cache() {
+                    Some(cache) => {
+                        cache.validate(&validator, &code, definition.blueprints.values())
+                    }
+                    None => validator.validate(&code, definition.blueprints.values()),
+                };
+                #[cfg(not(feature = "std"))]
+                let prepared_code = validator.validate(&code, definition.blueprints.values());
+                let instrumented_code = prepared_code
                     .map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::PackageError(
                             PackageError::InvalidWasm(e),
@@ -30,6 +30,22 @@ pub const ACTOR_OPEN_FIELD_FUNCTION_NAME: &str = "actor_open_field";
 pub const ACTOR_GET_OBJECT_ID_FUNCTION_NAME: &str = "actor_get_object_id";
 pub const ACTOR_EMIT_EVENT_FUNCTION_NAME: &str = "actor_emit_event";
 
+//=================
+// Actor Index
+//=================
+pub const ACTOR_INDEX_INSERT_FUNCTION_NAME: &str = "actor_index_insert";
+pub const ACTOR_INDEX_REMOVE_FUNCTION_NAME: &str = "actor_index_remove";
+pub const ACTOR_INDEX_SCAN_KEYS_FUNCTION_NAME: &str = "actor_index_scan_keys";
+pub const ACTOR_INDEX_DRAIN_FUNCTION_NAME: &str = "actor_index_drain";
+
+//=================
+// Actor Sorted Index
+//=================
+pub const ACTOR_SORTED_INDEX_INSERT_FUNCTION_NAME: &str = "actor_sorted_index_insert";
+pub const ACTOR_SORTED_INDEX_REMOVE_FUNCTION_NAME: &str = "actor_sorted_index_remove";
+pub const ACTOR_SORTED_INDEX_SCAN_FUNCTION_NAME: &str = "actor_sorted_index_scan";
+pub const ACTOR_SORTED_INDEX_SCAN_FROM_FUNCTION_NAME: &str = "actor_sorted_index_scan_from";
+
 //=================
 // Key Value Store
 //=================
@@ -174,6 +174,9 @@ pub enum WasmRuntimeError {
 
     InvalidHash(ParseHashError),
     Secp256k1KeyRecoveryError,
+
+    /// Sort prefix of a sorted index entry exceeds `u16`
+    InvalidSortPrefix(u32),
 }
 
 impl SelfError for WasmRuntimeError {
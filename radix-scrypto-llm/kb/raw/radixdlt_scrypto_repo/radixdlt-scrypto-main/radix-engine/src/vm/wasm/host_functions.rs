@@ -0,0 +1,919 @@
+use crate::errors::InvokeError;
+use crate::internal_prelude::*;
+use crate::vm::wasm::errors::*;
+use crate::vm::wasm::traits::*;
+use radix_engine_interface::api::actor_api::EventFlags;
+
+/// The caller of a host function, as implemented by the `Caller` of each WASM engine - so that
+/// the engines share the host functions, and only differ in how they are linked.
+pub(super) trait HostCaller {
+    /// The runtime of the current invocation, as set up by the instance before invoking its export.
+    fn runtime_ptr(&self) -> *mut Box<dyn WasmRuntime>;
+
+    /// The linear memory of the calling instance.
+    fn memory(&mut self) -> &mut [u8];
+
+    fn read_memory(
+        &mut self,
+        ptr: u32,
+        len: u32,
+    ) -> Result<Vec<u8>, InvokeError<WasmRuntimeError>> {
+        read_memory(self.memory(), ptr, len)
+    }
+
+    fn write_memory(&mut self, ptr: u32, data: &[u8]) -> Result<(), InvokeError<WasmRuntimeError>> {
+        write_memory(self.memory(), ptr, data)
+    }
+}
+
+macro_rules! grab_runtime {
+    ($caller: expr) => {{
+        let runtime: &mut Box<dyn WasmRuntime> = unsafe { &mut *$caller.runtime_ptr() };
+        runtime
+    }};
+}
+
+pub(super) fn read_memory(
+    memory: &[u8],
+    ptr: u32,
+    len: u32,
+) -> Result<Vec<u8>, InvokeError<WasmRuntimeError>> {
+    let ptr = ptr as usize;
+    let len = len as usize;
+
+    if ptr > memory.len() || ptr + len > memory.len() {
+        return Err(InvokeError::SelfError(WasmRuntimeError::MemoryAccessError));
+    }
+    Ok(memory[ptr..ptr + len].to_vec())
+}
+
+pub(super) fn write_memory(
+    memory: &mut [u8],
+    ptr: u32,
+    data: &[u8],
+) -> Result<(), InvokeError<WasmRuntimeError>> {
+    let ptr = ptr as usize;
+
+    if ptr > memory.len() || ptr + data.len() > memory.len() {
+        return Err(InvokeError::SelfError(WasmRuntimeError::MemoryAccessError));
+    }
+    memory[ptr..ptr + data.len()].copy_from_slice(data);
+    Ok(())
+}
+
+pub(super) fn read_slice(
+    memory: &[u8],
+    v: Slice,
+) -> Result<Vec<u8>, InvokeError<WasmRuntimeError>> {
+    let ptr = v.ptr();
+    let len = v.len();
+
+    read_memory(memory, ptr, len)
+}
+
+// native functions start
+pub(super) fn consume_buffer(
+    mut caller: impl HostCaller,
+    buffer_id: BufferId,
+    destination_ptr: u32,
+) -> Result<(), InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    let result = runtime.buffer_consume(buffer_id);
+    match result {
+        Ok(slice) => {
+            caller.write_memory(destination_ptr, &slice)?;
+            Ok(())
+        }
+        Err(e) => Err(e),
+    }
+}
+
+pub(super) fn call_method(
+    mut caller: impl HostCaller,
+    receiver_ptr: u32,
+    receiver_len: u32,
+    ident_ptr: u32,
+    ident_len: u32,
+    args_ptr: u32,
+    args_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    let receiver = caller.read_memory(receiver_ptr, receiver_len)?;
+    let ident = caller.read_memory(ident_ptr, ident_len)?;
+    let args = caller.read_memory(args_ptr, args_len)?;
+
+    runtime
+        .object_call(receiver, ident, args)
+        .map(|buffer| buffer.0)
+}
+
+pub(super) fn call_direct_method(
+    mut caller: impl HostCaller,
+    receiver_ptr: u32,
+    receiver_len: u32,
+    ident_ptr: u32,
+    ident_len: u32,
+    args_ptr: u32,
+    args_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    let receiver = caller.read_memory(receiver_ptr, receiver_len)?;
+    let ident = caller.read_memory(ident_ptr, ident_len)?;
+    let args = caller.read_memory(args_ptr, args_len)?;
+
+    runtime
+        .object_call_direct(receiver, ident, args)
+        .map(|buffer| buffer.0)
+}
+
+pub(super) fn call_module_method(
+    mut caller: impl HostCaller,
+    receiver_ptr: u32,
+    receiver_len: u32,
+    module_id: u32,
+    ident_ptr: u32,
+    ident_len: u32,
+    args_ptr: u32,
+    args_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    let receiver = caller.read_memory(receiver_ptr, receiver_len)?;
+    let ident = caller.read_memory(ident_ptr, ident_len)?;
+    let args = caller.read_memory(args_ptr, args_len)?;
+
+    runtime
+        .object_call_module(receiver, module_id, ident, args)
+        .map(|buffer| buffer.0)
+}
+
+pub(super) fn call_function(
+    mut caller: impl HostCaller,
+    package_address_ptr: u32,
+    package_address_len: u32,
+    blueprint_name_ptr: u32,
+    blueprint_name_len: u32,
+    ident_ptr: u32,
+    ident_len: u32,
+    args_ptr: u32,
+    args_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    let package_address = caller.read_memory(package_address_ptr, package_address_len)?;
+    let blueprint_name = caller.read_memory(blueprint_name_ptr, blueprint_name_len)?;
+    let ident = caller.read_memory(ident_ptr, ident_len)?;
+    let args = caller.read_memory(args_ptr, args_len)?;
+
+    runtime
+        .blueprint_call(package_address, blueprint_name, ident, args)
+        .map(|buffer| buffer.0)
+}
+
+pub(super) fn new_object(
+    mut caller: impl HostCaller,
+    blueprint_name_ptr: u32,
+    blueprint_name_len: u32,
+    object_states_ptr: u32,
+    object_states_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    runtime
+        .object_new(
+            caller.read_memory(blueprint_name_ptr, blueprint_name_len)?,
+            caller.read_memory(object_states_ptr, object_states_len)?,
+        )
+        .map(|buffer| buffer.0)
+}
+
+pub(super) fn new_key_value_store(
+    mut caller: impl HostCaller,
+    schema_id_ptr: u32,
+    schema_id_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    runtime
+        .key_value_store_new(caller.read_memory(schema_id_ptr, schema_id_len)?)
+        .map(|buffer| buffer.0)
+}
+
+pub(super) fn allocate_global_address(
+    mut caller: impl HostCaller,
+    package_address_ptr: u32,
+    package_address_len: u32,
+    blueprint_name_ptr: u32,
+    blueprint_name_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    runtime
+        .address_allocate(
+            caller.read_memory(package_address_ptr, package_address_len)?,
+            caller.read_memory(blueprint_name_ptr, blueprint_name_len)?,
+        )
+        .map(|buffer| buffer.0)
+}
+
+pub(super) fn get_reservation_address(
+    mut caller: impl HostCaller,
+    node_id_ptr: u32,
+    node_id_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    runtime
+        .address_get_reservation_address(caller.read_memory(node_id_ptr, node_id_len)?)
+        .map(|buffer| buffer.0)
+}
+
+pub(super) fn execution_cost_unit_limit(
+    caller: impl HostCaller,
+) -> Result<u32, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    runtime.costing_get_execution_cost_unit_limit()
+}
+
+pub(super) fn execution_cost_unit_price(
+    caller: impl HostCaller,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    runtime
+        .costing_get_execution_cost_unit_price()
+        .map(|buffer| buffer.0)
+}
+
+pub(super) fn finalization_cost_unit_limit(
+    caller: impl HostCaller,
+) -> Result<u32, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    runtime.costing_get_finalization_cost_unit_limit()
+}
+
+pub(super) fn finalization_cost_unit_price(
+    caller: impl HostCaller,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    runtime
+        .costing_get_finalization_cost_unit_price()
+        .map(|buffer| buffer.0)
+}
+
+pub(super) fn usd_price(caller: impl HostCaller) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    runtime.costing_get_usd_price().map(|buffer| buffer.0)
+}
+
+pub(super) fn tip_percentage(
+    caller: impl HostCaller,
+) -> Result<u32, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    runtime.costing_get_tip_percentage()
+}
+
+pub(super) fn fee_balance(caller: impl HostCaller) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    runtime.costing_get_fee_balance().map(|buffer| buffer.0)
+}
+
+pub(super) fn globalize_object(
+    mut caller: impl HostCaller,
+    obj_id_ptr: u32,
+    obj_id_len: u32,
+    modules_ptr: u32,
+    modules_len: u32,
+    address_ptr: u32,
+    address_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    runtime
+        .globalize_object(
+            caller.read_memory(obj_id_ptr, obj_id_len)?,
+            caller.read_memory(modules_ptr, modules_len)?,
+            caller.read_memory(address_ptr, address_len)?,
+        )
+        .map(|buffer| buffer.0)
+}
+
+pub(super) fn instance_of(
+    mut caller: impl HostCaller,
+    component_id_ptr: u32,
+    component_id_len: u32,
+    package_address_ptr: u32,
+    package_address_len: u32,
+    blueprint_name_ptr: u32,
+    blueprint_name_len: u32,
+) -> Result<u32, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    runtime.instance_of(
+        caller.read_memory(component_id_ptr, component_id_len)?,
+        caller.read_memory(package_address_ptr, package_address_len)?,
+        caller.read_memory(blueprint_name_ptr, blueprint_name_len)?,
+    )
+}
+
+pub(super) fn blueprint_id(
+    mut caller: impl HostCaller,
+    component_id_ptr: u32,
+    component_id_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    runtime
+        .blueprint_id(caller.read_memory(component_id_ptr, component_id_len)?)
+        .map(|buffer| buffer.0)
+}
+
+pub(super) fn get_outer_object(
+    mut caller: impl HostCaller,
+    component_id_ptr: u32,
+    component_id_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    runtime
+        .get_outer_object(caller.read_memory(component_id_ptr, component_id_len)?)
+        .map(|buffer| buffer.0)
+}
+
+pub(super) fn lock_key_value_store_entry(
+    mut caller: impl HostCaller,
+    node_id_ptr: u32,
+    node_id_len: u32,
+    offset_ptr: u32,
+    offset_len: u32,
+    flags: u32,
+) -> Result<u32, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    let node_id = caller.read_memory(node_id_ptr, node_id_len)?;
+    let substate_key = caller.read_memory(offset_ptr, offset_len)?;
+
+    runtime.key_value_store_open_entry(node_id, substate_key, flags)
+}
+
+pub(super) fn key_value_entry_get(
+    caller: impl HostCaller,
+    handle: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    runtime.key_value_entry_get(handle).map(|buffer| buffer.0)
+}
+
+pub(super) fn key_value_entry_set(
+    mut caller: impl HostCaller,
+    handle: u32,
+    buffer_ptr: u32,
+    buffer_len: u32,
+) -> Result<(), InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    let data = caller.read_memory(buffer_ptr, buffer_len)?;
+    runtime.key_value_entry_set(handle, data)
+}
+
+pub(super) fn key_value_entry_remove(
+    caller: impl HostCaller,
+    handle: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    runtime
+        .key_value_entry_remove(handle)
+        .map(|buffer| buffer.0)
+}
+
+pub(super) fn unlock_key_value_entry(
+    caller: impl HostCaller,
+    handle: u32,
+) -> Result<(), InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    runtime.key_value_entry_close(handle)
+}
+
+pub(super) fn key_value_store_remove(
+    mut caller: impl HostCaller,
+    node_id_ptr: u32,
+    node_id_len: u32,
+    key_ptr: u32,
+    key_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    let node_id = caller.read_memory(node_id_ptr, node_id_len)?;
+    let key = caller.read_memory(key_ptr, key_len)?;
+
+    runtime
+        .key_value_store_remove_entry(node_id, key)
+        .map(|buffer| buffer.0)
+}
+
+pub(super) fn lock_field(
+    caller: impl HostCaller,
+    object_handle: u32,
+    field: u32,
+    flags: u32,
+) -> Result<u32, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    runtime.actor_open_field(object_handle, field as u8, flags)
+}
+
+pub(super) fn field_lock_read(
+    caller: impl HostCaller,
+    handle: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    runtime.field_entry_read(handle).map(|buffer| buffer.0)
+}
+
+pub(super) fn field_lock_write(
+    mut caller: impl HostCaller,
+    handle: u32,
+    data_ptr: u32,
+    data_len: u32,
+) -> Result<(), InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    let data = caller.read_memory(data_ptr, data_len)?;
+
+    runtime.field_entry_write(handle, data)
+}
+
+pub(super) fn field_lock_release(
+    caller: impl HostCaller,
+    handle: u32,
+) -> Result<(), InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    runtime.field_entry_close(handle)
+}
+
+pub(super) fn actor_index_insert(
+    mut caller: impl HostCaller,
+    object_handle: u32,
+    collection_index: u32,
+    key_ptr: u32,
+    key_len: u32,
+    value_ptr: u32,
+    value_len: u32,
+) -> Result<(), InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    let key = caller.read_memory(key_ptr, key_len)?;
+    let value = caller.read_memory(value_ptr, value_len)?;
+
+    runtime.actor_index_insert(object_handle, collection_index as u8, key, value)
+}
+
+pub(super) fn actor_index_remove(
+    mut caller: impl HostCaller,
+    object_handle: u32,
+    collection_index: u32,
+    key_ptr: u32,
+    key_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    let key = caller.read_memory(key_ptr, key_len)?;
+
+    runtime
+        .actor_index_remove(object_handle, collection_index as u8, key)
+        .map(|buffer| buffer.0)
+}
+
+pub(super) fn actor_index_scan_keys(
+    caller: impl HostCaller,
+    object_handle: u32,
+    collection_index: u32,
+    limit: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    runtime
+        .actor_index_scan_keys(object_handle, collection_index as u8, limit)
+        .map(|buffer| buffer.0)
+}
+
+pub(super) fn actor_index_drain(
+    caller: impl HostCaller,
+    object_handle: u32,
+    collection_index: u32,
+    limit: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    runtime
+        .actor_index_drain(object_handle, collection_index as u8, limit)
+        .map(|buffer| buffer.0)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(super) fn actor_sorted_index_insert(
+    mut caller: impl HostCaller,
+    object_handle: u32,
+    collection_index: u32,
+    sort_prefix: u32,
+    key_ptr: u32,
+    key_len: u32,
+    value_ptr: u32,
+    value_len: u32,
+) -> Result<(), InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    let sort_prefix = u16::try_from(sort_prefix)
+        .map_err(|_| InvokeError::SelfError(WasmRuntimeError::InvalidSortPrefix(sort_prefix)))?;
+    let key = caller.read_memory(key_ptr, key_len)?;
+    let value = caller.read_memory(value_ptr, value_len)?;
+
+    runtime.actor_sorted_index_insert(
+        object_handle,
+        collection_index as u8,
+        sort_prefix,
+        key,
+        value,
+    )
+}
+
+pub(super) fn actor_sorted_index_remove(
+    mut caller: impl HostCaller,
+    object_handle: u32,
+    collection_index: u32,
+    sort_prefix: u32,
+    key_ptr: u32,
+    key_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    let sort_prefix = u16::try_from(sort_prefix)
+        .map_err(|_| InvokeError::SelfError(WasmRuntimeError::InvalidSortPrefix(sort_prefix)))?;
+    let key = caller.read_memory(key_ptr, key_len)?;
+
+    runtime
+        .actor_sorted_index_remove(object_handle, collection_index as u8, sort_prefix, key)
+        .map(|buffer| buffer.0)
+}
+
+pub(super) fn actor_sorted_index_scan(
+    caller: impl HostCaller,
+    object_handle: u32,
+    collection_index: u32,
+    limit: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    runtime
+        .actor_sorted_index_scan(object_handle, collection_index as u8, limit)
+        .map(|buffer| buffer.0)
+}
+
+pub(super) fn actor_sorted_index_scan_from(
+    caller: impl HostCaller,
+    object_handle: u32,
+    collection_index: u32,
+    from_sort_prefix: u32,
+    limit: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    let from_sort_prefix = u16::try_from(from_sort_prefix).map_err(|_| {
+        InvokeError::SelfError(WasmRuntimeError::InvalidSortPrefix(from_sort_prefix))
+    })?;
+
+    runtime
+        .actor_sorted_index_scan_from(
+            object_handle,
+            collection_index as u8,
+            from_sort_prefix,
+            limit,
+        )
+        .map(|buffer| buffer.0)
+}
+
+pub(super) fn actor_get_node_id(
+    caller: impl HostCaller,
+    handle: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    runtime.actor_get_node_id(handle).map(|buffer| buffer.0)
+}
+
+pub(super) fn get_package_address(
+    caller: impl HostCaller,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    runtime.actor_get_package_address().map(|buffer| buffer.0)
+}
+
+pub(super) fn get_blueprint_name(
+    caller: impl HostCaller,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    runtime.actor_get_blueprint_name().map(|buffer| buffer.0)
+}
+
+#[inline]
+pub(super) fn consume_wasm_execution_units(
+    caller: impl HostCaller,
+    n: u64,
+) -> Result<(), InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    // TODO: wasm-instrument uses u64 for cost units. We need to decide if we want to move from u32
+    // to u64 as well.
+    runtime.consume_wasm_execution_units(n as u32)
+}
+
+pub(super) fn emit_event(
+    mut caller: impl HostCaller,
+    event_name_ptr: u32,
+    event_name_len: u32,
+    event_data_ptr: u32,
+    event_data_len: u32,
+    flags: u32,
+) -> Result<(), InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    let event_name = caller.read_memory(event_name_ptr, event_name_len)?;
+    let event_data = caller.read_memory(event_data_ptr, event_data_len)?;
+    let event_flags = EventFlags::from_bits(flags).ok_or(InvokeError::SelfError(
+        WasmRuntimeError::InvalidEventFlags(flags),
+    ))?;
+
+    runtime.actor_emit_event(event_name, event_data, event_flags)
+}
+
+pub(super) fn get_transaction_hash(
+    caller: impl HostCaller,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    runtime.sys_get_transaction_hash().map(|buffer| buffer.0)
+}
+
+pub(super) fn generate_ruid(caller: impl HostCaller) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    runtime.sys_generate_ruid().map(|buffer| buffer.0)
+}
+
+pub(super) fn emit_log(
+    mut caller: impl HostCaller,
+    level_ptr: u32,
+    level_len: u32,
+    message_ptr: u32,
+    message_len: u32,
+) -> Result<(), InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    let level = caller.read_memory(level_ptr, level_len)?;
+    let message = caller.read_memory(message_ptr, message_len)?;
+
+    runtime.sys_log(level, message)
+}
+
+pub(super) fn bech32_encode_address(
+    mut caller: impl HostCaller,
+    address_ptr: u32,
+    address_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    let address = caller.read_memory(address_ptr, address_len)?;
+
+    runtime
+        .sys_bech32_encode_address(address)
+        .map(|buffer| buffer.0)
+}
+
+pub(super) fn panic(
+    mut caller: impl HostCaller,
+    message_ptr: u32,
+    message_len: u32,
+) -> Result<(), InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    let message = caller.read_memory(message_ptr, message_len)?;
+
+    runtime.sys_panic(message)
+}
+
+pub(super) fn bls12381_v1_verify(
+    mut caller: impl HostCaller,
+    message_ptr: u32,
+    message_len: u32,
+    public_key_ptr: u32,
+    public_key_len: u32,
+    signature_ptr: u32,
+    signature_len: u32,
+) -> Result<u32, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    let message = caller.read_memory(message_ptr, message_len)?;
+    let public_key = caller.read_memory(public_key_ptr, public_key_len)?;
+    let signature = caller.read_memory(signature_ptr, signature_len)?;
+
+    runtime.crypto_utils_bls12381_v1_verify(message, public_key, signature)
+}
+
+pub(super) fn bls12381_v1_aggregate_verify(
+    mut caller: impl HostCaller,
+    pub_keys_and_msgs_ptr: u32,
+    pub_keys_and_msgs_len: u32,
+    signature_ptr: u32,
+    signature_len: u32,
+) -> Result<u32, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    let pub_keys_and_msgs = caller.read_memory(pub_keys_and_msgs_ptr, pub_keys_and_msgs_len)?;
+    let signature = caller.read_memory(signature_ptr, signature_len)?;
+
+    runtime.crypto_utils_bls12381_v1_aggregate_verify(pub_keys_and_msgs, signature)
+}
+
+pub(super) fn bls12381_v1_fast_aggregate_verify(
+    mut caller: impl HostCaller,
+    message_ptr: u32,
+    message_len: u32,
+    public_keys_ptr: u32,
+    public_keys_len: u32,
+    signature_ptr: u32,
+    signature_len: u32,
+) -> Result<u32, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    let message = caller.read_memory(message_ptr, message_len)?;
+    let public_keys = caller.read_memory(public_keys_ptr, public_keys_len)?;
+    let signature = caller.read_memory(signature_ptr, signature_len)?;
+
+    runtime.crypto_utils_bls12381_v1_fast_aggregate_verify(message, public_keys, signature)
+}
+
+pub(super) fn bls12381_g2_signature_aggregate(
+    mut caller: impl HostCaller,
+    signatures_ptr: u32,
+    signatures_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    let signatures = caller.read_memory(signatures_ptr, signatures_len)?;
+
+    runtime
+        .crypto_utils_bls12381_g2_signature_aggregate(signatures)
+        .map(|buffer| buffer.0)
+}
+
+pub(super) fn keccak256_hash(
+    mut caller: impl HostCaller,
+    data_ptr: u32,
+    data_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    let data = caller.read_memory(data_ptr, data_len)?;
+
+    runtime
+        .crypto_utils_keccak256_hash(data)
+        .map(|buffer| buffer.0)
+}
+
+pub(super) fn blake2b_256_hash(
+    mut caller: impl HostCaller,
+    data_ptr: u32,
+    data_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    let data = caller.read_memory(data_ptr, data_len)?;
+
+    runtime
+        .crypto_utils_blake2b_256_hash(data)
+        .map(|buffer| buffer.0)
+}
+
+pub(super) fn ed25519_verify(
+    mut caller: impl HostCaller,
+    message_ptr: u32,
+    message_len: u32,
+    public_key_ptr: u32,
+    public_key_len: u32,
+    signature_ptr: u32,
+    signature_len: u32,
+) -> Result<u32, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    let message = caller.read_memory(message_ptr, message_len)?;
+    let public_key = caller.read_memory(public_key_ptr, public_key_len)?;
+    let signature = caller.read_memory(signature_ptr, signature_len)?;
+
+    runtime.crypto_utils_ed25519_verify(message, public_key, signature)
+}
+
+pub(super) fn secp256k1_ecdsa_verify(
+    mut caller: impl HostCaller,
+    message_ptr: u32,
+    message_len: u32,
+    public_key_ptr: u32,
+    public_key_len: u32,
+    signature_ptr: u32,
+    signature_len: u32,
+) -> Result<u32, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    let message = caller.read_memory(message_ptr, message_len)?;
+    let public_key = caller.read_memory(public_key_ptr, public_key_len)?;
+    let signature = caller.read_memory(signature_ptr, signature_len)?;
+
+    runtime.crypto_utils_secp256k1_ecdsa_verify(message, public_key, signature)
+}
+
+pub(super) fn secp256k1_ecdsa_verify_and_key_recover(
+    mut caller: impl HostCaller,
+    message_ptr: u32,
+    message_len: u32,
+    signature_ptr: u32,
+    signature_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    let message = caller.read_memory(message_ptr, message_len)?;
+    let signature = caller.read_memory(signature_ptr, signature_len)?;
+
+    runtime
+        .crypto_utils_secp256k1_ecdsa_verify_and_key_recover(message, signature)
+        .map(|buffer| buffer.0)
+}
+
+pub(super) fn secp256k1_ecdsa_verify_and_key_recover_uncompressed(
+    mut caller: impl HostCaller,
+    message_ptr: u32,
+    message_len: u32,
+    signature_ptr: u32,
+    signature_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let runtime = grab_runtime!(caller);
+
+    let message = caller.read_memory(message_ptr, message_len)?;
+    let signature = caller.read_memory(signature_ptr, signature_len)?;
+
+    runtime
+        .crypto_utils_secp256k1_ecdsa_verify_and_key_recover_uncompressed(message, signature)
+        .map(|buffer| buffer.0)
+}
+
+#[cfg(feature = "radix_engine_tests")]
+pub(super) fn test_host_read_memory(
+    mut caller: impl HostCaller,
+    memory_offs: u32,
+    data_len: u32,
+) -> Result<(), InvokeError<WasmRuntimeError>> {
+    // - attempt to read data of given length data starting from given memory offset memory_ptr
+    caller.read_memory(memory_offs, data_len)?;
+
+    Ok(())
+}
+
+#[cfg(feature = "radix_engine_tests")]
+pub(super) fn test_host_write_memory(
+    mut caller: impl HostCaller,
+    memory_ptr: u32,
+    data_len: u32,
+) -> Result<(), InvokeError<WasmRuntimeError>> {
+    // - generate some random data of of given length data_len
+    // - attempt to write this data into given memory offset memory_ptr
+    let data = vec![0u8; data_len as usize];
+    caller.write_memory(memory_ptr, &data)?;
+
+    Ok(())
+}
+
+#[cfg(feature = "radix_engine_tests")]
+pub(super) fn test_host_check_memory_is_clean(
+    mut caller: impl HostCaller,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    // - attempt to read data of given length data starting from given memory offset memory_ptr
+    let data = caller.memory();
+    let clean = !data.iter().any(|&x| x != 0x0);
+
+    Ok(clean as u64)
+}
+// native functions ends
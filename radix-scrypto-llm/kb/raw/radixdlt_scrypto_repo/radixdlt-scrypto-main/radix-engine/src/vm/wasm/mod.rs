@@ -1,16 +1,25 @@
 mod constants;
 mod errors;
+mod host_functions;
 mod prepare;
+#[cfg(feature = "std")]
+mod prepared_code_cache;
 mod traits;
 mod wasm_validator;
 mod wasm_validator_config;
 mod wasmi;
+#[cfg(feature = "wasmtime")]
+mod wasmtime;
 mod weights;
 
 pub use self::wasmi::*;
+#[cfg(feature = "wasmtime")]
+pub use self::wasmtime::*;
 pub use constants::*;
 pub use errors::*;
 pub use prepare::*;
+#[cfg(feature = "std")]
+pub use prepared_code_cache::*;
 pub use traits::*;
 pub use wasm_validator::*;
 pub use wasm_validator_config::*;
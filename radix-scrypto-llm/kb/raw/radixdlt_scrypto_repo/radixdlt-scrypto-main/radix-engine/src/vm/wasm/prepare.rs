@@ -992,6 +992,162 @@ impl WasmModule {
                         }
                     }
                     // Crypto Utils v2 end
+                    // Actor collections v1 begin
+                    ACTOR_INDEX_INSERT_FUNCTION_NAME => {
+                        if version < ScryptoVmVersion::actor_collections_v1() {
+                            return Err(PrepareError::InvalidImport(
+                                InvalidImport::ProtocolVersionMismatch {
+                                    name: entry.name.to_string(),
+                                    current_version: version.into(),
+                                    expected_version: ScryptoVmVersion::actor_collections_v1()
+                                        .into(),
+                                },
+                            ));
+                        }
+
+                        if let TypeRef::Func(type_index) = entry.ty {
+                            if Self::function_type_matches(
+                                &self.module,
+                                type_index,
+                                vec![
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                ],
+                                vec![],
+                            ) {
+                                continue;
+                            }
+                            return Err(PrepareError::InvalidImport(
+                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
+                            ));
+                        }
+                    }
+                    ACTOR_INDEX_REMOVE_FUNCTION_NAME
+                    | ACTOR_SORTED_INDEX_SCAN_FROM_FUNCTION_NAME => {
+                        if version < ScryptoVmVersion::actor_collections_v1() {
+                            return Err(PrepareError::InvalidImport(
+                                InvalidImport::ProtocolVersionMismatch {
+                                    name: entry.name.to_string(),
+                                    current_version: version.into(),
+                                    expected_version: ScryptoVmVersion::actor_collections_v1()
+                                        .into(),
+                                },
+                            ));
+                        }
+
+                        if let TypeRef::Func(type_index) = entry.ty {
+                            if Self::function_type_matches(
+                                &self.module,
+                                type_index,
+                                vec![ValType::I32, ValType::I32, ValType::I32, ValType::I32],
+                                vec![ValType::I64],
+                            ) {
+                                continue;
+                            }
+                            return Err(PrepareError::InvalidImport(
+                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
+                            ));
+                        }
+                    }
+                    ACTOR_INDEX_SCAN_KEYS_FUNCTION_NAME
+                    | ACTOR_INDEX_DRAIN_FUNCTION_NAME
+                    | ACTOR_SORTED_INDEX_SCAN_FUNCTION_NAME => {
+                        if version < ScryptoVmVersion::actor_collections_v1() {
+                            return Err(PrepareError::InvalidImport(
+                                InvalidImport::ProtocolVersionMismatch {
+                                    name: entry.name.to_string(),
+                                    current_version: version.into(),
+                                    expected_version: ScryptoVmVersion::actor_collections_v1()
+                                        .into(),
+                                },
+                            ));
+                        }
+
+                        if let TypeRef::Func(type_index) = entry.ty {
+                            if Self::function_type_matches(
+                                &self.module,
+                                type_index,
+                                vec![ValType::I32, ValType::I32, ValType::I32],
+                                vec![ValType::I64],
+                            ) {
+                                continue;
+                            }
+                            return Err(PrepareError::InvalidImport(
+                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
+                            ));
+                        }
+                    }
+                    ACTOR_SORTED_INDEX_INSERT_FUNCTION_NAME => {
+                        if version < ScryptoVmVersion::actor_collections_v1() {
+                            return Err(PrepareError::InvalidImport(
+                                InvalidImport::ProtocolVersionMismatch {
+                                    name: entry.name.to_string(),
+                                    current_version: version.into(),
+                                    expected_version: ScryptoVmVersion::actor_collections_v1()
+                                        .into(),
+                                },
+                            ));
+                        }
+
+                        if let TypeRef::Func(type_index) = entry.ty {
+                            if Self::function_type_matches(
+                                &self.module,
+                                type_index,
+                                vec![
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                ],
+                                vec![],
+                            ) {
+                                continue;
+                            }
+                            return Err(PrepareError::InvalidImport(
+                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
+                            ));
+                        }
+                    }
+                    ACTOR_SORTED_INDEX_REMOVE_FUNCTION_NAME => {
+                        if version < ScryptoVmVersion::actor_collections_v1() {
+                            return Err(PrepareError::InvalidImport(
+                                InvalidImport::ProtocolVersionMismatch {
+                                    name: entry.name.to_string(),
+                                    current_version: version.into(),
+                                    expected_version: ScryptoVmVersion::actor_collections_v1()
+                                        .into(),
+                                },
+                            ));
+                        }
+
+                        if let TypeRef::Func(type_index) = entry.ty {
+                            if Self::function_type_matches(
+                                &self.module,
+                                type_index,
+                                vec![
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                ],
+                                vec![ValType::I64],
+                            ) {
+                                continue;
+                            }
+                            return Err(PrepareError::InvalidImport(
+                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
+                            ));
+                        }
+                    }
+                    // Actor collections v1 end
                     _ => {}
                 };
             }
@@ -1585,6 +1741,20 @@ mod tests {
                     CRYPTO_UTILS_SECP256K1_ECDSA_VERIFY_AND_KEY_RECOVER_UNCOMPRESSED_FUNCTION_NAME,
                 ],
             ),
+            (
+                ScryptoVmVersion::V1_2,
+                ScryptoVmVersion::actor_collections_v1(),
+                vec![
+                    ACTOR_INDEX_INSERT_FUNCTION_NAME,
+                    ACTOR_INDEX_REMOVE_FUNCTION_NAME,
+                    ACTOR_INDEX_SCAN_KEYS_FUNCTION_NAME,
+                    ACTOR_INDEX_DRAIN_FUNCTION_NAME,
+                    ACTOR_SORTED_INDEX_INSERT_FUNCTION_NAME,
+                    ACTOR_SORTED_INDEX_REMOVE_FUNCTION_NAME,
+                    ACTOR_SORTED_INDEX_SCAN_FUNCTION_NAME,
+                    ACTOR_SORTED_INDEX_SCAN_FROM_FUNCTION_NAME,
+                ],
+            ),
         ] {
             for name in names {
                 assert_invalid_wasm!(
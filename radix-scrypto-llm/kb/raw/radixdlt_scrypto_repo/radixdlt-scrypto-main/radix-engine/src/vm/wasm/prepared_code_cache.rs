@@ -0,0 +1,218 @@
+use crate::internal_prelude::*;
+use crate::vm::wasm::*;
+use radix_engine_interface::blueprints::package::BlueprintDefinitionInit;
+use std::path::{Path, PathBuf};
+
+/// The version of `radix-wasm-instrument`, which is pinned in the workspace manifest as a change of
+/// its output must miss the cached entries - a test checks that it is the locked version.
+const WASM_INSTRUMENT_VERSION: &str = "1.0.0";
+
+/// An on-disk cache of the code prepared by the [`ScryptoV1WasmValidator`], which saves
+/// re-validating and re-instrumenting the WASM of every published package when a ledger is
+/// replayed or rebuilt.
+///
+/// Only the instrumented code is cached - compiling it into a module of the [`WasmEngine`] is
+/// still done on each run, and is only cached in memory (see the `moka` and `lru` features).
+///
+/// The entries are keyed by the hash of the original code and of the blueprint definitions it is
+/// validated against, and are kept in a sub-folder per [`ScryptoVmVersion`] and instrumentation
+/// rules (see [`PreparedCodeCache::rules_folder_name`]). A change of either therefore misses the
+/// old entries, which can be deleted at any time.
+///
+/// Only successful validations are cached, and an entry which can't be read is prepared again.
+/// The cached code is trusted to be metered - the folder must not be writable by anyone else.
+#[derive(Debug, Clone)]
+pub struct PreparedCodeCache {
+    folder: PathBuf,
+}
+
+impl PreparedCodeCache {
+    pub fn new(folder: impl Into<PathBuf>) -> Self {
+        Self {
+            folder: folder.into(),
+        }
+    }
+
+    pub fn folder(&self) -> &Path {
+        &self.folder
+    }
+
+    /// Same as [`ScryptoV1WasmValidator::validate`], but returns the cached instrumented code and
+    /// export names if the code was prepared with the same rules before.
+    pub fn validate<'a, I: Iterator<Item = &'a BlueprintDefinitionInit>>(
+        &self,
+        validator: &ScryptoV1WasmValidator,
+        code: &[u8],
+        blueprints: I,
+    ) -> Result<(Vec<u8>, Vec<String>), PrepareError> {
+        let blueprints: Vec<&BlueprintDefinitionInit> = blueprints.collect();
+        let path = self.entry_path(validator, code, &blueprints);
+
+        if let Some(prepared) = std::fs::read(&path)
+            .ok()
+            .and_then(|bytes| scrypto_decode::<(Vec<u8>, Vec<String>)>(&bytes).ok())
+        {
+            return Ok(prepared);
+        }
+
+        let prepared = validator.validate(code, blueprints.into_iter())?;
+        // The cache is best effort - failing to write it must not fail the validation
+        let _ = write_atomically(&path, &scrypto_encode(&prepared).unwrap());
+        Ok(prepared)
+    }
+
+    /// The name of the sub-folder with the entries prepared by the given validator, which is
+    /// derived from the VM version, the limits and instrumenter config of the validator, and the
+    /// versions of the engine and of the instrumentation library.
+    pub fn rules_folder_name(validator: &ScryptoV1WasmValidator) -> String {
+        let rules = format!(
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{:?}",
+            env!("CARGO_PKG_VERSION"),
+            WASM_INSTRUMENT_VERSION,
+            validator.max_memory_size_in_pages,
+            validator.max_initial_table_size,
+            validator.max_number_of_br_table_targets,
+            validator.max_number_of_functions,
+            validator.max_number_of_function_params,
+            validator.max_number_of_function_locals,
+            validator.max_number_of_globals,
+            validator.instrumenter_config,
+        );
+        format!(
+            "v{}-{}",
+            u64::from(validator.version),
+            &hash(rules).to_string()[..16]
+        )
+    }
+
+    fn entry_path(
+        &self,
+        validator: &ScryptoV1WasmValidator,
+        code: &[u8],
+        blueprints: &[&BlueprintDefinitionInit],
+    ) -> PathBuf {
+        let mut encoded_blueprints = Vec::new();
+        for blueprint in blueprints {
+            encoded_blueprints.extend(scrypto_encode(*blueprint).unwrap());
+        }
+        self.folder
+            .join(Self::rules_folder_name(validator))
+            .join(format!(
+                "{}-{}.sbor",
+                hash(code),
+                &hash(encoded_blueprints).to_string()[..16]
+            ))
+    }
+}
+
+fn write_atomically(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    if let Some(folder) = path.parent() {
+        std::fs::create_dir_all(folder)?;
+    }
+    // Concurrent runs sharing the cache must never read a partially written entry
+    let temp_path = path.with_extension(format!("{}.tmp", std::process::id()));
+    std::fs::write(&temp_path, contents)?;
+    std::fs::rename(&temp_path, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use radix_engine_interface::blueprints::package::PackageDefinition;
+    use wabt::wat2wasm;
+
+    fn test_code() -> Vec<u8> {
+        wat2wasm(
+            r#"
+        (module
+            (func $Test_f (param $0 i64) (result i64)
+              (i64.const 0)
+            )
+            (memory $0 1)
+            (export "memory" (memory $0))
+            (export "Test_f" (func $Test_f))
+        )"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn cached_code_is_the_same_as_freshly_prepared_code() {
+        // Arrange
+        let folder = tempfile::tempdir().unwrap();
+        let cache = PreparedCodeCache::new(folder.path());
+        let validator = ScryptoV1WasmValidator::new(ScryptoVmVersion::latest());
+        let definition = PackageDefinition::new_single_function_test_definition("Test", "f");
+        let code = test_code();
+
+        // Act
+        let first = cache.validate(&validator, &code, definition.blueprints.values());
+        let second = cache.validate(&validator, &code, definition.blueprints.values());
+
+        // Assert
+        let expected = validator
+            .validate(&code, definition.blueprints.values())
+            .unwrap();
+        assert_eq!(first, Ok(expected.clone()));
+        assert_eq!(second, Ok(expected));
+        assert_eq!(
+            std::fs::read_dir(
+                folder
+                    .path()
+                    .join(PreparedCodeCache::rules_folder_name(&validator))
+            )
+            .unwrap()
+            .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn each_vm_version_has_its_own_entries() {
+        assert_ne!(
+            PreparedCodeCache::rules_folder_name(&ScryptoV1WasmValidator::new(
+                ScryptoVmVersion::V1_0
+            )),
+            PreparedCodeCache::rules_folder_name(&ScryptoV1WasmValidator::new(
+                ScryptoVmVersion::V1_1
+            ))
+        );
+    }
+
+    #[test]
+    fn wasm_instrument_version_is_the_locked_version() {
+        // Arrange
+        let lock_file =
+            std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/../Cargo.lock")).unwrap();
+
+        // Act
+        let locked_version = lock_file
+            .split("[[package]]")
+            .find(|package| package.contains("name = \"radix-wasm-instrument\""))
+            .and_then(|package| {
+                package
+                    .lines()
+                    .find_map(|line| line.strip_prefix("version = "))
+            })
+            .map(|version| version.trim_matches('"'));
+
+        // Assert
+        assert_eq!(locked_version, Some(WASM_INSTRUMENT_VERSION));
+    }
+
+    #[test]
+    fn invalid_code_is_not_cached() {
+        // Arrange
+        let folder = tempfile::tempdir().unwrap();
+        let cache = PreparedCodeCache::new(folder.path());
+        let validator = ScryptoV1WasmValidator::new(ScryptoVmVersion::latest());
+        let definition = PackageDefinition::new_single_function_test_definition("Test", "g");
+
+        // Act
+        let result = cache.validate(&validator, &test_code(), definition.blueprints.values());
+
+        // Assert
+        assert!(result.is_err());
+        assert_eq!(std::fs::read_dir(folder.path()).unwrap().count(), 0);
+    }
+}
@@ -17,7 +17,7 @@ pub struct ScryptoV1WasmValidator {
 
 impl ScryptoV1WasmValidator {
     pub fn new(version: ScryptoVmVersion) -> Self {
-        if version > ScryptoVmVersion::latest() {
+        if version > ScryptoVmVersion::highest_supported() {
             panic!("Invalid minor version: {:?}", version);
         }
 
@@ -4,9 +4,11 @@ use crate::internal_prelude::*;
 use crate::utils::save_coverage_data;
 use crate::vm::wasm::constants::*;
 use crate::vm::wasm::errors::*;
+use crate::vm::wasm::host_functions::*;
+// The host functions named after the hashing functions of the prelude
+use crate::vm::wasm::host_functions::{blake2b_256_hash, keccak256_hash};
 use crate::vm::wasm::traits::*;
 use crate::vm::wasm::WasmEngine;
-use radix_engine_interface::api::actor_api::EventFlags;
 use radix_engine_interface::blueprints::package::CodeHash;
 use sbor::rust::mem::MaybeUninit;
 #[cfg(not(feature = "fuzzing"))]
@@ -47,13 +49,6 @@ impl WasmiInstanceEnv {
     }
 }
 
-macro_rules! grab_runtime {
-    ($caller: expr) => {{
-        let runtime: &mut Box<dyn WasmRuntime> =
-            unsafe { &mut *$caller.data().runtime_ptr.assume_init() };
-        runtime
-    }};
-}
 macro_rules! grab_memory {
     ($caller: expr) => {{
         match $caller.get_export(EXPORT_MEMORY) {
@@ -63,884 +58,16 @@ macro_rules! grab_memory {
     }};
 }
 
-// native functions start
-fn consume_buffer(
-    caller: Caller<'_, HostState>,
-    buffer_id: BufferId,
-    destination_ptr: u32,
-) -> Result<(), InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-    let memory = grab_memory!(caller);
-
-    let result = runtime.buffer_consume(buffer_id);
-    match result {
-        Ok(slice) => {
-            write_memory(caller, memory, destination_ptr, &slice)?;
-            Ok(())
-        }
-        Err(e) => Err(e),
+impl HostCaller for Caller<'_, HostState> {
+    fn runtime_ptr(&self) -> *mut Box<dyn WasmRuntime> {
+        unsafe { self.data().runtime_ptr.assume_init() }
     }
-}
-
-fn call_method(
-    mut caller: Caller<'_, HostState>,
-    receiver_ptr: u32,
-    receiver_len: u32,
-    ident_ptr: u32,
-    ident_len: u32,
-    args_ptr: u32,
-    args_len: u32,
-) -> Result<u64, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-    let memory = grab_memory!(caller);
-
-    let receiver = read_memory(caller.as_context_mut(), memory, receiver_ptr, receiver_len)?;
-    let ident = read_memory(caller.as_context_mut(), memory, ident_ptr, ident_len)?;
-    let args = read_memory(caller.as_context_mut(), memory, args_ptr, args_len)?;
-
-    runtime
-        .object_call(receiver, ident, args)
-        .map(|buffer| buffer.0)
-}
-
-fn call_direct_method(
-    mut caller: Caller<'_, HostState>,
-    receiver_ptr: u32,
-    receiver_len: u32,
-    ident_ptr: u32,
-    ident_len: u32,
-    args_ptr: u32,
-    args_len: u32,
-) -> Result<u64, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-    let memory = grab_memory!(caller);
-
-    let receiver = read_memory(caller.as_context_mut(), memory, receiver_ptr, receiver_len)?;
-    let ident = read_memory(caller.as_context_mut(), memory, ident_ptr, ident_len)?;
-    let args = read_memory(caller.as_context_mut(), memory, args_ptr, args_len)?;
-
-    runtime
-        .object_call_direct(receiver, ident, args)
-        .map(|buffer| buffer.0)
-}
-
-fn call_module_method(
-    mut caller: Caller<'_, HostState>,
-    receiver_ptr: u32,
-    receiver_len: u32,
-    module_id: u32,
-    ident_ptr: u32,
-    ident_len: u32,
-    args_ptr: u32,
-    args_len: u32,
-) -> Result<u64, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-    let memory = grab_memory!(caller);
-
-    let receiver = read_memory(caller.as_context_mut(), memory, receiver_ptr, receiver_len)?;
-    let ident = read_memory(caller.as_context_mut(), memory, ident_ptr, ident_len)?;
-    let args = read_memory(caller.as_context_mut(), memory, args_ptr, args_len)?;
-
-    runtime
-        .object_call_module(receiver, module_id, ident, args)
-        .map(|buffer| buffer.0)
-}
-
-fn call_function(
-    mut caller: Caller<'_, HostState>,
-    package_address_ptr: u32,
-    package_address_len: u32,
-    blueprint_name_ptr: u32,
-    blueprint_name_len: u32,
-    ident_ptr: u32,
-    ident_len: u32,
-    args_ptr: u32,
-    args_len: u32,
-) -> Result<u64, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-    let memory = grab_memory!(caller);
-
-    let package_address = read_memory(
-        caller.as_context_mut(),
-        memory,
-        package_address_ptr,
-        package_address_len,
-    )?;
-    let blueprint_name = read_memory(
-        caller.as_context_mut(),
-        memory,
-        blueprint_name_ptr,
-        blueprint_name_len,
-    )?;
-    let ident = read_memory(caller.as_context_mut(), memory, ident_ptr, ident_len)?;
-    let args = read_memory(caller.as_context_mut(), memory, args_ptr, args_len)?;
-
-    runtime
-        .blueprint_call(package_address, blueprint_name, ident, args)
-        .map(|buffer| buffer.0)
-}
-
-fn new_object(
-    mut caller: Caller<'_, HostState>,
-    blueprint_name_ptr: u32,
-    blueprint_name_len: u32,
-    object_states_ptr: u32,
-    object_states_len: u32,
-) -> Result<u64, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-    let memory = grab_memory!(caller);
-
-    runtime
-        .object_new(
-            read_memory(
-                caller.as_context_mut(),
-                memory,
-                blueprint_name_ptr,
-                blueprint_name_len,
-            )?,
-            read_memory(
-                caller.as_context_mut(),
-                memory,
-                object_states_ptr,
-                object_states_len,
-            )?,
-        )
-        .map(|buffer| buffer.0)
-}
-
-fn new_key_value_store(
-    mut caller: Caller<'_, HostState>,
-    schema_id_ptr: u32,
-    schema_id_len: u32,
-) -> Result<u64, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-    let memory = grab_memory!(caller);
-
-    runtime
-        .key_value_store_new(read_memory(
-            caller.as_context_mut(),
-            memory,
-            schema_id_ptr,
-            schema_id_len,
-        )?)
-        .map(|buffer| buffer.0)
-}
-
-fn allocate_global_address(
-    mut caller: Caller<'_, HostState>,
-    package_address_ptr: u32,
-    package_address_len: u32,
-    blueprint_name_ptr: u32,
-    blueprint_name_len: u32,
-) -> Result<u64, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-    let memory = grab_memory!(caller);
-
-    runtime
-        .address_allocate(
-            read_memory(
-                caller.as_context_mut(),
-                memory,
-                package_address_ptr,
-                package_address_len,
-            )?,
-            read_memory(
-                caller.as_context_mut(),
-                memory,
-                blueprint_name_ptr,
-                blueprint_name_len,
-            )?,
-        )
-        .map(|buffer| buffer.0)
-}
-
-fn get_reservation_address(
-    mut caller: Caller<'_, HostState>,
-    node_id_ptr: u32,
-    node_id_len: u32,
-) -> Result<u64, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-    let memory = grab_memory!(caller);
-
-    runtime
-        .address_get_reservation_address(read_memory(
-            caller.as_context_mut(),
-            memory,
-            node_id_ptr,
-            node_id_len,
-        )?)
-        .map(|buffer| buffer.0)
-}
-
-fn execution_cost_unit_limit(
-    caller: Caller<'_, HostState>,
-) -> Result<u32, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-
-    runtime.costing_get_execution_cost_unit_limit()
-}
-
-fn execution_cost_unit_price(
-    caller: Caller<'_, HostState>,
-) -> Result<u64, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-
-    runtime
-        .costing_get_execution_cost_unit_price()
-        .map(|buffer| buffer.0)
-}
-
-fn finalization_cost_unit_limit(
-    caller: Caller<'_, HostState>,
-) -> Result<u32, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-
-    runtime.costing_get_finalization_cost_unit_limit()
-}
-
-fn finalization_cost_unit_price(
-    caller: Caller<'_, HostState>,
-) -> Result<u64, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-
-    runtime
-        .costing_get_finalization_cost_unit_price()
-        .map(|buffer| buffer.0)
-}
-
-fn usd_price(caller: Caller<'_, HostState>) -> Result<u64, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-
-    runtime.costing_get_usd_price().map(|buffer| buffer.0)
-}
-
-fn tip_percentage(caller: Caller<'_, HostState>) -> Result<u32, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-
-    runtime.costing_get_tip_percentage()
-}
-
-fn fee_balance(caller: Caller<'_, HostState>) -> Result<u64, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-
-    runtime.costing_get_fee_balance().map(|buffer| buffer.0)
-}
-
-fn globalize_object(
-    mut caller: Caller<'_, HostState>,
-    obj_id_ptr: u32,
-    obj_id_len: u32,
-    modules_ptr: u32,
-    modules_len: u32,
-    address_ptr: u32,
-    address_len: u32,
-) -> Result<u64, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-    let memory = grab_memory!(caller);
-
-    runtime
-        .globalize_object(
-            read_memory(caller.as_context_mut(), memory, obj_id_ptr, obj_id_len)?,
-            read_memory(caller.as_context_mut(), memory, modules_ptr, modules_len)?,
-            read_memory(caller.as_context_mut(), memory, address_ptr, address_len)?,
-        )
-        .map(|buffer| buffer.0)
-}
-
-fn instance_of(
-    mut caller: Caller<'_, HostState>,
-    component_id_ptr: u32,
-    component_id_len: u32,
-    package_address_ptr: u32,
-    package_address_len: u32,
-    blueprint_name_ptr: u32,
-    blueprint_name_len: u32,
-) -> Result<u32, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-    let memory = grab_memory!(caller);
-
-    runtime.instance_of(
-        read_memory(
-            caller.as_context_mut(),
-            memory,
-            component_id_ptr,
-            component_id_len,
-        )?,
-        read_memory(
-            caller.as_context_mut(),
-            memory,
-            package_address_ptr,
-            package_address_len,
-        )?,
-        read_memory(
-            caller.as_context_mut(),
-            memory,
-            blueprint_name_ptr,
-            blueprint_name_len,
-        )?,
-    )
-}
-
-fn blueprint_id(
-    mut caller: Caller<'_, HostState>,
-    component_id_ptr: u32,
-    component_id_len: u32,
-) -> Result<u64, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-    let memory = grab_memory!(caller);
-
-    runtime
-        .blueprint_id(read_memory(
-            caller.as_context_mut(),
-            memory,
-            component_id_ptr,
-            component_id_len,
-        )?)
-        .map(|buffer| buffer.0)
-}
-
-fn get_outer_object(
-    mut caller: Caller<'_, HostState>,
-    component_id_ptr: u32,
-    component_id_len: u32,
-) -> Result<u64, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-    let memory = grab_memory!(caller);
-
-    runtime
-        .get_outer_object(read_memory(
-            caller.as_context_mut(),
-            memory,
-            component_id_ptr,
-            component_id_len,
-        )?)
-        .map(|buffer| buffer.0)
-}
-
-fn lock_key_value_store_entry(
-    mut caller: Caller<'_, HostState>,
-    node_id_ptr: u32,
-    node_id_len: u32,
-    offset_ptr: u32,
-    offset_len: u32,
-    flags: u32,
-) -> Result<u32, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-    let memory = grab_memory!(caller);
-
-    let node_id = read_memory(caller.as_context_mut(), memory, node_id_ptr, node_id_len)?;
-    let substate_key = read_memory(caller.as_context_mut(), memory, offset_ptr, offset_len)?;
-
-    runtime.key_value_store_open_entry(node_id, substate_key, flags)
-}
-
-fn key_value_entry_get(
-    caller: Caller<'_, HostState>,
-    handle: u32,
-) -> Result<u64, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-
-    runtime.key_value_entry_get(handle).map(|buffer| buffer.0)
-}
-
-fn key_value_entry_set(
-    mut caller: Caller<'_, HostState>,
-    handle: u32,
-    buffer_ptr: u32,
-    buffer_len: u32,
-) -> Result<(), InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-    let memory = grab_memory!(caller);
-    let data = read_memory(caller.as_context_mut(), memory, buffer_ptr, buffer_len)?;
-    runtime.key_value_entry_set(handle, data)
-}
-
-fn key_value_entry_remove(
-    caller: Caller<'_, HostState>,
-    handle: u32,
-) -> Result<u64, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-
-    runtime
-        .key_value_entry_remove(handle)
-        .map(|buffer| buffer.0)
-}
-
-fn unlock_key_value_entry(
-    caller: Caller<'_, HostState>,
-    handle: u32,
-) -> Result<(), InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-
-    runtime.key_value_entry_close(handle)
-}
-
-fn key_value_store_remove(
-    mut caller: Caller<'_, HostState>,
-    node_id_ptr: u32,
-    node_id_len: u32,
-    key_ptr: u32,
-    key_len: u32,
-) -> Result<u64, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-    let memory = grab_memory!(caller);
-    let node_id = read_memory(caller.as_context_mut(), memory, node_id_ptr, node_id_len)?;
-    let key = read_memory(caller.as_context_mut(), memory, key_ptr, key_len)?;
-
-    runtime
-        .key_value_store_remove_entry(node_id, key)
-        .map(|buffer| buffer.0)
-}
-
-fn lock_field(
-    caller: Caller<'_, HostState>,
-    object_handle: u32,
-    field: u32,
-    flags: u32,
-) -> Result<u32, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-
-    runtime.actor_open_field(object_handle, field as u8, flags)
-}
-
-fn field_lock_read(
-    caller: Caller<'_, HostState>,
-    handle: u32,
-) -> Result<u64, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-
-    runtime.field_entry_read(handle).map(|buffer| buffer.0)
-}
-
-fn field_lock_write(
-    mut caller: Caller<'_, HostState>,
-    handle: u32,
-    data_ptr: u32,
-    data_len: u32,
-) -> Result<(), InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-    let memory = grab_memory!(caller);
-
-    let data = read_memory(caller.as_context_mut(), memory, data_ptr, data_len)?;
-
-    runtime.field_entry_write(handle, data)
-}
-
-fn field_lock_release(
-    caller: Caller<'_, HostState>,
-    handle: u32,
-) -> Result<(), InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-
-    runtime.field_entry_close(handle)
-}
-
-fn actor_get_node_id(
-    caller: Caller<'_, HostState>,
-    handle: u32,
-) -> Result<u64, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-
-    runtime.actor_get_node_id(handle).map(|buffer| buffer.0)
-}
-
-fn get_package_address(
-    caller: Caller<'_, HostState>,
-) -> Result<u64, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-
-    runtime.actor_get_package_address().map(|buffer| buffer.0)
-}
-
-fn get_blueprint_name(caller: Caller<'_, HostState>) -> Result<u64, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-
-    runtime.actor_get_blueprint_name().map(|buffer| buffer.0)
-}
-
-#[inline]
-fn consume_wasm_execution_units(
-    caller: Caller<'_, HostState>,
-    n: u64,
-) -> Result<(), InvokeError<WasmRuntimeError>> {
-    let runtime: &mut Box<dyn WasmRuntime> =
-        unsafe { &mut *caller.data().runtime_ptr.assume_init() };
-
-    // TODO: wasm-instrument uses u64 for cost units. We need to decide if we want to move from u32
-    // to u64 as well.
-    runtime.consume_wasm_execution_units(n as u32)
-}
-
-fn emit_event(
-    mut caller: Caller<'_, HostState>,
-    event_name_ptr: u32,
-    event_name_len: u32,
-    event_data_ptr: u32,
-    event_data_len: u32,
-    flags: u32,
-) -> Result<(), InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-    let memory = grab_memory!(caller);
-
-    let event_name = read_memory(
-        caller.as_context_mut(),
-        memory,
-        event_name_ptr,
-        event_name_len,
-    )?;
-    let event_data = read_memory(
-        caller.as_context_mut(),
-        memory,
-        event_data_ptr,
-        event_data_len,
-    )?;
-    let event_flags = EventFlags::from_bits(flags).ok_or(InvokeError::SelfError(
-        WasmRuntimeError::InvalidEventFlags(flags),
-    ))?;
-
-    runtime.actor_emit_event(event_name, event_data, event_flags)
-}
-
-fn get_transaction_hash(
-    caller: Caller<'_, HostState>,
-) -> Result<u64, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-
-    runtime.sys_get_transaction_hash().map(|buffer| buffer.0)
-}
-
-fn generate_ruid(caller: Caller<'_, HostState>) -> Result<u64, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-
-    runtime.sys_generate_ruid().map(|buffer| buffer.0)
-}
-
-fn emit_log(
-    mut caller: Caller<'_, HostState>,
-    level_ptr: u32,
-    level_len: u32,
-    message_ptr: u32,
-    message_len: u32,
-) -> Result<(), InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-    let memory = grab_memory!(caller);
-
-    let level = read_memory(caller.as_context_mut(), memory, level_ptr, level_len)?;
-    let message = read_memory(caller.as_context_mut(), memory, message_ptr, message_len)?;
-
-    runtime.sys_log(level, message)
-}
-
-fn bech32_encode_address(
-    mut caller: Caller<'_, HostState>,
-    address_ptr: u32,
-    address_len: u32,
-) -> Result<u64, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-    let memory = grab_memory!(caller);
-
-    let address = read_memory(caller.as_context_mut(), memory, address_ptr, address_len)?;
-
-    runtime
-        .sys_bech32_encode_address(address)
-        .map(|buffer| buffer.0)
-}
-
-fn panic(
-    mut caller: Caller<'_, HostState>,
-    message_ptr: u32,
-    message_len: u32,
-) -> Result<(), InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-    let memory = grab_memory!(caller);
-
-    let message = read_memory(caller.as_context_mut(), memory, message_ptr, message_len)?;
-
-    runtime.sys_panic(message)
-}
-
-fn bls12381_v1_verify(
-    mut caller: Caller<'_, HostState>,
-    message_ptr: u32,
-    message_len: u32,
-    public_key_ptr: u32,
-    public_key_len: u32,
-    signature_ptr: u32,
-    signature_len: u32,
-) -> Result<u32, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-    let memory = grab_memory!(caller);
-
-    let message = read_memory(caller.as_context_mut(), memory, message_ptr, message_len)?;
-    let public_key = read_memory(
-        caller.as_context_mut(),
-        memory,
-        public_key_ptr,
-        public_key_len,
-    )?;
-    let signature = read_memory(
-        caller.as_context_mut(),
-        memory,
-        signature_ptr,
-        signature_len,
-    )?;
-
-    runtime.crypto_utils_bls12381_v1_verify(message, public_key, signature)
-}
-
-fn bls12381_v1_aggregate_verify(
-    mut caller: Caller<'_, HostState>,
-    pub_keys_and_msgs_ptr: u32,
-    pub_keys_and_msgs_len: u32,
-    signature_ptr: u32,
-    signature_len: u32,
-) -> Result<u32, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-    let memory = grab_memory!(caller);
-
-    let pub_keys_and_msgs = read_memory(
-        caller.as_context_mut(),
-        memory,
-        pub_keys_and_msgs_ptr,
-        pub_keys_and_msgs_len,
-    )?;
-    let signature = read_memory(
-        caller.as_context_mut(),
-        memory,
-        signature_ptr,
-        signature_len,
-    )?;
-
-    runtime.crypto_utils_bls12381_v1_aggregate_verify(pub_keys_and_msgs, signature)
-}
-
-fn bls12381_v1_fast_aggregate_verify(
-    mut caller: Caller<'_, HostState>,
-    message_ptr: u32,
-    message_len: u32,
-    public_keys_ptr: u32,
-    public_keys_len: u32,
-    signature_ptr: u32,
-    signature_len: u32,
-) -> Result<u32, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-    let memory = grab_memory!(caller);
-
-    let message = read_memory(caller.as_context_mut(), memory, message_ptr, message_len)?;
-    let public_keys = read_memory(
-        caller.as_context_mut(),
-        memory,
-        public_keys_ptr,
-        public_keys_len,
-    )?;
-    let signature = read_memory(
-        caller.as_context_mut(),
-        memory,
-        signature_ptr,
-        signature_len,
-    )?;
-
-    runtime.crypto_utils_bls12381_v1_fast_aggregate_verify(message, public_keys, signature)
-}
-
-fn bls12381_g2_signature_aggregate(
-    mut caller: Caller<'_, HostState>,
-    signatures_ptr: u32,
-    signatures_len: u32,
-) -> Result<u64, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-    let memory = grab_memory!(caller);
-
-    let signatures = read_memory(
-        caller.as_context_mut(),
-        memory,
-        signatures_ptr,
-        signatures_len,
-    )?;
-
-    runtime
-        .crypto_utils_bls12381_g2_signature_aggregate(signatures)
-        .map(|buffer| buffer.0)
-}
-
-fn keccak256_hash(
-    mut caller: Caller<'_, HostState>,
-    data_ptr: u32,
-    data_len: u32,
-) -> Result<u64, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-    let memory = grab_memory!(caller);
-
-    let data = read_memory(caller.as_context_mut(), memory, data_ptr, data_len)?;
-
-    runtime
-        .crypto_utils_keccak256_hash(data)
-        .map(|buffer| buffer.0)
-}
-
-fn blake2b_256_hash(
-    mut caller: Caller<'_, HostState>,
-    data_ptr: u32,
-    data_len: u32,
-) -> Result<u64, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-    let memory = grab_memory!(caller);
-
-    let data = read_memory(caller.as_context_mut(), memory, data_ptr, data_len)?;
-
-    runtime
-        .crypto_utils_blake2b_256_hash(data)
-        .map(|buffer| buffer.0)
-}
 
-fn ed25519_verify(
-    mut caller: Caller<'_, HostState>,
-    message_ptr: u32,
-    message_len: u32,
-    public_key_ptr: u32,
-    public_key_len: u32,
-    signature_ptr: u32,
-    signature_len: u32,
-) -> Result<u32, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-    let memory = grab_memory!(caller);
-
-    let message = read_memory(caller.as_context_mut(), memory, message_ptr, message_len)?;
-    let public_key = read_memory(
-        caller.as_context_mut(),
-        memory,
-        public_key_ptr,
-        public_key_len,
-    )?;
-    let signature = read_memory(
-        caller.as_context_mut(),
-        memory,
-        signature_ptr,
-        signature_len,
-    )?;
-
-    runtime.crypto_utils_ed25519_verify(message, public_key, signature)
-}
-
-fn secp256k1_ecdsa_verify(
-    mut caller: Caller<'_, HostState>,
-    message_ptr: u32,
-    message_len: u32,
-    public_key_ptr: u32,
-    public_key_len: u32,
-    signature_ptr: u32,
-    signature_len: u32,
-) -> Result<u32, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-    let memory = grab_memory!(caller);
-
-    let message = read_memory(caller.as_context_mut(), memory, message_ptr, message_len)?;
-    let public_key = read_memory(
-        caller.as_context_mut(),
-        memory,
-        public_key_ptr,
-        public_key_len,
-    )?;
-    let signature = read_memory(
-        caller.as_context_mut(),
-        memory,
-        signature_ptr,
-        signature_len,
-    )?;
-
-    runtime.crypto_utils_secp256k1_ecdsa_verify(message, public_key, signature)
-}
-
-fn secp256k1_ecdsa_verify_and_key_recover(
-    mut caller: Caller<'_, HostState>,
-    message_ptr: u32,
-    message_len: u32,
-    signature_ptr: u32,
-    signature_len: u32,
-) -> Result<u64, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-    let memory = grab_memory!(caller);
-
-    let message = read_memory(caller.as_context_mut(), memory, message_ptr, message_len)?;
-    let signature = read_memory(
-        caller.as_context_mut(),
-        memory,
-        signature_ptr,
-        signature_len,
-    )?;
-
-    runtime
-        .crypto_utils_secp256k1_ecdsa_verify_and_key_recover(message, signature)
-        .map(|buffer| buffer.0)
-}
-
-fn secp256k1_ecdsa_verify_and_key_recover_uncompressed(
-    mut caller: Caller<'_, HostState>,
-    message_ptr: u32,
-    message_len: u32,
-    signature_ptr: u32,
-    signature_len: u32,
-) -> Result<u64, InvokeError<WasmRuntimeError>> {
-    let runtime = grab_runtime!(caller);
-    let memory = grab_memory!(caller);
-
-    let message = read_memory(caller.as_context_mut(), memory, message_ptr, message_len)?;
-    let signature = read_memory(
-        caller.as_context_mut(),
-        memory,
-        signature_ptr,
-        signature_len,
-    )?;
-
-    runtime
-        .crypto_utils_secp256k1_ecdsa_verify_and_key_recover_uncompressed(message, signature)
-        .map(|buffer| buffer.0)
-}
-
-#[cfg(feature = "radix_engine_tests")]
-fn test_host_read_memory(
-    mut caller: Caller<'_, HostState>,
-    memory_offs: u32,
-    data_len: u32,
-) -> Result<(), InvokeError<WasmRuntimeError>> {
-    // - attempt to read data of given length data starting from given memory offset memory_ptr
-    let memory = grab_memory!(caller);
-
-    read_memory(caller.as_context_mut(), memory, memory_offs, data_len)?;
-
-    Ok(())
-}
-
-#[cfg(feature = "radix_engine_tests")]
-fn test_host_write_memory(
-    mut caller: Caller<'_, HostState>,
-    memory_ptr: u32,
-    data_len: u32,
-) -> Result<(), InvokeError<WasmRuntimeError>> {
-    // - generate some random data of of given length data_len
-    // - attempt to write this data into given memory offset memory_ptr
-    let memory = grab_memory!(caller);
-
-    let data = vec![0u8; data_len as usize];
-    write_memory(caller.as_context_mut(), memory, memory_ptr, &data)?;
-
-    Ok(())
-}
-
-#[cfg(feature = "radix_engine_tests")]
-fn test_host_check_memory_is_clean(
-    caller: Caller<'_, HostState>,
-) -> Result<u64, InvokeError<WasmRuntimeError>> {
-    // - attempt to read data of given length data starting from given memory offset memory_ptr
-    let memory = grab_memory!(caller);
-    let store_ctx = caller.as_context();
-
-    let data = memory.data(&store_ctx);
-    let clean = !data.iter().any(|&x| x != 0x0);
-
-    Ok(clean as u64)
+    fn memory(&mut self) -> &mut [u8] {
+        let memory = grab_memory!(self);
+        memory.data_mut(self.as_context_mut())
+    }
 }
-// native functions ends
 
 macro_rules! linker_define {
     ($linker: expr, $name: expr, $var: expr) => {
@@ -1368,6 +495,143 @@ impl WasmiModule {
             },
         );
 
+        let host_actor_index_insert = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             object_handle: u32,
+             collection_index: u32,
+             key_ptr: u32,
+             key_len: u32,
+             value_ptr: u32,
+             value_len: u32|
+             -> Result<(), Error> {
+                actor_index_insert(
+                    caller,
+                    object_handle,
+                    collection_index,
+                    key_ptr,
+                    key_len,
+                    value_ptr,
+                    value_len,
+                )
+                .map_err(|e| Error::host(e))
+            },
+        );
+
+        let host_actor_index_remove = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             object_handle: u32,
+             collection_index: u32,
+             key_ptr: u32,
+             key_len: u32|
+             -> Result<u64, Error> {
+                actor_index_remove(caller, object_handle, collection_index, key_ptr, key_len)
+                    .map_err(|e| Error::host(e))
+            },
+        );
+
+        let host_actor_index_scan_keys = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             object_handle: u32,
+             collection_index: u32,
+             limit: u32|
+             -> Result<u64, Error> {
+                actor_index_scan_keys(caller, object_handle, collection_index, limit)
+                    .map_err(|e| Error::host(e))
+            },
+        );
+
+        let host_actor_index_drain = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             object_handle: u32,
+             collection_index: u32,
+             limit: u32|
+             -> Result<u64, Error> {
+                actor_index_drain(caller, object_handle, collection_index, limit)
+                    .map_err(|e| Error::host(e))
+            },
+        );
+
+        let host_actor_sorted_index_insert = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             object_handle: u32,
+             collection_index: u32,
+             sort_prefix: u32,
+             key_ptr: u32,
+             key_len: u32,
+             value_ptr: u32,
+             value_len: u32|
+             -> Result<(), Error> {
+                actor_sorted_index_insert(
+                    caller,
+                    object_handle,
+                    collection_index,
+                    sort_prefix,
+                    key_ptr,
+                    key_len,
+                    value_ptr,
+                    value_len,
+                )
+                .map_err(|e| Error::host(e))
+            },
+        );
+
+        let host_actor_sorted_index_remove = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             object_handle: u32,
+             collection_index: u32,
+             sort_prefix: u32,
+             key_ptr: u32,
+             key_len: u32|
+             -> Result<u64, Error> {
+                actor_sorted_index_remove(
+                    caller,
+                    object_handle,
+                    collection_index,
+                    sort_prefix,
+                    key_ptr,
+                    key_len,
+                )
+                .map_err(|e| Error::host(e))
+            },
+        );
+
+        let host_actor_sorted_index_scan = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             object_handle: u32,
+             collection_index: u32,
+             limit: u32|
+             -> Result<u64, Error> {
+                actor_sorted_index_scan(caller, object_handle, collection_index, limit)
+                    .map_err(|e| Error::host(e))
+            },
+        );
+
+        let host_actor_sorted_index_scan_from = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             object_handle: u32,
+             collection_index: u32,
+             from_sort_prefix: u32,
+             limit: u32|
+             -> Result<u64, Error> {
+                actor_sorted_index_scan_from(
+                    caller,
+                    object_handle,
+                    collection_index,
+                    from_sort_prefix,
+                    limit,
+                )
+                .map_err(|e| Error::host(e))
+            },
+        );
+
         let host_actor_get_node_id = Func::wrap(
             store.as_context_mut(),
             |caller: Caller<'_, HostState>, handle: u32| -> Result<u64, Error> {
@@ -1758,6 +1022,46 @@ impl WasmiModule {
             FIELD_ENTRY_CLOSE_FUNCTION_NAME,
             host_field_lock_release
         );
+        linker_define!(
+            linker,
+            ACTOR_INDEX_INSERT_FUNCTION_NAME,
+            host_actor_index_insert
+        );
+        linker_define!(
+            linker,
+            ACTOR_INDEX_REMOVE_FUNCTION_NAME,
+            host_actor_index_remove
+        );
+        linker_define!(
+            linker,
+            ACTOR_INDEX_SCAN_KEYS_FUNCTION_NAME,
+            host_actor_index_scan_keys
+        );
+        linker_define!(
+            linker,
+            ACTOR_INDEX_DRAIN_FUNCTION_NAME,
+            host_actor_index_drain
+        );
+        linker_define!(
+            linker,
+            ACTOR_SORTED_INDEX_INSERT_FUNCTION_NAME,
+            host_actor_sorted_index_insert
+        );
+        linker_define!(
+            linker,
+            ACTOR_SORTED_INDEX_REMOVE_FUNCTION_NAME,
+            host_actor_sorted_index_remove
+        );
+        linker_define!(
+            linker,
+            ACTOR_SORTED_INDEX_SCAN_FUNCTION_NAME,
+            host_actor_sorted_index_scan
+        );
+        linker_define!(
+            linker,
+            ACTOR_SORTED_INDEX_SCAN_FROM_FUNCTION_NAME,
+            host_actor_sorted_index_scan_from
+        );
         linker_define!(
             linker,
             ACTOR_GET_OBJECT_ID_FUNCTION_NAME,
@@ -1907,52 +1211,6 @@ impl WasmiModule {
     }
 }
 
-fn read_memory(
-    store: impl AsContextMut,
-    memory: Memory,
-    ptr: u32,
-    len: u32,
-) -> Result<Vec<u8>, InvokeError<WasmRuntimeError>> {
-    let store_ctx = store.as_context();
-    let data = memory.data(&store_ctx);
-    let ptr = ptr as usize;
-    let len = len as usize;
-
-    if ptr > data.len() || ptr + len > data.len() {
-        return Err(InvokeError::SelfError(WasmRuntimeError::MemoryAccessError));
-    }
-    Ok(data[ptr..ptr + len].to_vec())
-}
-
-fn write_memory(
-    mut store: impl AsContextMut,
-    memory: Memory,
-    ptr: u32,
-    data: &[u8],
-) -> Result<(), InvokeError<WasmRuntimeError>> {
-    let mut store_ctx = store.as_context_mut();
-    let mem_data = memory.data(&mut store_ctx);
-
-    if ptr as usize > mem_data.len() || ptr as usize + data.len() > mem_data.len() {
-        return Err(InvokeError::SelfError(WasmRuntimeError::MemoryAccessError));
-    }
-
-    memory
-        .write(&mut store.as_context_mut(), ptr as usize, data)
-        .or_else(|_| Err(InvokeError::SelfError(WasmRuntimeError::MemoryAccessError)))
-}
-
-fn read_slice(
-    store: impl AsContextMut,
-    memory: Memory,
-    v: Slice,
-) -> Result<Vec<u8>, InvokeError<WasmRuntimeError>> {
-    let ptr = v.ptr();
-    let len = v.len();
-
-    read_memory(store, memory, ptr, len)
-}
-
 impl WasmiInstance {
     fn get_export_func(&mut self, name: &str) -> Result<Func, InvokeError<WasmRuntimeError>> {
         self.instance
@@ -2012,8 +1270,7 @@ impl WasmInstance for WasmiInstance {
         let result = match call_result {
             Ok(_) => match ret[0] {
                 Val::I64(ret) => read_slice(
-                    self.store.as_context_mut(),
-                    self.memory,
+                    self.memory.data(self.store.as_context()),
                     Slice::transmute_i64(ret),
                 ),
                 _ => Err(InvokeError::SelfError(WasmRuntimeError::InvalidWasmPointer)),
@@ -2034,8 +1291,7 @@ impl WasmInstance for WasmiInstance {
                     .unwrap();
                 let coverage_data = match ret[0] {
                     Val::I64(ret) => read_slice(
-                        self.store.as_context_mut(),
-                        self.memory,
+                        self.memory.data(self.store.as_context()),
                         Slice::transmute_i64(ret),
                     ),
                     _ => Err(InvokeError::SelfError(WasmRuntimeError::InvalidWasmPointer)),
@@ -0,0 +1,785 @@
+use crate::errors::InvokeError;
+use crate::internal_prelude::*;
+#[cfg(feature = "coverage")]
+use crate::utils::save_coverage_data;
+use crate::vm::wasm::constants::*;
+use crate::vm::wasm::errors::*;
+use crate::vm::wasm::host_functions::*;
+// The host functions named after the hashing functions of the prelude
+use crate::vm::wasm::host_functions::{blake2b_256_hash, keccak256_hash};
+use crate::vm::wasm::traits::*;
+use crate::vm::wasm::WasmEngine;
+use radix_engine_interface::blueprints::package::CodeHash;
+use sbor::rust::mem::MaybeUninit;
+#[cfg(not(feature = "fuzzing"))]
+use sbor::rust::sync::Arc;
+use wasmtime::{
+    AsContextMut, Caller, Config, Engine, Extern, Func, Instance, InstancePre, Linker, Memory,
+    Module, Store, Trap, Val,
+};
+
+type HostState = WasmtimeInstanceEnv;
+
+/// A `WasmtimeModule` defines a WASM module compiled ahead of time to native code, with its
+/// imports already resolved against the Scrypto host functions
+pub struct WasmtimeModule {
+    instance_pre: InstancePre<HostState>,
+    #[allow(dead_code)]
+    code_size_bytes: usize,
+}
+
+/// A `WasmtimeInstance` defines
+/// - an instantiated WASM module
+/// - a Store, which keeps user data
+/// - a Memory - linear memory reference to the Store
+pub struct WasmtimeInstance {
+    store: Store<HostState>,
+    instance: Instance,
+    memory: Memory,
+}
+
+/// This is to construct a `Store<WasmtimeInstanceEnv>`
+pub struct WasmtimeInstanceEnv {
+    runtime_ptr: MaybeUninit<*mut Box<dyn WasmRuntime>>,
+}
+
+impl WasmtimeInstanceEnv {
+    pub fn new() -> Self {
+        Self {
+            runtime_ptr: MaybeUninit::uninit(),
+        }
+    }
+}
+
+macro_rules! grab_memory {
+    ($caller: expr) => {{
+        match $caller.get_export(EXPORT_MEMORY) {
+            Some(Extern::Memory(memory)) => memory,
+            _ => panic!("Failed to find memory export"),
+        }
+    }};
+}
+
+impl HostCaller for Caller<'_, HostState> {
+    fn runtime_ptr(&self) -> *mut Box<dyn WasmRuntime> {
+        unsafe { self.data().runtime_ptr.assume_init() }
+    }
+
+    fn memory(&mut self) -> &mut [u8] {
+        let memory = grab_memory!(self);
+        memory.data_mut(self.as_context_mut())
+    }
+}
+
+macro_rules! linker_define {
+    ($linker: expr, $name: expr, $host_function: ident($($arg: ident: $arg_type: ty),*)) => {
+        $linker
+            .func_wrap(
+                MODULE_ENV_NAME,
+                $name,
+                |caller: Caller<'_, HostState>, $($arg: $arg_type),*| {
+                    $host_function(caller, $($arg),*).map_err(wasmtime::Error::new)
+                },
+            )
+            .expect(stringify!("Failed to define new linker item {}", $name));
+    };
+}
+
+/// Defines the Scrypto host functions, which are shared by all the modules of an engine.
+fn host_funcs_set(engine: &Engine) -> Linker<HostState> {
+    let mut linker = Linker::new(engine);
+
+    linker_define!(
+        linker,
+        BUFFER_CONSUME_FUNCTION_NAME,
+        consume_buffer(buffer_id: BufferId, destination_ptr: u32)
+    );
+    linker_define!(
+        linker,
+        OBJECT_CALL_FUNCTION_NAME,
+        call_method(
+            receiver_ptr: u32,
+            receiver_len: u32,
+            ident_ptr: u32,
+            ident_len: u32,
+            args_ptr: u32,
+            args_len: u32
+        )
+    );
+    linker_define!(
+        linker,
+        OBJECT_CALL_MODULE_FUNCTION_NAME,
+        call_module_method(
+            receiver_ptr: u32,
+            receiver_len: u32,
+            module_id: u32,
+            ident_ptr: u32,
+            ident_len: u32,
+            args_ptr: u32,
+            args_len: u32
+        )
+    );
+    linker_define!(
+        linker,
+        OBJECT_CALL_DIRECT_FUNCTION_NAME,
+        call_direct_method(
+            receiver_ptr: u32,
+            receiver_len: u32,
+            ident_ptr: u32,
+            ident_len: u32,
+            args_ptr: u32,
+            args_len: u32
+        )
+    );
+    linker_define!(
+        linker,
+        BLUEPRINT_CALL_FUNCTION_NAME,
+        call_function(
+            package_address_ptr: u32,
+            package_address_len: u32,
+            blueprint_name_ptr: u32,
+            blueprint_name_len: u32,
+            ident_ptr: u32,
+            ident_len: u32,
+            args_ptr: u32,
+            args_len: u32
+        )
+    );
+    linker_define!(
+        linker,
+        OBJECT_NEW_FUNCTION_NAME,
+        new_object(
+            blueprint_name_ptr: u32,
+            blueprint_name_len: u32,
+            object_states_ptr: u32,
+            object_states_len: u32
+        )
+    );
+    linker_define!(
+        linker,
+        ADDRESS_ALLOCATE_FUNCTION_NAME,
+        allocate_global_address(
+            package_address_ptr: u32,
+            package_address_len: u32,
+            blueprint_name_ptr: u32,
+            blueprint_name_len: u32
+        )
+    );
+    linker_define!(
+        linker,
+        ADDRESS_GET_RESERVATION_ADDRESS_FUNCTION_NAME,
+        get_reservation_address(node_id_ptr: u32, node_id_len: u32)
+    );
+    linker_define!(
+        linker,
+        COSTING_GET_EXECUTION_COST_UNIT_LIMIT_FUNCTION_NAME,
+        execution_cost_unit_limit()
+    );
+    linker_define!(
+        linker,
+        COSTING_GET_EXECUTION_COST_UNIT_PRICE_FUNCTION_NAME,
+        execution_cost_unit_price()
+    );
+    linker_define!(
+        linker,
+        COSTING_GET_FINALIZATION_COST_UNIT_LIMIT_FUNCTION_NAME,
+        finalization_cost_unit_limit()
+    );
+    linker_define!(
+        linker,
+        COSTING_GET_FINALIZATION_COST_UNIT_PRICE_FUNCTION_NAME,
+        finalization_cost_unit_price()
+    );
+    linker_define!(linker, COSTING_GET_USD_PRICE_FUNCTION_NAME, usd_price());
+    linker_define!(
+        linker,
+        COSTING_GET_TIP_PERCENTAGE_FUNCTION_NAME,
+        tip_percentage()
+    );
+    linker_define!(linker, COSTING_GET_FEE_BALANCE_FUNCTION_NAME, fee_balance());
+    linker_define!(
+        linker,
+        OBJECT_GLOBALIZE_FUNCTION_NAME,
+        globalize_object(
+            obj_id_ptr: u32,
+            obj_id_len: u32,
+            modules_ptr: u32,
+            modules_len: u32,
+            address_ptr: u32,
+            address_len: u32
+        )
+    );
+    linker_define!(
+        linker,
+        OBJECT_INSTANCE_OF_FUNCTION_NAME,
+        instance_of(
+            component_id_ptr: u32,
+            component_id_len: u32,
+            package_address_ptr: u32,
+            package_address_len: u32,
+            blueprint_name_ptr: u32,
+            blueprint_name_len: u32
+        )
+    );
+    linker_define!(
+        linker,
+        OBJECT_GET_BLUEPRINT_ID_FUNCTION_NAME,
+        blueprint_id(component_id_ptr: u32, component_id_len: u32)
+    );
+    linker_define!(
+        linker,
+        OBJECT_GET_OUTER_OBJECT_FUNCTION_NAME,
+        get_outer_object(component_id_ptr: u32, component_id_len: u32)
+    );
+    linker_define!(
+        linker,
+        ACTOR_OPEN_FIELD_FUNCTION_NAME,
+        lock_field(object_handle: u32, field: u32, flags: u32)
+    );
+    linker_define!(
+        linker,
+        KEY_VALUE_STORE_NEW_FUNCTION_NAME,
+        new_key_value_store(schema_id_ptr: u32, schema_id_len: u32)
+    );
+    linker_define!(
+        linker,
+        KEY_VALUE_STORE_OPEN_ENTRY_FUNCTION_NAME,
+        lock_key_value_store_entry(
+            node_id_ptr: u32,
+            node_id_len: u32,
+            offset_ptr: u32,
+            offset_len: u32,
+            flags: u32
+        )
+    );
+    linker_define!(
+        linker,
+        KEY_VALUE_ENTRY_READ_FUNCTION_NAME,
+        key_value_entry_get(handle: u32)
+    );
+    linker_define!(
+        linker,
+        KEY_VALUE_ENTRY_WRITE_FUNCTION_NAME,
+        key_value_entry_set(handle: u32, buffer_ptr: u32, buffer_len: u32)
+    );
+    linker_define!(
+        linker,
+        KEY_VALUE_ENTRY_REMOVE_FUNCTION_NAME,
+        key_value_entry_remove(handle: u32)
+    );
+    linker_define!(
+        linker,
+        KEY_VALUE_ENTRY_CLOSE_FUNCTION_NAME,
+        unlock_key_value_entry(handle: u32)
+    );
+    linker_define!(
+        linker,
+        KEY_VALUE_STORE_REMOVE_ENTRY_FUNCTION_NAME,
+        key_value_store_remove(node_id_ptr: u32, node_id_len: u32, key_ptr: u32, key_len: u32)
+    );
+    linker_define!(linker, FIELD_ENTRY_READ_FUNCTION_NAME, field_lock_read(handle: u32));
+    linker_define!(
+        linker,
+        FIELD_ENTRY_WRITE_FUNCTION_NAME,
+        field_lock_write(handle: u32, data_ptr: u32, data_len: u32)
+    );
+    linker_define!(linker, FIELD_ENTRY_CLOSE_FUNCTION_NAME, field_lock_release(handle: u32));
+    linker_define!(
+        linker,
+        ACTOR_INDEX_INSERT_FUNCTION_NAME,
+        actor_index_insert(
+            object_handle: u32,
+            collection_index: u32,
+            key_ptr: u32,
+            key_len: u32,
+            value_ptr: u32,
+            value_len: u32
+        )
+    );
+    linker_define!(
+        linker,
+        ACTOR_INDEX_REMOVE_FUNCTION_NAME,
+        actor_index_remove(
+            object_handle: u32,
+            collection_index: u32,
+            key_ptr: u32,
+            key_len: u32
+        )
+    );
+    linker_define!(
+        linker,
+        ACTOR_INDEX_SCAN_KEYS_FUNCTION_NAME,
+        actor_index_scan_keys(object_handle: u32, collection_index: u32, limit: u32)
+    );
+    linker_define!(
+        linker,
+        ACTOR_INDEX_DRAIN_FUNCTION_NAME,
+        actor_index_drain(object_handle: u32, collection_index: u32, limit: u32)
+    );
+    linker_define!(
+        linker,
+        ACTOR_SORTED_INDEX_INSERT_FUNCTION_NAME,
+        actor_sorted_index_insert(
+            object_handle: u32,
+            collection_index: u32,
+            sort_prefix: u32,
+            key_ptr: u32,
+            key_len: u32,
+            value_ptr: u32,
+            value_len: u32
+        )
+    );
+    linker_define!(
+        linker,
+        ACTOR_SORTED_INDEX_REMOVE_FUNCTION_NAME,
+        actor_sorted_index_remove(
+            object_handle: u32,
+            collection_index: u32,
+            sort_prefix: u32,
+            key_ptr: u32,
+            key_len: u32
+        )
+    );
+    linker_define!(
+        linker,
+        ACTOR_SORTED_INDEX_SCAN_FUNCTION_NAME,
+        actor_sorted_index_scan(object_handle: u32, collection_index: u32, limit: u32)
+    );
+    linker_define!(
+        linker,
+        ACTOR_SORTED_INDEX_SCAN_FROM_FUNCTION_NAME,
+        actor_sorted_index_scan_from(
+            object_handle: u32,
+            collection_index: u32,
+            from_sort_prefix: u32,
+            limit: u32
+        )
+    );
+    linker_define!(linker, ACTOR_GET_OBJECT_ID_FUNCTION_NAME, actor_get_node_id(handle: u32));
+    linker_define!(
+        linker,
+        ACTOR_GET_PACKAGE_ADDRESS_FUNCTION_NAME,
+        get_package_address()
+    );
+    linker_define!(
+        linker,
+        ACTOR_GET_BLUEPRINT_NAME_FUNCTION_NAME,
+        get_blueprint_name()
+    );
+    linker_define!(
+        linker,
+        COSTING_CONSUME_WASM_EXECUTION_UNITS_FUNCTION_NAME,
+        consume_wasm_execution_units(n: u64)
+    );
+    linker_define!(
+        linker,
+        ACTOR_EMIT_EVENT_FUNCTION_NAME,
+        emit_event(
+            event_name_ptr: u32,
+            event_name_len: u32,
+            event_data_ptr: u32,
+            event_data_len: u32,
+            flags: u32
+        )
+    );
+    linker_define!(
+        linker,
+        SYS_LOG_FUNCTION_NAME,
+        emit_log(level_ptr: u32, level_len: u32, message_ptr: u32, message_len: u32)
+    );
+    linker_define!(linker, SYS_PANIC_FUNCTION_NAME, panic(message_ptr: u32, message_len: u32));
+    linker_define!(
+        linker,
+        SYS_GET_TRANSACTION_HASH_FUNCTION_NAME,
+        get_transaction_hash()
+    );
+    linker_define!(
+        linker,
+        SYS_BECH32_ENCODE_ADDRESS_FUNCTION_NAME,
+        bech32_encode_address(address_ptr: u32, address_len: u32)
+    );
+    linker_define!(linker, SYS_GENERATE_RUID_FUNCTION_NAME, generate_ruid());
+    linker_define!(
+        linker,
+        CRYPTO_UTILS_BLS12381_V1_VERIFY_FUNCTION_NAME,
+        bls12381_v1_verify(
+            message_ptr: u32,
+            message_len: u32,
+            public_key_ptr: u32,
+            public_key_len: u32,
+            signature_ptr: u32,
+            signature_len: u32
+        )
+    );
+    linker_define!(
+        linker,
+        CRYPTO_UTILS_BLS12381_V1_AGGREGATE_VERIFY_FUNCTION_NAME,
+        bls12381_v1_aggregate_verify(
+            pub_keys_and_msgs_ptr: u32,
+            pub_keys_and_msgs_len: u32,
+            signature_ptr: u32,
+            signature_len: u32
+        )
+    );
+    linker_define!(
+        linker,
+        CRYPTO_UTILS_BLS12381_V1_FAST_AGGREGATE_VERIFY_FUNCTION_NAME,
+        bls12381_v1_fast_aggregate_verify(
+            message_ptr: u32,
+            message_len: u32,
+            public_keys_ptr: u32,
+            public_keys_len: u32,
+            signature_ptr: u32,
+            signature_len: u32
+        )
+    );
+    linker_define!(
+        linker,
+        CRYPTO_UTILS_BLS12381_G2_SIGNATURE_AGGREGATE_FUNCTION_NAME,
+        bls12381_g2_signature_aggregate(signatures_ptr: u32, signatures_len: u32)
+    );
+    linker_define!(
+        linker,
+        CRYPTO_UTILS_KECCAK256_HASH_FUNCTION_NAME,
+        keccak256_hash(data_ptr: u32, data_len: u32)
+    );
+    linker_define!(
+        linker,
+        CRYPTO_UTILS_BLAKE2B_256_HASH_FUNCTION_NAME,
+        blake2b_256_hash(data_ptr: u32, data_len: u32)
+    );
+    linker_define!(
+        linker,
+        CRYPTO_UTILS_ED25519_VERIFY_FUNCTION_NAME,
+        ed25519_verify(
+            message_ptr: u32,
+            message_len: u32,
+            public_key_ptr: u32,
+            public_key_len: u32,
+            signature_ptr: u32,
+            signature_len: u32
+        )
+    );
+    linker_define!(
+        linker,
+        CRYPTO_UTILS_SECP256K1_ECDSA_VERIFY_FUNCTION_NAME,
+        secp256k1_ecdsa_verify(
+            message_ptr: u32,
+            message_len: u32,
+            public_key_ptr: u32,
+            public_key_len: u32,
+            signature_ptr: u32,
+            signature_len: u32
+        )
+    );
+    linker_define!(
+        linker,
+        CRYPTO_UTILS_SECP256K1_ECDSA_VERIFY_AND_KEY_RECOVER_FUNCTION_NAME,
+        secp256k1_ecdsa_verify_and_key_recover(
+            message_ptr: u32,
+            message_len: u32,
+            signature_ptr: u32,
+            signature_len: u32
+        )
+    );
+    linker_define!(
+        linker,
+        CRYPTO_UTILS_SECP256K1_ECDSA_VERIFY_AND_KEY_RECOVER_UNCOMPRESSED_FUNCTION_NAME,
+        secp256k1_ecdsa_verify_and_key_recover_uncompressed(
+            message_ptr: u32,
+            message_len: u32,
+            signature_ptr: u32,
+            signature_len: u32
+        )
+    );
+    #[cfg(feature = "radix_engine_tests")]
+    {
+        linker_define!(
+            linker,
+            "test_host_read_memory",
+            test_host_read_memory(memory_offs: u32, data_len: u32)
+        );
+        linker_define!(
+            linker,
+            "test_host_write_memory",
+            test_host_write_memory(memory_offs: u32, data_len: u32)
+        );
+        linker_define!(
+            linker,
+            "test_host_check_memory_is_clean",
+            test_host_check_memory_is_clean()
+        );
+    }
+
+    linker
+}
+
+#[derive(Debug)]
+pub enum WasmtimeInstantiationError {
+    CompilationError(wasmtime::Error),
+    PreInstantiationError(wasmtime::Error),
+    InstantiationError(wasmtime::Error),
+}
+
+impl WasmtimeModule {
+    pub fn new(
+        engine: &Engine,
+        linker: &Linker<HostState>,
+        code: &[u8],
+    ) -> Result<Self, WasmtimeInstantiationError> {
+        // The code is compiled to native code up front, rather than interpreted.
+        //
+        // It is assumed that WASM code passed here is already WASM validated and instrumented,
+        // so the costing of the execution is identical to the `WasmiEngine`: the cost units are
+        // consumed by the injected calls to the host, and the stack height is bounded by the
+        // injected limiter, neither of which depends on how the code is executed.
+        let module =
+            Module::new(engine, code).map_err(WasmtimeInstantiationError::CompilationError)?;
+        let instance_pre = linker
+            .instantiate_pre(&module)
+            .map_err(WasmtimeInstantiationError::PreInstantiationError)?;
+
+        Ok(Self {
+            instance_pre,
+            code_size_bytes: code.len(),
+        })
+    }
+
+    pub fn instantiate(&self) -> Result<WasmtimeInstance, WasmtimeInstantiationError> {
+        let mut store = Store::new(
+            self.instance_pre.module().engine(),
+            WasmtimeInstanceEnv::new(),
+        );
+
+        let instance = self
+            .instance_pre
+            .instantiate(&mut store)
+            .map_err(WasmtimeInstantiationError::InstantiationError)?;
+
+        let memory = match instance.get_export(&mut store, EXPORT_MEMORY) {
+            Some(Extern::Memory(memory)) => memory,
+            _ => panic!("Failed to find memory export"),
+        };
+
+        Ok(WasmtimeInstance {
+            store,
+            instance,
+            memory,
+        })
+    }
+
+    fn instantiate_unchecked(&self) -> WasmtimeInstance {
+        self.instantiate().expect("Failed to instantiate")
+    }
+}
+
+/// Recovers the error returned by a host function, which traps the execution - the traps of the
+/// WASM code itself are described as wasmi describes them, so that both engines fail a transaction
+/// with the same error.
+fn into_invoke_error(err: wasmtime::Error) -> InvokeError<WasmRuntimeError> {
+    match err.downcast::<InvokeError<WasmRuntimeError>>() {
+        Ok(invoke_err) => invoke_err,
+        Err(err) => {
+            let e_str = match err
+                .downcast_ref::<Trap>()
+                .copied()
+                .and_then(wasmi_trap_code)
+            {
+                Some(trap_code) => format!("{:?}", wasmi::Error::from(trap_code)),
+                None => format!("{:?}", err),
+            };
+            InvokeError::SelfError(WasmRuntimeError::ExecutionError(e_str))
+        }
+    }
+}
+
+fn wasmi_trap_code(trap: Trap) -> Option<wasmi::core::TrapCode> {
+    use wasmi::core::TrapCode;
+
+    match trap {
+        Trap::StackOverflow => Some(TrapCode::StackOverflow),
+        Trap::MemoryOutOfBounds => Some(TrapCode::MemoryOutOfBounds),
+        Trap::TableOutOfBounds => Some(TrapCode::TableOutOfBounds),
+        Trap::IndirectCallToNull => Some(TrapCode::IndirectCallToNull),
+        Trap::BadSignature => Some(TrapCode::BadSignature),
+        Trap::IntegerOverflow => Some(TrapCode::IntegerOverflow),
+        Trap::IntegerDivisionByZero => Some(TrapCode::IntegerDivisionByZero),
+        Trap::BadConversionToInteger => Some(TrapCode::BadConversionToInteger),
+        Trap::UnreachableCodeReached => Some(TrapCode::UnreachableCodeReached),
+        Trap::OutOfFuel => Some(TrapCode::OutOfFuel),
+        _ => None,
+    }
+}
+
+impl WasmtimeInstance {
+    fn get_export_func(&mut self, name: &str) -> Result<Func, InvokeError<WasmRuntimeError>> {
+        self.instance
+            .get_func(&mut self.store, name)
+            .ok_or_else(|| {
+                InvokeError::SelfError(WasmRuntimeError::UnknownExport(name.to_string()))
+            })
+    }
+}
+
+impl WasmInstance for WasmtimeInstance {
+    fn invoke_export<'r>(
+        &mut self,
+        func_name: &str,
+        args: Vec<Buffer>,
+        runtime: &mut Box<dyn WasmRuntime + 'r>,
+    ) -> Result<Vec<u8>, InvokeError<WasmRuntimeError>> {
+        {
+            // set up runtime pointer
+            // Using triple casting is to workaround this error message:
+            // error[E0521]: borrowed data escapes outside of associated function
+            //  `runtime` escapes the associated function body here argument requires that `'r` must outlive `'static`
+            self.store
+                .data_mut()
+                .runtime_ptr
+                .write(runtime as *mut _ as usize as *mut _);
+        }
+
+        let func = self.get_export_func(func_name).unwrap();
+        let input: Vec<Val> = args
+            .into_iter()
+            .map(|buffer| Val::I64(buffer.as_i64()))
+            .collect();
+        let mut ret = [Val::I64(0)];
+
+        let call_result = func
+            .call(&mut self.store, &input, &mut ret)
+            .map_err(into_invoke_error);
+
+        let result = match call_result {
+            Ok(_) => match ret[0] {
+                Val::I64(ret) => {
+                    read_slice(self.memory.data(&self.store), Slice::transmute_i64(ret))
+                }
+                _ => Err(InvokeError::SelfError(WasmRuntimeError::InvalidWasmPointer)),
+            },
+            Err(err) => Err(err),
+        };
+
+        #[cfg(feature = "coverage")]
+        if let Ok(dump_coverage) = self.get_export_func("dump_coverage") {
+            if let Ok(blueprint_buffer) = runtime.actor_get_blueprint_name() {
+                let blueprint_name =
+                    String::from_utf8(runtime.buffer_consume(blueprint_buffer.id()).unwrap())
+                        .unwrap();
+
+                let mut ret = [Val::I64(0)];
+                dump_coverage.call(&mut self.store, &[], &mut ret).unwrap();
+                let coverage_data = match ret[0] {
+                    Val::I64(ret) => {
+                        read_slice(self.memory.data(&self.store), Slice::transmute_i64(ret))
+                    }
+                    _ => Err(InvokeError::SelfError(WasmRuntimeError::InvalidWasmPointer)),
+                }
+                .unwrap();
+                save_coverage_data(&blueprint_name, &coverage_data);
+            }
+        }
+
+        result
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WasmtimeEngineOptions {
+    max_cache_size: usize,
+}
+
+/// A Scrypto WASM engine which compiles the modules to native code ahead of their execution,
+/// with [wasmtime](https://wasmtime.dev/).
+///
+/// It consumes exactly the same cost units as the [`WasmiEngine`](super::WasmiEngine), but runs
+/// the WASM code much faster, at the expense of a slower first instantiation of each module.
+pub struct WasmtimeEngine {
+    engine: Engine,
+    linker: Linker<HostState>,
+    // This flag disables cache in wasm_instrumenter/wasmtime to prevent non-determinism when fuzzing
+    #[cfg(all(not(feature = "fuzzing"), not(feature = "moka")))]
+    modules_cache: RefCell<lru::LruCache<CodeHash, Arc<WasmtimeModule>>>,
+    #[cfg(all(not(feature = "fuzzing"), feature = "moka"))]
+    modules_cache: moka::sync::Cache<CodeHash, Arc<WasmtimeModule>>,
+    #[cfg(feature = "fuzzing")]
+    #[allow(dead_code)]
+    modules_cache: usize,
+}
+
+impl Default for WasmtimeEngine {
+    fn default() -> Self {
+        Self::new(WasmtimeEngineOptions {
+            max_cache_size: WASM_ENGINE_CACHE_SIZE,
+        })
+    }
+}
+
+impl WasmtimeEngine {
+    pub fn new(options: WasmtimeEngineOptions) -> Self {
+        let mut config = Config::new();
+        // Floating point instructions are rejected by the validator, but the NaNs are canonicalized
+        // anyway, so that the execution is deterministic across the platforms.
+        config.cranelift_nan_canonicalization(true);
+        let engine = Engine::new(&config).expect("Failed to create wasmtime engine");
+        let linker = host_funcs_set(&engine);
+
+        #[cfg(all(not(feature = "fuzzing"), not(feature = "moka")))]
+        let modules_cache = RefCell::new(lru::LruCache::new(
+            sbor::rust::num::NonZeroUsize::new(options.max_cache_size).unwrap(),
+        ));
+        #[cfg(all(not(feature = "fuzzing"), feature = "moka"))]
+        let modules_cache = moka::sync::Cache::builder()
+            .weigher(|_key: &CodeHash, _value: &Arc<WasmtimeModule>| -> u32 {
+                // No sophisticated weighing mechanism, just keep a fixed size cache
+                1u32
+            })
+            .max_capacity(options.max_cache_size as u64)
+            .build();
+        #[cfg(feature = "fuzzing")]
+        let modules_cache = options.max_cache_size;
+
+        Self {
+            engine,
+            linker,
+            modules_cache,
+        }
+    }
+}
+
+impl WasmEngine for WasmtimeEngine {
+    type WasmInstance = WasmtimeInstance;
+
+    #[allow(unused_variables)]
+    fn instantiate(&self, code_hash: CodeHash, instrumented_code: &[u8]) -> WasmtimeInstance {
+        #[cfg(not(feature = "fuzzing"))]
+        {
+            #[cfg(not(feature = "moka"))]
+            {
+                if let Some(cached_module) = self.modules_cache.borrow_mut().get(&code_hash) {
+                    return cached_module.instantiate_unchecked();
+                }
+            }
+            #[cfg(feature = "moka")]
+            if let Some(cached_module) = self.modules_cache.get(&code_hash) {
+                return cached_module.as_ref().instantiate_unchecked();
+            }
+        }
+
+        let module = WasmtimeModule::new(&self.engine, &self.linker, instrumented_code)
+            .expect("Failed to compile module");
+        let instance = module.instantiate_unchecked();
+
+        #[cfg(not(feature = "fuzzing"))]
+        {
+            #[cfg(not(feature = "moka"))]
+            self.modules_cache
+                .borrow_mut()
+                .put(code_hash, Arc::new(module));
+            #[cfg(feature = "moka")]
+            self.modules_cache.insert(code_hash, Arc::new(module));
+        }
+
+        instance
+    }
+}
@@ -99,7 +99,7 @@ impl<'y, Y: SystemApi<RuntimeError>> WasmRuntime for ScryptoRuntime<'y, Y> {
             // Practically speaking, there is little gain of keeping multiple buffers open before
             // [multi-value](https://github.com/WebAssembly/multi-value/blob/master/proposals/multi-value/Overview.md) is supported and used.
             // We reduce it to `4` so that the amount of memory that a transaction can consume is reduced, which is beneficial for parallel execution.
-            ScryptoVmVersion::V1_2 => 4,
+            ScryptoVmVersion::V1_2 | ScryptoVmVersion::V1_3 => 4,
         };
         if self.buffers.len() >= max_number_of_buffers {
             return Err(InvokeError::SelfError(WasmRuntimeError::TooManyBuffers));
@@ -388,6 +388,122 @@ impl<'y, Y: SystemApi<RuntimeError>> WasmRuntime for ScryptoRuntime<'y, Y> {
         Ok(())
     }
 
+    fn actor_index_insert(
+        &mut self,
+        object_handle: u32,
+        collection_index: u8,
+        key: Vec<u8>,
+        value: Vec<u8>,
+    ) -> Result<(), InvokeError<WasmRuntimeError>> {
+        self.api
+            .actor_index_insert(object_handle, collection_index, key, value)?;
+
+        Ok(())
+    }
+
+    fn actor_index_remove(
+        &mut self,
+        object_handle: u32,
+        collection_index: u8,
+        key: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let rtn = self
+            .api
+            .actor_index_remove(object_handle, collection_index, key)?;
+
+        self.allocate_buffer(scrypto_encode(&rtn).expect("Failed to encode removed value"))
+    }
+
+    fn actor_index_scan_keys(
+        &mut self,
+        object_handle: u32,
+        collection_index: u8,
+        limit: u32,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let keys = self
+            .api
+            .actor_index_scan_keys(object_handle, collection_index, limit)?;
+
+        self.allocate_buffer(scrypto_encode(&keys).expect("Failed to encode scanned keys"))
+    }
+
+    fn actor_index_drain(
+        &mut self,
+        object_handle: u32,
+        collection_index: u8,
+        limit: u32,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let entries = self
+            .api
+            .actor_index_drain(object_handle, collection_index, limit)?;
+
+        self.allocate_buffer(scrypto_encode(&entries).expect("Failed to encode drained entries"))
+    }
+
+    fn actor_sorted_index_insert(
+        &mut self,
+        object_handle: u32,
+        collection_index: u8,
+        sort_prefix: u16,
+        key: Vec<u8>,
+        value: Vec<u8>,
+    ) -> Result<(), InvokeError<WasmRuntimeError>> {
+        self.api.actor_sorted_index_insert(
+            object_handle,
+            collection_index,
+            (sort_prefix.to_be_bytes(), key),
+            value,
+        )?;
+
+        Ok(())
+    }
+
+    fn actor_sorted_index_remove(
+        &mut self,
+        object_handle: u32,
+        collection_index: u8,
+        sort_prefix: u16,
+        key: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let rtn = self.api.actor_sorted_index_remove(
+            object_handle,
+            collection_index,
+            &(sort_prefix.to_be_bytes(), key),
+        )?;
+
+        self.allocate_buffer(scrypto_encode(&rtn).expect("Failed to encode removed value"))
+    }
+
+    fn actor_sorted_index_scan(
+        &mut self,
+        object_handle: u32,
+        collection_index: u8,
+        limit: u32,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let entries = self
+            .api
+            .actor_sorted_index_scan(object_handle, collection_index, limit)?;
+
+        self.allocate_buffer(scrypto_encode(&entries).expect("Failed to encode scanned entries"))
+    }
+
+    fn actor_sorted_index_scan_from(
+        &mut self,
+        object_handle: u32,
+        collection_index: u8,
+        from_sort_prefix: u16,
+        limit: u32,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let entries = self.api.actor_sorted_index_scan_from(
+            object_handle,
+            collection_index,
+            from_sort_prefix.to_be_bytes(),
+            limit,
+        )?;
+
+        self.allocate_buffer(scrypto_encode(&entries).expect("Failed to encode scanned entries"))
+    }
+
     fn actor_get_node_id(
         &mut self,
         actor_ref_handle: ActorRefHandle,
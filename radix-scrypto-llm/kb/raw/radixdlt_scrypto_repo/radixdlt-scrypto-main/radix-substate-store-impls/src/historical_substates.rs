@@ -0,0 +1,270 @@
+use radix_common::prelude::*;
+use radix_substate_store_interface::interface::*;
+
+/// The values which the substates changed by each commit had before it, kept in memory - which
+/// allows reading the substates as of any state version since the history was started.
+///
+/// The value of a substate at state version `V` is the previous value recorded by its first change
+/// after `V`, or its current value if it hasn't changed since.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubstateHistory {
+    first_state_version: u64,
+    previous_values:
+        BTreeMap<DbPartitionKey, BTreeMap<DbSortKey, BTreeMap<u64, Option<DbSubstateValue>>>>,
+}
+
+impl SubstateHistory {
+    pub fn new(first_state_version: u64) -> Self {
+        Self {
+            first_state_version,
+            previous_values: BTreeMap::new(),
+        }
+    }
+
+    pub fn first_state_version(&self) -> u64 {
+        self.first_state_version
+    }
+
+    /// Records the current values of the substates changed by the given updates, which are about
+    /// to be committed to the `database` as the given state version.
+    pub fn record<D: SubstateDatabase + ?Sized>(
+        &mut self,
+        database: &D,
+        state_version: u64,
+        database_updates: &DatabaseUpdates,
+    ) {
+        for ((partition_key, sort_key), previous_value) in
+            list_previous_values(database, database_updates)
+        {
+            self.previous_values
+                .entry(partition_key)
+                .or_default()
+                .entry(sort_key)
+                .or_default()
+                .insert(state_version, previous_value);
+        }
+    }
+
+    /// Reads the substate as of the given state version, from the history recorded on top of the
+    /// `database` (which holds the current values).
+    pub fn get_raw_substate_at_version<D: SubstateDatabase + ?Sized>(
+        &self,
+        database: &D,
+        state_version: u64,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Option<DbSubstateValue> {
+        match self
+            .previous_values
+            .get(partition_key)
+            .and_then(|by_sort_key| by_sort_key.get(sort_key))
+            .and_then(|by_state_version| by_state_version.range(state_version + 1..).next())
+        {
+            Some((_, previous_value)) => previous_value.clone(),
+            None => database.get_raw_substate_by_db_key(partition_key, sort_key),
+        }
+    }
+
+    /// Lists the entries of the partition as of the given state version, from the history recorded
+    /// on top of the `database` (which holds the current values).
+    pub fn list_entries_at_version<'a, D: SubstateDatabase + ?Sized>(
+        &'a self,
+        database: &'a D,
+        state_version: u64,
+        partition_key: &DbPartitionKey,
+        from_sort_key: Option<&DbSortKey>,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + 'a> {
+        let from_sort_key = from_sort_key.cloned().unwrap_or(DbSortKey(vec![]));
+        let previous_values = self
+            .previous_values
+            .get(partition_key)
+            .into_iter()
+            .flat_map(|by_sort_key| by_sort_key.range(from_sort_key.clone()..))
+            .filter_map(|(sort_key, by_state_version)| {
+                by_state_version
+                    .range(state_version + 1..)
+                    .next()
+                    .map(|(_, previous_value)| (sort_key.clone(), previous_value.clone()))
+            });
+        apply_previous_values(
+            database.list_raw_values_from_db_key(partition_key, Some(&from_sort_key)),
+            previous_values,
+        )
+    }
+}
+
+/// Lists the current values of every substate which the given updates change - including the
+/// substates removed by a partition reset, and `None` for the ones which don't exist yet.
+pub fn list_previous_values<D: SubstateDatabase + ?Sized>(
+    database: &D,
+    database_updates: &DatabaseUpdates,
+) -> Vec<(DbSubstateKey, Option<DbSubstateValue>)> {
+    let mut previous_values = Vec::new();
+    for (node_key, node_updates) in &database_updates.node_updates {
+        for (partition_num, partition_updates) in &node_updates.partition_updates {
+            let partition_key = DbPartitionKey {
+                node_key: node_key.clone(),
+                partition_num: *partition_num,
+            };
+            match partition_updates {
+                PartitionDatabaseUpdates::Delta { substate_updates } => {
+                    for sort_key in substate_updates.keys() {
+                        let previous_value =
+                            database.get_raw_substate_by_db_key(&partition_key, sort_key);
+                        previous_values
+                            .push(((partition_key.clone(), sort_key.clone()), previous_value));
+                    }
+                }
+                PartitionDatabaseUpdates::Reset {
+                    new_substate_values,
+                } => {
+                    let existing_entries: IndexMap<DbSortKey, DbSubstateValue> = database
+                        .list_raw_values_from_db_key(&partition_key, None)
+                        .collect();
+                    for sort_key in new_substate_values.keys() {
+                        if !existing_entries.contains_key(sort_key) {
+                            previous_values.push(((partition_key.clone(), sort_key.clone()), None));
+                        }
+                    }
+                    for (sort_key, value) in existing_entries {
+                        previous_values.push(((partition_key.clone(), sort_key), Some(value)));
+                    }
+                }
+            }
+        }
+    }
+    previous_values
+}
+
+/// Overrides the current entries of a partition with the given previous values (where `None`
+/// means the substate was missing).
+///
+/// Note: this collects the whole partition, which is fine for the debugging reads of a previous
+/// state, but not for the hot path of a transaction.
+pub fn apply_previous_values<'a>(
+    current_entries: Box<dyn Iterator<Item = PartitionEntry> + 'a>,
+    previous_values: impl Iterator<Item = (DbSortKey, Option<DbSubstateValue>)>,
+) -> Box<dyn Iterator<Item = PartitionEntry> + 'a> {
+    let mut entries: BTreeMap<DbSortKey, DbSubstateValue> = current_entries.collect();
+    for (sort_key, previous_value) in previous_values {
+        match previous_value {
+            Some(value) => entries.insert(sort_key, value),
+            None => entries.remove(&sort_key),
+        };
+    }
+    Box::new(entries.into_iter())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory_db::InMemorySubstateDatabase;
+
+    fn partition_key(partition_num: u8) -> DbPartitionKey {
+        DbPartitionKey {
+            node_key: vec![1, 2, 3],
+            partition_num,
+        }
+    }
+
+    fn delta(partition_num: u8, updates: Vec<(u8, DatabaseUpdate)>) -> DatabaseUpdates {
+        DatabaseUpdates::from_delta_maps(indexmap!(
+            partition_key(partition_num) => updates
+                .into_iter()
+                .map(|(sort_key, update)| (DbSortKey(vec![sort_key]), update))
+                .collect()
+        ))
+    }
+
+    fn reset(partition_num: u8, values: Vec<(u8, u8)>) -> DatabaseUpdates {
+        DatabaseUpdates {
+            node_updates: indexmap!(
+                vec![1, 2, 3] => NodeDatabaseUpdates {
+                    partition_updates: indexmap!(
+                        partition_num => PartitionDatabaseUpdates::Reset {
+                            new_substate_values: values
+                                .into_iter()
+                                .map(|(sort_key, value)| (DbSortKey(vec![sort_key]), vec![value]))
+                                .collect(),
+                        }
+                    ),
+                }
+            ),
+        }
+    }
+
+    #[test]
+    fn substates_at_every_version_match_the_state_committed_then() {
+        // Arrange
+        let commits = vec![
+            delta(
+                0,
+                vec![
+                    (1, DatabaseUpdate::Set(vec![10])),
+                    (2, DatabaseUpdate::Set(vec![20])),
+                ],
+            ),
+            delta(
+                0,
+                vec![
+                    (1, DatabaseUpdate::Set(vec![11])),
+                    (3, DatabaseUpdate::Set(vec![30])),
+                ],
+            ),
+            reset(0, vec![(2, 21), (4, 40)]),
+            delta(
+                0,
+                vec![
+                    (2, DatabaseUpdate::Delete),
+                    (5, DatabaseUpdate::Set(vec![50])),
+                ],
+            ),
+            reset(1, vec![(1, 100)]),
+        ];
+        let mut database = InMemorySubstateDatabase::standard();
+        let mut history = SubstateHistory::new(0);
+        let mut snapshots = vec![database.clone()];
+
+        // Act
+        for (index, updates) in commits.iter().enumerate() {
+            history.record(&database, index as u64 + 1, updates);
+            database.commit(updates);
+            snapshots.push(database.clone());
+        }
+
+        // Assert
+        for (state_version, snapshot) in snapshots.iter().enumerate() {
+            let state_version = state_version as u64;
+            for partition_num in [0, 1] {
+                let partition_key = partition_key(partition_num);
+                for sort_key in 0..6 {
+                    let sort_key = DbSortKey(vec![sort_key]);
+                    assert_eq!(
+                        history.get_raw_substate_at_version(
+                            &database,
+                            state_version,
+                            &partition_key,
+                            &sort_key
+                        ),
+                        snapshot.get_raw_substate_by_db_key(&partition_key, &sort_key),
+                    );
+                }
+                for from_sort_key in [None, Some(DbSortKey(vec![2]))] {
+                    assert_eq!(
+                        history
+                            .list_entries_at_version(
+                                &database,
+                                state_version,
+                                &partition_key,
+                                from_sort_key.as_ref()
+                            )
+                            .collect::<Vec<_>>(),
+                        snapshot
+                            .list_raw_values_from_db_key(&partition_key, from_sort_key.as_ref())
+                            .collect::<Vec<_>>(),
+                    );
+                }
+            }
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::historical_substates::*;
 use itertools::Itertools;
 use radix_common::constants::MAX_SUBSTATE_KEY_SIZE;
 use radix_common::prelude::*;
@@ -12,11 +13,18 @@ use std::path::PathBuf;
 
 pub struct RocksdbSubstateStore {
     db: DBWithThreadMode<SingleThreaded>,
+    historical_substates_enabled: bool,
 }
 
 impl RocksdbSubstateStore {
-    // Technically we don't need CFs here at all; however, delete range API is only available for CF
+    // Technically the substates don't need a CF of their own; however, delete range API is only
+    // available for CF (the name is kept for the existing databases)
     const THE_ONLY_CF: &'static str = "the_only";
+    const META_CF: &'static str = "meta";
+    const HISTORICAL_SUBSTATES_CF: &'static str = "historical_substates";
+
+    const STATE_VERSION_KEY: &'static [u8] = b"state_version";
+    const FIRST_HISTORICAL_STATE_VERSION_KEY: &'static [u8] = b"first_historical_state_version";
 
     pub fn standard(root: PathBuf) -> Self {
         Self::with_options(&Options::default(), root)
@@ -28,18 +36,61 @@ impl RocksdbSubstateStore {
         let db = DB::open_cf_descriptors(
             &options,
             root.as_path(),
-            vec![ColumnFamilyDescriptor::new(
+            [
                 Self::THE_ONLY_CF,
-                Options::default(),
-            )],
+                Self::META_CF,
+                Self::HISTORICAL_SUBSTATES_CF,
+            ]
+            .into_iter()
+            .map(|name| ColumnFamilyDescriptor::new(name, Options::default()))
+            .collect::<Vec<_>>(),
         )
         .unwrap();
-        Self { db }
+        Self {
+            db,
+            historical_substates_enabled: false,
+        }
+    }
+
+    /// Keeps the previous values of the substates from the current state version on, so that
+    /// they can be read as of any later version (see [`HistoricalSubstateDatabase`]).
+    ///
+    /// The history is kept across re-opening the database, as long as no commit is made without
+    /// it.
+    pub fn with_historical_substates(mut self) -> Self {
+        self.historical_substates_enabled = true;
+        if self
+            .read_meta(Self::FIRST_HISTORICAL_STATE_VERSION_KEY)
+            .is_none()
+        {
+            self.write_meta(
+                Self::FIRST_HISTORICAL_STATE_VERSION_KEY,
+                self.get_current_state_version(),
+            );
+        }
+        self
     }
 
     fn cf(&self) -> &ColumnFamily {
         self.db.cf_handle(Self::THE_ONLY_CF).unwrap()
     }
+
+    fn named_cf(&self, name: &str) -> &ColumnFamily {
+        self.db.cf_handle(name).unwrap()
+    }
+
+    fn read_meta(&self, key: &[u8]) -> Option<u64> {
+        self.db
+            .get_cf(self.named_cf(Self::META_CF), key)
+            .expect("IO Error")
+            .map(|bytes| u64::from_be_bytes(copy_u8_array(&bytes)))
+    }
+
+    fn write_meta(&self, key: &[u8], value: u64) {
+        self.db
+            .put_cf(self.named_cf(Self::META_CF), key, value.to_be_bytes())
+            .expect("IO error");
+    }
 }
 
 impl SubstateDatabase for RocksdbSubstateStore {
@@ -81,6 +132,25 @@ impl SubstateDatabase for RocksdbSubstateStore {
 
 impl CommittableSubstateDatabase for RocksdbSubstateStore {
     fn commit(&mut self, database_updates: &DatabaseUpdates) {
+        let next_state_version = self.get_current_state_version() + 1;
+        if self.historical_substates_enabled {
+            put_previous_values(
+                &self.db,
+                self.named_cf(Self::HISTORICAL_SUBSTATES_CF),
+                self,
+                next_state_version,
+                database_updates,
+            );
+        } else {
+            // The history has a gap from now on
+            self.db
+                .delete_cf(
+                    self.named_cf(Self::META_CF),
+                    Self::FIRST_HISTORICAL_STATE_VERSION_KEY,
+                )
+                .expect("IO error");
+        }
+
         for (node_key, node_updates) in &database_updates.node_updates {
             for (partition_num, partition_updates) in &node_updates.partition_updates {
                 let partition_key = DbPartitionKey {
@@ -125,6 +195,60 @@ impl CommittableSubstateDatabase for RocksdbSubstateStore {
                 }
             }
         }
+
+        self.write_meta(Self::STATE_VERSION_KEY, next_state_version);
+    }
+}
+
+impl HistoricalSubstateDatabase for RocksdbSubstateStore {
+    fn get_first_historical_state_version(&self) -> u64 {
+        let current_state_version = self.get_current_state_version();
+        if !self.historical_substates_enabled {
+            return current_state_version;
+        }
+        self.read_meta(Self::FIRST_HISTORICAL_STATE_VERSION_KEY)
+            .unwrap_or(current_state_version)
+    }
+
+    fn get_current_state_version(&self) -> u64 {
+        self.read_meta(Self::STATE_VERSION_KEY).unwrap_or(0)
+    }
+
+    fn get_raw_substate_at_version(
+        &self,
+        state_version: u64,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Option<DbSubstateValue> {
+        match get_previous_value(
+            &self.db,
+            self.named_cf(Self::HISTORICAL_SUBSTATES_CF),
+            state_version,
+            partition_key,
+            sort_key,
+        ) {
+            Some(previous_value) => previous_value,
+            None => self.get_raw_substate_by_db_key(partition_key, sort_key),
+        }
+    }
+
+    fn list_entries_at_version(
+        &self,
+        state_version: u64,
+        partition_key: &DbPartitionKey,
+        from_sort_key: Option<&DbSortKey>,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        let previous_values = list_previous_values_of_partition(
+            &self.db,
+            self.named_cf(Self::HISTORICAL_SUBSTATES_CF),
+            state_version,
+            partition_key,
+            from_sort_key,
+        );
+        apply_previous_values(
+            self.list_raw_values_from_db_key(partition_key, from_sort_key),
+            previous_values.into_iter(),
+        )
     }
 }
 
@@ -170,6 +294,107 @@ pub fn decode_from_rocksdb_bytes(buffer: &[u8]) -> DbSubstateKey {
     (partition_key, sort_key)
 }
 
+/// Encodes the key of a previous substate value in a column family of historical substates.
+/// Unlike [`encode_to_rocksdb_bytes`], the sort key is length-prefixed, so that it can be followed
+/// by the (big-endian) state version which changed the substate.
+pub fn encode_historical_substate_key(
+    partition_key: &DbPartitionKey,
+    sort_key: &DbSortKey,
+    state_version: u64,
+) -> Vec<u8> {
+    let mut buffer = encode_to_rocksdb_bytes(partition_key, &DbSortKey(vec![]));
+    buffer.extend(u32::try_from(sort_key.0.len()).unwrap().to_be_bytes());
+    buffer.extend(sort_key.0.clone());
+    buffer.extend(state_version.to_be_bytes());
+    buffer
+}
+
+pub fn decode_historical_substate_key(buffer: &[u8]) -> (DbSubstateKey, u64) {
+    let (partition_key, _) = decode_from_rocksdb_bytes(&buffer[..buffer.len() - 8]);
+    let sort_key_byte_offset = 4 + partition_key.node_key.len() + 1 + 4;
+    let state_version_byte_offset = buffer.len() - 8;
+    let sort_key = DbSortKey(buffer[sort_key_byte_offset..state_version_byte_offset].to_vec());
+    let state_version = u64::from_be_bytes(copy_u8_array(&buffer[state_version_byte_offset..]));
+    ((partition_key, sort_key), state_version)
+}
+
+/// Stores the current values of the substates changed by the given updates (which must not be
+/// committed yet) in the column family of historical substates, under the given state version.
+pub(crate) fn put_previous_values<D: SubstateDatabase + ?Sized>(
+    db: &DBWithThreadMode<SingleThreaded>,
+    history_cf: &ColumnFamily,
+    database: &D,
+    state_version: u64,
+    database_updates: &DatabaseUpdates,
+) {
+    for ((partition_key, sort_key), previous_value) in
+        list_previous_values(database, database_updates)
+    {
+        db.put_cf(
+            history_cf,
+            encode_historical_substate_key(&partition_key, &sort_key, state_version),
+            scrypto_encode(&previous_value).unwrap(),
+        )
+        .expect("IO error");
+    }
+}
+
+/// Reads the value of the substate before its first change after the given state version, or
+/// [`Option::None`] if it didn't change since.
+pub(crate) fn get_previous_value(
+    db: &DBWithThreadMode<SingleThreaded>,
+    history_cf: &ColumnFamily,
+    state_version: u64,
+    partition_key: &DbPartitionKey,
+    sort_key: &DbSortKey,
+) -> Option<Option<DbSubstateValue>> {
+    let start_key_bytes =
+        encode_historical_substate_key(partition_key, sort_key, state_version + 1);
+    let substate_key_prefix = &start_key_bytes[..start_key_bytes.len() - 8];
+    db.iterator_cf(
+        history_cf,
+        IteratorMode::From(&start_key_bytes, Direction::Forward),
+    )
+    .next()
+    .map(|kv| kv.expect("IO error"))
+    .filter(|(iter_key_bytes, _)| iter_key_bytes.starts_with(substate_key_prefix))
+    .map(|(_, iter_value)| scrypto_decode(&iter_value).unwrap())
+}
+
+/// Lists the values of the partition's substates before their first change after the given
+/// state version - like [`get_previous_value`], but for a whole partition.
+pub(crate) fn list_previous_values_of_partition(
+    db: &DBWithThreadMode<SingleThreaded>,
+    history_cf: &ColumnFamily,
+    state_version: u64,
+    partition_key: &DbPartitionKey,
+    from_sort_key: Option<&DbSortKey>,
+) -> Vec<(DbSortKey, Option<DbSubstateValue>)> {
+    let partition_prefix = encode_to_rocksdb_bytes(partition_key, &DbSortKey(vec![]));
+    // The entries of each substate are ordered by state version, so the first one found after the
+    // given state version is the one to keep
+    let mut previous_values = IndexMap::new();
+    for kv in db.iterator_cf(
+        history_cf,
+        IteratorMode::From(&partition_prefix, Direction::Forward),
+    ) {
+        let (iter_key_bytes, iter_value) = kv.expect("IO error");
+        if !iter_key_bytes.starts_with(&partition_prefix) {
+            break;
+        }
+        let ((_, sort_key), change_state_version) = decode_historical_substate_key(&iter_key_bytes);
+        if change_state_version <= state_version
+            || from_sort_key.is_some_and(|from_sort_key| sort_key < *from_sort_key)
+        {
+            continue;
+        }
+        previous_values
+            .entry(sort_key)
+            .or_insert_with(|| scrypto_decode(&iter_value).unwrap());
+    }
+    previous_values.into_iter().collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -224,4 +449,62 @@ mod tests {
         });
         assert_eq!(db.list_partition_keys().count(), 8);
     }
+
+    #[cfg(not(feature = "alloc"))]
+    #[test]
+    fn test_historical_substates() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let partition_key = DbPartitionKey {
+            node_key: vec![1],
+            partition_num: 0,
+        };
+        let set = |sort_key: u8, value: u8| {
+            DatabaseUpdates::from_delta_maps(indexmap! {
+                partition_key.clone() => indexmap! {
+                    DbSortKey(vec![sort_key]) => DatabaseUpdate::Set(vec![value])
+                }
+            })
+        };
+
+        let mut db = RocksdbSubstateStore::standard(temp_dir.path().to_path_buf())
+            .with_historical_substates();
+        db.commit(&set(1, 10));
+        db.commit(&set(1, 11));
+        db.commit(&set(2, 20));
+        drop(db);
+        let db = RocksdbSubstateStore::standard(temp_dir.path().to_path_buf())
+            .with_historical_substates();
+
+        assert_eq!(db.get_first_historical_state_version(), 0);
+        assert_eq!(db.get_current_state_version(), 3);
+        assert_eq!(
+            db.get_raw_substate_at_version(0, &partition_key, &DbSortKey(vec![1])),
+            None
+        );
+        assert_eq!(
+            db.get_raw_substate_at_version(1, &partition_key, &DbSortKey(vec![1])),
+            Some(vec![10])
+        );
+        assert_eq!(
+            db.list_entries_at_version(2, &partition_key, None)
+                .collect::<Vec<_>>(),
+            vec![(DbSortKey(vec![1]), vec![11])]
+        );
+        assert_eq!(
+            db.list_entries_at_version(3, &partition_key, None)
+                .collect::<Vec<_>>(),
+            vec![
+                (DbSortKey(vec![1]), vec![11]),
+                (DbSortKey(vec![2]), vec![20])
+            ]
+        );
+
+        // A commit without the history leaves a gap, so the history starts over
+        let mut db = RocksdbSubstateStore::standard(temp_dir.path().to_path_buf());
+        db.commit(&set(2, 21));
+        drop(db);
+        let db = RocksdbSubstateStore::standard(temp_dir.path().to_path_buf())
+            .with_historical_substates();
+        assert_eq!(db.get_first_historical_state_version(), 4);
+    }
 }
@@ -1,7 +1,13 @@
+use crate::historical_substates::apply_previous_values;
+use crate::rocks_db::{get_previous_value, list_previous_values_of_partition, put_previous_values};
+use crate::state_tree::get_substate_proof_at_version;
+use crate::state_tree::substate_proof::SubstateWithProof;
 use crate::state_tree::tree_store::*;
 use itertools::Itertools;
 use radix_common::constants::MAX_SUBSTATE_KEY_SIZE;
 use radix_common::prelude::*;
+use radix_rust::copy_u8_array;
+use radix_substate_store_interface::db_key_mapper::*;
 use radix_substate_store_interface::interface::*;
 pub use rocksdb::{BlockBasedOptions, LogLevel, Options};
 use rocksdb::{
@@ -18,10 +24,14 @@ const META_CF: &str = "meta";
 const SUBSTATES_CF: &str = "substates";
 const MERKLE_NODES_CF: &str = "merkle_nodes";
 const STALE_MERKLE_TREE_PARTS_CF: &str = "stale_merkle_tree_parts";
+const HISTORICAL_SUBSTATES_CF: &str = "historical_substates";
+
+const FIRST_HISTORICAL_STATE_VERSION_KEY: &[u8] = b"first_historical_state_version";
 
 pub struct RocksDBWithMerkleTreeSubstateStore {
     db: DBWithThreadMode<SingleThreaded>,
     pruning_enabled: bool,
+    historical_substates_enabled: bool,
 }
 
 impl RocksDBWithMerkleTreeSubstateStore {
@@ -50,6 +60,7 @@ impl RocksDBWithMerkleTreeSubstateStore {
                 SUBSTATES_CF,
                 MERKLE_NODES_CF,
                 STALE_MERKLE_TREE_PARTS_CF,
+                HISTORICAL_SUBSTATES_CF,
             ]
             .into_iter()
             .map(|name| ColumnFamilyDescriptor::new(name, Options::default()))
@@ -59,7 +70,35 @@ impl RocksDBWithMerkleTreeSubstateStore {
         Self {
             db,
             pruning_enabled,
+            historical_substates_enabled: false,
+        }
+    }
+
+    /// Keeps the previous values of the substates from the current state version on, so that
+    /// they can be read as of any later version (see [`HistoricalSubstateDatabase`]) - unlike the
+    /// tree nodes, which only ever hold the hashes of the values.
+    ///
+    /// The history is kept across re-opening the database, as long as no commit is made without
+    /// it.
+    pub fn with_historical_substates(mut self) -> Self {
+        self.historical_substates_enabled = true;
+        if self.read_first_historical_state_version().is_none() {
+            self.db
+                .put_cf(
+                    self.cf(META_CF),
+                    FIRST_HISTORICAL_STATE_VERSION_KEY,
+                    self.get_current_version().to_be_bytes(),
+                )
+                .unwrap();
         }
+        self
+    }
+
+    fn read_first_historical_state_version(&self) -> Option<u64> {
+        self.db
+            .get_cf(self.cf(META_CF), FIRST_HISTORICAL_STATE_VERSION_KEY)
+            .unwrap()
+            .map(|bytes| u64::from_be_bytes(copy_u8_array(&bytes)))
     }
 
     fn cf(&self, cf: &str) -> &ColumnFamily {
@@ -90,6 +129,47 @@ impl RocksDBWithMerkleTreeSubstateStore {
             .unwrap_or(Hash([0u8; Hash::LENGTH]))
     }
 
+    /// Gets the substate (or its absence) at the given version, together with a proof verifiable
+    /// against that version's root hash.
+    /// Previous versions can be proven as far back as the first historical state version, but only
+    /// if the tree nodes are not pruned; `None` is returned for any other version.
+    pub fn get_substate_with_proof<'a>(
+        &self,
+        node_id: impl AsRef<NodeId>,
+        partition_number: PartitionNumber,
+        substate_key: impl ResolvableSubstateKey<'a>,
+        version: Version,
+    ) -> Option<SubstateWithProof> {
+        self.get_substate_with_proof_by_db_key(
+            &SpreadPrefixKeyMapper::to_db_partition_key(node_id.as_ref(), partition_number),
+            &SpreadPrefixKeyMapper::to_db_sort_key_from_ref(
+                substate_key.into_substate_key_or_ref().as_ref(),
+            ),
+            version,
+        )
+    }
+
+    /// Like [`Self::get_substate_with_proof`], but for a substate given by its database keys.
+    pub fn get_substate_with_proof_by_db_key(
+        &self,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+        version: Version,
+    ) -> Option<SubstateWithProof> {
+        let current_version = self.get_current_version();
+        let is_available = version == current_version
+            || (!self.pruning_enabled
+                && version >= self.get_first_historical_state_version()
+                && version <= current_version);
+        if !is_available {
+            return None;
+        }
+        Some(SubstateWithProof {
+            value: self.get_raw_substate_at_version(version, partition_key, sort_key),
+            proof: get_substate_proof_at_version(self, version, partition_key, sort_key),
+        })
+    }
+
     pub fn overwrite_metadata(&mut self, meta: &Metadata) {
         self.db
             .put_cf(self.cf(META_CF), &[], scrypto_encode(meta).unwrap())
@@ -151,6 +231,22 @@ impl CommittableSubstateDatabase for RocksDBWithMerkleTreeSubstateStore {
         let parent_state_version = metadata.current_state_version;
         let next_state_version = parent_state_version + 1;
 
+        // keep the previous values of the changed substates, before they are overwritten
+        if self.historical_substates_enabled {
+            put_previous_values(
+                &self.db,
+                self.cf(HISTORICAL_SUBSTATES_CF),
+                self,
+                next_state_version,
+                database_updates,
+            );
+        } else {
+            // the history has a gap from now on
+            self.db
+                .delete_cf(self.cf(META_CF), FIRST_HISTORICAL_STATE_VERSION_KEY)
+                .unwrap();
+        }
+
         // prepare a batch write (we use the same approach in the actual Node)
         let mut batch = WriteBatch::default();
 
@@ -281,6 +377,58 @@ impl CommittableSubstateDatabase for RocksDBWithMerkleTreeSubstateStore {
     }
 }
 
+impl HistoricalSubstateDatabase for RocksDBWithMerkleTreeSubstateStore {
+    fn get_first_historical_state_version(&self) -> u64 {
+        let current_state_version = self.get_current_version();
+        if !self.historical_substates_enabled {
+            return current_state_version;
+        }
+        self.read_first_historical_state_version()
+            .unwrap_or(current_state_version)
+    }
+
+    fn get_current_state_version(&self) -> u64 {
+        self.get_current_version()
+    }
+
+    fn get_raw_substate_at_version(
+        &self,
+        state_version: u64,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Option<DbSubstateValue> {
+        match get_previous_value(
+            &self.db,
+            self.cf(HISTORICAL_SUBSTATES_CF),
+            state_version,
+            partition_key,
+            sort_key,
+        ) {
+            Some(previous_value) => previous_value,
+            None => self.get_raw_substate_by_db_key(partition_key, sort_key),
+        }
+    }
+
+    fn list_entries_at_version(
+        &self,
+        state_version: u64,
+        partition_key: &DbPartitionKey,
+        from_sort_key: Option<&DbSortKey>,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        let previous_values = list_previous_values_of_partition(
+            &self.db,
+            self.cf(HISTORICAL_SUBSTATES_CF),
+            state_version,
+            partition_key,
+            from_sort_key,
+        );
+        apply_previous_values(
+            self.list_raw_values_from_db_key(partition_key, from_sort_key),
+            previous_values.into_iter(),
+        )
+    }
+}
+
 impl ListableSubstateDatabase for RocksDBWithMerkleTreeSubstateStore {
     fn list_partition_keys(&self) -> Box<dyn Iterator<Item = DbPartitionKey> + '_> {
         Box::new(
@@ -318,8 +466,8 @@ pub struct Metadata {
 mod tests {
     use super::*;
     use radix_substate_store_interface::interface::{
-        CommittableSubstateDatabase, DatabaseUpdates, DbSortKey, NodeDatabaseUpdates,
-        PartitionDatabaseUpdates,
+        CommittableSubstateDatabase, DatabaseUpdate, DatabaseUpdates, DbPartitionKey, DbSortKey,
+        NodeDatabaseUpdates, PartitionDatabaseUpdates,
     };
 
     #[cfg(not(feature = "alloc"))]
@@ -368,4 +516,58 @@ mod tests {
         });
         assert_eq!(db.list_partition_keys().count(), 8);
     }
+
+    #[cfg(not(feature = "alloc"))]
+    #[test]
+    fn test_historical_substate_proofs() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut options = Options::default();
+        options.create_if_missing(true);
+        options.create_missing_column_families(true);
+        let mut db =
+            RocksDBWithMerkleTreeSubstateStore::with_options(&options, temp_dir.into_path(), false)
+                .with_historical_substates();
+        let partition_key = DbPartitionKey {
+            node_key: vec![0],
+            partition_num: 0,
+        };
+        let sort_key = DbSortKey(vec![5]);
+        let update = |value: u8| DatabaseUpdates {
+            node_updates: indexmap! {
+                vec![0] => NodeDatabaseUpdates {
+                    partition_updates: indexmap! {
+                        0 => PartitionDatabaseUpdates::Delta {
+                            substate_updates: indexmap! {
+                                DbSortKey(vec![5]) => DatabaseUpdate::Set(vec![value])
+                            }
+                        }
+                    }
+                }
+            },
+        };
+
+        db.commit(&update(6));
+        let root_hash_v1 = db.get_current_root_hash();
+        db.commit(&update(7));
+
+        let proof = db
+            .get_substate_with_proof_by_db_key(&partition_key, &sort_key, 1)
+            .unwrap();
+        assert_eq!(proof.value, Some(vec![6]));
+        assert_eq!(
+            proof.verify(&root_hash_v1, &partition_key, &sort_key),
+            Ok(())
+        );
+        let proof = db
+            .get_substate_with_proof_by_db_key(&partition_key, &sort_key, 2)
+            .unwrap();
+        assert_eq!(proof.value, Some(vec![7]));
+        assert_eq!(
+            proof.verify(&db.get_current_root_hash(), &partition_key, &sort_key),
+            Ok(())
+        );
+        assert!(db
+            .get_substate_with_proof_by_db_key(&partition_key, &sort_key, 3)
+            .is_none());
+    }
 }
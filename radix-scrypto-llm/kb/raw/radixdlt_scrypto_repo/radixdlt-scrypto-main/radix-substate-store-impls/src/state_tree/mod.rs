@@ -3,19 +3,23 @@ pub mod tree_store;
 
 pub mod entity_tier;
 pub mod partition_tier;
+pub mod substate_proof;
 pub mod substate_tier;
 pub mod tier_framework;
 
 use entity_tier::EntityTier;
+use partition_tier::PartitionTier;
 use radix_common::crypto::Hash;
 use radix_rust::prelude::*;
 use radix_substate_store_interface::interface::*;
+use substate_proof::SubstateProof;
+use substate_tier::SubstateTier;
+use tier_framework::ReadableTier;
 use tree_store::*;
 use types::*;
 
-// The sources copied from Aptos (the `jellyfish` and `types` modules) contain support for
-// generating proofs, which we plan to use in near future. Hence, we do not delete that code, but
-// suppress warnings.
+// The sources copied from Aptos (the `jellyfish` and `types` modules) contain more than we use
+// (e.g. range proofs). We do not delete that code, but suppress warnings.
 
 #[allow(dead_code)]
 mod jellyfish;
@@ -68,3 +72,55 @@ pub fn list_substate_hashes_at_version<S: ReadableTreeStore>(
         })
         .collect()
 }
+
+/// Returns a proof of the inclusion (or non-inclusion) of the given substate in the state at the
+/// given version, verifiable against that version's root hash (see [`SubstateProof::verify()`]).
+/// The caller should use version `0` to denote an empty, initial state of the tree.
+///
+/// # Panics
+/// Panics if the tree nodes of the given version do not exist (e.g. have been pruned).
+pub fn get_substate_proof_at_version<S: ReadableTreeStore>(
+    tree_store: &S,
+    root_state_version: Version,
+    partition_key: &DbPartitionKey,
+    sort_key: &DbSortKey,
+) -> SubstateProof {
+    let entity_tier = EntityTier::new(
+        tree_store,
+        Some(root_state_version).filter(|version| *version > 0),
+    );
+    let (entity_root_version, entity_proof) =
+        entity_tier.get_persisted_leaf_payload_with_proof(&partition_key.node_key);
+    let Some(entity_root_version) = entity_root_version else {
+        return SubstateProof {
+            entity_proof,
+            partition_proof: None,
+            substate_proof: None,
+        };
+    };
+
+    let partition_tier = PartitionTier::new(
+        tree_store,
+        Some(entity_root_version),
+        partition_key.node_key.clone(),
+    );
+    let (partition_root_version, partition_proof) =
+        partition_tier.get_persisted_leaf_payload_with_proof(&partition_key.partition_num);
+    let substate_proof = partition_root_version.map(|partition_root_version| {
+        let substate_tier = SubstateTier::new(
+            tree_store,
+            Some(partition_root_version),
+            partition_key.node_key.clone(),
+            partition_key.partition_num,
+        );
+        let (_version, substate_proof) =
+            substate_tier.get_persisted_leaf_payload_with_proof(sort_key);
+        substate_proof
+    });
+
+    SubstateProof {
+        entity_proof,
+        partition_proof: Some(partition_proof),
+        substate_proof,
+    }
+}
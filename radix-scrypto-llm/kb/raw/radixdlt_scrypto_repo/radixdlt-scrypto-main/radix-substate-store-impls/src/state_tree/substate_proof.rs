@@ -0,0 +1,195 @@
+use super::types::*;
+use radix_common::prelude::*;
+use radix_substate_store_interface::interface::*;
+
+/// A proof of the inclusion (or non-inclusion) of a single substate in the "3-Tier JMT", which can
+/// be verified against the state root hash alone (see [`SubstateProof::verify()`]).
+///
+/// The proof descends the tiers for as long as the substate's key exists in them: a proof of a
+/// missing entity only has the [`Self::entity_proof`], and a proof of a missing partition has no
+/// [`Self::substate_proof`].
+#[derive(Clone, Debug, PartialEq, Eq, Sbor)]
+pub struct SubstateProof {
+    /// Proves the entity's leaf (whose value hash is its Partition-Tier's root hash) under the
+    /// state root.
+    pub entity_proof: TierProof,
+    /// Proves the partition's leaf (whose value hash is its Substate-Tier's root hash) under the
+    /// entity's root. Present only if the entity exists.
+    pub partition_proof: Option<TierProof>,
+    /// Proves the substate's leaf (whose value hash is the hash of the substate value) under the
+    /// partition's root. Present only if the partition exists.
+    pub substate_proof: Option<TierProof>,
+}
+
+/// A substate value (or its absence), together with the proof of it.
+#[derive(Clone, Debug, PartialEq, Eq, Sbor)]
+pub struct SubstateWithProof {
+    pub value: Option<DbSubstateValue>,
+    pub proof: SubstateProof,
+}
+
+impl SubstateWithProof {
+    /// Verifies that the substate under the given key has the contained value (or does not exist)
+    /// in the state of the given root hash.
+    pub fn verify(
+        &self,
+        state_root: &Hash,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Result<(), SubstateProofError> {
+        self.proof
+            .verify(state_root, partition_key, sort_key, self.value.as_ref())
+    }
+}
+
+/// A sparse Merkle proof of a single leaf key within a single tier.
+#[derive(Clone, Debug, PartialEq, Eq, Sbor)]
+pub struct TierProof {
+    /// The leaf found on the path of the proven key:
+    /// - if its key equals the proven key, this is an inclusion proof,
+    /// - otherwise this is a non-inclusion proof, and the leaf is the only one in the subtree
+    ///   where the proven key would be,
+    /// - if there is no leaf, this is a non-inclusion proof of an empty subtree.
+    pub leaf: Option<TierProofLeaf>,
+    /// The hashes of the siblings on the path of the proven key, from the bottom to the root.
+    pub siblings: Vec<Hash>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Sbor)]
+pub struct TierProofLeaf {
+    pub key: Vec<u8>,
+    pub value_hash: Hash,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SubstateProofError {
+    /// A tier proof is missing for a key which should exist in the tier above.
+    MissingTierProof,
+    /// A tier proof is given below a tier in which the key does not exist.
+    UnexpectedTierProof,
+    /// A key was expected to exist, but the tier proof has no leaf.
+    ExpectedInclusionProof,
+    /// A key was expected to be absent, but the tier proof has its leaf.
+    ExpectedNonInclusionProof,
+    /// The proof's leaf is neither the proven key's leaf nor on its path.
+    LeafKeyMismatch,
+    /// The proof's leaf has a different value hash than the proven one.
+    ValueHashMismatch,
+    /// The proof has more siblings than the proven key has bits.
+    TooManySiblings,
+    /// The root hash computed from the proof differs from the expected state root.
+    RootHashMismatch { expected: Hash, actual: Hash },
+}
+
+impl SubstateProof {
+    /// Verifies that the substate under the given key has the given value (or does not exist, if
+    /// `None`) in the state of the given root hash.
+    ///
+    /// The verification only needs the proof itself, so it can be done e.g. by light clients,
+    /// without any access to the state tree.
+    pub fn verify(
+        &self,
+        state_root: &Hash,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+        value: Option<&DbSubstateValue>,
+    ) -> Result<(), SubstateProofError> {
+        // Each tier's root hash is the value hash of the corresponding leaf in the tier above
+        let partition_root = match &self.substate_proof {
+            Some(proof) => Some(proof.compute_root(&sort_key.0, value.map(hash))?),
+            None if value.is_some() => return Err(SubstateProofError::MissingTierProof),
+            None => None,
+        };
+        let entity_root = match &self.partition_proof {
+            Some(proof) => {
+                Some(proof.compute_root(&[partition_key.partition_num], partition_root)?)
+            }
+            None if partition_root.is_some() => {
+                return Err(SubstateProofError::UnexpectedTierProof)
+            }
+            None => None,
+        };
+        let actual = self
+            .entity_proof
+            .compute_root(&partition_key.node_key, entity_root)?;
+        if actual != *state_root {
+            return Err(SubstateProofError::RootHashMismatch {
+                expected: *state_root,
+                actual,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl TierProof {
+    /// Computes the root hash of a tier in which the given key has a leaf with the given value
+    /// hash (or has no leaf, if `None`), according to this proof.
+    pub fn compute_root(
+        &self,
+        key: &[u8],
+        value_hash: Option<Hash>,
+    ) -> Result<Hash, SubstateProofError> {
+        let key = LeafKey::new(key);
+        if self.siblings.len() > key.bytes.len() * 8 {
+            return Err(SubstateProofError::TooManySiblings);
+        }
+        match (&self.leaf, value_hash) {
+            (Some(leaf), Some(value_hash)) => {
+                if leaf.key != key.bytes {
+                    return Err(SubstateProofError::LeafKeyMismatch);
+                }
+                if leaf.value_hash != value_hash {
+                    return Err(SubstateProofError::ValueHashMismatch);
+                }
+            }
+            (Some(leaf), None) => {
+                if leaf.key == key.bytes {
+                    return Err(SubstateProofError::ExpectedNonInclusionProof);
+                }
+                // The other leaf must be located on the proven key's path
+                let leaf_key = LeafKey::new(&leaf.key);
+                let common_prefix_bits = key
+                    .iter_bits()
+                    .zip(leaf_key.iter_bits())
+                    .take_while(|(key_bit, leaf_key_bit)| key_bit == leaf_key_bit)
+                    .count();
+                if common_prefix_bits < self.siblings.len() {
+                    return Err(SubstateProofError::LeafKeyMismatch);
+                }
+            }
+            (None, Some(_)) => return Err(SubstateProofError::ExpectedInclusionProof),
+            (None, None) => {}
+        }
+
+        let leaf_hash = self
+            .leaf
+            .as_ref()
+            .map(|leaf| SparseMerkleLeafNode::new(LeafKey::new(&leaf.key), leaf.value_hash).hash())
+            .unwrap_or(SPARSE_MERKLE_PLACEHOLDER_HASH);
+        let root_hash = self
+            .siblings
+            .iter()
+            .zip(key.iter_bits().take(self.siblings.len()).rev())
+            .fold(leaf_hash, |hash, (sibling_hash, is_right)| {
+                if is_right {
+                    SparseMerkleInternalNode::new(*sibling_hash, hash).hash()
+                } else {
+                    SparseMerkleInternalNode::new(hash, *sibling_hash).hash()
+                }
+            });
+        Ok(root_hash)
+    }
+}
+
+impl From<SparseMerkleProof> for TierProof {
+    fn from(proof: SparseMerkleProof) -> Self {
+        Self {
+            leaf: proof.leaf().map(|leaf| TierProofLeaf {
+                key: leaf.key().bytes.clone(),
+                value_hash: *leaf.value_hash(),
+            }),
+            siblings: proof.siblings().to_vec(),
+        }
+    }
+}
@@ -1,4 +1,6 @@
+use super::get_substate_proof_at_version;
 use super::jellyfish::JellyfishMerkleTree;
+use super::substate_proof::SubstateProofError;
 use super::tier_framework::{StateTreeTier, TIER_SEPARATOR};
 use super::tree_store::*;
 use super::types::*;
@@ -864,6 +866,152 @@ fn lists_historical_substate_summaries() {
     );
 }
 
+#[test]
+fn proves_included_substates() {
+    let mut tester = StateTreeTester::new_empty();
+    let root_hash = tester
+        .put_substate_changes(vec![
+            change(1, 9, 1, Some(196)),
+            change(3, 2, 4, Some(24)),
+            change(3, 2, 6, Some(36)),
+            change(3, 4, 7, Some(237)),
+        ])
+        .unwrap();
+
+    for (node_seed, partition_num, sort_seed, value_seed) in
+        [(1, 9, 1, 196), (3, 2, 4, 24), (3, 2, 6, 36), (3, 4, 7, 237)]
+    {
+        let partition_key = partition_key(from_seed(node_seed), partition_num);
+        let sort_key = DbSortKey(from_seed(sort_seed));
+        let proof = get_substate_proof_at_version(
+            &tester.tree_store,
+            tester.current_version.unwrap(),
+            &partition_key,
+            &sort_key,
+        );
+        assert_eq!(
+            proof.verify(
+                &root_hash,
+                &partition_key,
+                &sort_key,
+                Some(&from_seed(value_seed))
+            ),
+            Ok(())
+        );
+    }
+}
+
+#[test]
+fn proves_absent_substates_at_each_tier() {
+    let mut tester = StateTreeTester::new_empty();
+    let root_hash = tester
+        .put_substate_changes(vec![
+            change(1, 9, 1, Some(196)),
+            change(3, 2, 4, Some(24)),
+            change(3, 2, 6, Some(36)),
+        ])
+        .unwrap();
+    let version = tester.current_version.unwrap();
+
+    let absent_substate = (partition_key(from_seed(3), 2), DbSortKey(from_seed(5)));
+    let absent_partition = (partition_key(from_seed(3), 7), DbSortKey(from_seed(4)));
+    let absent_entity = (partition_key(from_seed(2), 2), DbSortKey(from_seed(4)));
+    for (partition_key, sort_key) in [&absent_substate, &absent_partition, &absent_entity] {
+        let proof =
+            get_substate_proof_at_version(&tester.tree_store, version, partition_key, sort_key);
+        assert_eq!(
+            proof.verify(&root_hash, partition_key, sort_key, None),
+            Ok(())
+        );
+        assert_ne!(
+            proof.verify(&root_hash, partition_key, sort_key, Some(&from_seed(24))),
+            Ok(())
+        );
+    }
+
+    let (partition_key, sort_key) = absent_partition;
+    let proof =
+        get_substate_proof_at_version(&tester.tree_store, version, &partition_key, &sort_key);
+    assert!(proof.partition_proof.is_some());
+    assert_eq!(proof.substate_proof, None);
+    let (partition_key, sort_key) = absent_entity;
+    let proof =
+        get_substate_proof_at_version(&tester.tree_store, version, &partition_key, &sort_key);
+    assert_eq!(proof.partition_proof, None);
+}
+
+#[test]
+fn rejects_proof_of_wrong_value_or_root() {
+    let mut tester = StateTreeTester::new_empty();
+    let hash_v1 = tester
+        .put_substate_changes(vec![change(3, 2, 4, Some(24)), change(3, 2, 6, Some(36))])
+        .unwrap();
+    let hash_v2 = tester
+        .put_substate_changes(vec![change(3, 2, 4, Some(44))])
+        .unwrap();
+
+    let partition_key = partition_key(from_seed(3), 2);
+    let sort_key = DbSortKey(from_seed(4));
+    let proof = get_substate_proof_at_version(&tester.tree_store, 2, &partition_key, &sort_key);
+    assert_eq!(
+        proof.verify(&hash_v2, &partition_key, &sort_key, Some(&from_seed(44))),
+        Ok(())
+    );
+    assert_eq!(
+        proof.verify(&hash_v2, &partition_key, &sort_key, Some(&from_seed(24))),
+        Err(SubstateProofError::ValueHashMismatch)
+    );
+    assert_eq!(
+        proof.verify(&hash_v2, &partition_key, &sort_key, None),
+        Err(SubstateProofError::ExpectedNonInclusionProof)
+    );
+    assert_eq!(
+        proof.verify(&hash_v1, &partition_key, &sort_key, Some(&from_seed(44))),
+        Err(SubstateProofError::RootHashMismatch {
+            expected: hash_v1,
+            actual: hash_v2,
+        })
+    );
+
+    // The (non-pruned) previous version can still be proven against its own root
+    let proof = get_substate_proof_at_version(&tester.tree_store, 1, &partition_key, &sort_key);
+    assert_eq!(
+        proof.verify(&hash_v1, &partition_key, &sort_key, Some(&from_seed(24))),
+        Ok(())
+    );
+}
+
+#[test]
+fn proves_absent_substates_in_empty_state() {
+    let partition_key = partition_key(from_seed(3), 2);
+    let sort_key = DbSortKey(from_seed(4));
+    let mut tester = StateTreeTester::new_empty();
+    let proof = get_substate_proof_at_version(&tester.tree_store, 0, &partition_key, &sort_key);
+    assert_eq!(
+        proof.verify(
+            &SPARSE_MERKLE_PLACEHOLDER_HASH,
+            &partition_key,
+            &sort_key,
+            None
+        ),
+        Ok(())
+    );
+
+    tester.put_substate_changes(vec![change(3, 2, 4, Some(24))]);
+    let hash_v2 = tester.put_substate_changes(vec![change(3, 2, 4, None)]);
+    assert_eq!(hash_v2, None);
+    let proof = get_substate_proof_at_version(&tester.tree_store, 2, &partition_key, &sort_key);
+    assert_eq!(
+        proof.verify(
+            &SPARSE_MERKLE_PLACEHOLDER_HASH,
+            &partition_key,
+            &sort_key,
+            None
+        ),
+        Ok(())
+    );
+}
+
 type SingleSubstateChange = (DbSubstateKey, DatabaseUpdate);
 
 fn change(
@@ -2,6 +2,7 @@ use core::iter;
 
 use super::jellyfish::JellyfishMerkleTree;
 use super::jellyfish::TreeUpdateBatch;
+use super::substate_proof::TierProof;
 use super::tree_store::*;
 use super::types::*;
 use radix_common::crypto::Hash;
@@ -49,6 +50,31 @@ pub trait ReadableTier: StateTreeTier {
         let (leaf_node_data, _proof) = self.jmt().get_with_proof(&leaf_key, root_version).unwrap();
         leaf_node_data.map(|(_hash, payload, _version)| payload)
     }
+
+    /// Gets the payload of the leaf under the given key (if it exists), together with a proof of
+    /// its inclusion (or non-inclusion) under the tier's root.
+    fn get_persisted_leaf_payload_with_proof(
+        &self,
+        key: &Self::TypedLeafKey,
+    ) -> (Option<Self::Payload>, TierProof) {
+        let Some(root_version) = self.root_version() else {
+            return (
+                None,
+                TierProof {
+                    leaf: None,
+                    siblings: vec![],
+                },
+            );
+        };
+
+        let leaf_key = Self::to_leaf_key(key);
+
+        let (leaf_node_data, proof) = self.jmt().get_with_proof(&leaf_key, root_version).unwrap();
+        (
+            leaf_node_data.map(|(_hash, payload, _version)| payload),
+            proof.into(),
+        )
+    }
 }
 
 pub struct TierLeaf<T: StateTreeTier> {
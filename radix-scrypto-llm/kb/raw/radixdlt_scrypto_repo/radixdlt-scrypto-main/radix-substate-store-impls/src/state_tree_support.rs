@@ -1,6 +1,11 @@
+use crate::historical_substates::SubstateHistory;
+use crate::state_tree::substate_proof::SubstateWithProof;
 use crate::state_tree::tree_store::{TypedInMemoryTreeStore, Version};
-use crate::state_tree::{list_substate_hashes_at_version, put_at_next_version};
+use crate::state_tree::{
+    get_substate_proof_at_version, list_substate_hashes_at_version, put_at_next_version,
+};
 use radix_common::prelude::*;
+use radix_substate_store_interface::db_key_mapper::*;
 use radix_substate_store_interface::interface::*;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -9,6 +14,7 @@ pub struct StateTreeUpdatingDatabase<D> {
     tree_store: TypedInMemoryTreeStore,
     current_version: Version,
     current_hash: Hash,
+    substate_history: Option<SubstateHistory>,
 }
 
 impl<D> StateTreeUpdatingDatabase<D> {
@@ -18,9 +24,23 @@ impl<D> StateTreeUpdatingDatabase<D> {
             tree_store: TypedInMemoryTreeStore::new().with_pruning_enabled(),
             current_version: 0,
             current_hash: Hash([0; Hash::LENGTH]),
+            substate_history: None,
         }
     }
 
+    /// Keeps the previous values of the substates from the current version on, so that they can be
+    /// read as of any later version (see [`HistoricalSubstateDatabase`]).
+    pub fn with_historical_substates(mut self) -> Self {
+        self.substate_history = Some(SubstateHistory::new(self.current_version));
+        self
+    }
+
+    /// The database which the substates are read from, without the state tree - which can be
+    /// shared across threads.
+    pub fn underlying(&self) -> &D {
+        &self.underlying
+    }
+
     pub fn get_current_root_hash(&self) -> Hash {
         self.current_hash
     }
@@ -63,14 +83,115 @@ impl<D: SubstateDatabase> SubstateDatabase for StateTreeUpdatingDatabase<D> {
     }
 }
 
+impl<D: SubstateDatabase> StateTreeUpdatingDatabase<D> {
+    /// Gets the substate (or its absence) at the given version, together with a proof verifiable
+    /// against that version's root hash.
+    /// Returns `None` for any version other than the current one, since the tree nodes of previous
+    /// versions are pruned.
+    pub fn get_substate_with_proof<'a>(
+        &self,
+        node_id: impl AsRef<NodeId>,
+        partition_number: PartitionNumber,
+        substate_key: impl ResolvableSubstateKey<'a>,
+        version: Version,
+    ) -> Option<SubstateWithProof> {
+        self.get_substate_with_proof_by_db_key(
+            &SpreadPrefixKeyMapper::to_db_partition_key(node_id.as_ref(), partition_number),
+            &SpreadPrefixKeyMapper::to_db_sort_key_from_ref(
+                substate_key.into_substate_key_or_ref().as_ref(),
+            ),
+            version,
+        )
+    }
+
+    /// Like [`Self::get_substate_with_proof`], but for a substate given by its database keys.
+    pub fn get_substate_with_proof_by_db_key(
+        &self,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+        version: Version,
+    ) -> Option<SubstateWithProof> {
+        if version != self.current_version {
+            return None;
+        }
+        Some(SubstateWithProof {
+            value: self
+                .underlying
+                .get_raw_substate_by_db_key(partition_key, sort_key),
+            proof: get_substate_proof_at_version(
+                &self.tree_store,
+                version,
+                partition_key,
+                sort_key,
+            ),
+        })
+    }
+}
+
+impl<D: SubstateDatabase> HistoricalSubstateDatabase for StateTreeUpdatingDatabase<D> {
+    fn get_first_historical_state_version(&self) -> u64 {
+        self.substate_history
+            .as_ref()
+            .map(|substate_history| substate_history.first_state_version())
+            .unwrap_or(self.current_version)
+    }
+
+    fn get_current_state_version(&self) -> u64 {
+        self.current_version
+    }
+
+    fn get_raw_substate_at_version(
+        &self,
+        state_version: u64,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Option<DbSubstateValue> {
+        match &self.substate_history {
+            Some(substate_history) => substate_history.get_raw_substate_at_version(
+                &self.underlying,
+                state_version,
+                partition_key,
+                sort_key,
+            ),
+            None => self
+                .underlying
+                .get_raw_substate_by_db_key(partition_key, sort_key),
+        }
+    }
+
+    fn list_entries_at_version(
+        &self,
+        state_version: u64,
+        partition_key: &DbPartitionKey,
+        from_sort_key: Option<&DbSortKey>,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        match &self.substate_history {
+            Some(substate_history) => substate_history.list_entries_at_version(
+                &self.underlying,
+                state_version,
+                partition_key,
+                from_sort_key,
+            ),
+            None => self
+                .underlying
+                .list_raw_values_from_db_key(partition_key, from_sort_key),
+        }
+    }
+}
+
 impl<D: ListableSubstateDatabase> ListableSubstateDatabase for StateTreeUpdatingDatabase<D> {
     fn list_partition_keys(&self) -> Box<dyn Iterator<Item = DbPartitionKey> + '_> {
         self.underlying.list_partition_keys()
     }
 }
 
-impl<D: CommittableSubstateDatabase> CommittableSubstateDatabase for StateTreeUpdatingDatabase<D> {
+impl<D: SubstateDatabase + CommittableSubstateDatabase> CommittableSubstateDatabase
+    for StateTreeUpdatingDatabase<D>
+{
     fn commit(&mut self, database_updates: &DatabaseUpdates) {
+        if let Some(substate_history) = &mut self.substate_history {
+            substate_history.record(&self.underlying, self.current_version + 1, database_updates);
+        }
         self.underlying.commit(database_updates);
         self.update_with(database_updates);
     }
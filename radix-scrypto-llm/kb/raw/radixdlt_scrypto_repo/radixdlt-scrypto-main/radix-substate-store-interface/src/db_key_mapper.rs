@@ -88,6 +88,10 @@ pub trait DatabaseKeyMapper: 'static {
 
     fn sorted_to_db_sort_key(sorted_key: &SortedKey) -> DbSortKey;
     fn sorted_from_db_sort_key(db_sort_key: &DbSortKey) -> SortedKey;
+
+    /// Returns a [`DbSortKey`] which is not greater than the [`DbSortKey`] of any [`SortedKey`]
+    /// with the given sort prefix, and greater than that of any [`SortedKey`] with a lower one.
+    fn sort_prefix_to_db_sort_key(sort_prefix: &[u8; 2]) -> DbSortKey;
 }
 
 /// A [`DatabaseKeyMapper`] tailored for databases which cannot tolerate long common prefixes
@@ -154,6 +158,10 @@ impl DatabaseKeyMapper for SpreadPrefixKeyMapper {
             SpreadPrefixKeyMapper::from_hash_prefixed(&db_sort_key.0[2..]).to_vec(),
         )
     }
+
+    fn sort_prefix_to_db_sort_key(sort_prefix: &[u8; 2]) -> DbSortKey {
+        DbSortKey(sort_prefix.to_vec())
+    }
 }
 
 impl SpreadPrefixKeyMapper {
@@ -733,3 +733,96 @@ pub trait ListableSubstateDatabaseExtensions: ListableSubstateDatabase {
         Box::new(iterator)
     }
 }
+
+/// A read interface for the substates of previous state versions - where the state version is the
+/// number of commits applied to the database.
+///
+/// The history is only retained from the
+/// [`first_historical_state_version`][HistoricalSubstateDatabase::get_first_historical_state_version]
+/// on. A database which doesn't retain it can only be read at its current state version.
+pub trait HistoricalSubstateDatabase {
+    /// The earliest state version which can be read.
+    fn get_first_historical_state_version(&self) -> u64;
+
+    /// The state version after the latest commit.
+    fn get_current_state_version(&self) -> u64;
+
+    /// Reads a substate value as of the given state version, or [`Option::None`] if it was missing
+    /// then.
+    /// The state version must be available - see [`SubstateDatabaseAtVersion::new`].
+    fn get_raw_substate_at_version(
+        &self,
+        state_version: u64,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Option<DbSubstateValue>;
+
+    /// Same as [`SubstateDatabase::list_raw_values_from_db_key`], but iterates over the entries
+    /// the partition had as of the given state version.
+    /// The state version must be available - see [`SubstateDatabaseAtVersion::new`].
+    fn list_entries_at_version(
+        &self,
+        state_version: u64,
+        partition_key: &DbPartitionKey,
+        from_sort_key: Option<&DbSortKey>,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateVersionNotAvailableError {
+    pub state_version: u64,
+    pub first_historical_state_version: u64,
+    pub current_state_version: u64,
+}
+
+/// A read-only [`SubstateDatabase`] view of a [`HistoricalSubstateDatabase`] as of a previous
+/// state version - which e.g. allows a `SystemDatabaseReader` to read the entities as they were
+/// before a given transaction.
+pub struct SubstateDatabaseAtVersion<'d, D: HistoricalSubstateDatabase + ?Sized> {
+    database: &'d D,
+    state_version: u64,
+}
+
+impl<'d, D: HistoricalSubstateDatabase + ?Sized> SubstateDatabaseAtVersion<'d, D> {
+    pub fn new(database: &'d D, state_version: u64) -> Result<Self, StateVersionNotAvailableError> {
+        let first_historical_state_version = database.get_first_historical_state_version();
+        let current_state_version = database.get_current_state_version();
+        if state_version < first_historical_state_version || state_version > current_state_version {
+            return Err(StateVersionNotAvailableError {
+                state_version,
+                first_historical_state_version,
+                current_state_version,
+            });
+        }
+        Ok(Self {
+            database,
+            state_version,
+        })
+    }
+
+    pub fn state_version(&self) -> u64 {
+        self.state_version
+    }
+}
+
+impl<'d, D: HistoricalSubstateDatabase + ?Sized> SubstateDatabase
+    for SubstateDatabaseAtVersion<'d, D>
+{
+    fn get_raw_substate_by_db_key(
+        &self,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Option<DbSubstateValue> {
+        self.database
+            .get_raw_substate_at_version(self.state_version, partition_key, sort_key)
+    }
+
+    fn list_raw_values_from_db_key(
+        &self,
+        partition_key: &DbPartitionKey,
+        from_sort_key: Option<&DbSortKey>,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        self.database
+            .list_entries_at_version(self.state_version, partition_key, from_sort_key)
+    }
+}
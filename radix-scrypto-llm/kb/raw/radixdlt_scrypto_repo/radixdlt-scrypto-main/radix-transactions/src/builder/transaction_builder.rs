@@ -298,6 +298,31 @@ impl PartialTransactionV2Builder {
         self
     }
 
+    /// Sets the message to the plaintext encrypted for the given decryptors, with keys from the
+    /// operating system's random number generator - see [`encrypt_message`].
+    ///
+    /// This fails if there are no decryptors, or any of their public keys is invalid.
+    #[cfg(feature = "std")]
+    pub fn encrypted_message(
+        self,
+        plaintext: PlaintextMessageV1,
+        decryptors: &[PublicKey],
+    ) -> Result<Self, MessageEncryptionError> {
+        self.encrypted_message_with_rng(plaintext, decryptors, &mut rand_core::OsRng)
+    }
+
+    /// Same as [`encrypted_message`][Self::encrypted_message], with keys from the given random
+    /// number generator.
+    pub fn encrypted_message_with_rng(
+        self,
+        plaintext: PlaintextMessageV1,
+        decryptors: &[PublicKey],
+        rng: &mut impl rand_core::CryptoRngCore,
+    ) -> Result<Self, MessageEncryptionError> {
+        let encrypted = encrypt_message(&plaintext, decryptors, rng)?;
+        Ok(self.message(MessageV2::Encrypted(encrypted)))
+    }
+
     pub fn intent_header(mut self, intent_header: IntentHeaderV2) -> Self {
         self.root_subintent_header = Some(intent_header);
         self
@@ -705,6 +730,31 @@ impl TransactionV2Builder {
         self
     }
 
+    /// Sets the message to the plaintext encrypted for the given decryptors, with keys from the
+    /// operating system's random number generator - see [`encrypt_message`].
+    ///
+    /// This fails if there are no decryptors, or any of their public keys is invalid.
+    #[cfg(feature = "std")]
+    pub fn encrypted_message(
+        self,
+        plaintext: PlaintextMessageV1,
+        decryptors: &[PublicKey],
+    ) -> Result<Self, MessageEncryptionError> {
+        self.encrypted_message_with_rng(plaintext, decryptors, &mut rand_core::OsRng)
+    }
+
+    /// Same as [`encrypted_message`][Self::encrypted_message], with keys from the given random
+    /// number generator.
+    pub fn encrypted_message_with_rng(
+        self,
+        plaintext: PlaintextMessageV1,
+        decryptors: &[PublicKey],
+        rng: &mut impl rand_core::CryptoRngCore,
+    ) -> Result<Self, MessageEncryptionError> {
+        let encrypted = encrypt_message(&plaintext, decryptors, rng)?;
+        Ok(self.message(MessageV2::Encrypted(encrypted)))
+    }
+
     pub fn intent_header(mut self, intent_header: IntentHeaderV2) -> Self {
         self.transaction_intent_header = Some(intent_header);
         self
@@ -1048,7 +1098,8 @@ mod tests {
 
     use super::*;
     use crate::builder::*;
-    use crate::internal_prelude::Secp256k1PrivateKey;
+    use rand_chacha::rand_core::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
 
     #[test]
     #[allow(deprecated)]
@@ -1082,4 +1133,64 @@ mod tests {
             true
         );
     }
+
+    #[test]
+    fn encrypted_message_can_be_decrypted_by_each_decryptor() {
+        // Arrange
+        let notary = Secp256k1PrivateKey::from_u64(1).unwrap();
+        let decryptor = Ed25519PrivateKey::from_u64(2).unwrap();
+        let plaintext = PlaintextMessageV1::text("Hello, Radix!");
+
+        // Act
+        let transaction = TransactionV2Builder::new()
+            .intent_header(IntentHeaderV2 {
+                network_id: NetworkDefinition::simulator().id,
+                start_epoch_inclusive: Epoch::zero(),
+                end_epoch_exclusive: Epoch::of(100),
+                min_proposer_timestamp_inclusive: None,
+                max_proposer_timestamp_exclusive: None,
+                intent_discriminator: 0,
+            })
+            .encrypted_message_with_rng(
+                plaintext.clone(),
+                &[decryptor.public_key().into()],
+                &mut ChaCha20Rng::seed_from_u64(0),
+            )
+            .unwrap()
+            .manifest_builder(|builder| builder.drop_auth_zone_proofs())
+            .transaction_header(TransactionHeaderV2 {
+                notary_public_key: notary.public_key().into(),
+                notary_is_signatory: false,
+                tip_basis_points: 0,
+            })
+            .notarize(&notary)
+            .build_minimal();
+
+        // Assert
+        let MessageV2::Encrypted(encrypted) = &transaction
+            .signed_transaction_intent
+            .transaction_intent
+            .root_intent_core
+            .message
+        else {
+            panic!("The message must be encrypted");
+        };
+        assert_eq!(
+            decrypt_message(encrypted, &PrivateKey::Ed25519(decryptor)),
+            Ok(plaintext)
+        );
+    }
+
+    #[test]
+    fn encrypted_message_without_decryptors_is_an_error() {
+        // Act
+        let result = TransactionV2Builder::new().encrypted_message_with_rng(
+            PlaintextMessageV1::text("Hello, Radix!"),
+            &[],
+            &mut ChaCha20Rng::seed_from_u64(0),
+        );
+
+        // Assert
+        assert!(matches!(result, Err(MessageEncryptionError::NoDecryptors)));
+    }
 }
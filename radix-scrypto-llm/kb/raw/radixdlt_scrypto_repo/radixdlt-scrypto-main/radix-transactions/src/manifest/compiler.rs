@@ -7,6 +7,26 @@ pub enum CompileError {
     GeneratorError(generator::GeneratorError),
 }
 
+impl CompileError {
+    pub fn span(&self) -> token::Span {
+        match self {
+            CompileError::LexerError(err) => err.span,
+            CompileError::ParserError(err) => err.span,
+            CompileError::GeneratorError(err) => err.span,
+        }
+    }
+
+    /// Returns the title and the inline label of the error, for tools which render the
+    /// diagnostics themselves, such as editors.
+    pub fn title_and_label(&self) -> (String, String) {
+        match self {
+            CompileError::LexerError(err) => lexer::lexer_error_title_and_label(err),
+            CompileError::ParserError(err) => parser::parser_error_title_and_label(err),
+            CompileError::GeneratorError(err) => generator::generator_error_title_and_label(err),
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum CompileErrorDiagnosticsStyle {
     PlainText,
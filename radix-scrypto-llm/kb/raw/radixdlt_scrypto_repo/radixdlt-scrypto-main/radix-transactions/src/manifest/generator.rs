@@ -2077,6 +2077,12 @@ pub fn generator_error_diagnostics(
     err: GeneratorError,
     style: CompileErrorDiagnosticsStyle,
 ) -> String {
+    let (title, label) = generator_error_title_and_label(&err);
+    create_snippet(s, &err.span, &title, &label, style)
+}
+
+/// Returns the title and the inline label of the error, as used in the diagnostics.
+pub fn generator_error_title_and_label(err: &GeneratorError) -> (String, String) {
     // The title should be a little longer, and include context about what triggered
     // the error. The label is inline next to arrows pointing to the span which is
     // invalid, so can be shorter.
@@ -2086,7 +2092,7 @@ pub fn generator_error_diagnostics(
     //     ...
     //   12 |       Bytes(1u32),
     //      |       ^^^^^ <LABEL>
-    let (title, label) = match err.error_kind {
+    match &err.error_kind {
         GeneratorErrorKind::InvalidAstType {
             expected_value_kind,
             actual,
@@ -2303,9 +2309,7 @@ pub fn generator_error_diagnostics(
             let description = format!("cannot be decoded as a {type_name}");
             (title, description)
         }
-    };
-
-    create_snippet(s, &err.span, &title, &label, style)
+    }
 }
 
 #[cfg(test)]
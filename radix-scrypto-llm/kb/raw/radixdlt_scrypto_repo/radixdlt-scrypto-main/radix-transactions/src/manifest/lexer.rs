@@ -415,7 +415,13 @@ pub fn lexer_error_diagnostics(
     err: LexerError,
     style: CompileErrorDiagnosticsStyle,
 ) -> String {
-    let (title, label) = match err.error_kind {
+    let (title, label) = lexer_error_title_and_label(&err);
+    create_snippet(s, &err.span, &title, &label, style)
+}
+
+/// Returns the title and the inline label of the error, as used in the diagnostics.
+pub fn lexer_error_title_and_label(err: &LexerError) -> (String, String) {
+    match &err.error_kind {
         LexerErrorKind::UnexpectedEof => (
             "unexpected end of file".to_string(),
             "unexpected end of file".to_string(),
@@ -460,8 +466,7 @@ pub fn lexer_error_diagnostics(
             format!("missing unicode '{:X}' surrogate pair", value),
             "missing unicode surrogate pair".to_string(),
         ),
-    };
-    create_snippet(s, &err.span, &title, &label, style)
+    }
 }
 
 #[cfg(test)]
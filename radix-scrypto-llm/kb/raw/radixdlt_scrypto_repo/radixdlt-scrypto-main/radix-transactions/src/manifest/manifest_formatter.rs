@@ -0,0 +1,460 @@
+use crate::internal_prelude::*;
+use crate::manifest::decompiler::{output_instruction, DecompilationContext};
+
+#[derive(Debug, Clone, Default)]
+pub struct ManifestFormatterOptions {
+    /// Names the addresses which the manifest allocates, and their address reservations, after
+    /// their blueprint - e.g. `NamedAddress("account")` and
+    /// `AddressReservation("account_reservation")` rather than `NamedAddress("address1")` and
+    /// `AddressReservation("reservation1")`.
+    ///
+    /// The manifest language can only name the addresses which a manifest allocates, so the raw
+    /// addresses of existing entities are kept.
+    pub name_addresses: bool,
+    /// Replaces the names of buckets, proofs, address reservations, named addresses and named
+    /// intents with the default names of the decompiler, e.g. `bucket1`, `proof1`.
+    pub reset_object_names: bool,
+}
+
+#[derive(Debug, Clone)]
+pub enum ManifestFormatError {
+    CompileError(CompileError),
+    DecompileError(DecompileError),
+}
+
+impl From<CompileError> for ManifestFormatError {
+    fn from(error: CompileError) -> Self {
+        Self::CompileError(error)
+    }
+}
+
+impl From<DecompileError> for ManifestFormatError {
+    fn from(error: DecompileError) -> Self {
+        Self::DecompileError(error)
+    }
+}
+
+pub fn format_any_manifest(
+    manifest_string: &str,
+    manifest_kind: ManifestKind,
+    network: &NetworkDefinition,
+    options: &ManifestFormatterOptions,
+) -> Result<String, ManifestFormatError> {
+    match manifest_kind {
+        ManifestKind::V1 => {
+            format_manifest::<TransactionManifestV1>(manifest_string, network, options)
+        }
+        ManifestKind::SystemV1 => {
+            format_manifest::<SystemTransactionManifestV1>(manifest_string, network, options)
+        }
+        ManifestKind::V2 => {
+            format_manifest::<TransactionManifestV2>(manifest_string, network, options)
+        }
+        ManifestKind::SubintentV2 => {
+            format_manifest::<SubintentManifestV2>(manifest_string, network, options)
+        }
+    }
+}
+
+/// Re-emits the manifest in the canonical layout of the decompiler.
+///
+/// Comments are kept: those between instructions are kept in place, those on the line on which
+/// an instruction ends stay at the end of that instruction, and those within an instruction are
+/// moved before it. Runs of blank lines between instructions are collapsed into one blank line.
+///
+/// Blobs aren't needed to format a manifest, so any blob hash is accepted.
+pub fn format_manifest<M: BuildableManifest>(
+    manifest_string: &str,
+    network: &NetworkDefinition,
+    options: &ManifestFormatterOptions,
+) -> Result<String, ManifestFormatError> {
+    let manifest = compile_manifest::<M>(manifest_string, network, MockBlobProvider::new())?;
+    let decompiled_instructions = decompile_instructions(&manifest, network, options)?;
+
+    // The manifest compiled, so it parses, and the pseudo-instructions come first in both
+    // the source and the decompiled manifest - so the instructions line up one-to-one.
+    let tokens = lexer::tokenize(manifest_string).map_err(CompileError::LexerError)?;
+    let instructions = parser::Parser::new(tokens, parser::PARSER_MAX_DEPTH)
+        .map_err(CompileError::ParserError)?
+        .parse_manifest()
+        .map_err(CompileError::ParserError)?;
+
+    let mut comments = find_comments(manifest_string).into_iter().peekable();
+    let mut output = String::new();
+    let mut last_line_idx = None;
+    for (instruction, decompiled_instruction) in instructions.iter().zip(decompiled_instructions) {
+        while let Some(comment) =
+            comments.next_if(|comment| comment.full_index < instruction.span.end.full_index)
+        {
+            write_comment(&mut output, &mut last_line_idx, comment);
+        }
+
+        write_blank_line_if_separated(&mut output, last_line_idx, instruction.span.start.line_idx);
+        output.push_str(&decompiled_instruction);
+        last_line_idx = Some(instruction.span.end.line_idx);
+
+        if let Some(comment) = comments.next_if(|comment| {
+            !comment.starts_line && comment.line_idx == instruction.span.end.line_idx
+        }) {
+            output.pop();
+            output.push(' ');
+            output.push_str(&comment.text);
+            output.push('\n');
+        }
+    }
+    for comment in comments {
+        write_comment(&mut output, &mut last_line_idx, comment);
+    }
+
+    Ok(output)
+}
+
+/// Decompiles each instruction, including the pseudo-instructions, in the same way as
+/// [`decompile`].
+fn decompile_instructions(
+    manifest: &impl TypedReadableManifest,
+    network: &NetworkDefinition,
+    options: &ManifestFormatterOptions,
+) -> Result<Vec<String>, DecompileError> {
+    let address_bech32_encoder = AddressBech32Encoder::new(network);
+    let transaction_hash_encoder = TransactionHashBech32Encoder::new(network);
+    // Objects without a known name are given the default names of the decompiler.
+    let mut object_names = match manifest.get_known_object_names_ref() {
+        ManifestObjectNamesRef::Known(known) if !options.reset_object_names => known.clone(),
+        _ => KnownManifestObjectNames::default(),
+    };
+    if options.name_addresses {
+        name_addresses_after_blueprints(manifest, &mut object_names);
+    }
+    let mut context = DecompilationContext::new(
+        &address_bech32_encoder,
+        &transaction_hash_encoder,
+        ManifestObjectNamesRef::Known(&object_names),
+    );
+
+    let mut decompiled_instructions = Vec::new();
+    for preallocated_address in manifest.get_preallocated_addresses() {
+        decompiled_instructions
+            .push(preallocated_address.decompile_as_pseudo_instruction(&mut context)?);
+    }
+    for child_subintent in manifest.get_child_subintent_hashes() {
+        decompiled_instructions
+            .push(child_subintent.decompile_as_pseudo_instruction(&mut context)?);
+    }
+    for inst in manifest.get_typed_instructions() {
+        decompiled_instructions.push(inst.decompile(&mut context)?);
+    }
+
+    decompiled_instructions
+        .into_iter()
+        .map(|decompiled_instruction| {
+            let mut buf = String::new();
+            output_instruction(&mut buf, &context, decompiled_instruction)?;
+            Ok(buf)
+        })
+        .collect()
+}
+
+/// Names the address reservations and named addresses after the blueprint of the address they are
+/// for, in the order in which the decompiler allocates them: the preallocated addresses first, and
+/// then the addresses allocated by the instructions.
+fn name_addresses_after_blueprints(
+    manifest: &impl TypedReadableManifest,
+    object_names: &mut KnownManifestObjectNames,
+) {
+    object_names.address_reservation_names.clear();
+    object_names.address_names.clear();
+
+    for preallocated_address in manifest.get_preallocated_addresses() {
+        let blueprint_name = to_snake_case(&preallocated_address.blueprint_id.blueprint_name);
+        let reservation =
+            ManifestAddressReservation(object_names.address_reservation_names.len() as u32);
+        let name = unique_name(
+            &object_names.address_reservation_names,
+            format!("{}_reservation", blueprint_name),
+        );
+        object_names
+            .address_reservation_names
+            .insert(reservation, name);
+    }
+    for instruction in manifest.get_typed_instructions() {
+        let ManifestInstructionEffect::CreateAddressAndReservation { blueprint_name, .. } =
+            instruction.effect()
+        else {
+            continue;
+        };
+        let blueprint_name = to_snake_case(blueprint_name);
+        let reservation =
+            ManifestAddressReservation(object_names.address_reservation_names.len() as u32);
+        let name = unique_name(
+            &object_names.address_reservation_names,
+            format!("{}_reservation", blueprint_name),
+        );
+        object_names
+            .address_reservation_names
+            .insert(reservation, name);
+        let address = ManifestNamedAddress(object_names.address_names.len() as u32);
+        let name = unique_name(&object_names.address_names, blueprint_name);
+        object_names.address_names.insert(address, name);
+    }
+}
+
+/// Suffixes the name with a number if it is already taken, e.g. `account_2`.
+fn unique_name<K>(names: &IndexMap<K, String>, name: String) -> String {
+    let mut unique_name = name.clone();
+    let mut suffix = 1;
+    while names.values().any(|existing| existing == &unique_name) {
+        suffix += 1;
+        unique_name = format!("{}_{}", name, suffix);
+    }
+    unique_name
+}
+
+/// Converts a blueprint name such as `OneResourcePool` into `one_resource_pool`.
+fn to_snake_case(name: &str) -> String {
+    let mut snake_case = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 {
+                snake_case.push('_');
+            }
+            snake_case.push(c.to_ascii_lowercase());
+        } else if c.is_ascii_alphanumeric() {
+            snake_case.push(c);
+        } else {
+            snake_case.push('_');
+        }
+    }
+    snake_case
+}
+
+struct Comment {
+    /// The index of the `#`, in chars
+    full_index: usize,
+    line_idx: usize,
+    /// Whether the comment is the first thing on its line
+    starts_line: bool,
+    text: String,
+}
+
+/// Finds the comments in the manifest, skipping over `#` characters in string literals, such as
+/// the ones in integer non-fungible local ids.
+fn find_comments(manifest_string: &str) -> Vec<Comment> {
+    let mut comments = Vec::new();
+    let mut current: Option<Comment> = None;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut line_idx = 0;
+    let mut line_is_blank = true;
+    for (full_index, c) in manifest_string.chars().enumerate() {
+        if let Some(comment) = &mut current {
+            if c == '\n' {
+                comment.text.truncate(comment.text.trim_end().len());
+                comments.extend(current.take());
+            } else {
+                comment.text.push(c);
+            }
+        } else if in_string {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+        } else if c == '#' {
+            current = Some(Comment {
+                full_index,
+                line_idx,
+                starts_line: line_is_blank,
+                text: String::from("#"),
+            });
+        } else if c == '"' {
+            in_string = true;
+        }
+
+        if c == '\n' {
+            line_idx += 1;
+            line_is_blank = true;
+        } else if !c.is_whitespace() {
+            line_is_blank = false;
+        }
+    }
+    if let Some(mut comment) = current {
+        comment.text.truncate(comment.text.trim_end().len());
+        comments.push(comment);
+    }
+    comments
+}
+
+fn write_comment(output: &mut String, last_line_idx: &mut Option<usize>, comment: Comment) {
+    write_blank_line_if_separated(output, *last_line_idx, comment.line_idx);
+    output.push_str(&comment.text);
+    output.push('\n');
+    *last_line_idx = Some(comment.line_idx);
+}
+
+fn write_blank_line_if_separated(
+    output: &mut String,
+    last_line_idx: Option<usize>,
+    line_idx: usize,
+) {
+    if let Some(last_line_idx) = last_line_idx {
+        if line_idx > last_line_idx + 1 {
+            output.push('\n');
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(manifest_string: &str, options: &ManifestFormatterOptions) -> String {
+        let formatted = format_any_manifest(
+            manifest_string,
+            ManifestKind::V2,
+            &NetworkDefinition::simulator(),
+            options,
+        )
+        .unwrap();
+        let reformatted = format_any_manifest(
+            &formatted,
+            ManifestKind::V2,
+            &NetworkDefinition::simulator(),
+            options,
+        )
+        .unwrap();
+        assert_eq!(formatted, reformatted, "formatting is not idempotent");
+        formatted
+    }
+
+    #[test]
+    fn test_format_manifest_layout_and_comments() {
+        let manifest = r##"# Take everything
+TAKE_ALL_FROM_WORKTOP Address("resource_sim1tknxxxxxxxxxradxrdxxxxxxxxx009923554798xxxxxxxxxakj8n3") Bucket("xrd");
+
+
+RETURN_TO_WORKTOP
+  Bucket("xrd") # not a NonFungibleLocalId("#1#")
+  ;
+DROP_ALL_PROOFS;  # done
+"##;
+        let expected = r##"# Take everything
+TAKE_ALL_FROM_WORKTOP
+    Address("resource_sim1tknxxxxxxxxxradxrdxxxxxxxxx009923554798xxxxxxxxxakj8n3")
+    Bucket("xrd")
+;
+
+# not a NonFungibleLocalId("#1#")
+RETURN_TO_WORKTOP
+    Bucket("xrd")
+;
+DROP_ALL_PROOFS; # done
+"##;
+        assert_eq!(
+            format(manifest, &ManifestFormatterOptions::default()),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_format_manifest_with_reset_object_names() {
+        let manifest = r##"TAKE_ALL_FROM_WORKTOP Address("resource_sim1tknxxxxxxxxxradxrdxxxxxxxxx009923554798xxxxxxxxxakj8n3") Bucket("xrd");
+RETURN_TO_WORKTOP Bucket("xrd");
+"##;
+        let expected = r##"TAKE_ALL_FROM_WORKTOP
+    Address("resource_sim1tknxxxxxxxxxradxrdxxxxxxxxx009923554798xxxxxxxxxakj8n3")
+    Bucket("bucket1")
+;
+RETURN_TO_WORKTOP
+    Bucket("bucket1")
+;
+"##;
+        assert_eq!(
+            format(
+                manifest,
+                &ManifestFormatterOptions {
+                    reset_object_names: true,
+                    ..Default::default()
+                }
+            ),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_format_manifest_with_named_addresses() {
+        let manifest = r##"ALLOCATE_GLOBAL_ADDRESS
+    Address("package_sim1pkgxxxxxxxxxaccntxxxxxxxxxx000929625493xxxxxxxxxrn8jm6")
+    "Account"
+    AddressReservation("reservation1")
+    NamedAddress("address1")
+;
+ALLOCATE_GLOBAL_ADDRESS
+    Address("package_sim1pkgxxxxxxxxxaccntxxxxxxxxxx000929625493xxxxxxxxxrn8jm6")
+    "Account"
+    AddressReservation("my_reservation")
+    NamedAddress("my_account")
+;
+ALLOCATE_GLOBAL_ADDRESS
+    Address("package_sim1pkgxxxxxxxxxplxxxxxxxxxxxxx020379220524xxxxxxxxxl5e8k6")
+    "OneResourcePool"
+    AddressReservation("reservation3")
+    NamedAddress("address3")
+;
+CALL_METHOD
+    NamedAddress("my_account")
+    "deposit_batch"
+    Expression("ENTIRE_WORKTOP")
+;
+"##;
+        let expected = r##"ALLOCATE_GLOBAL_ADDRESS
+    Address("package_sim1pkgxxxxxxxxxaccntxxxxxxxxxx000929625493xxxxxxxxxrn8jm6")
+    "Account"
+    AddressReservation("account_reservation")
+    NamedAddress("account")
+;
+ALLOCATE_GLOBAL_ADDRESS
+    Address("package_sim1pkgxxxxxxxxxaccntxxxxxxxxxx000929625493xxxxxxxxxrn8jm6")
+    "Account"
+    AddressReservation("account_reservation_2")
+    NamedAddress("account_2")
+;
+ALLOCATE_GLOBAL_ADDRESS
+    Address("package_sim1pkgxxxxxxxxxplxxxxxxxxxxxxx020379220524xxxxxxxxxl5e8k6")
+    "OneResourcePool"
+    AddressReservation("one_resource_pool_reservation")
+    NamedAddress("one_resource_pool")
+;
+CALL_METHOD
+    NamedAddress("account_2")
+    "deposit_batch"
+    Expression("ENTIRE_WORKTOP")
+;
+"##;
+        assert_eq!(
+            format(
+                manifest,
+                &ManifestFormatterOptions {
+                    name_addresses: true,
+                    ..Default::default()
+                }
+            ),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_format_manifest_rejects_invalid_manifest() {
+        assert!(matches!(
+            format_any_manifest(
+                "TAKE_ALL_FROM_WORKTOP;",
+                ManifestKind::V2,
+                &NetworkDefinition::simulator(),
+                &ManifestFormatterOptions::default(),
+            ),
+            Err(ManifestFormatError::CompileError(_))
+        ));
+    }
+}
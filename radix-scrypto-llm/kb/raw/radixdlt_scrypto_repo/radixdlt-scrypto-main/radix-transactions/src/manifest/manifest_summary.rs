@@ -0,0 +1,664 @@
+use crate::internal_prelude::*;
+use crate::manifest::static_resource_movements::*;
+use crate::manifest::*;
+use core::ops::ControlFlow;
+use radix_engine_interface::api::ModuleId;
+use radix_engine_interface::blueprints::account::*;
+use sbor::rust::fmt::Write;
+
+/// What a manifest does, as far as can be determined without executing it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ManifestSummary {
+    pub account_withdrawals: Vec<AccountMovementSummary>,
+    pub account_deposits: Vec<AccountMovementSummary>,
+    pub assertions: Vec<InstructionSummary>,
+    pub entities_called: Vec<InstructionSummary>,
+    pub proofs_created: Vec<InstructionSummary>,
+    /// Resources sent or returned by invocations whose effects aren't statically known.
+    pub unknown_resource_flows: Vec<InstructionSummary>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccountMovementSummary {
+    pub account: String,
+    /// The resource moved, or `None` for resources which can't be determined statically.
+    pub resource: Option<String>,
+    pub bounds: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InstructionSummary {
+    pub instruction_index: usize,
+    pub description: String,
+}
+
+impl ManifestSummary {
+    /// Validates the manifest and statically tracks the resources it moves.
+    pub fn new(
+        manifest: &AnyManifest,
+        network: &NetworkDefinition,
+    ) -> Result<Self, StaticResourceMovementsError> {
+        let encoder = AddressBech32Encoder::new(network);
+        let object_names = manifest.get_known_object_names_ref();
+
+        let mut visitor = SummaryVisitor {
+            describer: Describer {
+                encoder: &encoder,
+                object_names,
+            },
+            resource_movements: StaticResourceMovementsVisitor::new(manifest.is_subintent()),
+            bucket_resources: index_map_new(),
+            current_instruction: 0,
+            assertions: Vec::new(),
+            proofs_created: Vec::new(),
+        };
+        StaticManifestInterpreter::new(ValidationRuleset::all(), manifest)
+            .validate_and_apply_visitor(&mut visitor)?;
+        let SummaryVisitor {
+            describer,
+            resource_movements,
+            assertions,
+            proofs_created,
+            ..
+        } = visitor;
+        let output = resource_movements.output();
+
+        let mut account_withdrawals = Vec::new();
+        for (account, withdraws) in output.resolve_account_withdraws() {
+            for withdraw in withdraws {
+                let (resource, bounds) = match withdraw {
+                    AccountWithdraw::Amount(resource, amount) => {
+                        (resource, format!("exactly {}", amount))
+                    }
+                    AccountWithdraw::Ids(resource, ids) => {
+                        (resource, format!("exactly {}", describe_ids(&ids)))
+                    }
+                };
+                account_withdrawals.push(AccountMovementSummary {
+                    account: account.to_string(&encoder),
+                    resource: Some(resource.to_string(&encoder)),
+                    bounds,
+                });
+            }
+        }
+
+        let mut account_deposits = Vec::new();
+        for (account, deposits) in output.resolve_account_deposits() {
+            for deposit in deposits {
+                for (resource, bounds) in deposit.specified_resources() {
+                    account_deposits.push(AccountMovementSummary {
+                        account: account.to_string(&encoder),
+                        resource: Some(resource.to_string(&encoder)),
+                        bounds: describe_simple_bounds(bounds),
+                    });
+                }
+                if deposit.unspecified_resources().may_be_present() {
+                    account_deposits.push(AccountMovementSummary {
+                        account: account.to_string(&encoder),
+                        resource: None,
+                        bounds: "an unknown amount".to_owned(),
+                    });
+                }
+            }
+        }
+
+        let mut entities_called = Vec::new();
+        let mut unknown_resource_flows = Vec::new();
+        for (index, invocation) in &output.invocation_static_information {
+            let invocation_description = describer.invocation(&invocation.kind);
+            if invocation.input.unspecified_resources().may_be_present() {
+                unknown_resource_flows.push(InstructionSummary {
+                    instruction_index: *index,
+                    description: format!(
+                        "Resources which can't be determined statically are sent to {}",
+                        invocation_description
+                    ),
+                });
+            }
+            if invocation.output.unspecified_resources().may_be_present() {
+                unknown_resource_flows.push(InstructionSummary {
+                    instruction_index: *index,
+                    description: format!(
+                        "{} may return resources which can't be determined statically",
+                        invocation_description
+                    ),
+                });
+            }
+            entities_called.push(InstructionSummary {
+                instruction_index: *index,
+                description: invocation_description,
+            });
+        }
+
+        Ok(Self {
+            account_withdrawals,
+            account_deposits,
+            assertions,
+            entities_called,
+            proofs_created,
+            unknown_resource_flows,
+        })
+    }
+}
+
+/// Wraps the [`StaticResourceMovementsVisitor`], recording the assertions and proofs along the
+/// way.
+struct SummaryVisitor<'a> {
+    describer: Describer<'a>,
+    resource_movements: StaticResourceMovementsVisitor,
+    bucket_resources: IndexMap<ManifestBucket, ResourceAddress>,
+    current_instruction: usize,
+    assertions: Vec<InstructionSummary>,
+    proofs_created: Vec<InstructionSummary>,
+}
+
+impl<'a> ManifestInterpretationVisitor for SummaryVisitor<'a> {
+    type Output = StaticResourceMovementsError;
+
+    fn on_start_instruction(&mut self, event: OnStartInstruction) -> ControlFlow<Self::Output> {
+        self.current_instruction = event.index;
+        self.resource_movements.on_start_instruction(event)
+    }
+
+    fn on_end_instruction(&mut self, event: OnEndInstruction) -> ControlFlow<Self::Output> {
+        if let ManifestInstructionEffect::Invocation { kind, args } = event.effect {
+            if let Some(description) = self.describer.account_proof(&kind, args) {
+                self.proofs_created.push(InstructionSummary {
+                    instruction_index: event.index,
+                    description,
+                });
+            }
+        }
+        self.resource_movements.on_end_instruction(event)
+    }
+
+    fn on_new_bucket(&mut self, event: OnNewBucket) -> ControlFlow<Self::Output> {
+        self.bucket_resources
+            .insert(event.bucket, *event.state.source_amount.resource_address());
+        self.resource_movements.on_new_bucket(event)
+    }
+
+    fn on_consume_bucket(&mut self, event: OnConsumeBucket) -> ControlFlow<Self::Output> {
+        self.resource_movements.on_consume_bucket(event)
+    }
+
+    fn on_new_proof(&mut self, event: OnNewProof) -> ControlFlow<Self::Output> {
+        let description = self
+            .describer
+            .proof_source(&event.state.source_amount, &self.bucket_resources);
+        self.proofs_created.push(InstructionSummary {
+            instruction_index: self.current_instruction,
+            description,
+        });
+        ControlFlow::Continue(())
+    }
+
+    fn on_pass_expression(&mut self, event: OnPassExpression) -> ControlFlow<Self::Output> {
+        self.resource_movements.on_pass_expression(event)
+    }
+
+    fn on_resource_assertion(&mut self, event: OnResourceAssertion) -> ControlFlow<Self::Output> {
+        self.assertions.push(InstructionSummary {
+            instruction_index: self.current_instruction,
+            description: self.describer.assertion(&event.assertion),
+        });
+        self.resource_movements.on_resource_assertion(event)
+    }
+
+    fn on_new_named_address(&mut self, event: OnNewNamedAddress) -> ControlFlow<Self::Output> {
+        self.resource_movements.on_new_named_address(event)
+    }
+
+    fn on_finish(&mut self, event: OnFinish) -> ControlFlow<Self::Output> {
+        self.resource_movements.on_finish(event)
+    }
+}
+
+struct Describer<'a> {
+    encoder: &'a AddressBech32Encoder,
+    object_names: ManifestObjectNamesRef<'a>,
+}
+
+impl<'a> Describer<'a> {
+    fn invocation(&self, kind: &OwnedInvocationKind) -> String {
+        match kind {
+            OwnedInvocationKind::Method {
+                address,
+                module_id,
+                method,
+            } => {
+                let address = match address {
+                    ManifestGlobalAddress::Static(address) => address.to_string(self.encoder),
+                    ManifestGlobalAddress::Named(named_address) => format!(
+                        "NamedAddress(\"{}\")",
+                        self.object_names.address_name(*named_address)
+                    ),
+                };
+                match module_id {
+                    ModuleId::Main => format!("{} {}", address, method),
+                    module_id => format!("{} {} (on the {:?} module)", address, method, module_id),
+                }
+            }
+            OwnedInvocationKind::Function {
+                address,
+                blueprint,
+                function,
+            } => {
+                let address = match address {
+                    ManifestPackageAddress::Static(address) => address.to_string(self.encoder),
+                    ManifestPackageAddress::Named(named_address) => format!(
+                        "NamedAddress(\"{}\")",
+                        self.object_names.address_name(*named_address)
+                    ),
+                };
+                format!("{} {}::{}", address, blueprint, function)
+            }
+            OwnedInvocationKind::DirectMethod { address, method } => {
+                format!(
+                    "{} {} (direct access)",
+                    address.to_string(self.encoder),
+                    method
+                )
+            }
+            OwnedInvocationKind::YieldToParent => "the parent intent".to_owned(),
+            OwnedInvocationKind::YieldToChild { child_index } => format!(
+                "the child intent NamedIntent(\"{}\")",
+                self.object_names.intent_name(*child_index)
+            ),
+        }
+    }
+
+    /// Describes the proof created by an account `create_proof_of_amount` or
+    /// `create_proof_of_non_fungibles` call, if that is what the invocation is.
+    fn account_proof(&self, kind: &InvocationKind, args: &ManifestValue) -> Option<String> {
+        let InvocationKind::Method {
+            address: ManifestGlobalAddress::Static(global_address),
+            module_id: ModuleId::Main,
+            method,
+        } = kind
+        else {
+            return None;
+        };
+        let account = ComponentAddress::try_from(*global_address)
+            .ok()
+            .filter(|address| address.as_node_id().is_global_account())?;
+        let encoded_args = manifest_encode(args).ok()?;
+        let (amount, resource_address) = match *method {
+            ACCOUNT_CREATE_PROOF_OF_AMOUNT_IDENT => {
+                let AccountCreateProofOfAmountManifestInput {
+                    resource_address,
+                    amount,
+                } = manifest_decode(&encoded_args).ok()?;
+                (amount.to_string(), resource_address)
+            }
+            ACCOUNT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT => {
+                let AccountCreateProofOfNonFungiblesManifestInput {
+                    resource_address,
+                    ids,
+                } = manifest_decode(&encoded_args).ok()?;
+                (describe_ids(&ids), resource_address)
+            }
+            _ => return None,
+        };
+        let resource_address = match resource_address {
+            ManifestResourceAddress::Static(address) => address.to_string(self.encoder),
+            ManifestResourceAddress::Named(named_address) => format!(
+                "NamedAddress(\"{}\")",
+                self.object_names.address_name(named_address)
+            ),
+        };
+        Some(format!(
+            "{} of {} in account {}",
+            amount,
+            resource_address,
+            account.to_string(self.encoder)
+        ))
+    }
+
+    fn proof_source(
+        &self,
+        source_amount: &ProofSourceAmount,
+        bucket_resources: &IndexMap<ManifestBucket, ResourceAddress>,
+    ) -> String {
+        let bucket_resource = |bucket: &ManifestBucket| match bucket_resources.get(bucket) {
+            Some(resource) => resource.to_string(self.encoder),
+            None => "an unknown resource".to_owned(),
+        };
+        match source_amount {
+            ProofSourceAmount::AuthZonePopLastAddedProof => {
+                "The last proof added to the auth zone".to_owned()
+            }
+            ProofSourceAmount::AuthZoneAllOf { resource_address } => format!(
+                "All of {} in the auth zone",
+                resource_address.to_string(self.encoder)
+            ),
+            ProofSourceAmount::AuthZoneAmount {
+                resource_address,
+                amount,
+            } => format!(
+                "{} of {} in the auth zone",
+                amount,
+                resource_address.to_string(self.encoder)
+            ),
+            ProofSourceAmount::AuthZoneNonFungibles {
+                resource_address,
+                ids,
+            } => format!(
+                "{} of {} in the auth zone",
+                describe_ids(ids.iter()),
+                resource_address.to_string(self.encoder)
+            ),
+            ProofSourceAmount::BucketAllOf { bucket } => format!(
+                "All of {} in bucket \"{}\"",
+                bucket_resource(bucket),
+                self.object_names.bucket_name(*bucket)
+            ),
+            ProofSourceAmount::BucketAmount { bucket, amount } => format!(
+                "{} of {} in bucket \"{}\"",
+                amount,
+                bucket_resource(bucket),
+                self.object_names.bucket_name(*bucket)
+            ),
+            ProofSourceAmount::BucketNonFungibles { bucket, ids } => format!(
+                "{} of {} in bucket \"{}\"",
+                describe_ids(ids.iter()),
+                bucket_resource(bucket),
+                self.object_names.bucket_name(*bucket)
+            ),
+        }
+    }
+
+    fn assertion(&self, assertion: &ResourceAssertion) -> String {
+        match assertion {
+            ResourceAssertion::Worktop(WorktopAssertion::ResourceNonZeroAmount {
+                resource_address,
+            }) => format!(
+                "The worktop contains a non-zero amount of {}",
+                resource_address.to_string(self.encoder)
+            ),
+            ResourceAssertion::Worktop(WorktopAssertion::ResourceAtLeastAmount {
+                resource_address,
+                amount,
+            }) => format!(
+                "The worktop contains at least {} of {}",
+                amount,
+                resource_address.to_string(self.encoder)
+            ),
+            ResourceAssertion::Worktop(WorktopAssertion::ResourceAtLeastNonFungibles {
+                resource_address,
+                ids,
+            }) => format!(
+                "The worktop contains at least {} of {}",
+                describe_ids(ids.iter()),
+                resource_address.to_string(self.encoder)
+            ),
+            ResourceAssertion::Worktop(WorktopAssertion::ResourcesOnly { constraints }) => {
+                format!(
+                    "The worktop contains only {}",
+                    self.constraints(constraints)
+                )
+            }
+            ResourceAssertion::Worktop(WorktopAssertion::ResourcesInclude { constraints }) => {
+                format!("The worktop contains {}", self.constraints(constraints))
+            }
+            ResourceAssertion::NextCall(NextCallAssertion::ReturnsOnly { constraints }) => {
+                format!(
+                    "The next call returns only {}",
+                    self.constraints(constraints)
+                )
+            }
+            ResourceAssertion::NextCall(NextCallAssertion::ReturnsInclude { constraints }) => {
+                format!(
+                    "The next call returns at least {}",
+                    self.constraints(constraints)
+                )
+            }
+            ResourceAssertion::Bucket(BucketAssertion::Contents { bucket, constraint }) => {
+                format!(
+                    "Bucket \"{}\" contains {}",
+                    self.object_names.bucket_name(*bucket),
+                    describe_constraint(constraint)
+                )
+            }
+        }
+    }
+
+    fn constraints(&self, constraints: &ManifestResourceConstraints) -> String {
+        if constraints.specified_resources().is_empty() {
+            return "no resources".to_owned();
+        }
+        constraints
+            .iter()
+            .map(|(resource_address, constraint)| {
+                format!(
+                    "{} of {}",
+                    describe_constraint(constraint),
+                    resource_address.to_string(self.encoder)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+fn describe_constraint(constraint: &ManifestResourceConstraint) -> String {
+    match constraint {
+        ManifestResourceConstraint::NonZeroAmount => "a non-zero amount".to_owned(),
+        ManifestResourceConstraint::ExactAmount(amount) => format!("exactly {}", amount),
+        ManifestResourceConstraint::AtLeastAmount(amount) => format!("at least {}", amount),
+        ManifestResourceConstraint::ExactNonFungibles(ids) => {
+            format!("exactly {}", describe_ids(ids))
+        }
+        ManifestResourceConstraint::AtLeastNonFungibles(ids) => {
+            format!("at least {}", describe_ids(ids))
+        }
+        ManifestResourceConstraint::General(constraint) => describe_bounds(
+            &constraint.required_ids,
+            &constraint.lower_bound,
+            &constraint.upper_bound,
+            &constraint.allowed_ids,
+        ),
+    }
+}
+
+fn describe_simple_bounds(bounds: &SimpleResourceBounds) -> String {
+    match bounds {
+        SimpleResourceBounds::Fungible(bounds) => match bounds {
+            SimpleFungibleResourceBounds::Exact(amount) => format!("exactly {}", amount),
+            SimpleFungibleResourceBounds::AtMost(amount) => format!("at most {}", amount),
+            SimpleFungibleResourceBounds::AtLeast(amount) => format!("at least {}", amount),
+            SimpleFungibleResourceBounds::Between(lower, upper) => {
+                format!("between {} and {}", lower, upper)
+            }
+            SimpleFungibleResourceBounds::UnknownAmount => "an unknown amount".to_owned(),
+        },
+        SimpleResourceBounds::NonFungible(bounds) => match bounds {
+            SimpleNonFungibleResourceBounds::Exact {
+                amount,
+                certain_ids,
+            } if certain_ids.is_empty() => format!("exactly {} non-fungibles", amount),
+            SimpleNonFungibleResourceBounds::Exact { certain_ids, .. } => {
+                format!("exactly {}", describe_ids(certain_ids))
+            }
+            SimpleNonFungibleResourceBounds::NotExact {
+                certain_ids,
+                lower_bound,
+                upper_bound,
+                allowed_ids,
+            } => describe_bounds(certain_ids, lower_bound, upper_bound, allowed_ids),
+        },
+    }
+}
+
+fn describe_bounds(
+    required_ids: &IndexSet<NonFungibleLocalId>,
+    lower_bound: &LowerBound,
+    upper_bound: &UpperBound,
+    allowed_ids: &AllowedIds,
+) -> String {
+    let mut description = match (lower_bound, upper_bound) {
+        (LowerBound::NonZero, UpperBound::Unbounded) => "a non-zero amount".to_owned(),
+        (LowerBound::NonZero, UpperBound::Inclusive(upper)) => {
+            format!("a non-zero amount of at most {}", upper)
+        }
+        (LowerBound::Inclusive(lower), UpperBound::Unbounded) if lower.is_zero() => {
+            "an unknown amount".to_owned()
+        }
+        (LowerBound::Inclusive(lower), UpperBound::Unbounded) => format!("at least {}", lower),
+        (LowerBound::Inclusive(lower), UpperBound::Inclusive(upper)) if lower == upper => {
+            format!("exactly {}", lower)
+        }
+        (LowerBound::Inclusive(lower), UpperBound::Inclusive(upper)) => {
+            format!("between {} and {}", lower, upper)
+        }
+    };
+    if !required_ids.is_empty() {
+        write!(description, ", including {}", describe_ids(required_ids)).unwrap();
+    }
+    if let AllowedIds::Allowlist(allowed_ids) = allowed_ids {
+        write!(description, ", only from {}", describe_ids(allowed_ids)).unwrap();
+    }
+    description
+}
+
+fn describe_ids<'i>(ids: impl IntoIterator<Item = &'i NonFungibleLocalId>) -> String {
+    let ids = ids.into_iter().map(|id| id.to_string()).collect::<Vec<_>>();
+    format!("non-fungibles [{}]", ids.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summarize(manifest: &str) -> ManifestSummary {
+        let network = NetworkDefinition::simulator();
+        let manifest =
+            compile_any_manifest(manifest, ManifestKind::V2, &network, BlobProvider::new())
+                .unwrap();
+        ManifestSummary::new(&manifest, &network).unwrap()
+    }
+
+    fn account_address(seed: u64) -> String {
+        let public_key = Secp256k1PrivateKey::from_u64(seed).unwrap().public_key();
+        ComponentAddress::preallocated_account_from_public_key(&public_key)
+            .to_string(&AddressBech32Encoder::for_simulator())
+    }
+
+    #[test]
+    fn test_transfer_summary() {
+        let from = account_address(1);
+        let to = account_address(2);
+        let xrd = XRD.to_string(&AddressBech32Encoder::for_simulator());
+        let summary = summarize(&format!(
+            r#"
+            CALL_METHOD Address("{from}") "lock_fee" Decimal("10");
+            CALL_METHOD Address("{from}") "withdraw" Address("{xrd}") Decimal("100");
+            ASSERT_WORKTOP_CONTAINS Address("{xrd}") Decimal("100");
+            TAKE_ALL_FROM_WORKTOP Address("{xrd}") Bucket("xrd");
+            CREATE_PROOF_FROM_BUCKET_OF_ALL Bucket("xrd") Proof("proof");
+            DROP_PROOF Proof("proof");
+            CALL_METHOD Address("{to}") "try_deposit_or_abort" Bucket("xrd") Enum<0u8>();
+            "#
+        ));
+
+        assert_eq!(
+            summary.account_withdrawals,
+            vec![AccountMovementSummary {
+                account: from.clone(),
+                resource: Some(xrd.clone()),
+                bounds: "exactly 100".to_owned(),
+            }]
+        );
+        assert_eq!(
+            summary.account_deposits,
+            vec![AccountMovementSummary {
+                account: to.clone(),
+                resource: Some(xrd.clone()),
+                bounds: "exactly 100".to_owned(),
+            }]
+        );
+        assert_eq!(
+            summary.assertions,
+            vec![InstructionSummary {
+                instruction_index: 2,
+                description: format!("The worktop contains at least 100 of {}", xrd),
+            }]
+        );
+        assert_eq!(
+            summary.proofs_created,
+            vec![InstructionSummary {
+                instruction_index: 4,
+                description: format!("All of {} in bucket \"xrd\"", xrd),
+            }]
+        );
+        assert_eq!(
+            summary
+                .entities_called
+                .iter()
+                .map(|entity| entity.description.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                format!("{} lock_fee", from).as_str(),
+                format!("{} withdraw", from).as_str(),
+                format!("{} try_deposit_or_abort", to).as_str(),
+            ]
+        );
+        assert!(summary.unknown_resource_flows.is_empty());
+    }
+
+    #[test]
+    fn test_account_proofs_are_reported() {
+        let account = account_address(1);
+        let encoder = AddressBech32Encoder::for_simulator();
+        let xrd = XRD.to_string(&encoder);
+        let badge = ACCOUNT_OWNER_BADGE.to_string(&encoder);
+        let summary = summarize(&format!(
+            r##"
+            CALL_METHOD Address("{account}") "lock_fee" Decimal("10");
+            CALL_METHOD Address("{account}") "create_proof_of_amount" Address("{xrd}") Decimal("5");
+            CALL_METHOD Address("{account}") "create_proof_of_non_fungibles" Address("{badge}") Array<NonFungibleLocalId>(NonFungibleLocalId("#1#"));
+            "##
+        ));
+
+        assert_eq!(
+            summary.proofs_created,
+            vec![
+                InstructionSummary {
+                    instruction_index: 1,
+                    description: format!("5 of {} in account {}", xrd, account),
+                },
+                InstructionSummary {
+                    instruction_index: 2,
+                    description: format!("non-fungibles [#1#] of {} in account {}", badge, account),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unknown_resource_flows_are_reported() {
+        let account = account_address(1);
+        let summary = summarize(&format!(
+            r#"
+            CALL_METHOD Address("{account}") "lock_fee" Decimal("10");
+            CALL_METHOD Address("component_sim1cptxxxxxxxxxfaucetxxxxxxxxx000527798379xxxxxxxxxhkrefh") "free";
+            CALL_METHOD Address("{account}") "deposit_batch" Expression("ENTIRE_WORKTOP");
+            "#
+        ));
+
+        assert_eq!(summary.unknown_resource_flows.len(), 2);
+        assert_eq!(summary.unknown_resource_flows[0].instruction_index, 1);
+        assert_eq!(summary.unknown_resource_flows[1].instruction_index, 2);
+        assert_eq!(
+            summary.account_deposits,
+            vec![AccountMovementSummary {
+                account,
+                resource: None,
+                bounds: "an unknown amount".to_owned(),
+            }]
+        );
+    }
+}
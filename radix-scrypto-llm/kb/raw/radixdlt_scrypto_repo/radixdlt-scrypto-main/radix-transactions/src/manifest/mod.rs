@@ -11,9 +11,11 @@ pub mod e2e;
 pub mod generator;
 pub mod lexer;
 mod manifest_enums;
+mod manifest_formatter;
 mod manifest_instruction_effects;
 mod manifest_instructions;
 mod manifest_naming;
+mod manifest_summary;
 mod manifest_traits;
 pub mod parser;
 mod static_manifest_interpreter;
@@ -26,8 +28,10 @@ pub use blob_provider::*;
 pub use compiler::*;
 pub use decompiler::{decompile, decompile_any, DecompileError};
 pub use manifest_enums::*;
+pub use manifest_formatter::*;
 pub use manifest_instruction_effects::*;
 pub use manifest_instructions::*;
 pub use manifest_naming::*;
+pub use manifest_summary::*;
 pub use manifest_traits::*;
 pub use static_manifest_interpreter::*;
@@ -1113,7 +1113,13 @@ pub fn parser_error_diagnostics(
     err: ParserError,
     style: CompileErrorDiagnosticsStyle,
 ) -> String {
-    let (title, label) = match err.error_kind {
+    let (title, label) = parser_error_title_and_label(&err);
+    create_snippet(s, &err.span, &title, &label, style)
+}
+
+/// Returns the title and the inline label of the error, as used in the diagnostics.
+pub fn parser_error_title_and_label(err: &ParserError) -> (String, String) {
+    match &err.error_kind {
         ParserErrorKind::UnexpectedEof => (
             "unexpected end of file".to_string(),
             "unexpected end of file".to_string(),
@@ -1149,9 +1155,7 @@ pub fn parser_error_diagnostics(
             let title = format!("unknown enum discriminator found '{}'", actual);
             (title, "unknown enum discriminator".to_string())
         }
-    };
-
-    create_snippet(s, &err.span, &title, &label, style)
+    }
 }
 
 #[cfg(test)]
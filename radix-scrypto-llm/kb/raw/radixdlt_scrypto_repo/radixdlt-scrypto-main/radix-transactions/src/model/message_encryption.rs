@@ -0,0 +1,568 @@
+//! The canonical "MultiPartyECIES" encryption of transaction messages - see [`EncryptedMessageV2`].
+
+use crate::internal_prelude::*;
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes128Gcm, Aes256Gcm, Nonce};
+use aes_kw::KekAes256;
+use blake2::digest::consts::U32;
+use blake2::Blake2b;
+use hkdf::SimpleHkdf;
+use rand_core::CryptoRngCore;
+
+const AES_GCM_NONCE_LENGTH: usize = 12;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageEncryptionError {
+    NoDecryptors,
+    InvalidDecryptorPublicKey(PublicKey),
+    EncodeError(EncodeError),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageDecryptionError {
+    NotADecryptor,
+    MismatchingDecryptorCurve { expected: CurveType },
+    InvalidEphemeralPublicKey,
+    KeyUnwrapFailed,
+    PayloadDecryptionFailed,
+    DecodeError(DecodeError),
+}
+
+/// Encrypts the message for the given decryptors, with a fresh AES key, nonce, and ephemeral key
+/// for each of their curve types from the given random number generator.
+///
+/// Any of the decryptors can then decrypt it with [`decrypt_message`].
+pub fn encrypt_message(
+    plaintext: &PlaintextMessageV1,
+    decryptors: &[PublicKey],
+    rng: &mut impl CryptoRngCore,
+) -> Result<EncryptedMessageV2, MessageEncryptionError> {
+    let mut aes_key = [0u8; 32];
+    rng.fill_bytes(&mut aes_key);
+    let (nonce, ephemeral_keys) = random_nonce_and_ephemeral_keys(rng);
+    encrypt_message_with(plaintext, decryptors, &aes_key, &nonce, &ephemeral_keys)
+}
+
+/// Decrypts a message which the given private key is one of the decryptors of.
+pub fn decrypt_message(
+    encrypted: &EncryptedMessageV2,
+    private_key: &PrivateKey,
+) -> Result<PlaintextMessageV1, MessageDecryptionError> {
+    let (wrapped_key, shared_secret) = match (
+        private_key,
+        encrypted.decryptors_by_curve.get(&curve_type(private_key)),
+    ) {
+        (
+            PrivateKey::Ed25519(private_key),
+            Some(DecryptorsByCurveV2::Ed25519 {
+                dh_ephemeral_public_key,
+                decryptors,
+            }),
+        ) => (
+            find_wrapped_key(decryptors, private_key.public_key().into())?,
+            private_key
+                .ecdh_shared_secret(dh_ephemeral_public_key)
+                .map_err(|_| MessageDecryptionError::InvalidEphemeralPublicKey)?,
+        ),
+        (
+            PrivateKey::Secp256k1(private_key),
+            Some(DecryptorsByCurveV2::Secp256k1 {
+                dh_ephemeral_public_key,
+                decryptors,
+            }),
+        ) => (
+            find_wrapped_key(decryptors, private_key.public_key().into())?,
+            private_key
+                .ecdh_shared_secret(dh_ephemeral_public_key)
+                .map_err(|_| MessageDecryptionError::InvalidEphemeralPublicKey)?,
+        ),
+        (_, Some(_)) => {
+            return Err(MessageDecryptionError::MismatchingDecryptorCurve {
+                expected: curve_type(private_key),
+            })
+        }
+        (_, None) => return Err(MessageDecryptionError::NotADecryptor),
+    };
+
+    let mut aes_key = [0u8; 32];
+    unwrap_key(&shared_secret, &wrapped_key.0, &mut aes_key)?;
+    let payload = decrypt_payload::<Aes256Gcm>(&aes_key, &encrypted.encrypted)?;
+    manifest_decode(&payload).map_err(MessageDecryptionError::DecodeError)
+}
+
+/// Decrypts a message of a V1 transaction, which only differs from V2 in its 128-bit AES key.
+pub fn decrypt_message_v1(
+    encrypted: &EncryptedMessageV1,
+    private_key: &PrivateKey,
+) -> Result<PlaintextMessageV1, MessageDecryptionError> {
+    let (wrapped_key, shared_secret) = match (
+        private_key,
+        encrypted.decryptors_by_curve.get(&curve_type(private_key)),
+    ) {
+        (
+            PrivateKey::Ed25519(private_key),
+            Some(DecryptorsByCurve::Ed25519 {
+                dh_ephemeral_public_key,
+                decryptors,
+            }),
+        ) => (
+            find_wrapped_key(decryptors, private_key.public_key().into())?,
+            private_key
+                .ecdh_shared_secret(dh_ephemeral_public_key)
+                .map_err(|_| MessageDecryptionError::InvalidEphemeralPublicKey)?,
+        ),
+        (
+            PrivateKey::Secp256k1(private_key),
+            Some(DecryptorsByCurve::Secp256k1 {
+                dh_ephemeral_public_key,
+                decryptors,
+            }),
+        ) => (
+            find_wrapped_key(decryptors, private_key.public_key().into())?,
+            private_key
+                .ecdh_shared_secret(dh_ephemeral_public_key)
+                .map_err(|_| MessageDecryptionError::InvalidEphemeralPublicKey)?,
+        ),
+        (_, Some(_)) => {
+            return Err(MessageDecryptionError::MismatchingDecryptorCurve {
+                expected: curve_type(private_key),
+            })
+        }
+        (_, None) => return Err(MessageDecryptionError::NotADecryptor),
+    };
+
+    let mut aes_key = [0u8; 16];
+    unwrap_key(&shared_secret, &wrapped_key.0, &mut aes_key)?;
+    let payload = decrypt_payload::<Aes128Gcm>(&aes_key, &encrypted.encrypted)?;
+    manifest_decode(&payload).map_err(MessageDecryptionError::DecodeError)
+}
+
+struct EphemeralKeys {
+    ed25519: Ed25519PrivateKey,
+    secp256k1: Secp256k1PrivateKey,
+}
+
+fn random_nonce_and_ephemeral_keys(
+    rng: &mut impl CryptoRngCore,
+) -> ([u8; AES_GCM_NONCE_LENGTH], EphemeralKeys) {
+    let mut nonce = [0u8; AES_GCM_NONCE_LENGTH];
+    rng.fill_bytes(&mut nonce);
+
+    let mut key_bytes = [0u8; 32];
+    rng.fill_bytes(&mut key_bytes);
+    let ed25519 = Ed25519PrivateKey::from_bytes(&key_bytes).unwrap();
+    // A random 256-bit number is out of the secp256k1 scalar range with a negligible probability
+    let secp256k1 = loop {
+        rng.fill_bytes(&mut key_bytes);
+        if let Ok(key) = Secp256k1PrivateKey::from_bytes(&key_bytes) {
+            break key;
+        }
+    };
+    key_bytes.fill(0);
+
+    (nonce, EphemeralKeys { ed25519, secp256k1 })
+}
+
+fn encrypt_message_with(
+    plaintext: &PlaintextMessageV1,
+    decryptors: &[PublicKey],
+    aes_key: &[u8; 32],
+    nonce: &[u8; AES_GCM_NONCE_LENGTH],
+    ephemeral_keys: &EphemeralKeys,
+) -> Result<EncryptedMessageV2, MessageEncryptionError> {
+    if decryptors.is_empty() {
+        return Err(MessageEncryptionError::NoDecryptors);
+    }
+
+    let payload = manifest_encode(plaintext).map_err(MessageEncryptionError::EncodeError)?;
+    let encrypted = AesGcmPayload(
+        [
+            nonce.as_slice(),
+            &Aes256Gcm::new_from_slice(aes_key)
+                .unwrap()
+                .encrypt(Nonce::from_slice(nonce), payload.as_slice())
+                .expect("The payload of a transaction message is within the AES-GCM limits"),
+        ]
+        .concat(),
+    );
+
+    let mut decryptors_by_curve = index_map_new();
+    for decryptor in decryptors {
+        let invalid_decryptor = || MessageEncryptionError::InvalidDecryptorPublicKey(*decryptor);
+        let fingerprint = PublicKeyFingerprint::from(*decryptor);
+        match decryptor {
+            PublicKey::Ed25519(public_key) => {
+                let shared_secret = ephemeral_keys
+                    .ed25519
+                    .ecdh_shared_secret(public_key)
+                    .map_err(|_| invalid_decryptor())?;
+                let entry = decryptors_by_curve
+                    .entry(CurveType::Ed25519)
+                    .or_insert_with(|| DecryptorsByCurveV2::Ed25519 {
+                        dh_ephemeral_public_key: ephemeral_keys.ed25519.public_key(),
+                        decryptors: index_map_new(),
+                    });
+                if let DecryptorsByCurveV2::Ed25519 { decryptors, .. } = entry {
+                    decryptors.insert(fingerprint, wrap_key(&shared_secret, aes_key));
+                }
+            }
+            PublicKey::Secp256k1(public_key) => {
+                let shared_secret = ephemeral_keys
+                    .secp256k1
+                    .ecdh_shared_secret(public_key)
+                    .map_err(|_| invalid_decryptor())?;
+                let entry = decryptors_by_curve
+                    .entry(CurveType::Secp256k1)
+                    .or_insert_with(|| DecryptorsByCurveV2::Secp256k1 {
+                        dh_ephemeral_public_key: ephemeral_keys.secp256k1.public_key(),
+                        decryptors: index_map_new(),
+                    });
+                if let DecryptorsByCurveV2::Secp256k1 { decryptors, .. } = entry {
+                    decryptors.insert(fingerprint, wrap_key(&shared_secret, aes_key));
+                }
+            }
+        }
+    }
+
+    Ok(EncryptedMessageV2 {
+        encrypted,
+        decryptors_by_curve,
+    })
+}
+
+fn curve_type(private_key: &PrivateKey) -> CurveType {
+    match private_key {
+        PrivateKey::Ed25519(_) => CurveType::Ed25519,
+        PrivateKey::Secp256k1(_) => CurveType::Secp256k1,
+    }
+}
+
+fn find_wrapped_key<K: Clone>(
+    decryptors: &IndexMap<PublicKeyFingerprint, K>,
+    public_key: PublicKey,
+) -> Result<K, MessageDecryptionError> {
+    decryptors
+        .get(&PublicKeyFingerprint::from(public_key))
+        .cloned()
+        .ok_or(MessageDecryptionError::NotADecryptor)
+}
+
+/// `KEK = HKDF(hash: Blake2b, secret: x co-ord of G, salt: [], length: 256 bits)`
+fn derive_key_encrypting_key(shared_secret: &[u8; 32]) -> KekAes256 {
+    let mut key_encrypting_key = [0u8; 32];
+    SimpleHkdf::<Blake2b<U32>>::new(None, shared_secret)
+        .expand(&[], &mut key_encrypting_key)
+        .expect("32 bytes is a valid HKDF output length");
+    KekAes256::from(key_encrypting_key)
+}
+
+fn wrap_key(shared_secret: &[u8; 32], aes_key: &[u8; 32]) -> AesWrapped256BitKey {
+    let mut wrapped_key = [0u8; AesWrapped256BitKey::LENGTH];
+    derive_key_encrypting_key(shared_secret)
+        .wrap(aes_key, &mut wrapped_key)
+        .expect("A 256-bit key wraps into 40 bytes");
+    AesWrapped256BitKey(wrapped_key)
+}
+
+fn unwrap_key(
+    shared_secret: &[u8; 32],
+    wrapped_key: &[u8],
+    aes_key: &mut [u8],
+) -> Result<(), MessageDecryptionError> {
+    derive_key_encrypting_key(shared_secret)
+        .unwrap(wrapped_key, aes_key)
+        .map_err(|_| MessageDecryptionError::KeyUnwrapFailed)
+}
+
+fn decrypt_payload<C: Aead + KeyInit>(
+    aes_key: &[u8],
+    encrypted: &AesGcmPayload,
+) -> Result<Vec<u8>, MessageDecryptionError> {
+    if encrypted.0.len() < AES_GCM_NONCE_LENGTH {
+        return Err(MessageDecryptionError::PayloadDecryptionFailed);
+    }
+    let (nonce, cipher) = encrypted.0.split_at(AES_GCM_NONCE_LENGTH);
+    C::new_from_slice(aes_key)
+        .unwrap()
+        .decrypt(aes_gcm::aead::Nonce::<C>::from_slice(nonce), cipher)
+        .map_err(|_| MessageDecryptionError::PayloadDecryptionFailed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_chacha::rand_core::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    // Test vectors for other implementers, where the decryptors are the Ed25519 key `1` and the
+    // Secp256k1 key `2`, the ephemeral keys are the Ed25519 key `3` and the Secp256k1 key `4`
+    // (as big-endian 32-byte private keys), the AES key is `0x11` repeated, and the nonce is
+    // `0x22` repeated.
+    //
+    // They were computed independently of this implementation, from the scheme described on
+    // `EncryptedMessageV2`, with Python's `cryptography` package: OpenSSL's X25519 (with the
+    // Ed25519 keys converted to their Montgomery form), secp256k1 ECDH, AES-GCM and RFC 3394 key
+    // wrap, and HKDF as HMAC over BLAKE2b-256.
+    const PLAINTEXT: &str = "Hello, Radix!";
+    const PLAINTEXT_PAYLOAD: &str =
+        "4d21020c0a746578742f706c61696e2200010c0d48656c6c6f2c20526164697821";
+    const ED25519_EPHEMERAL_PUBLIC_KEY: &str =
+        "f381626e41e7027ea431bfe3009e94bdd25a746beec468948d6c3c7c5dc9a54b";
+    const SECP256K1_EPHEMERAL_PUBLIC_KEY: &str =
+        "02e493dbf1c10d80f3581e4904930b1404cc6c13900ee0758474fa94abe8c4cd13";
+    const ED25519_SHARED_SECRET: &str =
+        "544c418035f29f04eaee5d25d04114217bf35e0340ef0c0005af661fa64b395a";
+    const SECP256K1_SHARED_SECRET: &str =
+        "2f01e5e15cca351daff3843fb70f3c2f0a1bdd05e5af888a67784ef3e10a2a01";
+    const V2_ENCRYPTED: &str = "2222222222222222222222225ad60545cabbfa279110ae5029cf87f71f92c1ef22e4c0273d632aba68850c7b6404950ba50abbbb12c454b0cc0e9ce0bb";
+    const V2_ED25519_WRAPPED_KEY: &str =
+        "424b37640827d350ce90ce3f498e73a452f1f6038527246dcab7f772764a9237a4939932d5eb1b02";
+    const V2_SECP256K1_WRAPPED_KEY: &str =
+        "846a11d8681288bbb305dc40966d71d4fcecc04fc1c6342e7fabf0e899da8621ee476df524209b25";
+    const V1_ENCRYPTED: &str = "222222222222222222222222e4d45e1b8d2c49ec3869e926190b0b819303ca7b36b6d2ce1421904748c9c2b159b2db51dcfb410636fdb27ec795f77ab1";
+    const V1_ED25519_WRAPPED_KEY: &str = "26780749e094d9177426e5de66473bd07e8bb28dced00bd1";
+    const V1_SECP256K1_WRAPPED_KEY: &str = "a61643432d25b5a3c397ab8e5cb8a62650b509cdad7e8994";
+
+    fn ed25519_decryptor() -> Ed25519PrivateKey {
+        Ed25519PrivateKey::from_u64(1).unwrap()
+    }
+
+    fn secp256k1_decryptor() -> Secp256k1PrivateKey {
+        Secp256k1PrivateKey::from_u64(2).unwrap()
+    }
+
+    fn test_vector_ephemeral_keys() -> EphemeralKeys {
+        EphemeralKeys {
+            ed25519: Ed25519PrivateKey::from_u64(3).unwrap(),
+            secp256k1: Secp256k1PrivateKey::from_u64(4).unwrap(),
+        }
+    }
+
+    fn decryptor_public_keys() -> Vec<PublicKey> {
+        vec![
+            ed25519_decryptor().public_key().into(),
+            secp256k1_decryptor().public_key().into(),
+        ]
+    }
+
+    #[test]
+    fn plaintext_payload_matches_test_vector() {
+        assert_eq!(
+            hex::encode(manifest_encode(&PlaintextMessageV1::text(PLAINTEXT)).unwrap()),
+            PLAINTEXT_PAYLOAD
+        );
+    }
+
+    #[test]
+    fn shared_secrets_match_test_vectors_from_both_sides() {
+        let ephemeral_keys = test_vector_ephemeral_keys();
+        assert_eq!(
+            hex::encode(ephemeral_keys.ed25519.public_key().0),
+            ED25519_EPHEMERAL_PUBLIC_KEY
+        );
+        assert_eq!(
+            hex::encode(ephemeral_keys.secp256k1.public_key().0),
+            SECP256K1_EPHEMERAL_PUBLIC_KEY
+        );
+        for shared_secret in [
+            ephemeral_keys
+                .ed25519
+                .ecdh_shared_secret(&ed25519_decryptor().public_key()),
+            ed25519_decryptor().ecdh_shared_secret(&ephemeral_keys.ed25519.public_key()),
+        ] {
+            assert_eq!(hex::encode(shared_secret.unwrap()), ED25519_SHARED_SECRET);
+        }
+        for shared_secret in [
+            ephemeral_keys
+                .secp256k1
+                .ecdh_shared_secret(&secp256k1_decryptor().public_key()),
+            secp256k1_decryptor().ecdh_shared_secret(&ephemeral_keys.secp256k1.public_key()),
+        ] {
+            assert_eq!(hex::encode(shared_secret.unwrap()), SECP256K1_SHARED_SECRET);
+        }
+    }
+
+    #[test]
+    fn encrypted_message_matches_test_vector() {
+        // Act
+        let encrypted = encrypt_message_with(
+            &PlaintextMessageV1::text(PLAINTEXT),
+            &decryptor_public_keys(),
+            &[0x11; 32],
+            &[0x22; AES_GCM_NONCE_LENGTH],
+            &test_vector_ephemeral_keys(),
+        )
+        .unwrap();
+
+        // Assert
+        assert_eq!(hex::encode(&encrypted.encrypted.0), V2_ENCRYPTED);
+        assert_eq!(
+            encrypted.decryptors_by_curve,
+            indexmap!(
+                CurveType::Ed25519 => DecryptorsByCurveV2::Ed25519 {
+                    dh_ephemeral_public_key: test_vector_ephemeral_keys().ed25519.public_key(),
+                    decryptors: indexmap!(
+                        PublicKeyFingerprint::from(PublicKey::from(ed25519_decryptor().public_key())) =>
+                            AesWrapped256BitKey(copy_u8_array(&hex::decode(V2_ED25519_WRAPPED_KEY).unwrap())),
+                    ),
+                },
+                CurveType::Secp256k1 => DecryptorsByCurveV2::Secp256k1 {
+                    dh_ephemeral_public_key: test_vector_ephemeral_keys().secp256k1.public_key(),
+                    decryptors: indexmap!(
+                        PublicKeyFingerprint::from(PublicKey::from(secp256k1_decryptor().public_key())) =>
+                            AesWrapped256BitKey(copy_u8_array(&hex::decode(V2_SECP256K1_WRAPPED_KEY).unwrap())),
+                    ),
+                },
+            )
+        );
+    }
+
+    #[test]
+    fn v2_test_vector_decrypts_for_each_decryptor() {
+        // Arrange
+        let encrypted = EncryptedMessageV2 {
+            encrypted: AesGcmPayload(hex::decode(V2_ENCRYPTED).unwrap()),
+            decryptors_by_curve: indexmap!(
+                CurveType::Ed25519 => DecryptorsByCurveV2::Ed25519 {
+                    dh_ephemeral_public_key: Ed25519PublicKey::from_str(ED25519_EPHEMERAL_PUBLIC_KEY).unwrap(),
+                    decryptors: indexmap!(
+                        PublicKeyFingerprint::from(PublicKey::from(ed25519_decryptor().public_key())) =>
+                            AesWrapped256BitKey(copy_u8_array(&hex::decode(V2_ED25519_WRAPPED_KEY).unwrap())),
+                    ),
+                },
+                CurveType::Secp256k1 => DecryptorsByCurveV2::Secp256k1 {
+                    dh_ephemeral_public_key: Secp256k1PublicKey::from_str(SECP256K1_EPHEMERAL_PUBLIC_KEY).unwrap(),
+                    decryptors: indexmap!(
+                        PublicKeyFingerprint::from(PublicKey::from(secp256k1_decryptor().public_key())) =>
+                            AesWrapped256BitKey(copy_u8_array(&hex::decode(V2_SECP256K1_WRAPPED_KEY).unwrap())),
+                    ),
+                },
+            ),
+        };
+
+        // Act & Assert
+        for private_key in [
+            PrivateKey::from(ed25519_decryptor()),
+            PrivateKey::from(secp256k1_decryptor()),
+        ] {
+            assert_eq!(
+                decrypt_message(&encrypted, &private_key),
+                Ok(PlaintextMessageV1::text(PLAINTEXT))
+            );
+        }
+    }
+
+    #[test]
+    fn key_wrap_matches_rfc_3394_test_vectors() {
+        // Arrange
+        let key_encrypting_key = KekAes256::from(copy_u8_array::<32>(
+            &hex::decode("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f")
+                .unwrap(),
+        ));
+        let mut wrapped_128_bit_key = [0u8; 24];
+        let mut wrapped_256_bit_key = [0u8; 40];
+
+        // Act
+        key_encrypting_key
+            .wrap(
+                &hex::decode("00112233445566778899aabbccddeeff").unwrap(),
+                &mut wrapped_128_bit_key,
+            )
+            .unwrap();
+        key_encrypting_key
+            .wrap(
+                &hex::decode("00112233445566778899aabbccddeeff000102030405060708090a0b0c0d0e0f")
+                    .unwrap(),
+                &mut wrapped_256_bit_key,
+            )
+            .unwrap();
+
+        // Assert - sections 4.3 and 4.6 of RFC 3394
+        assert_eq!(
+            hex::encode(wrapped_128_bit_key),
+            "64e8c3f9ce0f5ba263e9777905818a2a93c8191e7d6e8ae7"
+        );
+        assert_eq!(
+            hex::encode(wrapped_256_bit_key),
+            "28c9f404c4b810f4cbccb35cfb87f8263f5786e2d80ed326cbc7f0e71a99f43bfb988b9b7a02dd21"
+        );
+    }
+
+    #[test]
+    fn v1_test_vector_decrypts_for_each_decryptor() {
+        // Arrange
+        let encrypted = EncryptedMessageV1 {
+            encrypted: AesGcmPayload(hex::decode(V1_ENCRYPTED).unwrap()),
+            decryptors_by_curve: indexmap!(
+                CurveType::Ed25519 => DecryptorsByCurve::Ed25519 {
+                    dh_ephemeral_public_key: test_vector_ephemeral_keys().ed25519.public_key(),
+                    decryptors: indexmap!(
+                        PublicKeyFingerprint::from(PublicKey::from(ed25519_decryptor().public_key())) =>
+                            AesWrapped128BitKey(copy_u8_array(&hex::decode(V1_ED25519_WRAPPED_KEY).unwrap())),
+                    ),
+                },
+                CurveType::Secp256k1 => DecryptorsByCurve::Secp256k1 {
+                    dh_ephemeral_public_key: test_vector_ephemeral_keys().secp256k1.public_key(),
+                    decryptors: indexmap!(
+                        PublicKeyFingerprint::from(PublicKey::from(secp256k1_decryptor().public_key())) =>
+                            AesWrapped128BitKey(copy_u8_array(&hex::decode(V1_SECP256K1_WRAPPED_KEY).unwrap())),
+                    ),
+                },
+            ),
+        };
+
+        // Act & Assert
+        for private_key in [
+            PrivateKey::from(ed25519_decryptor()),
+            PrivateKey::from(secp256k1_decryptor()),
+        ] {
+            assert_eq!(
+                decrypt_message_v1(&encrypted, &private_key),
+                Ok(PlaintextMessageV1::text(PLAINTEXT))
+            );
+        }
+    }
+
+    #[test]
+    fn encrypted_message_decrypts_for_each_decryptor_only() {
+        // Arrange
+        let mut rng = ChaCha20Rng::seed_from_u64(0);
+        let plaintext = PlaintextMessageV1 {
+            mime_type: "application/octet-stream".to_string(),
+            message: MessageContentsV1::Bytes(vec![1, 2, 3]),
+        };
+
+        // Act
+        let encrypted = encrypt_message(&plaintext, &decryptor_public_keys(), &mut rng).unwrap();
+
+        // Assert
+        for private_key in [
+            PrivateKey::from(ed25519_decryptor()),
+            PrivateKey::from(secp256k1_decryptor()),
+        ] {
+            assert_eq!(
+                decrypt_message(&encrypted, &private_key),
+                Ok(plaintext.clone())
+            );
+        }
+        assert_eq!(
+            decrypt_message(
+                &encrypted,
+                &PrivateKey::from(Ed25519PrivateKey::from_u64(5).unwrap())
+            ),
+            Err(MessageDecryptionError::NotADecryptor)
+        );
+    }
+
+    #[test]
+    fn messages_without_decryptors_are_not_encrypted() {
+        assert_eq!(
+            encrypt_message(
+                &PlaintextMessageV1::text(PLAINTEXT),
+                &[],
+                &mut ChaCha20Rng::seed_from_u64(0)
+            ),
+            Err(MessageEncryptionError::NoDecryptors)
+        );
+    }
+}
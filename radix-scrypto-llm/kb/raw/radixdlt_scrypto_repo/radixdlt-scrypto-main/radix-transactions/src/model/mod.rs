@@ -3,6 +3,7 @@ mod concepts;
 mod execution;
 mod hash;
 mod ledger_transaction;
+mod message_encryption;
 mod preparation;
 mod test_transaction;
 mod user_transaction;
@@ -15,6 +16,7 @@ pub use concepts::*;
 pub use execution::*;
 pub use hash::*;
 pub use ledger_transaction::*;
+pub use message_encryption::*;
 pub use preparation::*;
 pub use test_transaction::*;
 pub use user_transaction::*;
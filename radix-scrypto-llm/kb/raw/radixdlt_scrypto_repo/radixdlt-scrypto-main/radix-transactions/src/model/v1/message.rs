@@ -179,5 +179,5 @@ impl AesWrapped128BitKey {
 #[allow(deprecated)]
 pub type PreparedMessageV1 = SummarizedRawFullValue<MessageV1>;
 
-// TODO: Add tests with a canonical implementation of message encryption/decryption,
-// and corresponding test vectors for other implementers.
+// The canonical implementation of message encryption/decryption, and the test vectors for
+// other implementers, are in `message_encryption.rs`.
@@ -96,5 +96,5 @@ impl AesWrapped256BitKey {
 
 pub type PreparedMessageV2 = SummarizedRawValueBody<MessageV2>;
 
-// TODO: Add tests with a canonical implementation of message encryption/decryption,
-// and corresponding test vectors for other implementers.
+// The canonical implementation of message encryption/decryption, and the test vectors for
+// other implementers, are in `message_encryption.rs`.
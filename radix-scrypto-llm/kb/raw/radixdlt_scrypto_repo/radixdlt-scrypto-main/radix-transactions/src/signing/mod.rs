@@ -1,3 +1,7 @@
+#[cfg(feature = "remote_signer")]
+mod remote_signer;
 mod signer;
 
+#[cfg(feature = "remote_signer")]
+pub use remote_signer::*;
 pub use signer::*;
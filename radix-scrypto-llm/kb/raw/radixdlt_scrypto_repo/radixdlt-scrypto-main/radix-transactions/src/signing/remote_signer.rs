@@ -0,0 +1,598 @@
+//! A [`Signer`] whose key is held by a separate signing daemon, so that it never enters the
+//! process building the transaction.
+//!
+//! The daemon is reached over a Unix socket, or over the stdin and stdout of a spawned process,
+//! and speaks a line-based JSON protocol: each request and each response is a single line.
+//!
+//! * `{"method":"public_key"}` is answered with
+//!   `{"type":"public_key","curve":"secp256k1","public_key":"<hex>"}`.
+//! * `{"method":"sign","hash":"<hex>","purpose":"subintent","manifest_summary":{...}}` is answered
+//!   with `{"type":"signature","signature":"<hex>"}`, or `{"type":"rejected","reason":"..."}`.
+//!
+//! The purpose is one of `transaction_intent`, `subintent`, `notarization` or `unspecified`, and
+//! the manifest summary is the [`ManifestSummary`] of the intent being signed (if known) - its
+//! account withdrawals and deposits, assertions, entities called, proofs created and unknown
+//! resource flows - which the daemon can show to a custodian or check against its policy.
+//! Secp256k1 signatures are the 65 byte recoverable signatures, and every signature is verified
+//! against the public key of the daemon before it's used.
+//!
+//! [`FileSigningDaemon`] is a reference implementation of the daemon, with the key in a file.
+
+use crate::internal_prelude::*;
+use crate::manifest::*;
+use crate::validation::verify_and_recover;
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+use std::process::{Child, Command, Stdio};
+use std::sync::Mutex;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RemoteSignerCurve {
+    Secp256k1,
+    Ed25519,
+}
+
+/// What a hash is signed for - the daemon never sees the intent itself, only its manifest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SigningPurpose {
+    TransactionIntent,
+    Subintent,
+    Notarization,
+    #[default]
+    Unspecified,
+}
+
+/// The context sent to the daemon with a hash to sign.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SigningContext {
+    pub purpose: SigningPurpose,
+    pub manifest_summary: Option<ManifestSummary>,
+}
+
+impl SigningContext {
+    pub fn for_subintent(subintent: &SubintentV2, network: &NetworkDefinition) -> Self {
+        Self {
+            purpose: SigningPurpose::Subintent,
+            manifest_summary: ManifestSummary::new(
+                &SubintentManifestV2::from_intent_core(&subintent.intent_core).into(),
+                network,
+            )
+            .ok(),
+        }
+    }
+
+    pub fn for_transaction_intent(
+        transaction_intent: &TransactionIntentV2,
+        network: &NetworkDefinition,
+    ) -> Self {
+        Self {
+            purpose: SigningPurpose::TransactionIntent,
+            manifest_summary: ManifestSummary::new(
+                &TransactionManifestV2::from_intent_core(&transaction_intent.root_intent_core)
+                    .into(),
+                network,
+            )
+            .ok(),
+        }
+    }
+
+    pub fn for_notarization(
+        transaction_intent: &TransactionIntentV2,
+        network: &NetworkDefinition,
+    ) -> Self {
+        Self {
+            purpose: SigningPurpose::Notarization,
+            ..Self::for_transaction_intent(transaction_intent, network)
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum RemoteSignerRequest {
+    PublicKey,
+    Sign {
+        hash: String,
+        purpose: SigningPurpose,
+        manifest_summary: Option<ManifestSummary>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RemoteSignerResponse {
+    PublicKey {
+        curve: RemoteSignerCurve,
+        public_key: String,
+    },
+    Signature {
+        signature: String,
+    },
+    Rejected {
+        reason: String,
+    },
+}
+
+#[derive(Debug)]
+pub enum RemoteSignerError {
+    IOError(std::io::Error),
+    JsonError(serde_json::Error),
+    /// The daemon closed the connection.
+    Disconnected,
+    Rejected(String),
+    UnexpectedResponse(RemoteSignerResponse),
+    InvalidPublicKey(String),
+    InvalidSignature(String),
+}
+
+impl From<std::io::Error> for RemoteSignerError {
+    fn from(error: std::io::Error) -> Self {
+        Self::IOError(error)
+    }
+}
+
+impl From<serde_json::Error> for RemoteSignerError {
+    fn from(error: serde_json::Error) -> Self {
+        Self::JsonError(error)
+    }
+}
+
+impl fmt::Display for RemoteSignerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::IOError(error) => write!(f, "{}", error),
+            Self::JsonError(error) => write!(f, "{}", error),
+            Self::Disconnected => write!(f, "The signing daemon closed the connection"),
+            Self::Rejected(reason) => write!(f, "The signing daemon rejected: {}", reason),
+            Self::UnexpectedResponse(response) => write!(f, "Unexpected response {:?}", response),
+            Self::InvalidPublicKey(key) => write!(f, "Invalid public key {}", key),
+            Self::InvalidSignature(signature) => write!(f, "Invalid signature {}", signature),
+        }
+    }
+}
+
+struct RemoteSignerConnection {
+    reader: Box<dyn BufRead + Send>,
+    writer: Box<dyn Write + Send>,
+    process: Option<Child>,
+}
+
+impl RemoteSignerConnection {
+    fn send(
+        &mut self,
+        request: &RemoteSignerRequest,
+    ) -> Result<RemoteSignerResponse, RemoteSignerError> {
+        let mut line = serde_json::to_string(request)?;
+        line.push('\n');
+        self.writer.write_all(line.as_bytes())?;
+        self.writer.flush()?;
+
+        let mut line = String::new();
+        if self.reader.read_line(&mut line)? == 0 {
+            return Err(RemoteSignerError::Disconnected);
+        }
+        Ok(serde_json::from_str(&line)?)
+    }
+}
+
+impl Drop for RemoteSignerConnection {
+    fn drop(&mut self) {
+        if let Some(process) = &mut self.process {
+            let _ = process.kill();
+            let _ = process.wait();
+        }
+    }
+}
+
+/// A [`Signer`] which asks a signing daemon to sign - see the [module docs][self] for the
+/// protocol.
+///
+/// The [`Signer`] methods can't fail, so they panic if the daemon can't be reached or rejects the
+/// request; use [`sign_with_context`][Self::sign_with_context] to handle the errors instead. They
+/// also send no manifest summary, unless the signer is wrapped by
+/// [`with_context`][Self::with_context].
+pub struct RemoteSigner {
+    connection: Mutex<RemoteSignerConnection>,
+    public_key: PublicKey,
+}
+
+impl RemoteSigner {
+    /// Connects to a daemon listening on the given Unix socket.
+    pub fn connect(socket_path: impl AsRef<Path>) -> Result<Self, RemoteSignerError> {
+        #[cfg(unix)]
+        {
+            let stream = std::os::unix::net::UnixStream::connect(socket_path)?;
+            Self::new(BufReader::new(stream.try_clone()?), stream)
+        }
+        #[cfg(not(unix))]
+        {
+            let _ = socket_path;
+            Err(RemoteSignerError::IOError(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "Unix sockets are not supported on this platform",
+            )))
+        }
+    }
+
+    /// Spawns the daemon, and talks to it over its stdin and stdout. The daemon is killed when
+    /// the signer is dropped.
+    pub fn spawn(mut command: Command) -> Result<Self, RemoteSignerError> {
+        let mut process = command
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()?;
+        let reader = BufReader::new(process.stdout.take().expect("stdout is piped"));
+        let writer = process.stdin.take().expect("stdin is piped");
+        Self::from_connection(RemoteSignerConnection {
+            reader: Box::new(reader),
+            writer: Box::new(writer),
+            process: Some(process),
+        })
+    }
+
+    /// Talks to a daemon over the given reader and writer, e.g. the two ends of a pipe.
+    pub fn new(
+        reader: impl BufRead + Send + 'static,
+        writer: impl Write + Send + 'static,
+    ) -> Result<Self, RemoteSignerError> {
+        Self::from_connection(RemoteSignerConnection {
+            reader: Box::new(reader),
+            writer: Box::new(writer),
+            process: None,
+        })
+    }
+
+    fn from_connection(mut connection: RemoteSignerConnection) -> Result<Self, RemoteSignerError> {
+        let public_key = match connection.send(&RemoteSignerRequest::PublicKey)? {
+            RemoteSignerResponse::PublicKey { curve, public_key } => {
+                parse_public_key(curve, &public_key)
+                    .ok_or(RemoteSignerError::InvalidPublicKey(public_key))?
+            }
+            RemoteSignerResponse::Rejected { reason } => {
+                return Err(RemoteSignerError::Rejected(reason))
+            }
+            response => return Err(RemoteSignerError::UnexpectedResponse(response)),
+        };
+        Ok(Self {
+            connection: Mutex::new(connection),
+            public_key,
+        })
+    }
+
+    /// Wraps the signer to send the given context with every hash to sign.
+    pub fn with_context(&self, context: SigningContext) -> RemoteSignerWithContext<'_> {
+        RemoteSignerWithContext {
+            signer: self,
+            context,
+        }
+    }
+
+    pub fn sign_with_context(
+        &self,
+        message_hash: &impl IsHash,
+        context: &SigningContext,
+    ) -> Result<SignatureWithPublicKeyV1, RemoteSignerError> {
+        let request = RemoteSignerRequest::Sign {
+            hash: message_hash.as_hash().to_string(),
+            purpose: context.purpose,
+            manifest_summary: context.manifest_summary.clone(),
+        };
+        let response = self
+            .connection
+            .lock()
+            .expect("The connection is only poisoned by a panic in send")
+            .send(&request)?;
+        let signature = match response {
+            RemoteSignerResponse::Signature { signature } => signature,
+            RemoteSignerResponse::Rejected { reason } => {
+                return Err(RemoteSignerError::Rejected(reason))
+            }
+            response => return Err(RemoteSignerError::UnexpectedResponse(response)),
+        };
+
+        let signature_with_public_key = match &self.public_key {
+            PublicKey::Secp256k1(_) => Secp256k1Signature::from_str(&signature)
+                .ok()
+                .map(|signature| SignatureWithPublicKeyV1::Secp256k1 { signature }),
+            PublicKey::Ed25519(public_key) => {
+                Ed25519Signature::from_str(&signature)
+                    .ok()
+                    .map(|signature| SignatureWithPublicKeyV1::Ed25519 {
+                        public_key: *public_key,
+                        signature,
+                    })
+            }
+        };
+        match signature_with_public_key {
+            Some(signature_with_public_key)
+                if verify_and_recover(message_hash.as_hash(), &signature_with_public_key)
+                    == Some(self.public_key) =>
+            {
+                Ok(signature_with_public_key)
+            }
+            _ => Err(RemoteSignerError::InvalidSignature(signature)),
+        }
+    }
+}
+
+impl Signer for RemoteSigner {
+    fn public_key(&self) -> PublicKey {
+        self.public_key
+    }
+
+    fn sign_without_public_key(&self, message_hash: &impl IsHash) -> SignatureV1 {
+        self.sign_with_public_key(message_hash).signature()
+    }
+
+    fn sign_with_public_key(&self, message_hash: &impl IsHash) -> SignatureWithPublicKeyV1 {
+        self.sign_with_context(message_hash, &SigningContext::default())
+            .unwrap_or_else(|error| panic!("The remote signer failed to sign: {}", error))
+    }
+}
+
+/// A [`RemoteSigner`] which sends a [`SigningContext`] with every hash to sign.
+pub struct RemoteSignerWithContext<'a> {
+    signer: &'a RemoteSigner,
+    context: SigningContext,
+}
+
+impl<'a> Signer for RemoteSignerWithContext<'a> {
+    fn public_key(&self) -> PublicKey {
+        self.signer.public_key
+    }
+
+    fn sign_without_public_key(&self, message_hash: &impl IsHash) -> SignatureV1 {
+        self.sign_with_public_key(message_hash).signature()
+    }
+
+    fn sign_with_public_key(&self, message_hash: &impl IsHash) -> SignatureWithPublicKeyV1 {
+        self.signer
+            .sign_with_context(message_hash, &self.context)
+            .unwrap_or_else(|error| panic!("The remote signer failed to sign: {}", error))
+    }
+}
+
+fn parse_public_key(curve: RemoteSignerCurve, public_key: &str) -> Option<PublicKey> {
+    match curve {
+        RemoteSignerCurve::Secp256k1 => Secp256k1PublicKey::from_str(public_key)
+            .ok()
+            .map(Into::into),
+        RemoteSignerCurve::Ed25519 => Ed25519PublicKey::from_str(public_key).ok().map(Into::into),
+    }
+}
+
+/// A reference signing daemon, with its key read from a file, which signs every request and
+/// keeps them for inspection.
+///
+/// It's intended for tests and local development - a production daemon would keep the key in
+/// hardware, and check the manifest summary against a policy or with a custodian.
+pub struct FileSigningDaemon {
+    private_key: PrivateKey,
+    sign_requests: Mutex<Vec<(Hash, SigningContext)>>,
+}
+
+impl FileSigningDaemon {
+    pub fn new(private_key: PrivateKey) -> Self {
+        Self {
+            private_key,
+            sign_requests: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Reads a key file, which holds a hex encoded Secp256k1 private key.
+    pub fn from_key_file(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        let private_key = hex::decode(content.trim())
+            .ok()
+            .and_then(|bytes| Secp256k1PrivateKey::from_bytes(&bytes).ok())
+            .ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "The key file has no valid Secp256k1 private key",
+                )
+            })?;
+        Ok(Self::new(private_key.into()))
+    }
+
+    pub fn sign_requests(&self) -> Vec<(Hash, SigningContext)> {
+        self.sign_requests.lock().unwrap().clone()
+    }
+
+    pub fn handle(&self, request: RemoteSignerRequest) -> RemoteSignerResponse {
+        match request {
+            RemoteSignerRequest::PublicKey => match self.private_key.public_key() {
+                PublicKey::Secp256k1(public_key) => RemoteSignerResponse::PublicKey {
+                    curve: RemoteSignerCurve::Secp256k1,
+                    public_key: public_key.to_string(),
+                },
+                PublicKey::Ed25519(public_key) => RemoteSignerResponse::PublicKey {
+                    curve: RemoteSignerCurve::Ed25519,
+                    public_key: public_key.to_string(),
+                },
+            },
+            RemoteSignerRequest::Sign {
+                hash,
+                purpose,
+                manifest_summary,
+            } => {
+                let Ok(hash) = Hash::from_str(&hash) else {
+                    return RemoteSignerResponse::Rejected {
+                        reason: format!("Invalid hash {}", hash),
+                    };
+                };
+                self.sign_requests.lock().unwrap().push((
+                    hash,
+                    SigningContext {
+                        purpose,
+                        manifest_summary,
+                    },
+                ));
+                let signature = match self.private_key.sign_without_public_key(&hash) {
+                    SignatureV1::Secp256k1(signature) => signature.to_string(),
+                    SignatureV1::Ed25519(signature) => signature.to_string(),
+                };
+                RemoteSignerResponse::Signature { signature }
+            }
+        }
+    }
+
+    /// Serves the requests of one connection, until it's closed.
+    pub fn serve(&self, reader: impl BufRead, mut writer: impl Write) -> std::io::Result<()> {
+        for line in reader.lines() {
+            let response = match serde_json::from_str(&line?) {
+                Ok(request) => self.handle(request),
+                Err(error) => RemoteSignerResponse::Rejected {
+                    reason: format!("Invalid request: {}", error),
+                },
+            };
+            let mut line = serde_json::to_string(&response)?;
+            line.push('\n');
+            writer.write_all(line.as_bytes())?;
+            writer.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Serves the connections to the Unix socket, one at a time - a failed connection is logged
+    /// to stderr, and doesn't stop the daemon from accepting the next one.
+    #[cfg(unix)]
+    pub fn serve_unix_socket(&self, listener: std::os::unix::net::UnixListener) {
+        for stream in listener.incoming() {
+            let result =
+                stream.and_then(|stream| self.serve(BufReader::new(stream.try_clone()?), stream));
+            if let Err(error) = result {
+                eprintln!("Signing daemon connection failed: {}", error);
+            }
+        }
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::os::unix::net::UnixStream;
+    use std::sync::Arc;
+
+    fn start_daemon(private_key: PrivateKey) -> (Arc<FileSigningDaemon>, RemoteSigner) {
+        let daemon = Arc::new(FileSigningDaemon::new(private_key));
+        let (client, server) = UnixStream::pair().unwrap();
+        let serving_daemon = daemon.clone();
+        std::thread::spawn(move || {
+            serving_daemon
+                .serve(BufReader::new(server.try_clone().unwrap()), server)
+                .unwrap()
+        });
+        let signer =
+            RemoteSigner::new(BufReader::new(client.try_clone().unwrap()), client).unwrap();
+        (daemon, signer)
+    }
+
+    #[test]
+    fn remote_signatures_are_the_same_as_local_signatures() {
+        for private_key in [
+            PrivateKey::from(Secp256k1PrivateKey::from_u64(1).unwrap()),
+            PrivateKey::from(Ed25519PrivateKey::from_u64(1).unwrap()),
+        ] {
+            // Arrange
+            let expected_public_key = private_key.public_key();
+            let hash = hash("message");
+            let expected_signature = private_key.sign_with_public_key(&hash);
+            let (_, signer) = start_daemon(private_key);
+
+            // Act
+            let signature = signer.sign_with_public_key(&hash);
+
+            // Assert
+            assert_eq!(signer.public_key(), expected_public_key);
+            assert_eq!(signature, expected_signature);
+        }
+    }
+
+    #[test]
+    fn daemon_keeps_accepting_connections_after_a_failed_connection() {
+        // Arrange
+        let socket_path = std::env::temp_dir().join(format!(
+            "radix-signing-daemon-test-{}.sock",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&socket_path);
+        let listener = std::os::unix::net::UnixListener::bind(&socket_path).unwrap();
+        let private_key = PrivateKey::from(Secp256k1PrivateKey::from_u64(1).unwrap());
+        let expected_public_key = private_key.public_key();
+        let daemon = FileSigningDaemon::new(private_key);
+        std::thread::spawn(move || daemon.serve_unix_socket(listener));
+
+        // Act
+        let mut failed_connection = UnixStream::connect(&socket_path).unwrap();
+        failed_connection.write_all(b"\xff\xfe\n").unwrap();
+        let mut response = Vec::new();
+        std::io::Read::read_to_end(&mut failed_connection, &mut response).unwrap();
+        let signer = RemoteSigner::connect(&socket_path).unwrap();
+        let signature = signer.sign_with_public_key(&hash("message"));
+        std::fs::remove_file(&socket_path).unwrap();
+
+        // Assert
+        assert!(response.is_empty());
+        assert_eq!(signer.public_key(), expected_public_key);
+        assert_eq!(
+            verify_and_recover(&hash("message"), &signature),
+            Some(expected_public_key)
+        );
+    }
+
+    #[test]
+    fn daemon_receives_the_subintent_manifest_with_the_hash() {
+        // Arrange
+        let (daemon, signer) = start_daemon(Secp256k1PrivateKey::from_u64(1).unwrap().into());
+        let network = NetworkDefinition::simulator();
+        let mut builder = PartialTransactionV2Builder::new()
+            .intent_header(IntentHeaderV2 {
+                network_id: network.id,
+                start_epoch_inclusive: Epoch::zero(),
+                end_epoch_exclusive: Epoch::of(100),
+                min_proposer_timestamp_inclusive: None,
+                max_proposer_timestamp_exclusive: None,
+                intent_discriminator: 0,
+            })
+            .manifest_builder(|builder| builder.yield_to_parent(()));
+        let context = SigningContext::for_subintent(builder.create_subintent(), &network);
+        let subintent_hash = builder.subintent_hash();
+
+        // Act
+        let partial_transaction = builder
+            .sign(signer.with_context(context.clone()))
+            .build_minimal_and_validate();
+
+        // Assert
+        assert_eq!(
+            partial_transaction
+                .root_subintent_signatures
+                .signatures
+                .len(),
+            1
+        );
+        assert_eq!(
+            context.manifest_summary,
+            Some(ManifestSummary {
+                entities_called: vec![InstructionSummary {
+                    instruction_index: 0,
+                    description: "the parent intent".to_owned(),
+                }],
+                unknown_resource_flows: vec![InstructionSummary {
+                    instruction_index: 0,
+                    description: "the parent intent may return resources which can't be \
+                        determined statically"
+                        .to_owned(),
+                }],
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            daemon.sign_requests(),
+            vec![(subintent_hash.into_hash(), context)]
+        );
+    }
+}
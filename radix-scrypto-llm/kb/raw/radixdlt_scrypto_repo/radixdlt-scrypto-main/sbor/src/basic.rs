@@ -396,6 +396,23 @@ mod serde_serialization {
             unreachable!("No custom values exist")
         }
     }
+
+    impl DeserializableCustomExtension for NoCustomExtension {
+        type CustomValue = NoCustomValue;
+        type CustomDeserializationContext<'a> = ();
+
+        fn parse_custom_value_kind(_: &str) -> Option<Self::CustomValueKind> {
+            None
+        }
+
+        fn parse_custom_value(
+            _: &Self::CustomDeserializationContext<'_>,
+            _: Self::CustomValueKind,
+            _: &serde_json::Value,
+        ) -> Result<Self::CustomValue, String> {
+            unreachable!("No custom values exist")
+        }
+    }
 }
 
 #[cfg(test)]
@@ -21,14 +21,26 @@
 //!     // efficient in some cases.
 //!     let json = serde_json::to_string(&serializable).unwrap();
 //! ```
+//!
+//! Values in the `Programmatic` format can be read back, and validated against a schema:
+//! ```ignore
+//!     let json: serde_json::Value = serde_json::from_str(&json_string).unwrap();
+//!     let payload = programmatic_json_to_payload(
+//!         &json,
+//!         &DeserializationParameters::WithSchema { /* ... */ },
+//!         &validation_context,
+//!     )?;
+//! ```
 
 // Imports and Exports
 mod contextual_serialize;
+mod serde_deserializer;
 mod serde_serializer;
 mod traits;
 mod value_map_aggregator;
 
 pub use contextual_serialize::*;
+pub use serde_deserializer::*;
 pub use serde_serializer::*;
 pub use traits::*;
 pub use value_map_aggregator::*;
@@ -0,0 +1,937 @@
+use crate::rust::prelude::*;
+use crate::traversal::*;
+use crate::*;
+use serde_json::{Map as JsonMap, Value as JsonValue};
+
+/// A custom extension whose custom values can be read back from their [`SerializationMode::Programmatic`]
+/// JSON representation.
+pub trait DeserializableCustomExtension: CustomExtension {
+    type CustomValue: CustomValue<Self::CustomValueKind>
+        + for<'b> Encode<Self::CustomValueKind, VecEncoder<'b, Self::CustomValueKind>>;
+
+    type CustomDeserializationContext<'a>: Copy;
+
+    /// Parses the `kind` of a custom value, as output when the value is serialized.
+    fn parse_custom_value_kind(kind_name: &str) -> Option<Self::CustomValueKind>;
+
+    /// Parses the `value` field of a programmatic JSON object of the given custom value kind.
+    fn parse_custom_value(
+        context: &Self::CustomDeserializationContext<'_>,
+        custom_value_kind: Self::CustomValueKind,
+        value: &JsonValue,
+    ) -> Result<Self::CustomValue, String>;
+}
+
+pub type DeserializedValue<E> = Value<
+    <E as CustomExtension>::CustomValueKind,
+    <E as DeserializableCustomExtension>::CustomValue,
+>;
+
+pub enum DeserializationParameters<'s, 'a, E: DeserializableCustomExtension> {
+    Schemaless {
+        custom_context: E::CustomDeserializationContext<'a>,
+        depth_limit: usize,
+    },
+    WithSchema {
+        custom_context: E::CustomDeserializationContext<'a>,
+        schema: &'s Schema<E::CustomSchema>,
+        type_id: LocalTypeId,
+        depth_limit: usize,
+    },
+}
+
+impl<'s, 'a, E: DeserializableCustomExtension> DeserializationParameters<'s, 'a, E> {
+    pub fn get_context_params(
+        &self,
+    ) -> (
+        &'s Schema<E::CustomSchema>,
+        LocalTypeId,
+        E::CustomDeserializationContext<'a>,
+        usize,
+    ) {
+        match self {
+            DeserializationParameters::Schemaless {
+                custom_context,
+                depth_limit,
+            } => (
+                E::CustomSchema::empty_schema(),
+                LocalTypeId::any(),
+                *custom_context,
+                *depth_limit,
+            ),
+            DeserializationParameters::WithSchema {
+                custom_context,
+                schema,
+                type_id,
+                depth_limit,
+            } => (*schema, *type_id, *custom_context, *depth_limit),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeserializationError {
+    ExpectedJsonObject,
+    MissingField(&'static str),
+    UnexpectedJsonType {
+        field: &'static str,
+        expected: &'static str,
+    },
+    UnknownValueKind(String),
+    InvalidInteger {
+        value_kind: String,
+        value: String,
+    },
+    InvalidHex(String),
+    UnknownEnumVariantName(String),
+    MismatchingChildValueKind {
+        expected: String,
+        actual: String,
+    },
+    InvalidCustomValue {
+        value_kind: String,
+        message: String,
+    },
+    DepthLimitExceeded(usize),
+    EncodeError(EncodeError),
+    PayloadValidationError(String),
+}
+
+impl fmt::Display for DeserializationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ExpectedJsonObject => write!(f, "Expected a JSON object with a \"kind\" field"),
+            Self::MissingField(field) => write!(f, "Missing field \"{}\"", field),
+            Self::UnexpectedJsonType { field, expected } => {
+                write!(f, "Field \"{}\" should be a JSON {}", field, expected)
+            }
+            Self::UnknownValueKind(kind) => write!(f, "Unknown value kind \"{}\"", kind),
+            Self::InvalidInteger { value_kind, value } => {
+                write!(f, "\"{}\" is not a valid {}", value, value_kind)
+            }
+            Self::InvalidHex(hex) => write!(f, "\"{}\" is not valid hex", hex),
+            Self::UnknownEnumVariantName(name) => write!(f, "Unknown enum variant \"{}\"", name),
+            Self::MismatchingChildValueKind { expected, actual } => write!(
+                f,
+                "Child value has kind {} but its parent declares {}",
+                actual, expected
+            ),
+            Self::InvalidCustomValue {
+                value_kind,
+                message,
+            } => write!(f, "Invalid {}: {}", value_kind, message),
+            Self::DepthLimitExceeded(limit) => write!(f, "Depth limit of {} exceeded", limit),
+            Self::EncodeError(error) => write!(f, "{:?}", error),
+            Self::PayloadValidationError(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+/// An error while deserializing programmatic JSON, located at an SBOR value path.
+///
+/// The path is given in the same format as payload validation errors, EG:
+/// `MyStruct.[0|hello]->MyEnum::{1|Option2}.[0|inner]->Array.[3]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocatedDeserializationError {
+    pub error: DeserializationError,
+    pub ancestor_path: Vec<AnnotatedSborAncestor<'static>>,
+    pub leaf: Option<AnnotatedSborPartialLeaf<'static>>,
+}
+
+impl PathAnnotate for LocatedDeserializationError {
+    fn iter_ancestor_path(&self) -> Box<dyn Iterator<Item = AnnotatedSborAncestor<'_>> + '_> {
+        Box::new(self.ancestor_path.iter().cloned())
+    }
+
+    fn annotated_leaf(&self) -> Option<AnnotatedSborPartialLeaf<'_>> {
+        self.leaf.clone()
+    }
+}
+
+impl LocatedDeserializationError {
+    pub fn error_message(&self) -> String {
+        format!(
+            "[ERROR] value path: {}, cause: {}",
+            self.format_path(),
+            self.error
+        )
+    }
+}
+
+impl fmt::Display for LocatedDeserializationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.error_message())
+    }
+}
+
+/// Parses a value from its [`SerializationMode::Programmatic`] JSON representation.
+///
+/// Any schema is used to resolve enum variant names and to annotate error paths - but the value
+/// is NOT validated against it. Use [`programmatic_json_to_payload`] to get a validated payload.
+pub fn programmatic_json_to_value<E: DeserializableCustomExtension>(
+    json: &JsonValue,
+    params: &DeserializationParameters<'_, '_, E>,
+) -> Result<DeserializedValue<E>, LocatedDeserializationError> {
+    let (schema, type_id, custom_context, depth_limit) = params.get_context_params();
+    ProgrammaticJsonParser::<E> {
+        schema,
+        custom_context,
+        depth_limit,
+        ancestor_path: vec![],
+    }
+    .parse_value(json, type_id)
+}
+
+/// Parses a value from its [`SerializationMode::Programmatic`] JSON representation, encodes it
+/// as a full payload, and validates the payload against the schema (if provided).
+pub fn programmatic_json_to_payload<
+    E: DeserializableCustomExtension + ValidatableCustomExtension<T>,
+    T,
+>(
+    json: &JsonValue,
+    params: &DeserializationParameters<'_, '_, E>,
+    validation_context: &T,
+) -> Result<Vec<u8>, LocatedDeserializationError> {
+    let value = programmatic_json_to_value(json, params)?;
+    let (schema, type_id, _, depth_limit) = params.get_context_params();
+
+    let mut payload = Vec::with_capacity(512);
+    VecEncoder::<E::CustomValueKind>::new(&mut payload, depth_limit)
+        .encode_payload(&value, E::PAYLOAD_PREFIX)
+        .map_err(|error| LocatedDeserializationError {
+            error: DeserializationError::EncodeError(error),
+            ancestor_path: vec![],
+            leaf: None,
+        })?;
+
+    validate_payload_against_schema::<E, T>(
+        &payload,
+        schema,
+        type_id,
+        validation_context,
+        depth_limit,
+    )
+    .map_err(|located_error| {
+        let annotated_location = (&located_error.location, schema);
+        LocatedDeserializationError {
+            error: DeserializationError::PayloadValidationError(located_error.error.to_string()),
+            ancestor_path: annotated_location
+                .iter_ancestor_path()
+                .map(|ancestor| ancestor.into_owned())
+                .collect(),
+            leaf: annotated_location
+                .annotated_leaf()
+                .map(|leaf| leaf.into_owned()),
+        }
+    })?;
+
+    Ok(payload)
+}
+
+struct ProgrammaticJsonParser<'s, 'a, E: DeserializableCustomExtension> {
+    schema: &'s Schema<E::CustomSchema>,
+    custom_context: E::CustomDeserializationContext<'a>,
+    depth_limit: usize,
+    ancestor_path: Vec<AnnotatedSborAncestor<'static>>,
+}
+
+/// Parses a `kind` field, as output by `SerializationMode::Programmatic`.
+///
+/// Byte arrays are output with a kind of `Bytes`, so this returns whether the kind is a byte array.
+fn parse_value_kind<E: DeserializableCustomExtension>(
+    kind_name: &str,
+) -> Option<(ValueKind<E::CustomValueKind>, bool)> {
+    let value_kind = match kind_name {
+        "Bool" => ValueKind::Bool,
+        "I8" => ValueKind::I8,
+        "I16" => ValueKind::I16,
+        "I32" => ValueKind::I32,
+        "I64" => ValueKind::I64,
+        "I128" => ValueKind::I128,
+        "U8" => ValueKind::U8,
+        "U16" => ValueKind::U16,
+        "U32" => ValueKind::U32,
+        "U64" => ValueKind::U64,
+        "U128" => ValueKind::U128,
+        "String" => ValueKind::String,
+        "Enum" => ValueKind::Enum,
+        "Array" => ValueKind::Array,
+        "Bytes" => return Some((ValueKind::Array, true)),
+        "Tuple" => ValueKind::Tuple,
+        "Map" => ValueKind::Map,
+        _ => ValueKind::Custom(E::parse_custom_value_kind(kind_name)?),
+    };
+    Some((value_kind, false))
+}
+
+fn get_field<'j>(
+    object: &'j JsonMap<String, JsonValue>,
+    field: &'static str,
+) -> Result<&'j JsonValue, DeserializationError> {
+    object
+        .get(field)
+        .ok_or(DeserializationError::MissingField(field))
+}
+
+fn get_str_field<'j>(
+    object: &'j JsonMap<String, JsonValue>,
+    field: &'static str,
+) -> Result<&'j str, DeserializationError> {
+    get_field(object, field)?
+        .as_str()
+        .ok_or(DeserializationError::UnexpectedJsonType {
+            field,
+            expected: "string",
+        })
+}
+
+fn get_array_field<'j>(
+    object: &'j JsonMap<String, JsonValue>,
+    field: &'static str,
+) -> Result<&'j Vec<JsonValue>, DeserializationError> {
+    get_field(object, field)?
+        .as_array()
+        .ok_or(DeserializationError::UnexpectedJsonType {
+            field,
+            expected: "array",
+        })
+}
+
+/// Integers are output as strings in programmatic JSON, but we also accept JSON numbers.
+fn get_integer_field<T: FromStr>(
+    object: &JsonMap<String, JsonValue>,
+    field: &'static str,
+    value_kind_name: &str,
+) -> Result<T, DeserializationError> {
+    let raw = match get_field(object, field)? {
+        JsonValue::String(value) => value.clone(),
+        JsonValue::Number(value) => value.to_string(),
+        _ => {
+            return Err(DeserializationError::UnexpectedJsonType {
+                field,
+                expected: "string or number",
+            })
+        }
+    };
+    raw.parse()
+        .map_err(|_| DeserializationError::InvalidInteger {
+            value_kind: value_kind_name.to_string(),
+            value: raw,
+        })
+}
+
+fn get_value_kind_field<E: DeserializableCustomExtension>(
+    object: &JsonMap<String, JsonValue>,
+    field: &'static str,
+) -> Result<ValueKind<E::CustomValueKind>, DeserializationError> {
+    let kind_name = get_str_field(object, field)?;
+    parse_value_kind::<E>(kind_name)
+        .map(|(value_kind, _)| value_kind)
+        .ok_or_else(|| DeserializationError::UnknownValueKind(kind_name.to_string()))
+}
+
+impl<'s, 'a, E: DeserializableCustomExtension> ProgrammaticJsonParser<'s, 'a, E> {
+    fn parse_value(
+        &mut self,
+        json: &JsonValue,
+        type_id: LocalTypeId,
+    ) -> Result<DeserializedValue<E>, LocatedDeserializationError> {
+        if self.ancestor_path.len() >= self.depth_limit {
+            return Err(self.error(
+                type_id,
+                None,
+                DeserializationError::DepthLimitExceeded(self.depth_limit),
+            ));
+        }
+        let object = json
+            .as_object()
+            .ok_or_else(|| self.error(type_id, None, DeserializationError::ExpectedJsonObject))?;
+        let kind_name =
+            get_str_field(object, "kind").map_err(|error| self.error(type_id, None, error))?;
+        let (value_kind, is_bytes) = parse_value_kind::<E>(kind_name).ok_or_else(|| {
+            self.error(
+                type_id,
+                None,
+                DeserializationError::UnknownValueKind(kind_name.to_string()),
+            )
+        })?;
+        let leaf_error = |error| self.error(type_id, Some(kind_name), error);
+
+        let value = match value_kind {
+            ValueKind::Bool => Value::Bool {
+                value: get_field(object, "value")
+                    .and_then(|value| {
+                        value
+                            .as_bool()
+                            .ok_or(DeserializationError::UnexpectedJsonType {
+                                field: "value",
+                                expected: "boolean",
+                            })
+                    })
+                    .map_err(leaf_error)?,
+            },
+            ValueKind::I8 => Value::I8 {
+                value: get_integer_field(object, "value", kind_name).map_err(leaf_error)?,
+            },
+            ValueKind::I16 => Value::I16 {
+                value: get_integer_field(object, "value", kind_name).map_err(leaf_error)?,
+            },
+            ValueKind::I32 => Value::I32 {
+                value: get_integer_field(object, "value", kind_name).map_err(leaf_error)?,
+            },
+            ValueKind::I64 => Value::I64 {
+                value: get_integer_field(object, "value", kind_name).map_err(leaf_error)?,
+            },
+            ValueKind::I128 => Value::I128 {
+                value: get_integer_field(object, "value", kind_name).map_err(leaf_error)?,
+            },
+            ValueKind::U8 => Value::U8 {
+                value: get_integer_field(object, "value", kind_name).map_err(leaf_error)?,
+            },
+            ValueKind::U16 => Value::U16 {
+                value: get_integer_field(object, "value", kind_name).map_err(leaf_error)?,
+            },
+            ValueKind::U32 => Value::U32 {
+                value: get_integer_field(object, "value", kind_name).map_err(leaf_error)?,
+            },
+            ValueKind::U64 => Value::U64 {
+                value: get_integer_field(object, "value", kind_name).map_err(leaf_error)?,
+            },
+            ValueKind::U128 => Value::U128 {
+                value: get_integer_field(object, "value", kind_name).map_err(leaf_error)?,
+            },
+            ValueKind::String => Value::String {
+                value: get_str_field(object, "value")
+                    .map_err(leaf_error)?
+                    .to_string(),
+            },
+            ValueKind::Tuple => self.parse_tuple(object, type_id)?,
+            ValueKind::Enum => self.parse_enum_variant(object, type_id)?,
+            ValueKind::Array => self.parse_array(object, type_id, is_bytes)?,
+            ValueKind::Map => self.parse_map(object, type_id)?,
+            ValueKind::Custom(custom_value_kind) => {
+                let value = get_field(object, "value").map_err(leaf_error)?;
+                Value::Custom {
+                    value: E::parse_custom_value(&self.custom_context, custom_value_kind, value)
+                        .map_err(|message| {
+                            leaf_error(DeserializationError::InvalidCustomValue {
+                                value_kind: kind_name.to_string(),
+                                message,
+                            })
+                        })?,
+                }
+            }
+        };
+        Ok(value)
+    }
+
+    fn parse_tuple(
+        &mut self,
+        object: &JsonMap<String, JsonValue>,
+        type_id: LocalTypeId,
+    ) -> Result<DeserializedValue<E>, LocatedDeserializationError> {
+        let json_fields =
+            get_array_field(object, "fields").map_err(|e| self.error(type_id, Some("Tuple"), e))?;
+        let field_types = match self.schema.resolve_type_kind(type_id) {
+            Some(TypeKind::Tuple { field_types }) if field_types.len() == json_fields.len() => {
+                Some(field_types)
+            }
+            _ => None,
+        };
+        let metadata = self.schema.resolve_type_metadata(type_id);
+        let name = self.type_name_or(type_id, "Tuple");
+
+        let mut fields = Vec::with_capacity(json_fields.len());
+        for (field_index, json_field) in json_fields.iter().enumerate() {
+            let field_name = metadata
+                .and_then(|m| m.get_field_name(field_index))
+                .map(|n| Cow::Owned(n.to_string()));
+            let child_type_id = field_types
+                .and_then(|types| types.get(field_index).copied())
+                .unwrap_or(LocalTypeId::any());
+            self.ancestor_path.push(AnnotatedSborAncestor {
+                name: Cow::Owned(name.clone()),
+                container: AnnotatedSborAncestorContainer::Tuple {
+                    field_index,
+                    field_name,
+                },
+            });
+            fields.push(self.parse_value(json_field, child_type_id)?);
+            self.ancestor_path.pop();
+        }
+        Ok(Value::Tuple { fields })
+    }
+
+    fn parse_enum_variant(
+        &mut self,
+        object: &JsonMap<String, JsonValue>,
+        type_id: LocalTypeId,
+    ) -> Result<DeserializedValue<E>, LocatedDeserializationError> {
+        let metadata = self.schema.resolve_type_metadata(type_id);
+        // The variant_id is authoritative, but we fall back to resolving the variant_name
+        // against the schema, to make hand-written JSON easier
+        let discriminator: u8 = if object.contains_key("variant_id") {
+            get_integer_field(object, "variant_id", "U8")
+                .map_err(|e| self.error(type_id, Some("Enum"), e))?
+        } else {
+            let variant_name = get_str_field(object, "variant_name")
+                .map_err(|_| DeserializationError::MissingField("variant_id"))
+                .map_err(|e| self.error(type_id, Some("Enum"), e))?;
+            let variants = match metadata.and_then(|m| m.child_names.as_ref()) {
+                Some(ChildNames::EnumVariants(variants)) => Some(variants),
+                _ => None,
+            };
+            variants
+                .and_then(|variants| {
+                    variants
+                        .iter()
+                        .find(|(_, m)| m.get_name() == Some(variant_name))
+                        .map(|(discriminator, _)| *discriminator)
+                })
+                .ok_or_else(|| {
+                    self.error(
+                        type_id,
+                        Some("Enum"),
+                        DeserializationError::UnknownEnumVariantName(variant_name.to_string()),
+                    )
+                })?
+        };
+        let json_fields =
+            get_array_field(object, "fields").map_err(|e| self.error(type_id, Some("Enum"), e))?;
+        let field_types = match self.schema.resolve_type_kind(type_id) {
+            Some(TypeKind::Enum { variants }) => variants
+                .get(&discriminator)
+                .filter(|types| types.len() == json_fields.len()),
+            _ => None,
+        };
+        let variant_metadata = metadata.and_then(|m| m.get_enum_variant_data(discriminator));
+        let variant_name = variant_metadata
+            .and_then(|m| m.get_name())
+            .map(|n| Cow::Owned(n.to_string()));
+        let name = self.type_name_or(type_id, "Enum");
+
+        let mut fields = Vec::with_capacity(json_fields.len());
+        for (field_index, json_field) in json_fields.iter().enumerate() {
+            let field_name = variant_metadata
+                .and_then(|m| m.get_field_name(field_index))
+                .map(|n| Cow::Owned(n.to_string()));
+            let child_type_id = field_types
+                .and_then(|types| types.get(field_index).copied())
+                .unwrap_or(LocalTypeId::any());
+            self.ancestor_path.push(AnnotatedSborAncestor {
+                name: Cow::Owned(name.clone()),
+                container: AnnotatedSborAncestorContainer::EnumVariant {
+                    discriminator,
+                    variant_name: variant_name.clone(),
+                    field_index,
+                    field_name,
+                },
+            });
+            fields.push(self.parse_value(json_field, child_type_id)?);
+            self.ancestor_path.pop();
+        }
+        Ok(Value::Enum {
+            discriminator,
+            fields,
+        })
+    }
+
+    fn parse_array(
+        &mut self,
+        object: &JsonMap<String, JsonValue>,
+        type_id: LocalTypeId,
+        is_bytes: bool,
+    ) -> Result<DeserializedValue<E>, LocatedDeserializationError> {
+        let leaf_name = if is_bytes { "Bytes" } else { "Array" };
+        let element_value_kind = if is_bytes {
+            ValueKind::U8
+        } else {
+            get_value_kind_field::<E>(object, "element_kind")
+                .map_err(|e| self.error(type_id, Some(leaf_name), e))?
+        };
+
+        // Byte arrays are output with a hex field, rather than elements
+        if element_value_kind == ValueKind::U8 && object.contains_key("hex") {
+            let hex = get_str_field(object, "hex")
+                .map_err(|e| self.error(type_id, Some(leaf_name), e))?;
+            let bytes = hex::decode(hex).map_err(|_| {
+                self.error(
+                    type_id,
+                    Some(leaf_name),
+                    DeserializationError::InvalidHex(hex.to_string()),
+                )
+            })?;
+            return Ok(Value::Array {
+                element_value_kind,
+                elements: bytes.into_iter().map(|value| Value::U8 { value }).collect(),
+            });
+        }
+
+        let json_elements = get_array_field(object, "elements")
+            .map_err(|e| self.error(type_id, Some(leaf_name), e))?;
+        let element_type_id = match self.schema.resolve_type_kind(type_id) {
+            Some(TypeKind::Array { element_type }) => *element_type,
+            _ => LocalTypeId::any(),
+        };
+        let name = self.type_name_or(type_id, "Array");
+
+        let mut elements = Vec::with_capacity(json_elements.len());
+        for (index, json_element) in json_elements.iter().enumerate() {
+            self.ancestor_path.push(AnnotatedSborAncestor {
+                name: Cow::Owned(name.clone()),
+                container: AnnotatedSborAncestorContainer::Array { index: Some(index) },
+            });
+            let element = self.parse_value(json_element, element_type_id)?;
+            self.check_child_value_kind(element_type_id, element_value_kind, &element)?;
+            elements.push(element);
+            self.ancestor_path.pop();
+        }
+        Ok(Value::Array {
+            element_value_kind,
+            elements,
+        })
+    }
+
+    fn parse_map(
+        &mut self,
+        object: &JsonMap<String, JsonValue>,
+        type_id: LocalTypeId,
+    ) -> Result<DeserializedValue<E>, LocatedDeserializationError> {
+        let key_value_kind = get_value_kind_field::<E>(object, "key_kind")
+            .map_err(|e| self.error(type_id, Some("Map"), e))?;
+        let value_value_kind = get_value_kind_field::<E>(object, "value_kind")
+            .map_err(|e| self.error(type_id, Some("Map"), e))?;
+        let json_entries =
+            get_array_field(object, "entries").map_err(|e| self.error(type_id, Some("Map"), e))?;
+        let (key_type_id, value_type_id) = match self.schema.resolve_type_kind(type_id) {
+            Some(TypeKind::Map {
+                key_type,
+                value_type,
+            }) => (*key_type, *value_type),
+            _ => (LocalTypeId::any(), LocalTypeId::any()),
+        };
+        let name = self.type_name_or(type_id, "Map");
+
+        let mut entries = Vec::with_capacity(json_entries.len());
+        for (index, json_entry) in json_entries.iter().enumerate() {
+            let entry_object = json_entry.as_object().ok_or_else(|| {
+                self.error(
+                    type_id,
+                    Some("Map"),
+                    DeserializationError::UnexpectedJsonType {
+                        field: "entries",
+                        expected: "array of objects with key and value fields",
+                    },
+                )
+            })?;
+            let key = self.parse_map_entry_part(
+                entry_object,
+                &name,
+                index,
+                MapEntryPart::Key,
+                key_type_id,
+                key_value_kind,
+            )?;
+            let value = self.parse_map_entry_part(
+                entry_object,
+                &name,
+                index,
+                MapEntryPart::Value,
+                value_type_id,
+                value_value_kind,
+            )?;
+            entries.push((key, value));
+        }
+        Ok(Value::Map {
+            key_value_kind,
+            value_value_kind,
+            entries,
+        })
+    }
+
+    fn parse_map_entry_part(
+        &mut self,
+        entry_object: &JsonMap<String, JsonValue>,
+        map_name: &str,
+        index: usize,
+        entry_part: MapEntryPart,
+        child_type_id: LocalTypeId,
+        child_value_kind: ValueKind<E::CustomValueKind>,
+    ) -> Result<DeserializedValue<E>, LocatedDeserializationError> {
+        let field = match entry_part {
+            MapEntryPart::Key => "key",
+            MapEntryPart::Value => "value",
+        };
+        self.ancestor_path.push(AnnotatedSborAncestor {
+            name: Cow::Owned(map_name.to_string()),
+            container: AnnotatedSborAncestorContainer::Map {
+                index: Some(index),
+                entry_part,
+            },
+        });
+        let json_part =
+            get_field(entry_object, field).map_err(|e| self.error(child_type_id, None, e))?;
+        let part = self.parse_value(json_part, child_type_id)?;
+        self.check_child_value_kind(child_type_id, child_value_kind, &part)?;
+        self.ancestor_path.pop();
+        Ok(part)
+    }
+
+    /// Arrays and maps declare the kinds of their children, which must match the kinds of the children themselves
+    fn check_child_value_kind(
+        &self,
+        type_id: LocalTypeId,
+        expected: ValueKind<E::CustomValueKind>,
+        child: &DeserializedValue<E>,
+    ) -> Result<(), LocatedDeserializationError> {
+        let actual = child.get_value_kind();
+        if actual != expected {
+            return Err(self.error(
+                type_id,
+                None,
+                DeserializationError::MismatchingChildValueKind {
+                    expected: expected.to_string(),
+                    actual: actual.to_string(),
+                },
+            ));
+        }
+        Ok(())
+    }
+
+    fn type_name_or(&self, type_id: LocalTypeId, fallback: &str) -> String {
+        self.schema
+            .resolve_type_name_from_metadata(type_id)
+            .unwrap_or(fallback)
+            .to_string()
+    }
+
+    fn error(
+        &self,
+        type_id: LocalTypeId,
+        kind_name: Option<&str>,
+        error: DeserializationError,
+    ) -> LocatedDeserializationError {
+        let leaf_name = self
+            .schema
+            .resolve_type_name_from_metadata(type_id)
+            .or(kind_name);
+        LocatedDeserializationError {
+            error,
+            ancestor_path: self.ancestor_path.clone(),
+            leaf: leaf_name.map(|name| AnnotatedSborPartialLeaf {
+                name: Cow::Owned(name.to_string()),
+                partial_leaf_locator: None,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")] // Ensures that VS Code runs this module with the features serde tag!
+mod tests {
+    use super::*;
+    use crate::representations::*;
+    use radix_rust::ContextualSerialize;
+    use serde_json::{json, to_value};
+
+    #[derive(Sbor, Debug, PartialEq, Eq)]
+    enum TestEnum {
+        UnitVariant,
+        SingleFieldVariant { field: u8 },
+    }
+
+    #[derive(Sbor, Debug, PartialEq, Eq)]
+    struct MyFieldStruct {
+        field1: u64,
+        field2: Vec<String>,
+    }
+
+    #[derive(BasicSbor, Debug, PartialEq, Eq)]
+    struct MyComplexStruct {
+        numbers: Vec<u16>,
+        bytes: Vec<u8>,
+        map: IndexMap<String, MyFieldStruct>,
+        enums: (TestEnum, TestEnum),
+        signed: (i8, i64, i128),
+    }
+
+    fn test_value() -> MyComplexStruct {
+        MyComplexStruct {
+            numbers: vec![1, 2, 3],
+            bytes: vec![0x3a, 0x92],
+            map: indexmap! {
+                "hello".to_string() => MyFieldStruct { field1: 1, field2: vec!["world".to_string()] },
+            },
+            enums: (
+                TestEnum::UnitVariant,
+                TestEnum::SingleFieldVariant { field: 7 },
+            ),
+            signed: (-5, i64::MIN, i128::MAX),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")] // Workaround for VS Code "Run Test" feature
+    fn programmatic_json_round_trips_to_identical_payload() {
+        let (type_id, schema) =
+            generate_full_schema_from_single_type::<MyComplexStruct, NoCustomSchema>();
+        let payload = basic_encode(&test_value()).unwrap();
+        let json = to_value(
+            &BasicRawPayload::new_from_valid_slice_with_checks(&payload)
+                .unwrap()
+                .serializable(SerializationParameters::WithSchema {
+                    mode: SerializationMode::Programmatic,
+                    schema: schema.v1(),
+                    custom_context: (),
+                    type_id,
+                    depth_limit: 64,
+                }),
+        )
+        .unwrap();
+
+        let parsed_payload = programmatic_json_to_payload::<NoCustomExtension, ()>(
+            &json,
+            &DeserializationParameters::WithSchema {
+                custom_context: (),
+                schema: schema.v1(),
+                type_id,
+                depth_limit: 64,
+            },
+            &(),
+        )
+        .unwrap();
+        assert_eq!(parsed_payload, payload);
+
+        let schemaless_value = programmatic_json_to_value::<NoCustomExtension>(
+            &json,
+            &DeserializationParameters::Schemaless {
+                custom_context: (),
+                depth_limit: 64,
+            },
+        )
+        .unwrap();
+        assert_eq!(basic_encode(&schemaless_value).unwrap(), payload);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")] // Workaround for VS Code "Run Test" feature
+    fn enum_variant_can_be_resolved_from_name() {
+        let (type_id, schema) = generate_full_schema_from_single_type::<TestEnum, NoCustomSchema>();
+        let json = json!({
+            "kind": "Enum",
+            "variant_name": "SingleFieldVariant",
+            "fields": [{ "kind": "U8", "value": 7 }]
+        });
+        let payload = programmatic_json_to_payload::<NoCustomExtension, ()>(
+            &json,
+            &DeserializationParameters::WithSchema {
+                custom_context: (),
+                schema: schema.v1(),
+                type_id,
+                depth_limit: 64,
+            },
+            &(),
+        )
+        .unwrap();
+        assert_eq!(
+            basic_decode::<TestEnum>(&payload).unwrap(),
+            TestEnum::SingleFieldVariant { field: 7 }
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")] // Workaround for VS Code "Run Test" feature
+    fn parse_error_path_is_readable() {
+        let (type_id, schema) =
+            generate_full_schema_from_single_type::<MyFieldStruct, NoCustomSchema>();
+        let json = json!({
+            "kind": "Tuple",
+            "fields": [
+                { "kind": "U64", "value": "1" },
+                {
+                    "kind": "Array",
+                    "element_kind": "String",
+                    "elements": [
+                        { "kind": "String", "value": "hello" },
+                        { "kind": "String" },
+                    ]
+                }
+            ]
+        });
+        let error = programmatic_json_to_value::<NoCustomExtension>(
+            &json,
+            &DeserializationParameters::WithSchema {
+                custom_context: (),
+                schema: schema.v1(),
+                type_id,
+                depth_limit: 64,
+            },
+        )
+        .unwrap_err();
+        assert_eq!(error.error, DeserializationError::MissingField("value"));
+        assert_eq!(
+            error.format_path(),
+            "MyFieldStruct.[1|field2]->Array.[1]->String"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")] // Workaround for VS Code "Run Test" feature
+    fn validation_error_path_is_readable() {
+        let (type_id, schema) =
+            generate_full_schema_from_single_type::<MyFieldStruct, NoCustomSchema>();
+        let json = json!({
+            "kind": "Tuple",
+            "fields": [
+                { "kind": "U64", "value": "1" },
+                {
+                    "kind": "Array",
+                    "element_kind": "U32",
+                    "elements": [{ "kind": "U32", "value": "1" }]
+                }
+            ]
+        });
+        let error = programmatic_json_to_payload::<NoCustomExtension, ()>(
+            &json,
+            &DeserializationParameters::WithSchema {
+                custom_context: (),
+                schema: schema.v1(),
+                type_id,
+                depth_limit: 64,
+            },
+            &(),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            error.error,
+            DeserializationError::PayloadValidationError(_)
+        ));
+        assert!(error.format_path().starts_with("MyFieldStruct.[1|field2]"));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")] // Workaround for VS Code "Run Test" feature
+    fn mismatching_child_value_kind_is_rejected() {
+        let json = json!({
+            "kind": "Array",
+            "element_kind": "U32",
+            "elements": [{ "kind": "U16", "value": "1" }]
+        });
+        let error = programmatic_json_to_value::<NoCustomExtension>(
+            &json,
+            &DeserializationParameters::Schemaless {
+                custom_context: (),
+                depth_limit: 64,
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.error,
+            DeserializationError::MismatchingChildValueKind {
+                expected: "U32".to_string(),
+                actual: "U16".to_string(),
+            }
+        );
+        assert_eq!(error.format_path(), "Array.[0]");
+    }
+}
@@ -2,6 +2,7 @@ use core::ops::Deref;
 
 use crate::rust::prelude::*;
 
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AnnotatedSborAncestor<'a> {
     /// Ideally the type's type name, else the value kind name or type name, depending on what's available
     pub name: Cow<'a, str>,
@@ -9,6 +10,13 @@ pub struct AnnotatedSborAncestor<'a> {
 }
 
 impl<'a> AnnotatedSborAncestor<'a> {
+    pub fn into_owned(self) -> AnnotatedSborAncestor<'static> {
+        AnnotatedSborAncestor {
+            name: Cow::Owned(self.name.into_owned()),
+            container: self.container.into_owned(),
+        }
+    }
+
     pub fn write(
         &self,
         f: &mut impl core::fmt::Write,
@@ -27,6 +35,7 @@ impl<'a> AnnotatedSborAncestor<'a> {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AnnotatedSborAncestorContainer<'a> {
     Tuple {
         field_index: usize,
@@ -50,6 +59,33 @@ pub enum AnnotatedSborAncestorContainer<'a> {
 }
 
 impl<'a> AnnotatedSborAncestorContainer<'a> {
+    pub fn into_owned(self) -> AnnotatedSborAncestorContainer<'static> {
+        match self {
+            Self::Tuple {
+                field_index,
+                field_name,
+            } => AnnotatedSborAncestorContainer::Tuple {
+                field_index,
+                field_name: field_name.map(|n| Cow::Owned(n.into_owned())),
+            },
+            Self::EnumVariant {
+                discriminator,
+                variant_name,
+                field_index,
+                field_name,
+            } => AnnotatedSborAncestorContainer::EnumVariant {
+                discriminator,
+                variant_name: variant_name.map(|n| Cow::Owned(n.into_owned())),
+                field_index,
+                field_name: field_name.map(|n| Cow::Owned(n.into_owned())),
+            },
+            Self::Array { index } => AnnotatedSborAncestorContainer::Array { index },
+            Self::Map { index, entry_part } => {
+                AnnotatedSborAncestorContainer::Map { index, entry_part }
+            }
+        }
+    }
+
     pub fn write(&self, f: &mut impl core::fmt::Write) -> core::fmt::Result {
         // This should align with AnnotatedSborPartialLeafLocator
         match self {
@@ -99,6 +135,7 @@ impl<'a> AnnotatedSborAncestorContainer<'a> {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AnnotatedSborPartialLeaf<'a> {
     /// Ideally the type's type name, else the value kind name or type name, depending on what's available
     pub name: Cow<'a, str>,
@@ -106,6 +143,13 @@ pub struct AnnotatedSborPartialLeaf<'a> {
 }
 
 impl<'a> AnnotatedSborPartialLeaf<'a> {
+    pub fn into_owned(self) -> AnnotatedSborPartialLeaf<'static> {
+        AnnotatedSborPartialLeaf {
+            name: Cow::Owned(self.name.into_owned()),
+            partial_leaf_locator: self.partial_leaf_locator.map(|l| l.into_owned()),
+        }
+    }
+
     pub fn write(
         &self,
         f: &mut impl core::fmt::Write,
@@ -129,6 +173,7 @@ impl<'a> AnnotatedSborPartialLeaf<'a> {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AnnotatedSborPartialLeafLocator<'a> {
     Tuple {
         field_index: Option<usize>,
@@ -150,6 +195,33 @@ pub enum AnnotatedSborPartialLeafLocator<'a> {
 }
 
 impl<'a> AnnotatedSborPartialLeafLocator<'a> {
+    pub fn into_owned(self) -> AnnotatedSborPartialLeafLocator<'static> {
+        match self {
+            Self::Tuple {
+                field_index,
+                field_name,
+            } => AnnotatedSborPartialLeafLocator::Tuple {
+                field_index,
+                field_name: field_name.map(|n| Cow::Owned(n.into_owned())),
+            },
+            Self::EnumVariant {
+                variant_discriminator,
+                variant_name,
+                field_index,
+                field_name,
+            } => AnnotatedSborPartialLeafLocator::EnumVariant {
+                variant_discriminator,
+                variant_name: variant_name.map(|n| Cow::Owned(n.into_owned())),
+                field_index,
+                field_name: field_name.map(|n| Cow::Owned(n.into_owned())),
+            },
+            Self::Array { index } => AnnotatedSborPartialLeafLocator::Array { index },
+            Self::Map { index, entry_part } => {
+                AnnotatedSborPartialLeafLocator::Map { index, entry_part }
+            }
+        }
+    }
+
     pub fn write(&self, f: &mut impl core::fmt::Write) -> core::fmt::Result {
         // This should align with AnnotatedSborAncestorContainer
         match self {
@@ -3,3 +3,4 @@ pub mod macros;
 pub mod schema;
 pub mod translation;
 pub mod types;
+pub mod typescript;
@@ -10,17 +10,41 @@ pub trait PackageSchemaResolver {
     fn resolve_type_kind(
         &self,
         type_identifier: &ScopedTypeId,
-    ) -> Result<LocalTypeKind<ScryptoCustomSchema>, SchemaError>;
+    ) -> Result<LocalTypeKind<ScryptoCustomSchema>, SchemaError> {
+        self.lookup_schema(&type_identifier.0)
+            .ok_or(SchemaError::FailedToGetSchemaFromSchemaHash)?
+            .as_latest_version()
+            .ok_or(SchemaError::FailedToGetSchemaFromSchemaHash)?
+            .resolve_type_kind(type_identifier.1)
+            .ok_or(SchemaError::NonExistentLocalTypeIndex(type_identifier.1))
+            .cloned()
+    }
 
     fn resolve_type_metadata(
         &self,
         type_identifier: &ScopedTypeId,
-    ) -> Result<TypeMetadata, SchemaError>;
+    ) -> Result<TypeMetadata, SchemaError> {
+        self.lookup_schema(&type_identifier.0)
+            .ok_or(SchemaError::FailedToGetSchemaFromSchemaHash)?
+            .as_latest_version()
+            .ok_or(SchemaError::FailedToGetSchemaFromSchemaHash)?
+            .resolve_type_metadata(type_identifier.1)
+            .ok_or(SchemaError::NonExistentLocalTypeIndex(type_identifier.1))
+            .cloned()
+    }
 
     fn resolve_type_validation(
         &self,
         type_identifier: &ScopedTypeId,
-    ) -> Result<TypeValidation<ScryptoCustomTypeValidation>, SchemaError>;
+    ) -> Result<TypeValidation<ScryptoCustomTypeValidation>, SchemaError> {
+        self.lookup_schema(&type_identifier.0)
+            .ok_or(SchemaError::FailedToGetSchemaFromSchemaHash)?
+            .as_latest_version()
+            .ok_or(SchemaError::FailedToGetSchemaFromSchemaHash)?
+            .resolve_type_validation(type_identifier.1)
+            .ok_or(SchemaError::NonExistentLocalTypeIndex(type_identifier.1))
+            .cloned()
+    }
 
     fn package_address(&self) -> PackageAddress;
 }
@@ -45,11 +69,26 @@ where
             }
         }
 
-        let functions = &mut package_interface
+        let blueprint_interface = package_interface
             .blueprints
             .entry(blueprint_name)
-            .or_default()
-            .functions;
+            .or_default();
+
+        for (event_name, event_payload) in blueprint_definition.interface.events {
+            let BlueprintPayloadDef::Static(event_type_identifier) = event_payload else {
+                Err(SchemaError::GenericTypeRefsNotSupported)?
+            };
+            get_scoped_type_ids_in_path(
+                &event_type_identifier,
+                schema_resolver,
+                &mut package_interface.auxiliary_types,
+            )?;
+            blueprint_interface
+                .events
+                .insert(event_name, event_type_identifier);
+        }
+
+        let functions = &mut blueprint_interface.functions;
 
         for (function_name, function_schema) in blueprint_definition.interface.functions {
             let BlueprintPayloadDef::Static(input_type_identifier) = &function_schema.input else {
@@ -147,6 +186,8 @@ pub struct PackageInterface {
 pub struct BlueprintInterface {
     /// The functions and methods encountered in the blueprint interface.
     pub functions: Vec<Function>,
+    /// The [`ScopedTypeId`] of the events emitted by the blueprint, by event name.
+    pub events: IndexMap<String, ScopedTypeId>,
 }
 
 #[derive(Clone, Debug)]
@@ -0,0 +1,1042 @@
+//! This module converts the models from `schema.rs` to a TypeScript module, for the clients of the
+//! package which can't use the Scrypto stubs.
+//!
+//! The module has a type for every auxiliary type of the package interface, a function building
+//! the manifest instruction of every blueprint function and method, and functions decoding their
+//! outputs and the blueprints' events from the programmatic JSON representation of SBOR values.
+
+use super::schema;
+use radix_blueprint_schema_init::*;
+use radix_common::prelude::*;
+use std::fmt::Write;
+
+/// The helpers shared by the generated code. Encoders return the manifest text of a value, while
+/// decoders take a value in the programmatic JSON representation.
+const RUNTIME: &str = r#"export type ProgrammaticScryptoSborValue = { kind: string; [field: string]: any };
+
+type Decoder = (value: ProgrammaticScryptoSborValue) => any;
+type Encoder = (value: any) => string;
+
+const sbor = {
+  expectKind(value: ProgrammaticScryptoSborValue, kind: string): any {
+    if (value.kind !== kind) {
+      throw new Error(`Expected a value of kind ${kind}, found ${value.kind}`);
+    }
+    return value;
+  },
+  fields(value: ProgrammaticScryptoSborValue, kind: string, length: number): ProgrammaticScryptoSborValue[] {
+    const fields = sbor.expectKind(value, kind).fields;
+    if (fields.length !== length) {
+      throw new Error(`Expected ${length} fields, found ${fields.length}`);
+    }
+    return fields;
+  },
+  decodeAny: ((value) => value) as Decoder,
+  decodeBool: ((value) => sbor.expectKind(value, "Bool").value) as Decoder,
+  decodeNumber: (kind: string): Decoder => (value) => Number(sbor.expectKind(value, kind).value),
+  decodeString: (kind: string): Decoder => (value) => String(sbor.expectKind(value, kind).value),
+  decodeBytes: ((value) => sbor.expectKind(value, "Bytes").hex) as Decoder,
+  decodeArray: (element: Decoder): Decoder => (value) =>
+    sbor.expectKind(value, "Array").elements.map(element),
+  decodeMap: (key: Decoder, entryValue: Decoder): Decoder => (value) =>
+    sbor.expectKind(value, "Map").entries.map((entry: any) => [key(entry.key), entryValue(entry.value)]),
+  decodeTuple: (elements: Decoder[]): Decoder => (value) =>
+    sbor.fields(value, "Tuple", elements.length).map((field, i) => elements[i](field)),
+  decodeOption: (some: Decoder): Decoder => (value) => {
+    const { variantId, fields } = sbor.variant(value);
+    return variantId === 0 ? null : some(fields[0]);
+  },
+  decodeResult: (ok: Decoder, err: Decoder): Decoder => (value) => {
+    const { variantId, fields } = sbor.variant(value);
+    return variantId === 0 ? { ok: ok(fields[0]) } : { err: err(fields[0]) };
+  },
+  variant(value: ProgrammaticScryptoSborValue): { variantId: number; fields: ProgrammaticScryptoSborValue[] } {
+    const variant = sbor.expectKind(value, "Enum");
+    return { variantId: Number(variant.variant_id), fields: variant.fields };
+  },
+  encodeUnsupported: (kind: string): Encoder => () => {
+    throw new Error(`A value of kind ${kind} can't be passed in a manifest`);
+  },
+  encodeBool: ((value: boolean) => `${value}`) as Encoder,
+  encodeInteger: (suffix: string): Encoder => (value: number | string) => `${value}${suffix}`,
+  encodeString: ((value: string) => JSON.stringify(value)) as Encoder,
+  encodeCustom: (kind: string): Encoder => (value: string) => `${kind}(${JSON.stringify(value)})`,
+  encodeBytes: ((value: string) => `Bytes("${value}")`) as Encoder,
+  encodeArray: (kind: string | null, element: Encoder): Encoder => (value: any[]) => {
+    if (kind === null) {
+      return sbor.encodeUnsupported("Own")(value);
+    }
+    return `Array<${kind}>(${value.map(element).join(", ")})`;
+  },
+  encodeMap: (keyKind: string | null, valueKind: string | null, key: Encoder, entryValue: Encoder): Encoder =>
+    (value: [any, any][]) => {
+      if (keyKind === null || valueKind === null) {
+        return sbor.encodeUnsupported("Own")(value);
+      }
+      const entries = value.map(([k, v]) => `${key(k)} => ${entryValue(v)}`);
+      return `Map<${keyKind}, ${valueKind}>(${entries.join(", ")})`;
+    },
+  encodeFields: (fields: string[]): string => `Tuple(${fields.join(", ")})`,
+  encodeVariant: (variantId: number, fields: string[]): string => `Enum<${variantId}u8>(${fields.join(", ")})`,
+  encodeTuple: (elements: Encoder[]): Encoder => (value: any[]) =>
+    sbor.encodeFields(value.map((element, i) => elements[i](element))),
+  encodeOption: (some: Encoder): Encoder => (value) =>
+    value === null ? sbor.encodeVariant(0, []) : sbor.encodeVariant(1, [some(value)]),
+  encodeResult: (ok: Encoder, err: Encoder): Encoder => (value) =>
+    "ok" in value ? sbor.encodeVariant(0, [ok(value.ok)]) : sbor.encodeVariant(1, [err(value.err)]),
+  callFunction(packageAddress: string, blueprintName: string, functionName: string, args: string[]): string {
+    return [
+      "CALL_FUNCTION",
+      `Address(${JSON.stringify(packageAddress)})`,
+      JSON.stringify(blueprintName),
+      JSON.stringify(functionName),
+      ...args,
+    ].join(" ") + ";";
+  },
+  callMethod(componentAddress: string, methodName: string, args: string[]): string {
+    return [
+      "CALL_METHOD",
+      `Address(${JSON.stringify(componentAddress)})`,
+      JSON.stringify(methodName),
+      ...args,
+    ].join(" ") + ";";
+  },
+};
+"#;
+
+/// TypeScript reserved words, which can't be used as parameter names.
+const RESERVED_WORDS: &[&str] = &[
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "import",
+    "in",
+    "instanceof",
+    "new",
+    "null",
+    "return",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+];
+
+pub fn package_schema_interface_to_typescript<S>(
+    schema_interface: schema::PackageInterface,
+    package_address: &str,
+    schema_resolver: &S,
+) -> Result<String, schema::SchemaError>
+where
+    S: schema::PackageSchemaResolver,
+{
+    let mut generator = TypeScriptGenerator {
+        schema_resolver,
+        declarations: IndexMap::default(),
+    };
+
+    // The auxiliary types are declared even if they're only used in the blueprints' state.
+    for scoped_type_id in schema_interface
+        .auxiliary_types
+        .into_iter()
+        .filter_map(|item| match item.1 {
+            LocalTypeId::SchemaLocalIndex(local_index) => Some((item.0, local_index)),
+            LocalTypeId::WellKnown(..) => None,
+        })
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .map(|(schema_hash, local_type_index)| {
+            ScopedTypeId(schema_hash, LocalTypeId::SchemaLocalIndex(local_type_index))
+        })
+    {
+        generator.ts_type(&scoped_type_id)?;
+    }
+
+    let mut blueprints = String::new();
+    for (blueprint_name, blueprint_interface) in schema_interface.blueprints {
+        generator.write_blueprint(&mut blueprints, &blueprint_name, blueprint_interface)?;
+    }
+
+    let mut module = String::new();
+    writeln!(module, "{}", RUNTIME).unwrap();
+    writeln!(
+        module,
+        "export const PACKAGE_ADDRESS = {};",
+        json_string(package_address)
+    )
+    .unwrap();
+    for declaration in generator.declarations.into_values() {
+        writeln!(module).unwrap();
+        write!(module, "{}", declaration).unwrap();
+    }
+    write!(module, "{}", blueprints).unwrap();
+
+    Ok(module)
+}
+
+/// The TypeScript type of a schema type, together with the expressions of its decoder and encoder
+/// functions.
+struct TsType {
+    ty: String,
+    decoder: String,
+    encoder: String,
+    /// The name of the value kind in the manifest, if values of the type can be passed in one.
+    manifest_kind: Option<&'static str>,
+}
+
+impl TsType {
+    fn new(
+        ty: impl Into<String>,
+        decoder: impl Into<String>,
+        encoder: impl Into<String>,
+        manifest_kind: Option<&'static str>,
+    ) -> Self {
+        Self {
+            ty: ty.into(),
+            decoder: decoder.into(),
+            encoder: encoder.into(),
+            manifest_kind,
+        }
+    }
+
+    fn manifest_kind_literal(&self) -> String {
+        match self.manifest_kind {
+            Some(kind) => json_string(kind),
+            None => "null".to_owned(),
+        }
+    }
+}
+
+struct TypeScriptGenerator<'s, S> {
+    schema_resolver: &'s S,
+    /// The declarations of the named types, by name. A declaration is empty while it's being
+    /// generated, so that recursive types refer to themselves by name.
+    declarations: IndexMap<String, String>,
+}
+
+impl<'s, S> TypeScriptGenerator<'s, S>
+where
+    S: schema::PackageSchemaResolver,
+{
+    fn write_blueprint(
+        &mut self,
+        out: &mut String,
+        blueprint_name: &str,
+        blueprint_interface: schema::BlueprintInterface,
+    ) -> Result<(), schema::SchemaError> {
+        for function in blueprint_interface.functions {
+            let function_name = to_upper_camel_case(&function.ident);
+            let (mut parameters, mut arguments) = (Vec::new(), Vec::new());
+            if function.receiver.is_some() {
+                parameters.push("componentAddress: string".to_owned());
+            }
+            for (argument_name, argument_type) in function.arguments {
+                let ts_type = self.ts_type(&argument_type)?;
+                let parameter_name = parameter_name(&argument_name);
+                arguments.push(format!("{}({})", ts_type.encoder, parameter_name));
+                parameters.push(format!("{}: {}", parameter_name, ts_type.ty));
+            }
+            let call = match function.receiver {
+                Some(..) => format!(
+                    "sbor.callMethod(componentAddress, {}, [{}])",
+                    json_string(&function.ident),
+                    arguments.join(", ")
+                ),
+                None => format!(
+                    "sbor.callFunction(PACKAGE_ADDRESS, {}, {}, [{}])",
+                    json_string(blueprint_name),
+                    json_string(&function.ident),
+                    arguments.join(", ")
+                ),
+            };
+            let instruction = match function.receiver {
+                Some(..) => "CALL_METHOD",
+                None => "CALL_FUNCTION",
+            };
+            let output = self.ts_type(&function.returns)?;
+
+            writeln!(out).unwrap();
+            writeln!(
+                out,
+                "/** Builds the `{}` instruction of `{}::{}`. */",
+                instruction, blueprint_name, function.ident
+            )
+            .unwrap();
+            writeln!(
+                out,
+                "export function build{}{}Call({}): string {{",
+                blueprint_name,
+                function_name,
+                parameters.join(", ")
+            )
+            .unwrap();
+            writeln!(out, "  return {};", call).unwrap();
+            writeln!(out, "}}").unwrap();
+            writeln!(out).unwrap();
+            writeln!(
+                out,
+                "/** Decodes the output of `{}::{}`. */",
+                blueprint_name, function.ident
+            )
+            .unwrap();
+            writeln!(
+                out,
+                "export function decode{}{}Output(value: ProgrammaticScryptoSborValue): {} {{",
+                blueprint_name, function_name, output.ty
+            )
+            .unwrap();
+            writeln!(out, "  return {}(value);", output.decoder).unwrap();
+            writeln!(out, "}}").unwrap();
+        }
+
+        if blueprint_interface.events.is_empty() {
+            return Ok(());
+        }
+        let mut event_types = Vec::new();
+        let mut event_cases = Vec::new();
+        for (event_name, event_type) in blueprint_interface.events {
+            let ts_type = self.ts_type(&event_type)?;
+            event_types.push(format!(
+                "  | {{ name: {}; data: {} }}",
+                json_string(&event_name),
+                ts_type.ty
+            ));
+            event_cases.push(format!(
+                "    case {}:\n      return {{ name, data: {}(value) }};",
+                json_string(&event_name),
+                ts_type.decoder
+            ));
+        }
+        writeln!(out).unwrap();
+        writeln!(out, "export type {}Event =", blueprint_name).unwrap();
+        writeln!(out, "{};", event_types.join("\n")).unwrap();
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "/** Decodes an event emitted by `{}`, given its name. */",
+            blueprint_name
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "export function decode{}Event(name: string, value: ProgrammaticScryptoSborValue): {}Event {{",
+            blueprint_name, blueprint_name
+        )
+        .unwrap();
+        writeln!(out, "  switch (name) {{").unwrap();
+        writeln!(out, "{}", event_cases.join("\n")).unwrap();
+        writeln!(out, "    default:").unwrap();
+        writeln!(
+            out,
+            "      throw new Error(`Unknown event of {}: ${{name}}`);",
+            blueprint_name
+        )
+        .unwrap();
+        writeln!(out, "  }}").unwrap();
+        writeln!(out, "}}").unwrap();
+
+        Ok(())
+    }
+
+    fn ts_type(&mut self, type_identifier: &ScopedTypeId) -> Result<TsType, schema::SchemaError> {
+        let type_kind = self.schema_resolver.resolve_type_kind(type_identifier)?;
+        let type_metadata = self
+            .schema_resolver
+            .resolve_type_metadata(type_identifier)?;
+        let type_validation = self
+            .schema_resolver
+            .resolve_type_validation(type_identifier)?;
+        let scoped = |local_type_id: LocalTypeId| ScopedTypeId(type_identifier.0, local_type_id);
+
+        let ts_type = match type_kind {
+            TypeKind::Any => TsType::new(
+                "ProgrammaticScryptoSborValue",
+                "sbor.decodeAny",
+                "sbor.encodeUnsupported(\"Any\")",
+                None,
+            ),
+            TypeKind::Bool => TsType::new(
+                "boolean",
+                "sbor.decodeBool",
+                "sbor.encodeBool",
+                Some("Bool"),
+            ),
+            TypeKind::I8 => integer("I8", "i8", true),
+            TypeKind::I16 => integer("I16", "i16", true),
+            TypeKind::I32 => integer("I32", "i32", true),
+            TypeKind::I64 => integer("I64", "i64", false),
+            TypeKind::I128 => integer("I128", "i128", false),
+            TypeKind::U8 => integer("U8", "u8", true),
+            TypeKind::U16 => integer("U16", "u16", true),
+            TypeKind::U32 => integer("U32", "u32", true),
+            TypeKind::U64 => integer("U64", "u64", false),
+            TypeKind::U128 => integer("U128", "u128", false),
+            TypeKind::String => TsType::new(
+                "string",
+                "sbor.decodeString(\"String\")",
+                "sbor.encodeString",
+                Some("String"),
+            ),
+            TypeKind::Array { element_type } => {
+                let element_kind = self
+                    .schema_resolver
+                    .resolve_type_kind(&scoped(element_type))?;
+                if let TypeKind::U8 = element_kind {
+                    TsType::new(
+                        "string",
+                        "sbor.decodeBytes",
+                        "sbor.encodeBytes",
+                        Some("Array"),
+                    )
+                } else {
+                    let element = self.ts_type(&scoped(element_type))?;
+                    TsType::new(
+                        format!("Array<{}>", element.ty),
+                        format!("sbor.decodeArray({})", element.decoder),
+                        format!(
+                            "sbor.encodeArray({}, {})",
+                            element.manifest_kind_literal(),
+                            element.encoder
+                        ),
+                        Some("Array"),
+                    )
+                }
+            }
+            TypeKind::Tuple { field_types } => match type_metadata.get_name() {
+                Some(name) => self.named_type(name, type_identifier, |generator, name| {
+                    generator.struct_declaration(name, type_identifier, &field_types)
+                })?,
+                None => {
+                    let fields = field_types
+                        .iter()
+                        .map(|field_type| self.ts_type(&scoped(*field_type)))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    TsType::new(
+                        format!(
+                            "[{}]",
+                            fields
+                                .iter()
+                                .map(|field| field.ty.as_str())
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ),
+                        format!(
+                            "sbor.decodeTuple([{}])",
+                            fields
+                                .iter()
+                                .map(|field| field.decoder.as_str())
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ),
+                        format!(
+                            "sbor.encodeTuple([{}])",
+                            fields
+                                .iter()
+                                .map(|field| field.encoder.as_str())
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ),
+                        Some("Tuple"),
+                    )
+                }
+            },
+            TypeKind::Enum { variants } => {
+                // Same as in `translation.rs`, the generic enums of the standard library are
+                // recognized by their name and variants.
+                match (
+                    type_metadata.get_name(),
+                    variants.len(),
+                    variants.get(&0).as_ref().map(|vec| vec.as_slice()),
+                    variants.get(&1).as_ref().map(|vec| vec.as_slice()),
+                ) {
+                    (Some("Option"), 2usize, Some([]), Some([some_type_index])) => {
+                        let some = self.ts_type(&scoped(*some_type_index))?;
+                        TsType::new(
+                            format!("{} | null", some.ty),
+                            format!("sbor.decodeOption({})", some.decoder),
+                            format!("sbor.encodeOption({})", some.encoder),
+                            Some("Enum"),
+                        )
+                    }
+                    (Some("Result"), 2usize, Some([ok_type_index]), Some([err_type_index])) => {
+                        let ok = self.ts_type(&scoped(*ok_type_index))?;
+                        let err = self.ts_type(&scoped(*err_type_index))?;
+                        TsType::new(
+                            format!("{{ ok: {} }} | {{ err: {} }}", ok.ty, err.ty),
+                            format!("sbor.decodeResult({}, {})", ok.decoder, err.decoder),
+                            format!("sbor.encodeResult({}, {})", ok.encoder, err.encoder),
+                            Some("Enum"),
+                        )
+                    }
+                    (Some(name), ..) => {
+                        self.named_type(name, type_identifier, |generator, name| {
+                            generator.enum_declaration(
+                                name,
+                                type_identifier,
+                                &type_metadata,
+                                &variants,
+                            )
+                        })?
+                    }
+                    (None, ..) => return Err(schema::SchemaError::NoNameFound),
+                }
+            }
+            TypeKind::Map {
+                key_type,
+                value_type,
+            } => {
+                let key = self.ts_type(&scoped(key_type))?;
+                let value = self.ts_type(&scoped(value_type))?;
+                TsType::new(
+                    format!("Array<[{}, {}]>", key.ty, value.ty),
+                    format!("sbor.decodeMap({}, {})", key.decoder, value.decoder),
+                    format!(
+                        "sbor.encodeMap({}, {}, {}, {})",
+                        key.manifest_kind_literal(),
+                        value.manifest_kind_literal(),
+                        key.encoder,
+                        value.encoder
+                    ),
+                    Some("Map"),
+                )
+            }
+            TypeKind::Custom(custom_type_kind) => match custom_type_kind {
+                ScryptoCustomTypeKind::Reference => TsType::new(
+                    "string",
+                    "sbor.decodeString(\"Reference\")",
+                    "sbor.encodeCustom(\"Address\")",
+                    Some("Address"),
+                ),
+                ScryptoCustomTypeKind::Own => {
+                    let manifest_kind = match type_validation {
+                        TypeValidation::Custom(ScryptoCustomTypeValidation::Own(
+                            OwnValidation::IsBucket,
+                        )) => Some("Bucket"),
+                        TypeValidation::Custom(ScryptoCustomTypeValidation::Own(
+                            OwnValidation::IsProof,
+                        )) => Some("Proof"),
+                        TypeValidation::Custom(ScryptoCustomTypeValidation::Own(
+                            OwnValidation::IsGlobalAddressReservation,
+                        )) => Some("AddressReservation"),
+                        _ => None,
+                    };
+                    // Buckets, proofs and address reservations are passed in a manifest by the
+                    // names they were bound to.
+                    let encoder = match manifest_kind {
+                        Some(kind) => format!("sbor.encodeCustom({})", json_string(kind)),
+                        None => "sbor.encodeUnsupported(\"Own\")".to_owned(),
+                    };
+                    TsType::new(
+                        "string",
+                        "sbor.decodeString(\"Own\")",
+                        encoder,
+                        manifest_kind,
+                    )
+                }
+                ScryptoCustomTypeKind::Decimal => custom("Decimal"),
+                ScryptoCustomTypeKind::PreciseDecimal => custom("PreciseDecimal"),
+                ScryptoCustomTypeKind::NonFungibleLocalId => custom("NonFungibleLocalId"),
+            },
+        };
+
+        Ok(ts_type)
+    }
+
+    /// Refers to a named type, generating its declaration on its first use.
+    fn named_type<F>(
+        &mut self,
+        name: &str,
+        type_identifier: &ScopedTypeId,
+        declaration: F,
+    ) -> Result<TsType, schema::SchemaError>
+    where
+        F: FnOnce(&mut Self, &str) -> Result<String, schema::SchemaError>,
+    {
+        // Types with the same name (e.g. the same generic type with different type arguments)
+        // are assumed to be the same, as the stubs do.
+        if !self.declarations.contains_key(name) {
+            self.declarations.insert(name.to_owned(), String::new());
+            let declaration = declaration(self, name)?;
+            self.declarations.insert(name.to_owned(), declaration);
+        }
+
+        let type_kind = self.schema_resolver.resolve_type_kind(type_identifier)?;
+        let manifest_kind = match type_kind {
+            TypeKind::Enum { .. } => "Enum",
+            _ => "Tuple",
+        };
+        Ok(TsType::new(
+            name,
+            format!("decode{}", name),
+            format!("encode{}", name),
+            Some(manifest_kind),
+        ))
+    }
+
+    fn struct_declaration(
+        &mut self,
+        name: &str,
+        type_identifier: &ScopedTypeId,
+        field_types: &[LocalTypeId],
+    ) -> Result<String, schema::SchemaError> {
+        let type_metadata = self
+            .schema_resolver
+            .resolve_type_metadata(type_identifier)?;
+        let fields = field_types
+            .iter()
+            .map(|field_type| self.ts_type(&ScopedTypeId(type_identifier.0, *field_type)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut out = String::new();
+        let (ty, decoded, encoded) =
+            fields_expressions(&fields, type_metadata.get_field_names(), "value");
+        match type_metadata.get_field_names() {
+            Some(..) => writeln!(out, "export interface {} {}", name, ty).unwrap(),
+            None => writeln!(out, "export type {} = {};", name, ty).unwrap(),
+        }
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "export function decode{}(value: ProgrammaticScryptoSborValue): {} {{",
+            name, name
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "  const fields = sbor.fields(value, \"Tuple\", {});",
+            fields.len()
+        )
+        .unwrap();
+        writeln!(out, "  return {};", decoded).unwrap();
+        writeln!(out, "}}").unwrap();
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "export function encode{}(value: {}): string {{",
+            name, name
+        )
+        .unwrap();
+        writeln!(out, "  return sbor.encodeFields({});", encoded).unwrap();
+        writeln!(out, "}}").unwrap();
+
+        Ok(out)
+    }
+
+    fn enum_declaration(
+        &mut self,
+        name: &str,
+        type_identifier: &ScopedTypeId,
+        type_metadata: &TypeMetadata,
+        variants: &IndexMap<u8, Vec<LocalTypeId>>,
+    ) -> Result<String, schema::SchemaError> {
+        let mut variant_types = Vec::new();
+        let mut decode_cases = Vec::new();
+        let mut encode_cases = Vec::new();
+        for (variant_id, field_types) in variants {
+            let variant_metadata = type_metadata
+                .get_enum_variant_data(*variant_id)
+                .expect("Unexpected state: variant id can not be found!");
+            let variant_name = variant_metadata
+                .get_name()
+                .expect("Unexpected state: an enum variant with no name!");
+            let fields = field_types
+                .iter()
+                .map(|field_type| self.ts_type(&ScopedTypeId(type_identifier.0, *field_type)))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            if fields.is_empty() {
+                variant_types.push(format!("  | {{ variant: {} }}", json_string(variant_name)));
+                decode_cases.push(format!(
+                    "    case {}:\n      return {{ variant: {} }};",
+                    variant_id,
+                    json_string(variant_name)
+                ));
+                encode_cases.push(format!(
+                    "    case {}:\n      return sbor.encodeVariant({}, []);",
+                    json_string(variant_name),
+                    variant_id
+                ));
+            } else {
+                let (ty, decoded, encoded) =
+                    fields_expressions(&fields, variant_metadata.get_field_names(), "value.fields");
+                variant_types.push(format!(
+                    "  | {{ variant: {}; fields: {} }}",
+                    json_string(variant_name),
+                    ty
+                ));
+                decode_cases.push(format!(
+                    "    case {}:\n      return {{ variant: {}, fields: {} }};",
+                    variant_id,
+                    json_string(variant_name),
+                    decoded
+                ));
+                encode_cases.push(format!(
+                    "    case {}:\n      return sbor.encodeVariant({}, {});",
+                    json_string(variant_name),
+                    variant_id,
+                    encoded
+                ));
+            }
+        }
+
+        let mut out = String::new();
+        writeln!(out, "export type {} =", name).unwrap();
+        writeln!(out, "{};", variant_types.join("\n")).unwrap();
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "export function decode{}(value: ProgrammaticScryptoSborValue): {} {{",
+            name, name
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "  const {{ variantId, fields }} = sbor.variant(value);"
+        )
+        .unwrap();
+        writeln!(out, "  switch (variantId) {{").unwrap();
+        writeln!(out, "{}", decode_cases.join("\n")).unwrap();
+        writeln!(out, "    default:").unwrap();
+        writeln!(
+            out,
+            "      throw new Error(`Unknown variant of {}: ${{variantId}}`);",
+            name
+        )
+        .unwrap();
+        writeln!(out, "  }}").unwrap();
+        writeln!(out, "}}").unwrap();
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "export function encode{}(value: {}): string {{",
+            name, name
+        )
+        .unwrap();
+        writeln!(out, "  switch (value.variant) {{").unwrap();
+        writeln!(out, "{}", encode_cases.join("\n")).unwrap();
+        writeln!(out, "  }}").unwrap();
+        writeln!(out, "}}").unwrap();
+
+        Ok(out)
+    }
+}
+
+/// Returns the type, the decoding expression (from an array of values named `fields`) and the
+/// encoding expression (into an array of manifest values, from `value_name`) of a struct or an
+/// enum variant.
+fn fields_expressions(
+    fields: &[TsType],
+    field_names: Option<&[Cow<'static, str>]>,
+    value_name: &str,
+) -> (String, String, String) {
+    match field_names {
+        Some(field_names) => {
+            let ty = field_names
+                .iter()
+                .zip(fields)
+                .map(|(field_name, field)| format!("{}: {}", field_name, field.ty))
+                .collect::<Vec<_>>();
+            let decoded = field_names
+                .iter()
+                .zip(fields)
+                .enumerate()
+                .map(|(i, (field_name, field))| {
+                    format!("{}: {}(fields[{}])", field_name, field.decoder, i)
+                })
+                .collect::<Vec<_>>();
+            let encoded = field_names
+                .iter()
+                .zip(fields)
+                .map(|(field_name, field)| {
+                    format!("{}({}.{})", field.encoder, value_name, field_name)
+                })
+                .collect::<Vec<_>>();
+            (
+                format!("{{ {} }}", ty.join("; ")),
+                format!("{{ {} }}", decoded.join(", ")),
+                format!("[{}]", encoded.join(", ")),
+            )
+        }
+        None => {
+            let ty = fields
+                .iter()
+                .map(|field| field.ty.as_str())
+                .collect::<Vec<_>>();
+            let decoded = fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| format!("{}(fields[{}])", field.decoder, i))
+                .collect::<Vec<_>>();
+            let encoded = fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| format!("{}({}[{}])", field.encoder, value_name, i))
+                .collect::<Vec<_>>();
+            (
+                format!("[{}]", ty.join(", ")),
+                format!("[{}]", decoded.join(", ")),
+                format!("[{}]", encoded.join(", ")),
+            )
+        }
+    }
+}
+
+/// Integers wider than 32 bits are represented as strings, as in the programmatic JSON.
+fn integer(kind: &'static str, suffix: &str, is_number: bool) -> TsType {
+    let (ty, decoder) = if is_number {
+        ("number", "sbor.decodeNumber")
+    } else {
+        ("string", "sbor.decodeString")
+    };
+    TsType::new(
+        ty,
+        format!("{}({})", decoder, json_string(kind)),
+        format!("sbor.encodeInteger({})", json_string(suffix)),
+        Some(kind),
+    )
+}
+
+fn custom(kind: &'static str) -> TsType {
+    TsType::new(
+        "string",
+        format!("sbor.decodeString({})", json_string(kind)),
+        format!("sbor.encodeCustom({})", json_string(kind)),
+        Some(kind),
+    )
+}
+
+fn parameter_name(argument_name: &str) -> String {
+    if RESERVED_WORDS.contains(&argument_name) {
+        format!("{}_", argument_name)
+    } else {
+        argument_name.to_owned()
+    }
+}
+
+fn to_upper_camel_case(snake_case: &str) -> String {
+    snake_case
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+fn json_string(value: &str) -> String {
+    serde_json::Value::String(value.to_owned()).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use radix_engine_interface::blueprints::resource::{Bucket, Proof};
+
+    #[derive(ScryptoSbor)]
+    struct Order {
+        price: Decimal,
+        amount: u64,
+        note: Option<String>,
+        tags: IndexMap<String, u32>,
+    }
+
+    #[derive(ScryptoSbor)]
+    enum OrderStatus {
+        Open,
+        Filled { at_price: Decimal },
+        Cancelled(String),
+    }
+
+    #[derive(ScryptoSbor)]
+    struct OrderPlacedEvent {
+        order: Order,
+        id: u128,
+    }
+
+    struct TestSchemaResolver {
+        schema_hash: SchemaHash,
+        schema: Rc<VersionedScryptoSchema>,
+    }
+
+    impl schema::PackageSchemaResolver for TestSchemaResolver {
+        fn lookup_schema(&self, schema_hash: &SchemaHash) -> Option<Rc<VersionedScryptoSchema>> {
+            (*schema_hash == self.schema_hash).then(|| self.schema.clone())
+        }
+
+        fn package_address(&self) -> PackageAddress {
+            PACKAGE_PACKAGE
+        }
+    }
+
+    #[test]
+    fn typescript_module_of_a_small_package() {
+        // Arrange
+        let mut aggregator = TypeAggregator::<ScryptoCustomTypeKind>::new();
+        let fee = aggregator.add_child_type_and_descendents::<Decimal>();
+        let owner_badge = aggregator.add_child_type_and_descendents::<Proof>();
+        let component = aggregator.add_child_type_and_descendents::<ComponentAddress>();
+        let order = aggregator.add_child_type_and_descendents::<Order>();
+        let payment = aggregator.add_child_type_and_descendents::<Bucket>();
+        let status = aggregator.add_child_type_and_descendents::<Result<OrderStatus, String>>();
+        let event = aggregator.add_child_type_and_descendents::<OrderPlacedEvent>();
+        let schema = generate_full_schema::<ScryptoCustomSchema>(aggregator);
+        let schema_hash = schema.generate_schema_hash();
+        let scoped = |local_type_id| ScopedTypeId(schema_hash, local_type_id);
+        let schema_interface = schema::PackageInterface {
+            blueprints: indexmap!(
+                "Market".to_owned() => schema::BlueprintInterface {
+                    functions: vec![
+                        schema::Function {
+                            ident: "instantiate".to_owned(),
+                            receiver: None,
+                            arguments: indexmap!(
+                                "fee".to_owned() => scoped(fee),
+                                "owner_badge".to_owned() => scoped(owner_badge),
+                            ),
+                            returns: scoped(component),
+                        },
+                        schema::Function {
+                            ident: "place_order".to_owned(),
+                            receiver: Some(ReceiverInfo::normal_ref_mut()),
+                            arguments: indexmap!(
+                                "order".to_owned() => scoped(order),
+                                "new".to_owned() => scoped(payment),
+                            ),
+                            returns: scoped(status),
+                        },
+                    ],
+                    events: indexmap!("OrderPlacedEvent".to_owned() => scoped(event)),
+                },
+            ),
+            auxiliary_types: HashSet::default(),
+        };
+        let schema_resolver = TestSchemaResolver {
+            schema_hash,
+            schema: Rc::new(schema),
+        };
+
+        // Act
+        let module = package_schema_interface_to_typescript(
+            schema_interface,
+            "package_sim1",
+            &schema_resolver,
+        )
+        .unwrap();
+
+        // Assert
+        let expected = r#"
+export const PACKAGE_ADDRESS = "package_sim1";
+
+export interface Order { price: string; amount: string; note: string | null; tags: Array<[string, number]> }
+
+export function decodeOrder(value: ProgrammaticScryptoSborValue): Order {
+  const fields = sbor.fields(value, "Tuple", 4);
+  return { price: sbor.decodeString("Decimal")(fields[0]), amount: sbor.decodeString("U64")(fields[1]), note: sbor.decodeOption(sbor.decodeString("String"))(fields[2]), tags: sbor.decodeMap(sbor.decodeString("String"), sbor.decodeNumber("U32"))(fields[3]) };
+}
+
+export function encodeOrder(value: Order): string {
+  return sbor.encodeFields([sbor.encodeCustom("Decimal")(value.price), sbor.encodeInteger("u64")(value.amount), sbor.encodeOption(sbor.encodeString)(value.note), sbor.encodeMap("String", "U32", sbor.encodeString, sbor.encodeInteger("u32"))(value.tags)]);
+}
+
+export type OrderStatus =
+  | { variant: "Open" }
+  | { variant: "Filled"; fields: { at_price: string } }
+  | { variant: "Cancelled"; fields: [string] };
+
+export function decodeOrderStatus(value: ProgrammaticScryptoSborValue): OrderStatus {
+  const { variantId, fields } = sbor.variant(value);
+  switch (variantId) {
+    case 0:
+      return { variant: "Open" };
+    case 1:
+      return { variant: "Filled", fields: { at_price: sbor.decodeString("Decimal")(fields[0]) } };
+    case 2:
+      return { variant: "Cancelled", fields: [sbor.decodeString("String")(fields[0])] };
+    default:
+      throw new Error(`Unknown variant of OrderStatus: ${variantId}`);
+  }
+}
+
+export function encodeOrderStatus(value: OrderStatus): string {
+  switch (value.variant) {
+    case "Open":
+      return sbor.encodeVariant(0, []);
+    case "Filled":
+      return sbor.encodeVariant(1, [sbor.encodeCustom("Decimal")(value.fields.at_price)]);
+    case "Cancelled":
+      return sbor.encodeVariant(2, [sbor.encodeString(value.fields[0])]);
+  }
+}
+
+export interface OrderPlacedEvent { order: Order; id: string }
+
+export function decodeOrderPlacedEvent(value: ProgrammaticScryptoSborValue): OrderPlacedEvent {
+  const fields = sbor.fields(value, "Tuple", 2);
+  return { order: decodeOrder(fields[0]), id: sbor.decodeString("U128")(fields[1]) };
+}
+
+export function encodeOrderPlacedEvent(value: OrderPlacedEvent): string {
+  return sbor.encodeFields([encodeOrder(value.order), sbor.encodeInteger("u128")(value.id)]);
+}
+
+/** Builds the `CALL_FUNCTION` instruction of `Market::instantiate`. */
+export function buildMarketInstantiateCall(fee: string, owner_badge: string): string {
+  return sbor.callFunction(PACKAGE_ADDRESS, "Market", "instantiate", [sbor.encodeCustom("Decimal")(fee), sbor.encodeCustom("Proof")(owner_badge)]);
+}
+
+/** Decodes the output of `Market::instantiate`. */
+export function decodeMarketInstantiateOutput(value: ProgrammaticScryptoSborValue): string {
+  return sbor.decodeString("Reference")(value);
+}
+
+/** Builds the `CALL_METHOD` instruction of `Market::place_order`. */
+export function buildMarketPlaceOrderCall(componentAddress: string, order: Order, new_: string): string {
+  return sbor.callMethod(componentAddress, "place_order", [encodeOrder(order), sbor.encodeCustom("Bucket")(new_)]);
+}
+
+/** Decodes the output of `Market::place_order`. */
+export function decodeMarketPlaceOrderOutput(value: ProgrammaticScryptoSborValue): { ok: OrderStatus } | { err: string } {
+  return sbor.decodeResult(decodeOrderStatus, sbor.decodeString("String"))(value);
+}
+
+export type MarketEvent =
+  | { name: "OrderPlacedEvent"; data: OrderPlacedEvent };
+
+/** Decodes an event emitted by `Market`, given its name. */
+export function decodeMarketEvent(name: string, value: ProgrammaticScryptoSborValue): MarketEvent {
+  switch (name) {
+    case "OrderPlacedEvent":
+      return { name, data: decodeOrderPlacedEvent(value) };
+    default:
+      throw new Error(`Unknown event of Market: ${name}`);
+  }
+}
+"#;
+        assert_eq!(module.strip_prefix(RUNTIME).unwrap(), expected);
+    }
+}
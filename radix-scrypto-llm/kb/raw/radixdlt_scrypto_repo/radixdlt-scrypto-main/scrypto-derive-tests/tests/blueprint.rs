@@ -98,3 +98,25 @@ mod kv_entry_clone {
         }
     }
 }
+
+#[blueprint]
+#[collections(entries: Index<u32, String>, sorted_entries: SortedIndex<Hash, Decimal>)]
+mod collections {
+    use super::*;
+
+    struct Collections {}
+
+    impl Collections {
+        pub fn insert(&self, key: u32, value: String) {
+            self.entries().insert(key, value);
+        }
+
+        pub fn lowest(&self, limit: u32) -> Vec<Hash> {
+            self.sorted_entries()
+                .scan(limit)
+                .into_iter()
+                .map(|(_, key, _)| key)
+                .collect()
+        }
+    }
+}
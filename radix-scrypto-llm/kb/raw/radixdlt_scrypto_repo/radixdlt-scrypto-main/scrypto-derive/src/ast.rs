@@ -3,7 +3,7 @@ use syn::punctuated::Punctuated;
 use syn::token::{As, Brace, Paren};
 use syn::{
     braced, parenthesized, Attribute, Ident, ItemConst, ItemImpl, ItemMacro, ItemStruct, ItemUse,
-    Path, Result, Token, Visibility,
+    Path, Result, Token, Type, Visibility,
 };
 
 /// Represents a blueprint which is a module with an optional set of attributes
@@ -142,3 +142,37 @@ impl Parse for AliasableType {
         })
     }
 }
+
+#[allow(dead_code)] // Fields for tokens from parse for completeness
+pub struct CollectionsInner {
+    pub paren_token: Paren,
+    pub collections: Punctuated<Collection, Token![,]>,
+}
+
+impl Parse for CollectionsInner {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+        Ok(Self {
+            paren_token: parenthesized!(content in input),
+            collections: content.parse_terminated(Collection::parse)?,
+        })
+    }
+}
+
+/// A `name: Type` pair of the `collections` attribute
+#[allow(dead_code)] // Fields for tokens from parse for completeness
+pub struct Collection {
+    pub name: Ident,
+    pub colon_token: Token![:],
+    pub collection_type: Type,
+}
+
+impl Parse for Collection {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(Self {
+            name: input.parse()?,
+            colon_token: input.parse()?,
+            collection_type: input.parse()?,
+        })
+    }
+}
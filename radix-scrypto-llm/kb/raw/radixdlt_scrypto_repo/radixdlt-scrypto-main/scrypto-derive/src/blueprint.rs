@@ -689,6 +689,40 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
         }
     }
 
+    let mut collection_names = Vec::<Ident>::new();
+    let mut collection_types = Vec::<Type>::new();
+    let mut collection_schemas = Vec::<TokenStream>::new();
+    for attribute in &blueprint.attributes {
+        if attribute.path.is_ident("collections") {
+            let collections_inner = parse2::<ast::CollectionsInner>(attribute.tokens.clone())?;
+            for collection in collections_inner.collections {
+                if collection_names.contains(&collection.name) {
+                    return Err(Error::new(
+                        collection.name.span(),
+                        "A collection with an identical name has already been declared",
+                    ));
+                }
+                let (variant, key_type, value_type) =
+                    parse_collection_type(&collection.collection_type)?;
+                collection_schemas.push(quote! {
+                    BlueprintCollectionSchema::#variant(BlueprintKeyValueSchema {
+                        key: TypeRef::Static(aggregator.add_child_type_and_descendents::<#key_type>()),
+                        value: TypeRef::Static(aggregator.add_child_type_and_descendents::<#value_type>()),
+                        allow_ownership: false,
+                    })
+                });
+                collection_names.push(collection.name);
+                collection_types.push(collection.collection_type);
+            }
+        }
+    }
+    if collection_names.len() > u8::MAX as usize + 1 {
+        return Err(Error::new(
+            Span::call_site(),
+            "A blueprint can't declare more than 256 collections",
+        ));
+    }
+
     #[cfg(feature = "no-schema")]
     let output_schema = quote! {};
     #[cfg(not(feature = "no-schema"))]
@@ -738,6 +772,11 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
         };
 
         let schema_ident = format_ident!("{}_schema", bp_ident);
+        let collections = if collection_schemas.is_empty() {
+            quote! { Vec::new() }
+        } else {
+            quote! { vec![#(#collection_schemas),*] }
+        };
         let fn_names = generated_schema_info.fn_names;
         let fn_schemas = generated_schema_info.fn_schemas;
 
@@ -756,7 +795,8 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
                             ));
                         }
                     }
-                } else if attribute.path.is_ident("types") {
+                } else if attribute.path.is_ident("types") || attribute.path.is_ident("collections")
+                {
                 }
                 // None of the attributes to apply at the top-level of blueprint macros matched. So,
                 // we provide an error to the user that they're using an incorrect attribute macro
@@ -812,9 +852,12 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
                     let type_index = aggregator.add_child_type_and_descendents::<#bp_ident>();
                     fields.push(FieldSchema::static_field(type_index));
 
+                    // Aggregate collections
+                    let collections = #collections;
+
                     let state = BlueprintStateSchemaInit {
                         fields,
-                        collections: Vec::new(),
+                        collections,
                     };
 
                     // Aggregate functions
@@ -895,6 +938,21 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
         }
     };
 
+    let output_collection_accessors = if collection_names.is_empty() {
+        quote! {}
+    } else {
+        let collection_indices = (0..collection_names.len()).map(|index| index as u8);
+        quote! {
+            impl #bp_ident {
+                #(
+                    pub fn #collection_names(&self) -> #collection_types {
+                        <#collection_types>::new(#collection_indices)
+                    }
+                )*
+            }
+        }
+    };
+
     let output_original_code = quote! {
         #[derive(::scrypto::prelude::ScryptoSbor)]
         pub struct #bp_ident #bp_fields #bp_semi_token
@@ -903,6 +961,8 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
             #(#bp_items)*
         }
 
+        #output_collection_accessors
+
         impl ::scrypto::component::ComponentState for #bp_ident {
             const BLUEPRINT_NAME: &'static str = #bp_name;
         }
@@ -1392,6 +1452,41 @@ fn create_argument_ident(argument: &Pat, index: usize) -> Result<Ident> {
     })
 }
 
+/// Splits an `Index<K, V>` or `SortedIndex<K, V>` collection type into the variant of its
+/// `BlueprintCollectionSchema` and its key and value types.
+fn parse_collection_type(collection_type: &Type) -> Result<(Ident, Type, Type)> {
+    let error = || {
+        Error::new(
+            collection_type.span(),
+            "Collections must be of type `Index<K, V>` or `SortedIndex<K, V>`",
+        )
+    };
+    let segment = match collection_type {
+        Type::Path(type_path) if type_path.qself.is_none() => {
+            type_path.path.segments.last().ok_or_else(error)?
+        }
+        _ => return Err(error()),
+    };
+    if segment.ident != "Index" && segment.ident != "SortedIndex" {
+        return Err(error());
+    }
+    let type_args = match &segment.arguments {
+        PathArguments::AngleBracketed(args) => args
+            .args
+            .iter()
+            .map(|arg| match arg {
+                GenericArgument::Type(ty) => Ok(ty.clone()),
+                _ => Err(error()),
+            })
+            .collect::<Result<Vec<_>>>()?,
+        _ => return Err(error()),
+    };
+    match <[Type; 2]>::try_from(type_args) {
+        Ok([key_type, value_type]) => Ok((segment.ident.clone(), key_type, value_type)),
+        Err(_) => Err(error()),
+    }
+}
+
 fn validate_type_ident(ident: &Ident) -> Result<()> {
     validate_type_name(&ident.to_string(), ident.span())
 }
@@ -1860,6 +1955,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_collection_type() {
+        let (variant, key_type, value_type) =
+            parse_collection_type(&parse_quote! { Index<u64, Order> }).unwrap();
+        assert_eq!(variant, "Index");
+        assert_eq!(key_type, parse_quote! { u64 });
+        assert_eq!(value_type, parse_quote! { Order });
+
+        let (variant, key_type, value_type) =
+            parse_collection_type(&parse_quote! { scrypto::prelude::SortedIndex<String, Vec<u8>> })
+                .unwrap();
+        assert_eq!(variant, "SortedIndex");
+        assert_eq!(key_type, parse_quote! { String });
+        assert_eq!(value_type, parse_quote! { Vec<u8> });
+
+        assert_matches!(
+            parse_collection_type(&parse_quote! { KeyValueStore<u64, Order> }),
+            Err(_)
+        );
+        assert_matches!(parse_collection_type(&parse_quote! { Index<u64> }), Err(_));
+        assert_matches!(parse_collection_type(&parse_quote! { Index }), Err(_));
+    }
+
+    #[test]
+    fn test_invalid_collection_should_fail() {
+        let input = TokenStream::from_str(
+            "#[collections(orders: Index<u64, u32>, orders: SortedIndex<u64, u32>)] mod test { struct Test {} impl Test {} }",
+        )
+        .unwrap();
+        assert_matches!(handle_blueprint(input), Err(_));
+    }
+
     #[test]
     fn test_blueprint() {
         let input = TokenStream::from_str(
@@ -2017,9 +2144,11 @@ mod tests {
                             let type_index = aggregator.add_child_type_and_descendents::<Test>();
                             fields.push(FieldSchema::static_field(type_index));
 
+                            let collections = Vec::new();
+
                             let state = BlueprintStateSchemaInit {
                                 fields,
-                                collections: Vec::new(),
+                                collections,
                             };
 
                             let functions = {
@@ -183,6 +183,13 @@ implement_system_api! {
             collection_index: CollectionIndex,
             count: u32,
         ) -> Result<Vec<(SortedKey, Vec<u8>)>, RuntimeError>,
+        actor_sorted_index_scan_from: (
+            &mut self,
+            object_handle: ActorStateHandle,
+            collection_index: CollectionIndex,
+            from_sort_prefix: [u8; 2],
+            count: u32,
+        ) -> Result<Vec<(SortedKey, Vec<u8>)>, RuntimeError>,
     },
     SystemBlueprintApi: {
         call_function: (
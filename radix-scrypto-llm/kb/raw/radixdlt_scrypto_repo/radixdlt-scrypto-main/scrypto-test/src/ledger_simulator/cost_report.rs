@@ -0,0 +1,48 @@
+use crate::prelude::*;
+use radix_engine::transaction::*;
+use radix_engine::utils::format_cost_breakdown;
+use std::path::{Path, PathBuf};
+
+/// The folder which [`LedgerSimulator::execute_manifest_with_cost_report`] writes the cost reports
+/// to: `cost-reports` within `CARGO_TARGET_DIR` (or `target` if not set).
+pub fn default_cost_report_folder() -> PathBuf {
+    std::env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("target"))
+        .join("cost-reports")
+}
+
+/// Writes the cost breakdown of the given receipt to `<name>.txt` in the given folder.
+///
+/// With the `flamegraph` feature, the flamegraph of the execution cost units consumed by each call
+/// is also written to `<name>.svg` - if the transaction was executed with the debug information.
+///
+/// Nothing is written for a rejected transaction, which has no cost breakdown.
+pub fn write_cost_report(receipt: &TransactionReceipt, folder: impl AsRef<Path>, name: &str) {
+    let folder = folder.as_ref();
+    std::fs::create_dir_all(folder)
+        .unwrap_or_else(|err| panic!("Failed to create folder {}: {:?}", folder.display(), err));
+
+    // Rejected transactions have no fee details
+    if let Some(fee_details) = &receipt.fee_details {
+        let path = folder.join(format!("{}.txt", name));
+        std::fs::write(
+            &path,
+            format_cost_breakdown(&receipt.fee_summary, fee_details),
+        )
+        .unwrap_or_else(|err| panic!("Failed to write {}: {:?}", path.display(), err));
+    }
+
+    #[cfg(feature = "flamegraph")]
+    if receipt.debug_information.is_some() {
+        let svg_bytes = receipt
+            .generate_execution_breakdown_flamegraph_svg_bytes(
+                name,
+                &NetworkDefinition::simulator(),
+            )
+            .expect("Failed to generate the flamegraph");
+        let path = folder.join(format!("{}.svg", name));
+        std::fs::write(&path, svg_bytes)
+            .unwrap_or_else(|err| panic!("Failed to write {}: {:?}", path.display(), err));
+    }
+}
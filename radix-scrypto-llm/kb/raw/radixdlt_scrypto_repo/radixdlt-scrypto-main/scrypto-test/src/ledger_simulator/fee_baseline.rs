@@ -0,0 +1,284 @@
+use crate::prelude::*;
+use radix_engine::transaction::*;
+use std::path::{Path, PathBuf};
+
+/// Setting this environment variable to `true` or `1` overwrites the fee baselines with the costs
+/// of the current run, instead of checking them.
+pub const UPDATE_FEE_BASELINES_ENV_VAR: &str = "SCRYPTO_UPDATE_FEE_BASELINES";
+
+/// The percentage by which the execution or finalization cost units may go up before the check
+/// of a fee baseline fails. Defaults to zero.
+pub const FEE_BASELINE_TOLERANCE_ENV_VAR: &str = "SCRYPTO_FEE_BASELINE_TOLERANCE";
+
+const EXECUTION_COST_UNITS: &str = "Execution Cost Units";
+const FINALIZATION_COST_UNITS: &str = "Finalization Cost Units";
+const EXECUTION: &str = "Execution";
+const FINALIZATION: &str = "Finalization";
+
+/// The cost units consumed by a transaction, per [`ExecutionCostingEntry`] and
+/// [`FinalizationCostingEntry`], which later runs are checked against.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct FeeBaseline {
+    pub execution_cost_units: u32,
+    pub finalization_cost_units: u32,
+    pub execution_cost_breakdown: BTreeMap<String, u32>,
+    pub finalization_cost_breakdown: BTreeMap<String, u32>,
+}
+
+impl FeeBaseline {
+    /// Panics if the transaction was executed without the cost breakdown.
+    pub fn from_receipt(receipt: &TransactionReceipt) -> Self {
+        let fee_details = receipt
+            .fee_details
+            .as_ref()
+            .expect("The transaction must be executed with the cost breakdown enabled");
+        Self {
+            execution_cost_units: receipt.fee_summary.total_execution_cost_units_consumed,
+            finalization_cost_units: receipt.fee_summary.total_finalization_cost_units_consumed,
+            execution_cost_breakdown: fee_details.execution_cost_breakdown.clone(),
+            finalization_cost_breakdown: fee_details.finalization_cost_breakdown.clone(),
+        }
+    }
+
+    /// Encodes the baseline as lines of comma-separated values, which keeps the diffs of the
+    /// checked-in files readable.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::new();
+        csv.push_str(&format!(
+            "{},{}\n",
+            EXECUTION_COST_UNITS, self.execution_cost_units
+        ));
+        csv.push_str(&format!(
+            "{},{}\n",
+            FINALIZATION_COST_UNITS, self.finalization_cost_units
+        ));
+        for (entry, cost_units) in &self.execution_cost_breakdown {
+            csv.push_str(&format!("{},{},{}\n", EXECUTION, entry, cost_units));
+        }
+        for (entry, cost_units) in &self.finalization_cost_breakdown {
+            csv.push_str(&format!("{},{},{}\n", FINALIZATION, entry, cost_units));
+        }
+        csv
+    }
+
+    pub fn from_csv(csv: &str) -> Result<Self, String> {
+        let mut baseline = Self::default();
+        for line in csv.lines().filter(|line| !line.trim().is_empty()) {
+            let invalid_line = || format!("Invalid fee baseline line: {}", line);
+            let (name, cost_units) = line.rsplit_once(',').ok_or_else(invalid_line)?;
+            let cost_units = cost_units.trim().parse().map_err(|_| invalid_line())?;
+            match name.split_once(',') {
+                None if name == EXECUTION_COST_UNITS => baseline.execution_cost_units = cost_units,
+                None if name == FINALIZATION_COST_UNITS => {
+                    baseline.finalization_cost_units = cost_units
+                }
+                Some((EXECUTION, entry)) => {
+                    baseline
+                        .execution_cost_breakdown
+                        .insert(entry.to_string(), cost_units);
+                }
+                Some((FINALIZATION, entry)) => {
+                    baseline
+                        .finalization_cost_breakdown
+                        .insert(entry.to_string(), cost_units);
+                }
+                _ => return Err(invalid_line()),
+            }
+        }
+        Ok(baseline)
+    }
+
+    /// Checks that neither the execution nor the finalization cost units went up by more than
+    /// the given percentage since the `baseline`.
+    ///
+    /// On failure, the returned message has the diff of every changed cost entry.
+    pub fn check_against(&self, baseline: &Self, tolerance_percentage: f64) -> Result<(), String> {
+        let exceeds = |expected: u32, actual: u32| {
+            actual as f64 > expected as f64 * (1.0 + tolerance_percentage / 100.0)
+        };
+        if !exceeds(baseline.execution_cost_units, self.execution_cost_units)
+            && !exceeds(
+                baseline.finalization_cost_units,
+                self.finalization_cost_units,
+            )
+        {
+            return Ok(());
+        }
+
+        let mut message = format!(
+            "Cost units went up by more than {}% since the fee baseline\n",
+            tolerance_percentage
+        );
+        message.push_str(&format_diff_line(
+            EXECUTION_COST_UNITS,
+            baseline.execution_cost_units,
+            self.execution_cost_units,
+        ));
+        message.push_str(&format_diff_line(
+            FINALIZATION_COST_UNITS,
+            baseline.finalization_cost_units,
+            self.finalization_cost_units,
+        ));
+        for (prefix, expected, actual) in [
+            (
+                EXECUTION,
+                &baseline.execution_cost_breakdown,
+                &self.execution_cost_breakdown,
+            ),
+            (
+                FINALIZATION,
+                &baseline.finalization_cost_breakdown,
+                &self.finalization_cost_breakdown,
+            ),
+        ] {
+            let entries: BTreeSet<&String> = expected.keys().chain(actual.keys()).collect();
+            for entry in entries {
+                let expected = expected.get(entry).copied().unwrap_or_default();
+                let actual = actual.get(entry).copied().unwrap_or_default();
+                if expected != actual {
+                    message.push_str(&format_diff_line(
+                        &format!("- {}::{}", prefix, entry),
+                        expected,
+                        actual,
+                    ));
+                }
+            }
+        }
+        Err(message)
+    }
+}
+
+fn format_diff_line(name: &str, expected: u32, actual: u32) -> String {
+    let change = actual as i64 - expected as i64;
+    let percentage = if expected == 0 {
+        100.0
+    } else {
+        change as f64 * 100.0 / expected as f64
+    };
+    format!(
+        "{:<75},{:>12} ->{:>12}, {:>+12}, {:>+8.1}%\n",
+        name, expected, actual, change, percentage
+    )
+}
+
+/// The folder of the checked-in fee baselines: `fee-baselines` within the tested package.
+pub fn default_fee_baseline_folder() -> PathBuf {
+    std::env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_default()
+        .join("fee-baselines")
+}
+
+/// Checks the costs of the given receipt against the fee baseline `<name>.csv` in the given
+/// folder - see [`FeeBaseline::check_against`] and [`FEE_BASELINE_TOLERANCE_ENV_VAR`].
+///
+/// The baseline is recorded instead if the [`UPDATE_FEE_BASELINES_ENV_VAR`] is set.
+///
+/// Panics if the check fails, if the baseline doesn't exist, or if the transaction was executed
+/// without the cost breakdown.
+pub fn assert_fee_baseline(receipt: &TransactionReceipt, folder: impl AsRef<Path>, name: &str) {
+    let actual = FeeBaseline::from_receipt(receipt);
+    let path = folder.as_ref().join(format!("{}.csv", name));
+
+    if is_env_var_active(UPDATE_FEE_BASELINES_ENV_VAR) {
+        write_ensuring_folder_exists(&path, actual.to_csv())
+            .unwrap_or_else(|err| panic!("Failed to write {}: {:?}", path.display(), err));
+        return;
+    }
+    if !path.exists() {
+        panic!(
+            "{}: the fee baseline doesn't exist.\nRun with {}=1 (or `scrypto test --update-fee-baselines`) to record it, and check it in.",
+            path.display(),
+            UPDATE_FEE_BASELINES_ENV_VAR
+        );
+    }
+
+    let content = std::fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("Failed to read {}: {:?}", path.display(), err));
+    let baseline =
+        FeeBaseline::from_csv(&content).unwrap_or_else(|err| panic!("{}: {}", path.display(), err));
+    let tolerance_percentage = match std::env::var(FEE_BASELINE_TOLERANCE_ENV_VAR) {
+        Ok(value) => value.trim().parse().unwrap_or_else(|_| {
+            panic!(
+                "{} must be a percentage, got: {}",
+                FEE_BASELINE_TOLERANCE_ENV_VAR, value
+            )
+        }),
+        Err(_) => 0.0,
+    };
+    if let Err(message) = actual.check_against(&baseline, tolerance_percentage) {
+        panic!(
+            "{}: {}\nRerun with {}=1 to update the baseline if this is expected.",
+            path.display(),
+            message,
+            UPDATE_FEE_BASELINES_ENV_VAR
+        );
+    }
+}
+
+fn is_env_var_active(name: &str) -> bool {
+    std::env::var(name).is_ok_and(|val| {
+        let normalized = val.to_lowercase();
+        &normalized == "true" || &normalized == "1"
+    })
+}
+
+fn write_ensuring_folder_exists(
+    path: impl AsRef<Path>,
+    contents: impl AsRef<[u8]>,
+) -> std::io::Result<()> {
+    if let Some(folder) = path.as_ref().parent() {
+        std::fs::create_dir_all(folder)?;
+    }
+    std::fs::write(path, contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn baseline(execution_cost_units: u32, finalization_cost_units: u32) -> FeeBaseline {
+        FeeBaseline {
+            execution_cost_units,
+            finalization_cost_units,
+            execution_cost_breakdown: btreemap!(
+                "RunWasmCode::Hello_free_token".to_string() => execution_cost_units
+            ),
+            finalization_cost_breakdown: btreemap!(
+                "CommitStateUpdates::GlobalGenericComponent".to_string() => finalization_cost_units
+            ),
+        }
+    }
+
+    #[test]
+    fn fee_baseline_csv_round_trips() {
+        // Arrange
+        let baseline = baseline(1000, 200);
+
+        // Act
+        let decoded = FeeBaseline::from_csv(&baseline.to_csv());
+
+        // Assert
+        assert_eq!(decoded, Ok(baseline));
+    }
+
+    #[test]
+    fn cost_increase_within_tolerance_passes() {
+        // Act
+        let result = baseline(1050, 200).check_against(&baseline(1000, 200), 5.0);
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn cost_increase_above_tolerance_fails_with_diff() {
+        // Act
+        let result = baseline(1000, 220).check_against(&baseline(1000, 200), 5.0);
+
+        // Assert
+        let message = result.unwrap_err();
+        assert!(message.contains("Finalization::CommitStateUpdates::GlobalGenericComponent"));
+        assert!(!message.contains("RunWasmCode"));
+    }
+}
@@ -506,10 +506,11 @@ impl<
         &mut self,
         node_id: &NodeId,
         partition_num: PartitionNumber,
+        from_sort_prefix: Option<[u8; 2]>,
         count: u32,
     ) -> Result<Vec<(SortedKey, IndexedScryptoValue)>, RuntimeError> {
         self.api
-            .kernel_scan_sorted_substates(node_id, partition_num, count)
+            .kernel_scan_sorted_substates(node_id, partition_num, from_sort_prefix, count)
     }
 
     fn kernel_scan_keys<K: SubstateKeyContent>(
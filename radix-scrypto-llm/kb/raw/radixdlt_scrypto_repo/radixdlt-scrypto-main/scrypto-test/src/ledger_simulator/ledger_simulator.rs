@@ -1237,6 +1237,41 @@ impl<E: NativeVmExtension, D: TestDatabase> LedgerSimulator<E, D> {
         self.execute_transaction(executable, execution_config)
     }
 
+    /// Executes the manifest with the cost breakdown and the debug information enabled, and
+    /// writes its cost report named after the given name (e.g. the test's name) to the
+    /// [`default_cost_report_folder`] - see [`write_cost_report`].
+    pub fn execute_manifest_with_cost_report(
+        &mut self,
+        manifest: impl BuildableManifest,
+        initial_proofs: impl IntoIterator<Item = NonFungibleGlobalId>,
+        name: &str,
+    ) -> TransactionReceipt {
+        let config = self
+            .resolve_suggested_config(&manifest)
+            .with_cost_breakdown(true)
+            .with_debug_information(true);
+        let receipt = self.execute_manifest_with_execution_config(manifest, initial_proofs, config);
+        write_cost_report(&receipt, default_cost_report_folder(), name);
+        receipt
+    }
+
+    /// Executes the manifest with the cost breakdown enabled, and checks its costs against the
+    /// checked-in fee baseline named after the given name (e.g. the test's name) in the
+    /// [`default_fee_baseline_folder`] - see [`assert_fee_baseline`].
+    pub fn execute_manifest_with_fee_baseline(
+        &mut self,
+        manifest: impl BuildableManifest,
+        initial_proofs: impl IntoIterator<Item = NonFungibleGlobalId>,
+        name: &str,
+    ) -> TransactionReceipt {
+        let config = self
+            .resolve_suggested_config(&manifest)
+            .with_cost_breakdown(true);
+        let receipt = self.execute_manifest_with_execution_config(manifest, initial_proofs, config);
+        assert_fee_baseline(&receipt, default_fee_baseline_folder(), name);
+        receipt
+    }
+
     pub fn execute_manifest_with_costing_params(
         &mut self,
         manifest: impl BuildableManifest,
@@ -1,7 +1,11 @@
 mod compile;
+mod cost_report;
+mod fee_baseline;
 mod inject_costing_err;
 mod ledger_simulator;
 
 pub use compile::*;
+pub use cost_report::*;
+pub use fee_baseline::*;
 pub use inject_costing_err::*;
 pub use ledger_simulator::*;
@@ -0,0 +1,164 @@
+use crate::engine::scrypto_env::ScryptoVmV1Api;
+use radix_common::data::scrypto::*;
+use radix_engine_interface::api::{CollectionIndex, ACTOR_STATE_SELF};
+use sbor::rust::marker::PhantomData;
+use sbor::rust::ops::{Bound, RangeBounds};
+use sbor::rust::prelude::*;
+
+/// An iterable collection of the component's state, declared in the `collections` attribute of
+/// `#[blueprint]`, e.g. `#[collections(orders: Index<u64, Order>)]`.
+///
+/// Unlike a [`KeyValueStore`](crate::component::KeyValueStore), its entries can be scanned and
+/// drained, in no particular order.
+pub struct Index<K: ScryptoEncode + ScryptoDecode, V: ScryptoEncode + ScryptoDecode> {
+    collection_index: CollectionIndex,
+    key: PhantomData<K>,
+    value: PhantomData<V>,
+}
+
+impl<K: ScryptoEncode + ScryptoDecode, V: ScryptoEncode + ScryptoDecode> Index<K, V> {
+    /// Refers to the collection with the given index in the blueprint's schema.
+    ///
+    /// Called by the accessors which `#[blueprint]` generates for the declared collections.
+    pub fn new(collection_index: CollectionIndex) -> Self {
+        Self {
+            collection_index,
+            key: PhantomData,
+            value: PhantomData,
+        }
+    }
+
+    /// Inserts a new key-value pair, replacing the value of an existing key.
+    pub fn insert(&self, key: K, value: V) {
+        ScryptoVmV1Api::actor_index_insert(
+            ACTOR_STATE_SELF,
+            self.collection_index,
+            scrypto_encode(&key).unwrap(),
+            scrypto_encode(&value).unwrap(),
+        );
+    }
+
+    /// Removes an entry and returns its value if it exists.
+    pub fn remove(&self, key: &K) -> Option<V> {
+        ScryptoVmV1Api::actor_index_remove(
+            ACTOR_STATE_SELF,
+            self.collection_index,
+            scrypto_encode(key).unwrap(),
+        )
+        .map(|value| scrypto_decode(&value).unwrap())
+    }
+
+    /// Returns up to `limit` keys.
+    pub fn scan_keys(&self, limit: u32) -> Vec<K> {
+        ScryptoVmV1Api::actor_index_scan_keys(ACTOR_STATE_SELF, self.collection_index, limit)
+            .into_iter()
+            .map(|key| scrypto_decode(&key).unwrap())
+            .collect()
+    }
+
+    /// Removes up to `limit` entries and returns them.
+    pub fn drain(&self, limit: u32) -> Vec<(K, V)> {
+        ScryptoVmV1Api::actor_index_drain(ACTOR_STATE_SELF, self.collection_index, limit)
+            .into_iter()
+            .map(|(key, value)| {
+                (
+                    scrypto_decode(&key).unwrap(),
+                    scrypto_decode(&value).unwrap(),
+                )
+            })
+            .collect()
+    }
+}
+
+/// An iterable collection of the component's state whose entries are ordered by a `u16` sort
+/// prefix, declared in the `collections` attribute of `#[blueprint]`, e.g.
+/// `#[collections(bids: SortedIndex<u64, Order>)]`.
+///
+/// The key is only unique together with its sort prefix, so the same key can be inserted under
+/// several prefixes. Entries with the same prefix are ordered by their encoded keys.
+pub struct SortedIndex<K: ScryptoEncode + ScryptoDecode, V: ScryptoEncode + ScryptoDecode> {
+    collection_index: CollectionIndex,
+    key: PhantomData<K>,
+    value: PhantomData<V>,
+}
+
+impl<K: ScryptoEncode + ScryptoDecode, V: ScryptoEncode + ScryptoDecode> SortedIndex<K, V> {
+    /// Refers to the collection with the given index in the blueprint's schema.
+    ///
+    /// Called by the accessors which `#[blueprint]` generates for the declared collections.
+    pub fn new(collection_index: CollectionIndex) -> Self {
+        Self {
+            collection_index,
+            key: PhantomData,
+            value: PhantomData,
+        }
+    }
+
+    /// Inserts a new key-value pair under the given sort prefix, replacing the value of an
+    /// existing entry.
+    pub fn insert(&self, sort_prefix: u16, key: K, value: V) {
+        ScryptoVmV1Api::actor_sorted_index_insert(
+            ACTOR_STATE_SELF,
+            self.collection_index,
+            sort_prefix,
+            scrypto_encode(&key).unwrap(),
+            scrypto_encode(&value).unwrap(),
+        );
+    }
+
+    /// Removes an entry and returns its value if it exists.
+    pub fn remove(&self, sort_prefix: u16, key: &K) -> Option<V> {
+        ScryptoVmV1Api::actor_sorted_index_remove(
+            ACTOR_STATE_SELF,
+            self.collection_index,
+            sort_prefix,
+            scrypto_encode(key).unwrap(),
+        )
+        .map(|value| scrypto_decode(&value).unwrap())
+    }
+
+    /// Returns up to `limit` entries with the lowest sort prefixes, in ascending order.
+    pub fn scan(&self, limit: u32) -> Vec<(u16, K, V)> {
+        ScryptoVmV1Api::actor_sorted_index_scan(ACTOR_STATE_SELF, self.collection_index, limit)
+            .into_iter()
+            .map(|((sort_prefix, key), value)| {
+                (
+                    u16::from_be_bytes(sort_prefix),
+                    scrypto_decode(&key).unwrap(),
+                    scrypto_decode(&value).unwrap(),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns up to `limit` entries whose sort prefixes are within the given range, in
+    /// ascending order.
+    pub fn range(&self, sort_prefixes: impl RangeBounds<u16>, limit: u32) -> Vec<(u16, K, V)> {
+        let from_sort_prefix = match sort_prefixes.start_bound() {
+            Bound::Included(start) => *start,
+            Bound::Excluded(start) => match start.checked_add(1) {
+                Some(start) => start,
+                None => return Vec::new(),
+            },
+            Bound::Unbounded => 0,
+        };
+
+        ScryptoVmV1Api::actor_sorted_index_scan_from(
+            ACTOR_STATE_SELF,
+            self.collection_index,
+            from_sort_prefix,
+            limit,
+        )
+        .into_iter()
+        .map(|((sort_prefix, key), value)| (u16::from_be_bytes(sort_prefix), key, value))
+        .take_while(|(sort_prefix, _, _)| sort_prefixes.contains(sort_prefix))
+        .map(|(sort_prefix, key, value)| {
+            (
+                sort_prefix,
+                scrypto_decode(&key).unwrap(),
+                scrypto_decode(&value).unwrap(),
+            )
+        })
+        .collect()
+    }
+}
@@ -173,6 +173,79 @@ pub mod actor {
             event_data_len: usize,
             event_flags: u32,
         );
+
+        /// Insert an entry into an index collection of the current actor
+        pub fn actor_index_insert(
+            actor_state_handle: ActorStateHandle,
+            collection_index: u32,
+            key_ptr: *const u8,
+            key_len: usize,
+            value_ptr: *const u8,
+            value_len: usize,
+        );
+
+        /// Remove an entry from an index collection of the current actor, returning the
+        /// encoded `Option` of the removed value
+        pub fn actor_index_remove(
+            actor_state_handle: ActorStateHandle,
+            collection_index: u32,
+            key_ptr: *const u8,
+            key_len: usize,
+        ) -> Buffer;
+
+        /// Scan up to `limit` keys of an index collection of the current actor
+        pub fn actor_index_scan_keys(
+            actor_state_handle: ActorStateHandle,
+            collection_index: u32,
+            limit: u32,
+        ) -> Buffer;
+
+        /// Remove and return up to `limit` entries of an index collection of the current actor
+        pub fn actor_index_drain(
+            actor_state_handle: ActorStateHandle,
+            collection_index: u32,
+            limit: u32,
+        ) -> Buffer;
+
+        /// Insert an entry under a `u16` sort prefix into a sorted index collection of the
+        /// current actor
+        pub fn actor_sorted_index_insert(
+            actor_state_handle: ActorStateHandle,
+            collection_index: u32,
+            sort_prefix: u32,
+            key_ptr: *const u8,
+            key_len: usize,
+            value_ptr: *const u8,
+            value_len: usize,
+        );
+
+        /// Remove an entry from a sorted index collection of the current actor, returning the
+        /// encoded `Option` of the removed value
+        pub fn actor_sorted_index_remove(
+            actor_state_handle: ActorStateHandle,
+            collection_index: u32,
+            sort_prefix: u32,
+            key_ptr: *const u8,
+            key_len: usize,
+        ) -> Buffer;
+
+        /// Scan up to `limit` entries of a sorted index collection of the current actor, in the
+        /// order of their sort prefixes
+        pub fn actor_sorted_index_scan(
+            actor_state_handle: ActorStateHandle,
+            collection_index: u32,
+            limit: u32,
+        ) -> Buffer;
+
+        /// Scan up to `limit` entries of a sorted index collection of the current actor, in the
+        /// order of their sort prefixes, starting from the first entry with the given sort prefix
+        /// or a higher one
+        pub fn actor_sorted_index_scan_from(
+            actor_state_handle: ActorStateHandle,
+            collection_index: u32,
+            from_sort_prefix: u32,
+            limit: u32,
+        ) -> Buffer;
     }
 }
 